@@ -3,8 +3,9 @@ use uuid::Uuid;
 use crate::{
     core::domain::LazyLocator,
     features::auth::{
-        AccountData, CreateOfflineAccountUseCase, GetAccountsUseCase, LogoutUseCase,
-        SetActiveAccountUseCase,
+        AccountData, BeginMicrosoftLoginUseCase, CompleteMicrosoftLoginUseCase,
+        CreateOfflineAccountUseCase, CreateYggdrasilAccountUseCase, GetAccountsUseCase,
+        LogoutUseCase, MicrosoftDeviceCode, SetActiveAccountUseCase,
     },
 };
 
@@ -19,6 +20,47 @@ pub async fn create_offline_account(username: String) -> crate::Result<AccountDa
     )
 }
 
+#[tracing::instrument]
+pub async fn begin_microsoft_login() -> crate::Result<MicrosoftDeviceCode> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(
+        BeginMicrosoftLoginUseCase::new(lazy_locator.get_microsoft_auth_service().await)
+            .execute()
+            .await?,
+    )
+}
+
+#[tracing::instrument(skip(device_code))]
+pub async fn complete_microsoft_login(
+    device_code: MicrosoftDeviceCode,
+) -> crate::Result<AccountData> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(CompleteMicrosoftLoginUseCase::new(
+        lazy_locator.get_credentials_storage().await,
+        lazy_locator.get_microsoft_auth_service().await,
+    )
+    .execute(device_code)
+    .await?)
+}
+
+#[tracing::instrument(skip(password))]
+pub async fn create_yggdrasil_account(
+    server_url: String,
+    username: String,
+    password: String,
+) -> crate::Result<AccountData> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(CreateYggdrasilAccountUseCase::new(
+        lazy_locator.get_credentials_storage().await,
+        lazy_locator.get_yggdrasil_auth_service().await,
+    )
+    .execute(server_url, username, password)
+    .await?)
+}
+
 pub async fn get_accounts() -> crate::Result<Vec<AccountData>> {
     let lazy_locator = LazyLocator::get().await?;
 
@@ -50,3 +92,36 @@ pub async fn logout(account_id: Uuid) -> crate::Result<()> {
             .await?,
     )
 }
+
+pub async fn is_credentials_locked() -> crate::Result<bool> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(lazy_locator
+        .get_credentials_storage()
+        .await
+        .is_locked()
+        .await?)
+}
+
+#[tracing::instrument(skip(passphrase))]
+pub async fn unlock_credentials(passphrase: String) -> crate::Result<()> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(lazy_locator
+        .get_credentials_storage()
+        .await
+        .unlock(passphrase)
+        .await?)
+}
+
+/// Protects stored credentials with a passphrase, `None` switches back to the key file
+#[tracing::instrument(skip(passphrase))]
+pub async fn set_credentials_passphrase(passphrase: Option<String>) -> crate::Result<()> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(lazy_locator
+        .get_credentials_storage()
+        .await
+        .set_passphrase(passphrase)
+        .await?)
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use dashmap::DashMap;
 
@@ -6,15 +6,18 @@ use crate::{
     core::{domain::LazyLocator, LauncherState},
     features::instance::{
         app::{
-            ChangeContentState, ChangeContentStateUseCase, ContentStateAction,
-            GetProviderMetadataUseCase, ImportContent, ImportContentUseCase, InstallContentUseCase,
-            ListContentUseCase, ListProvidersUseCase, RemoveContent, RemoveContentUseCase,
-            SearchContentUseCase,
+            ChangeContentState, ChangeContentStateUseCase, CheckContentUpdatesUseCase,
+            ContentStateAction, GetProviderMetadataUseCase, ImportContent, ImportContentUseCase,
+            InstallContentUseCase, ListContentUseCase, ListProvidersUseCase, RemoveContent,
+            RemoveContentUseCase, SearchContentUseCase, UpdateContentsUseCase,
         },
         ContentFile, ContentInstallParams, ContentSearchParams, ContentSearchResult, ContentType,
+        ContentUpdate,
     },
 };
 
+use super::get_create_snapshot_use_case;
+
 pub async fn list_content(instance_id: String) -> crate::Result<DashMap<String, ContentFile>> {
     let state = LauncherState::get().await?;
     let lazy_locator = LazyLocator::get().await?;
@@ -35,6 +38,7 @@ pub async fn remove_contents(instance_id: String, content_paths: Vec<String>) ->
         lazy_locator.get_event_emitter().await,
         lazy_locator.get_pack_storage().await,
         state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
     )
     .execute(RemoveContent::multiple(instance_id, content_paths))
     .await?)
@@ -47,6 +51,7 @@ pub async fn enable_contents(instance_id: String, content_paths: Vec<String>) ->
     Ok(ChangeContentStateUseCase::new(
         lazy_locator.get_event_emitter().await,
         state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
     )
     .execute(ChangeContentState::multiple(
         instance_id,
@@ -66,6 +71,7 @@ pub async fn disable_contents(
     Ok(ChangeContentStateUseCase::new(
         lazy_locator.get_event_emitter().await,
         state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
     )
     .execute(ChangeContentState::multiple(
         instance_id,
@@ -87,6 +93,7 @@ pub async fn import_contents(
         lazy_locator.get_event_emitter().await,
         lazy_locator.get_pack_storage().await,
         state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
     )
     .execute(ImportContent::multiple(
         instance_id,
@@ -134,10 +141,74 @@ pub async fn install_content(
 ) -> crate::Result<()> {
     let lazy_locator = LazyLocator::get().await?;
 
-    Ok(InstallContentUseCase::new(
+    InstallContentUseCase::new(
         lazy_locator.get_pack_storage().await,
         lazy_locator.get_content_provider_registry().await,
+        lazy_locator.get_instance_lock_service().await,
     )
     .execute(instance_id, install_params)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn check_content_updates(instance_id: String) -> crate::Result<Vec<ContentUpdate>> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    let list_content_use_case = Arc::new(ListContentUseCase::new(
+        lazy_locator.get_pack_storage().await,
+        state.location_info.clone(),
+    ));
+
+    Ok(CheckContentUpdatesUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        list_content_use_case,
+        lazy_locator.get_pack_storage().await,
+        lazy_locator.get_content_provider_registry().await,
+    )
+    .execute(instance_id)
+    .await?)
+}
+
+pub async fn update_contents(
+    instance_id: String,
+    content_paths: Vec<String>,
+) -> crate::Result<Vec<String>> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    let list_content_use_case = Arc::new(ListContentUseCase::new(
+        lazy_locator.get_pack_storage().await,
+        state.location_info.clone(),
+    ));
+
+    let check_content_updates_use_case = Arc::new(CheckContentUpdatesUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        list_content_use_case,
+        lazy_locator.get_pack_storage().await,
+        lazy_locator.get_content_provider_registry().await,
+    ));
+
+    let install_content_use_case = Arc::new(InstallContentUseCase::new(
+        lazy_locator.get_pack_storage().await,
+        lazy_locator.get_content_provider_registry().await,
+        lazy_locator.get_instance_lock_service().await,
+    ));
+
+    let create_snapshot_use_case =
+        Arc::new(get_create_snapshot_use_case(&state, &lazy_locator).await);
+
+    Ok(UpdateContentsUseCase::new(
+        lazy_locator.get_event_emitter().await,
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_pack_storage().await,
+        check_content_updates_use_case,
+        install_content_use_case,
+        create_snapshot_use_case,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, content_paths)
     .await?)
 }
@@ -1,15 +1,19 @@
 use std::sync::Arc;
 
 use crate::{
-    core::{domain::LazyLocator, LauncherState},
+    core::{
+        domain::{CreateInstanceUseCaseType, LazyLocator},
+        LauncherState,
+    },
     features::{
         instance::{
             app::{
-                CreateInstanceUseCase, EditInstance, EditInstanceUseCase, GetInstanceUseCase,
-                InstallInstanceUseCase, ListInstancesUseCase, NewInstance, RemoveInstanceUseCase,
-                UpdateInstanceUseCase,
+                ChangeSharedFolderUseCase, CreateInstanceUseCase, DuplicateInstanceOptions,
+                DuplicateInstanceUseCase, EditInstance, EditInstanceUseCase, GetInstanceUseCase,
+                InstallInstanceUseCase, ListInstancesUseCase, NewInstance, QueryInstancesUseCase,
+                RemoveInstanceUseCase, UpdateInstanceUseCase,
             },
-            Instance,
+            Instance, InstanceQuery, SharedFolder,
         },
         java::{
             app::{GetJavaUseCase, InstallJavaUseCase},
@@ -27,11 +31,12 @@ use crate::{
     shared::FileCache,
 };
 
-#[tracing::instrument]
-pub async fn create(new_instance: NewInstance) -> crate::Result<String> {
-    let state = LauncherState::get().await?;
-    let lazy_locator = LazyLocator::get().await?;
+use super::get_create_snapshot_use_case;
 
+pub(crate) async fn get_create_instance_use_case(
+    state: &LauncherState,
+    lazy_locator: &LazyLocator,
+) -> crate::Result<CreateInstanceUseCaseType> {
     let loader_version_resolver = Arc::new(LoaderVersionResolver::new(
         lazy_locator.get_metadata_storage().await,
     ));
@@ -103,6 +108,7 @@ pub async fn create(new_instance: NewInstance) -> crate::Result<String> {
         install_minecraft_use_case,
         lazy_locator.get_progress_service().await,
         state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
     ));
 
     Ok(CreateInstanceUseCase::new(
@@ -112,9 +118,18 @@ pub async fn create(new_instance: NewInstance) -> crate::Result<String> {
         state.location_info.clone(),
         lazy_locator.get_event_emitter().await,
         lazy_locator.get_instance_watcher_service().await?,
-    )
-    .execute(new_instance)
-    .await?)
+    ))
+}
+
+#[tracing::instrument]
+pub async fn create(new_instance: NewInstance) -> crate::Result<String> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(get_create_instance_use_case(&state, &lazy_locator)
+        .await?
+        .execute(new_instance)
+        .await?)
 }
 
 #[tracing::instrument]
@@ -193,6 +208,7 @@ pub async fn install(instance_id: String, force: bool) -> crate::Result<()> {
         install_minecraft_use_case,
         lazy_locator.get_progress_service().await,
         state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
     )
     .execute(instance_id, force)
     .await?)
@@ -200,12 +216,18 @@ pub async fn install(instance_id: String, force: bool) -> crate::Result<()> {
 
 #[tracing::instrument]
 pub async fn update(instance_id: String) -> crate::Result<()> {
+    let state = LauncherState::get().await?;
     let lazy_locator = LazyLocator::get().await?;
 
+    let create_snapshot_use_case =
+        Arc::new(get_create_snapshot_use_case(&state, &lazy_locator).await);
+
     Ok(UpdateInstanceUseCase::new(
         lazy_locator.get_instance_storage().await,
         lazy_locator.get_plugin_registry().await,
         lazy_locator.get_updaters_registry().await,
+        create_snapshot_use_case,
+        lazy_locator.get_instance_lock_service().await,
     )
     .execute(instance_id)
     .await?)
@@ -221,6 +243,16 @@ pub async fn list() -> crate::Result<Vec<Instance>> {
     )
 }
 
+pub async fn query(query: InstanceQuery) -> crate::Result<Vec<Instance>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(
+        QueryInstancesUseCase::new(lazy_locator.get_instance_storage().await)
+            .execute(query)
+            .await?,
+    )
+}
+
 pub async fn get(instance_id: String) -> crate::Result<Instance> {
     let lazy_locator = LazyLocator::get().await?;
 
@@ -235,11 +267,12 @@ pub async fn get(instance_id: String) -> crate::Result<Instance> {
 pub async fn edit(instance_id: String, edit_instance: EditInstance) -> crate::Result<Instance> {
     let lazy_locator = LazyLocator::get().await?;
 
-    Ok(
-        EditInstanceUseCase::new(lazy_locator.get_instance_storage().await)
-            .execute(instance_id, edit_instance)
-            .await?,
+    Ok(EditInstanceUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_instance_lock_service().await,
     )
+    .execute(instance_id, edit_instance)
+    .await?)
 }
 
 #[tracing::instrument]
@@ -249,7 +282,47 @@ pub async fn remove(instance_id: String) -> crate::Result<()> {
     Ok(RemoveInstanceUseCase::new(
         lazy_locator.get_instance_storage().await,
         lazy_locator.get_instance_watcher_service().await?,
+        lazy_locator.get_instance_lock_service().await,
     )
     .execute(instance_id)
     .await?)
 }
+
+#[tracing::instrument]
+pub async fn duplicate(
+    instance_id: String,
+    new_name: String,
+    options: DuplicateInstanceOptions,
+) -> crate::Result<String> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(DuplicateInstanceUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_instance_watcher_service().await?,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, new_name, options)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn set_shared_folder(
+    instance_id: String,
+    shared_folder: SharedFolder,
+    shared: bool,
+) -> crate::Result<()> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(ChangeSharedFolderUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_pack_storage().await,
+        lazy_locator.get_instance_watcher_service().await?,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, shared_folder, shared)
+    .await?)
+}
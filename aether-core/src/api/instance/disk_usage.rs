@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{
+        domain::{LazyLocator, ProgressServiceType},
+        LauncherState,
+    },
+    features::{
+        events::infra::TauriEventEmitter,
+        instance::{
+            app::{
+                CollectMetadataGarbageUseCase, GetInstanceDiskUsageUseCase,
+                GetMetadataDiskUsageUseCase,
+            },
+            infra::{EventEmittingInstanceStorage, FsInstanceStorage},
+            GarbageReport, InstanceDiskUsage, MetadataDiskUsage,
+        },
+        java::infra::FsJavaStorage,
+        minecraft::{
+            app::{GetVersionFilesUseCase, GetVersionManifestUseCase},
+            infra::{
+                AssetsService, CachedMetadataStorage, ClientService, LibrariesService,
+                MinecraftDownloadResolver, MinecraftDownloadService, MinecraftMetadataResolver,
+                ModrinthMetadataStorage,
+            },
+            LoaderVersionResolver,
+        },
+    },
+    libs::request_client::ReqwestClient,
+    shared::FileCache,
+};
+
+/// `cancel_disk_usage` with `request_id` stops the calculation
+pub async fn get_disk_usage(
+    instance_id: String,
+    refresh: bool,
+    request_id: String,
+) -> crate::Result<InstanceDiskUsage> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(GetInstanceDiskUsageUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_disk_usage_service().await,
+        state.location_info.clone(),
+    )
+    .execute(instance_id, refresh, &request_id)
+    .await?)
+}
+
+/// Sizes of versions, libraries, assets, java runtimes and caches shared by instances
+pub async fn get_metadata_disk_usage(
+    refresh: bool,
+    request_id: String,
+) -> crate::Result<MetadataDiskUsage> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(GetMetadataDiskUsageUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_java_storage().await,
+        lazy_locator.get_disk_usage_service().await,
+        state.location_info.clone(),
+    )
+    .execute(refresh, &request_id)
+    .await?)
+}
+
+/// Running calculation started with `request_id` fails with `DiskUsageCancelled`
+pub async fn cancel_disk_usage(request_id: String) -> crate::Result<()> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    lazy_locator
+        .get_disk_usage_service()
+        .await
+        .cancel(&request_id);
+
+    Ok(())
+}
+
+async fn get_collect_metadata_garbage_use_case(
+    state: &LauncherState,
+    lazy_locator: &LazyLocator,
+) -> CollectMetadataGarbageUseCase<
+    EventEmittingInstanceStorage<TauriEventEmitter, FsInstanceStorage>,
+    CachedMetadataStorage<
+        FileCache<MinecraftMetadataResolver>,
+        ModrinthMetadataStorage<ReqwestClient<ProgressServiceType>>,
+    >,
+    MinecraftDownloadService<
+        ReqwestClient<ProgressServiceType>,
+        ProgressServiceType,
+        FileCache<MinecraftDownloadResolver>,
+        FileCache<MinecraftDownloadResolver>,
+    >,
+    FsJavaStorage,
+> {
+    let loader_version_resolver = Arc::new(LoaderVersionResolver::new(
+        lazy_locator.get_metadata_storage().await,
+    ));
+
+    let get_version_manifest_use_case = Arc::new(GetVersionManifestUseCase::new(
+        lazy_locator.get_metadata_storage().await,
+    ));
+
+    let minecraft_cache = Arc::new(FileCache::new(MinecraftDownloadResolver::new(
+        state.location_info.clone(),
+    )));
+
+    let client_service = ClientService::new(
+        lazy_locator.get_progress_service().await,
+        lazy_locator.get_request_client().await,
+        minecraft_cache.clone(),
+    );
+
+    let assets_service = AssetsService::new(
+        lazy_locator.get_progress_service().await,
+        lazy_locator.get_request_client().await,
+        state.location_info.clone(),
+        minecraft_cache.clone(),
+    );
+    let libraries_service = LibrariesService::new(
+        lazy_locator.get_progress_service().await,
+        lazy_locator.get_request_client().await,
+        state.location_info.clone(),
+    );
+    let minecraft_download_service = MinecraftDownloadService::new(
+        client_service,
+        assets_service,
+        libraries_service,
+        lazy_locator.get_request_client().await,
+        lazy_locator.get_progress_service().await,
+        minecraft_cache.clone(),
+    );
+
+    let get_version_files_use_case = Arc::new(GetVersionFilesUseCase::new(
+        loader_version_resolver,
+        get_version_manifest_use_case,
+        minecraft_download_service,
+        state.location_info.clone(),
+    ));
+
+    CollectMetadataGarbageUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_java_storage().await,
+        get_version_files_use_case,
+        lazy_locator.get_disk_usage_service().await,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+}
+
+/// Dry run of `collect_metadata_garbage`
+pub async fn list_unreferenced_metadata() -> crate::Result<GarbageReport> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(get_collect_metadata_garbage_use_case(&state, &lazy_locator)
+        .await
+        .execute(true)
+        .await?)
+}
+
+/// Removes versions, libraries, assets and java runtimes no instance references
+#[tracing::instrument]
+pub async fn collect_metadata_garbage() -> crate::Result<GarbageReport> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(get_collect_metadata_garbage_use_case(&state, &lazy_locator)
+        .await
+        .execute(false)
+        .await?)
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{domain::LazyLocator, LauncherState},
+    features::{
+        instance::app::{
+            ExportCurseForgeOptions, ExportCurseForgeUseCase, ExportMrpackOptions,
+            ExportMrpackUseCase, ExportPackwizOptions, ExportPackwizUseCase, ListContentUseCase,
+        },
+        minecraft::LoaderVersionResolver,
+    },
+};
+
+#[tracing::instrument]
+pub async fn export_mrpack(instance_id: String, options: ExportMrpackOptions) -> crate::Result<()> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    let list_content_use_case = Arc::new(ListContentUseCase::new(
+        lazy_locator.get_pack_storage().await,
+        state.location_info.clone(),
+    ));
+
+    let loader_version_resolver = Arc::new(LoaderVersionResolver::new(
+        lazy_locator.get_metadata_storage().await,
+    ));
+
+    Ok(ExportMrpackUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_pack_storage().await,
+        list_content_use_case,
+        loader_version_resolver,
+        state.location_info.clone(),
+    )
+    .execute(instance_id, options)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn export_curseforge(
+    instance_id: String,
+    options: ExportCurseForgeOptions,
+) -> crate::Result<()> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    let list_content_use_case = Arc::new(ListContentUseCase::new(
+        lazy_locator.get_pack_storage().await,
+        state.location_info.clone(),
+    ));
+
+    let loader_version_resolver = Arc::new(LoaderVersionResolver::new(
+        lazy_locator.get_metadata_storage().await,
+    ));
+
+    Ok(ExportCurseForgeUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_pack_storage().await,
+        list_content_use_case,
+        loader_version_resolver,
+        state.location_info.clone(),
+    )
+    .execute(instance_id, options)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn export_packwiz(
+    instance_id: String,
+    options: ExportPackwizOptions,
+) -> crate::Result<()> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    let list_content_use_case = Arc::new(ListContentUseCase::new(
+        lazy_locator.get_pack_storage().await,
+        state.location_info.clone(),
+    ));
+
+    let loader_version_resolver = Arc::new(LoaderVersionResolver::new(
+        lazy_locator.get_metadata_storage().await,
+    ));
+
+    Ok(ExportPackwizUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_pack_storage().await,
+        list_content_use_case,
+        loader_version_resolver,
+        state.location_info.clone(),
+    )
+    .execute(instance_id, options)
+    .await?)
+}
@@ -0,0 +1,36 @@
+use crate::{
+    core::domain::LazyLocator,
+    features::instance::app::{ListGroupsUseCase, RemoveGroupUseCase, RenameGroupUseCase},
+};
+
+pub async fn list_groups() -> crate::Result<Vec<String>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(
+        ListGroupsUseCase::new(lazy_locator.get_instance_storage().await)
+            .execute()
+            .await?,
+    )
+}
+
+#[tracing::instrument]
+pub async fn rename_group(group: String, new_group: String) -> crate::Result<Vec<String>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(
+        RenameGroupUseCase::new(lazy_locator.get_instance_storage().await)
+            .execute(group, new_group)
+            .await?,
+    )
+}
+
+#[tracing::instrument]
+pub async fn remove_group(group: String) -> crate::Result<Vec<String>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(
+        RemoveGroupUseCase::new(lazy_locator.get_instance_storage().await)
+            .execute(group)
+            .await?,
+    )
+}
@@ -1,8 +1,18 @@
+use std::{path::PathBuf, sync::Arc};
+
 use crate::{
-    core::domain::LazyLocator,
-    features::instance::app::{ImportInstance, ImportInstanceUseCase},
+    core::{domain::LazyLocator, LauncherState},
+    features::instance::{
+        app::{
+            ImportCurseForgeUseCase, ImportInstance, ImportInstanceUseCase, ImportMrpackUseCase,
+            ImportPackwizUseCase,
+        },
+        CurseForgeManifestResolver,
+    },
 };
 
+use super::get_create_instance_use_case;
+
 #[tracing::instrument]
 pub async fn import(import_instance: ImportInstance) -> crate::Result<()> {
     let lazy_locator = LazyLocator::get().await?;
@@ -14,3 +24,73 @@ pub async fn import(import_instance: ImportInstance) -> crate::Result<()> {
     .execute(import_instance)
     .await?)
 }
+
+#[tracing::instrument]
+pub async fn import_mrpack(path: PathBuf) -> crate::Result<String> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    let create_instance_use_case =
+        Arc::new(get_create_instance_use_case(&state, &lazy_locator).await?);
+
+    Ok(ImportMrpackUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        create_instance_use_case,
+        lazy_locator.get_instance_watcher_service().await?,
+        lazy_locator.get_pack_storage().await,
+        lazy_locator.get_content_provider_registry().await,
+        lazy_locator.get_request_client().await,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(path)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn import_curseforge(path: PathBuf) -> crate::Result<String> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    let create_instance_use_case =
+        Arc::new(get_create_instance_use_case(&state, &lazy_locator).await?);
+
+    let manifest_resolver = Arc::new(CurseForgeManifestResolver::new(
+        lazy_locator.get_curseforge_file_resolver().await,
+    ));
+
+    Ok(ImportCurseForgeUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        create_instance_use_case,
+        lazy_locator.get_instance_watcher_service().await?,
+        lazy_locator.get_pack_storage().await,
+        manifest_resolver,
+        lazy_locator.get_request_client().await,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(path)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn import_packwiz(path: PathBuf) -> crate::Result<String> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    let create_instance_use_case =
+        Arc::new(get_create_instance_use_case(&state, &lazy_locator).await?);
+
+    Ok(ImportPackwizUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        create_instance_use_case,
+        lazy_locator.get_instance_watcher_service().await?,
+        lazy_locator.get_pack_storage().await,
+        lazy_locator.get_curseforge_file_resolver().await,
+        lazy_locator.get_request_client().await,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(path)
+    .await?)
+}
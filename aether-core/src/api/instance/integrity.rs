@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{
+        domain::{LazyLocator, ProgressServiceType},
+        LauncherState,
+    },
+    features::{
+        events::infra::TauriEventEmitter,
+        instance::{
+            app::{InstallContentUseCase, VerifyInstanceUseCase},
+            infra::{
+                EventEmittingInstanceStorage, FsInstanceStorage, FsPackStorage,
+                ModrinthContentProvider,
+            },
+            InstanceIntegrityReport,
+        },
+        java::{
+            app::GetJavaUseCase,
+            infra::{FsJavaInstallationService, FsJavaStorage},
+        },
+        minecraft::{
+            app::{GetVersionManifestUseCase, VerifyMinecraftUseCase},
+            infra::{
+                AssetsService, CachedMetadataStorage, ClientService, LibrariesService,
+                MinecraftDownloadResolver, MinecraftDownloadService, MinecraftMetadataResolver,
+                ModrinthMetadataStorage,
+            },
+            LoaderVersionResolver,
+        },
+    },
+    libs::request_client::ReqwestClient,
+    shared::FileCache,
+};
+
+async fn get_verify_instance_use_case(
+    state: &LauncherState,
+    lazy_locator: &LazyLocator,
+) -> VerifyInstanceUseCase<
+    EventEmittingInstanceStorage<TauriEventEmitter, FsInstanceStorage>,
+    FsPackStorage,
+    ModrinthContentProvider<ReqwestClient<ProgressServiceType>>,
+    CachedMetadataStorage<
+        FileCache<MinecraftMetadataResolver>,
+        ModrinthMetadataStorage<ReqwestClient<ProgressServiceType>>,
+    >,
+    MinecraftDownloadService<
+        ReqwestClient<ProgressServiceType>,
+        ProgressServiceType,
+        FileCache<MinecraftDownloadResolver>,
+        FileCache<MinecraftDownloadResolver>,
+    >,
+    FsJavaInstallationService,
+    FsJavaStorage,
+> {
+    let loader_version_resolver = Arc::new(LoaderVersionResolver::new(
+        lazy_locator.get_metadata_storage().await,
+    ));
+
+    let get_version_manifest_use_case = Arc::new(GetVersionManifestUseCase::new(
+        lazy_locator.get_metadata_storage().await,
+    ));
+
+    let minecraft_cache = Arc::new(FileCache::new(MinecraftDownloadResolver::new(
+        state.location_info.clone(),
+    )));
+
+    let client_service = ClientService::new(
+        lazy_locator.get_progress_service().await,
+        lazy_locator.get_request_client().await,
+        minecraft_cache.clone(),
+    );
+
+    let assets_service = AssetsService::new(
+        lazy_locator.get_progress_service().await,
+        lazy_locator.get_request_client().await,
+        state.location_info.clone(),
+        minecraft_cache.clone(),
+    );
+    let libraries_service = LibrariesService::new(
+        lazy_locator.get_progress_service().await,
+        lazy_locator.get_request_client().await,
+        state.location_info.clone(),
+    );
+    let minecraft_download_service = MinecraftDownloadService::new(
+        client_service,
+        assets_service,
+        libraries_service,
+        lazy_locator.get_request_client().await,
+        lazy_locator.get_progress_service().await,
+        minecraft_cache.clone(),
+    );
+
+    let get_java_use_case = Arc::new(GetJavaUseCase::new(
+        lazy_locator.get_java_storage().await,
+        FsJavaInstallationService,
+    ));
+
+    let verify_minecraft_use_case = Arc::new(VerifyMinecraftUseCase::new(
+        loader_version_resolver,
+        get_version_manifest_use_case,
+        minecraft_download_service,
+        FsJavaInstallationService,
+        get_java_use_case,
+    ));
+
+    let install_content_use_case = Arc::new(InstallContentUseCase::new(
+        lazy_locator.get_pack_storage().await,
+        lazy_locator.get_content_provider_registry().await,
+        lazy_locator.get_instance_lock_service().await,
+    ));
+
+    VerifyInstanceUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_pack_storage().await,
+        lazy_locator.get_content_provider_registry().await,
+        verify_minecraft_use_case,
+        install_content_use_case,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+}
+
+pub async fn verify(instance_id: String) -> crate::Result<InstanceIntegrityReport> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(get_verify_instance_use_case(&state, &lazy_locator)
+        .await
+        .execute(instance_id, false)
+        .await?)
+}
+
+#[tracing::instrument]
+pub async fn repair(instance_id: String) -> crate::Result<InstanceIntegrityReport> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(get_verify_instance_use_case(&state, &lazy_locator)
+        .await
+        .execute(instance_id, true)
+        .await?)
+}
@@ -11,9 +11,9 @@ use crate::{
         instance::{
             app::{
                 InstallInstanceUseCase, LaunchInstanceUseCase,
-                LaunchInstanceWithActiveAccountUseCase,
+                LaunchInstanceWithActiveAccountUseCase, RecordPlaySessionUseCase,
             },
-            infra::{EventEmittingInstanceStorage, FsInstanceStorage},
+            infra::{EventEmittingInstanceStorage, FsInstanceStorage, FsPlaySessionStorage},
         },
         java::{
             app::{GetJavaUseCase, InstallJavaUseCase},
@@ -29,7 +29,7 @@ use crate::{
                 MinecraftDownloadResolver, MinecraftDownloadService, MinecraftMetadataResolver,
                 ModrinthMetadataStorage,
             },
-            LoaderVersionResolver,
+            LoaderVersionResolver, QuickPlayTarget,
         },
         process::{
             app::{
@@ -67,6 +67,7 @@ async fn get_launch_instance_use_case(
     FsJavaInstallationService,
     FsJavaStorage,
     AzulJreProvider<ProgressServiceType, ReqwestClient<ProgressServiceType>>,
+    FsPlaySessionStorage,
 > {
     let loader_version_resolver = Arc::new(LoaderVersionResolver::new(
         lazy_locator.get_metadata_storage().await,
@@ -143,15 +144,22 @@ async fn get_launch_instance_use_case(
         install_minecraft_use_case,
         lazy_locator.get_progress_service().await,
         state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
     ));
 
     let get_process_by_instance_id_use_case = Arc::new(GetProcessMetadataByInstanceIdUseCase::new(
         lazy_locator.get_process_storage().await,
     ));
 
+    let record_play_session_use_case = Arc::new(RecordPlaySessionUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_play_session_storage().await,
+    ));
+
     let track_process_use_case = Arc::new(TrackProcessUseCase::new(
         lazy_locator.get_process_storage().await,
         lazy_locator.get_instance_storage().await,
+        record_play_session_use_case,
     ));
 
     let manage_process_use_case = Arc::new(ManageProcessUseCase::new(
@@ -209,21 +217,27 @@ async fn get_launch_instance_use_case(
         install_instance_use_case,
         get_minecraft_launch_command_use_case,
         start_process_use_case,
+        lazy_locator.get_instance_lock_service().await,
     )
 }
 
 #[tracing::instrument]
-pub async fn run(instance_id: String) -> crate::Result<MinecraftProcessMetadata> {
+pub async fn run(
+    instance_id: String,
+    quick_play: Option<QuickPlayTarget>,
+) -> crate::Result<MinecraftProcessMetadata> {
     let state = LauncherState::get().await?;
     let lazy_locator = LazyLocator::get().await?;
 
     let launch_instance_use_case = get_launch_instance_use_case(&state, &lazy_locator).await;
 
     Ok(LaunchInstanceWithActiveAccountUseCase::new(
+        lazy_locator.get_instance_storage().await,
         lazy_locator.get_credentials_storage().await,
+        lazy_locator.get_token_refresh_service().await,
         launch_instance_use_case,
     )
-    .execute(instance_id)
+    .execute(instance_id, quick_play)
     .await?)
 }
 
@@ -231,12 +245,13 @@ pub async fn run(instance_id: String) -> crate::Result<MinecraftProcessMetadata>
 pub async fn run_credentials(
     instance_id: String,
     credentials: Credentials,
+    quick_play: Option<QuickPlayTarget>,
 ) -> crate::Result<MinecraftProcessMetadata> {
     let state = LauncherState::get().await?;
     let lazy_locator = LazyLocator::get().await?;
 
     Ok(get_launch_instance_use_case(&state, &lazy_locator)
         .await
-        .execute(instance_id, credentials)
+        .execute(instance_id, credentials, quick_play)
         .await?)
 }
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{domain::LazyLocator, LauncherState},
+    features::{
+        instance::{
+            app::{
+                CheckInstanceMigrationUseCase, InstallContentUseCase, InstanceMigration,
+                InstanceMigrationReport, ListContentUseCase, MigrateInstanceUseCase,
+            },
+            ContentCompatibility,
+        },
+        minecraft::LoaderVersionResolver,
+    },
+};
+
+use super::get_create_snapshot_use_case;
+
+pub async fn check_migration(
+    instance_id: String,
+    migration: InstanceMigration,
+) -> crate::Result<Vec<ContentCompatibility>> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    let list_content_use_case = Arc::new(ListContentUseCase::new(
+        lazy_locator.get_pack_storage().await,
+        state.location_info.clone(),
+    ));
+
+    Ok(CheckInstanceMigrationUseCase::new(
+        list_content_use_case,
+        lazy_locator.get_pack_storage().await,
+        lazy_locator.get_content_provider_registry().await,
+    )
+    .execute(instance_id, &migration)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn migrate(
+    instance_id: String,
+    migration: InstanceMigration,
+    update_content: bool,
+) -> crate::Result<InstanceMigrationReport> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    let list_content_use_case = Arc::new(ListContentUseCase::new(
+        lazy_locator.get_pack_storage().await,
+        state.location_info.clone(),
+    ));
+
+    let check_instance_migration_use_case = Arc::new(CheckInstanceMigrationUseCase::new(
+        list_content_use_case,
+        lazy_locator.get_pack_storage().await,
+        lazy_locator.get_content_provider_registry().await,
+    ));
+
+    let install_content_use_case = Arc::new(InstallContentUseCase::new(
+        lazy_locator.get_pack_storage().await,
+        lazy_locator.get_content_provider_registry().await,
+        lazy_locator.get_instance_lock_service().await,
+    ));
+
+    let loader_version_resolver = Arc::new(LoaderVersionResolver::new(
+        lazy_locator.get_metadata_storage().await,
+    ));
+
+    let create_snapshot_use_case =
+        Arc::new(get_create_snapshot_use_case(&state, &lazy_locator).await);
+
+    Ok(MigrateInstanceUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_pack_storage().await,
+        loader_version_resolver,
+        check_instance_migration_use_case,
+        install_content_use_case,
+        create_snapshot_use_case,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, migration, update_content)
+    .await?)
+}
@@ -1,11 +1,29 @@
 pub mod content;
 pub mod crud;
+pub mod disk_usage;
+pub mod export;
+pub mod group;
 pub mod helpers;
 pub mod import;
+pub mod integrity;
 pub mod launch;
+pub mod migrate;
+pub mod playtime;
+pub mod server;
+pub mod snapshot;
+pub mod world;
 
 pub use content::*;
 pub use crud::*;
+pub use disk_usage::*;
+pub use export::*;
+pub use group::*;
 pub use helpers::*;
 pub use import::*;
+pub use integrity::*;
 pub use launch::*;
+pub use migrate::*;
+pub use playtime::*;
+pub use server::*;
+pub use snapshot::*;
+pub use world::*;
@@ -0,0 +1,31 @@
+use crate::{
+    core::domain::LazyLocator,
+    features::instance::{
+        app::{GetPlaytimeStatsUseCase, ListPlaySessionsUseCase},
+        PlaySession, PlaytimePeriod, PlaytimeStats,
+    },
+};
+
+pub async fn list_play_sessions(instance_id: String) -> crate::Result<Vec<PlaySession>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(
+        ListPlaySessionsUseCase::new(lazy_locator.get_play_session_storage().await)
+            .execute(instance_id)
+            .await?,
+    )
+}
+
+pub async fn get_playtime_stats(
+    instance_id: String,
+    period: PlaytimePeriod,
+    utc_offset_minutes: i32,
+) -> crate::Result<Vec<PlaytimeStats>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(
+        GetPlaytimeStatsUseCase::new(lazy_locator.get_play_session_storage().await)
+            .execute(instance_id, period, utc_offset_minutes)
+            .await?,
+    )
+}
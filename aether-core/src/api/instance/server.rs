@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use crate::{
+    core::domain::LazyLocator,
+    features::instance::{
+        app::{
+            AddServerUseCase, EditServer, EditServerUseCase, ListServersUseCase,
+            MergeServersUseCase, MoveServerUseCase, PingServerUseCase, RemoveServerUseCase,
+        },
+        infra::TcpServerPinger,
+        ServerEntry, ServerStatus,
+    },
+};
+
+pub async fn list_servers(instance_id: String) -> crate::Result<Vec<ServerEntry>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(
+        ListServersUseCase::new(lazy_locator.get_server_list_storage().await)
+            .execute(instance_id)
+            .await?,
+    )
+}
+
+#[tracing::instrument]
+pub async fn add_server(
+    instance_id: String,
+    server: ServerEntry,
+    index: Option<usize>,
+) -> crate::Result<Vec<ServerEntry>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(AddServerUseCase::new(
+        lazy_locator.get_server_list_storage().await,
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, server, index)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn edit_server(
+    instance_id: String,
+    index: usize,
+    edit_server: EditServer,
+) -> crate::Result<Vec<ServerEntry>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(EditServerUseCase::new(
+        lazy_locator.get_server_list_storage().await,
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, index, edit_server)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn remove_server(instance_id: String, index: usize) -> crate::Result<Vec<ServerEntry>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(RemoveServerUseCase::new(
+        lazy_locator.get_server_list_storage().await,
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, index)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn move_server(
+    instance_id: String,
+    from: usize,
+    to: usize,
+) -> crate::Result<Vec<ServerEntry>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(MoveServerUseCase::new(
+        lazy_locator.get_server_list_storage().await,
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, from, to)
+    .await?)
+}
+
+/// Adds servers shipped with a pack without touching the ones the user already has
+#[tracing::instrument]
+pub async fn merge_servers(
+    instance_id: String,
+    servers: Vec<ServerEntry>,
+) -> crate::Result<Vec<ServerEntry>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(MergeServersUseCase::new(
+        lazy_locator.get_server_list_storage().await,
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, servers)
+    .await?)
+}
+
+/// Queries the server status with the Server List Ping protocol
+pub async fn ping_server(address: String) -> crate::Result<ServerStatus> {
+    Ok(PingServerUseCase::new(Arc::new(TcpServerPinger::default()))
+        .execute(address)
+        .await?)
+}
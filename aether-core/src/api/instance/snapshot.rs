@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{
+        domain::{LazyLocator, ProgressServiceType},
+        LauncherState,
+    },
+    features::{
+        events::infra::TauriEventEmitter,
+        instance::{
+            app::{
+                ApplySnapshotRetentionUseCase, CreateSnapshotUseCase, ListSnapshotsUseCase,
+                RemoveSnapshotUseCase, RestoreSnapshotUseCase,
+            },
+            infra::{EventEmittingInstanceStorage, FsInstanceStorage, FsSnapshotStorage},
+            InstanceSnapshot, SnapshotReason, SnapshotRetention,
+        },
+    },
+};
+
+pub(crate) async fn get_create_snapshot_use_case(
+    state: &LauncherState,
+    lazy_locator: &LazyLocator,
+) -> CreateSnapshotUseCase<
+    EventEmittingInstanceStorage<TauriEventEmitter, FsInstanceStorage>,
+    FsSnapshotStorage,
+    ProgressServiceType,
+> {
+    let apply_snapshot_retention_use_case = Arc::new(ApplySnapshotRetentionUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_snapshot_storage().await,
+        lazy_locator.get_instance_lock_service().await,
+    ));
+
+    CreateSnapshotUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_snapshot_storage().await,
+        apply_snapshot_retention_use_case,
+        lazy_locator.get_progress_service().await,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+}
+
+#[tracing::instrument]
+pub async fn create_snapshot(instance_id: String) -> crate::Result<InstanceSnapshot> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(get_create_snapshot_use_case(&state, &lazy_locator)
+        .await
+        .execute(instance_id, SnapshotReason::Manual)
+        .await?)
+}
+
+pub async fn list_snapshots(instance_id: String) -> crate::Result<Vec<InstanceSnapshot>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(
+        ListSnapshotsUseCase::new(lazy_locator.get_snapshot_storage().await)
+            .execute(instance_id)
+            .await?,
+    )
+}
+
+#[tracing::instrument]
+pub async fn restore_snapshot(instance_id: String, snapshot_id: String) -> crate::Result<()> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(RestoreSnapshotUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_snapshot_storage().await,
+        lazy_locator.get_progress_service().await,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, snapshot_id)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn remove_snapshot(instance_id: String, snapshot_id: String) -> crate::Result<()> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(
+        RemoveSnapshotUseCase::new(lazy_locator.get_snapshot_storage().await)
+            .execute(instance_id, snapshot_id)
+            .await?,
+    )
+}
+
+/// Applies `retention` or the instance policy if it's `None`, returns ids of removed snapshots
+#[tracing::instrument]
+pub async fn apply_snapshot_retention(
+    instance_id: String,
+    retention: Option<SnapshotRetention>,
+) -> crate::Result<Vec<String>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(ApplySnapshotRetentionUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_snapshot_storage().await,
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, retention)
+    .await?)
+}
@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+
+use crate::{
+    core::{domain::LazyLocator, LauncherState},
+    features::instance::{
+        app::{
+            CopyWorldUseCase, ExportWorldUseCase, ImportWorldUseCase, ListWorldsUseCase,
+            RemoveWorldUseCase,
+        },
+        World,
+    },
+};
+
+pub async fn list_worlds(instance_id: String) -> crate::Result<Vec<World>> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(
+        ListWorldsUseCase::new(lazy_locator.get_world_storage().await)
+            .execute(instance_id)
+            .await?,
+    )
+}
+
+#[tracing::instrument]
+pub async fn remove_world(instance_id: String, world_id: String) -> crate::Result<()> {
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(RemoveWorldUseCase::new(
+        lazy_locator.get_world_storage().await,
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, world_id)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn export_world(
+    instance_id: String,
+    world_id: String,
+    output_path: PathBuf,
+) -> crate::Result<()> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(ExportWorldUseCase::new(
+        lazy_locator.get_world_storage().await,
+        state.location_info.clone(),
+    )
+    .execute(instance_id, world_id, output_path)
+    .await?)
+}
+
+#[tracing::instrument]
+pub async fn import_world(instance_id: String, path: PathBuf) -> crate::Result<World> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(ImportWorldUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_world_storage().await,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, path)
+    .await?)
+}
+
+/// Copies a world into another instance, returns the id of the copy
+#[tracing::instrument]
+pub async fn copy_world(
+    instance_id: String,
+    world_id: String,
+    target_instance_id: String,
+) -> crate::Result<String> {
+    let state = LauncherState::get().await?;
+    let lazy_locator = LazyLocator::get().await?;
+
+    Ok(CopyWorldUseCase::new(
+        lazy_locator.get_instance_storage().await,
+        lazy_locator.get_world_storage().await,
+        state.location_info.clone(),
+        lazy_locator.get_instance_lock_service().await,
+    )
+    .execute(instance_id, world_id, target_instance_id)
+    .await?)
+}
@@ -1,6 +1,12 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
 
-use tokio::sync::{OnceCell, Semaphore};
+use tokio::{
+    sync::{watch, OnceCell, Semaphore},
+    task::JoinHandle,
+};
 
 use crate::{
     core::domain::LazyLocator,
@@ -16,6 +22,9 @@ use crate::{
 // RwLock on state only has concurrent reads, except for config dir change which takes control of the State
 static LAUNCHER_STATE: OnceCell<Arc<LauncherState>> = OnceCell::const_new();
 
+/// How often the active account tokens are checked and refreshed in the background
+const TOKEN_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+
 #[derive(Debug)]
 pub struct LauncherState {
     // Information about files location
@@ -31,6 +40,12 @@ pub struct LauncherState {
     /// Semaphore to limit concurrent API requests. This is separate from the fetch semaphore
     /// to keep API functionality while the app is performing intensive tasks.
     pub api_semaphore: Arc<FetchSemaphore>,
+
+    /// Signals background tasks to stop on shutdown
+    shutdown_sender: watch::Sender<bool>,
+
+    // Background loop refreshing the active account tokens
+    token_refresh_task: Mutex<Option<JoinHandle<()>>>,
 }
 
 impl LauncherState {
@@ -67,6 +82,25 @@ impl LauncherState {
         LAUNCHER_STATE.initialized()
     }
 
+    /// Stops background tasks, must be called before the app exits.
+    /// A refresh in progress is allowed to finish so credentials are not left half written.
+    pub async fn shutdown() {
+        let Some(state) = LAUNCHER_STATE.get() else {
+            return;
+        };
+
+        state.shutdown_sender.send_replace(true);
+
+        let task = state.token_refresh_task.lock().unwrap().take();
+        if let Some(task) = task {
+            if let Err(err) = task.await {
+                tracing::warn!("Token refresh task failed: {err}");
+            }
+        }
+
+        log::info!("State shut down");
+    }
+
     #[tracing::instrument(skip(app_handle))]
     async fn initialize(
         launcher_dir: PathBuf,
@@ -99,10 +133,14 @@ impl LauncherState {
 
         log::info!("State initialized");
 
+        let (shutdown_sender, _) = watch::channel(false);
+
         let state = Arc::new(Self {
             location_info,
             fetch_semaphore,
             api_semaphore,
+            shutdown_sender,
+            token_refresh_task: Mutex::new(None),
         });
 
         LazyLocator::init(state.clone(), app_handle).await?;
@@ -123,6 +161,23 @@ impl LauncherState {
             },
         );
 
+        let token_refresh_service = lazy_locator.get_token_refresh_service().await;
+        let mut shutdown_receiver = state.shutdown_sender.subscribe();
+        let token_refresh_task = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(TOKEN_REFRESH_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    _ = shutdown_receiver.wait_for(|stopped| *stopped) => break,
+                }
+
+                if let Err(err) = token_refresh_service.refresh_active().await {
+                    tracing::warn!("Failed to refresh active account: {err}");
+                }
+            }
+        });
+        *state.token_refresh_task.lock().unwrap() = Some(token_refresh_task);
+
         log::info!("Service locator initialized");
 
         Ok(state)
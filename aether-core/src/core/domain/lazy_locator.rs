@@ -6,22 +6,32 @@ use tokio::sync::OnceCell;
 
 use crate::{
     features::{
-        auth::infra::FsCredentialsStorage,
+        auth::{
+            infra::{
+                EncryptedFsCredentialsStorage, MicrosoftAuthClient, MicrosoftAuthConfig,
+                YggdrasilAuthClient,
+            },
+            TokenRefreshService,
+        },
         events::{
             infra::{InMemoryProgressBarStorage, TauriEventEmitter},
             ProgressServiceImpl,
         },
         file_watcher::infra::NotifyFileWatcher,
         instance::{
+            app::CreateInstanceUseCase,
             infra::{
-                EventEmittingInstanceStorage, FsInstanceStorage, FsPackStorage,
-                InstanceEventHandler, ModrinthContentProvider,
+                CurseForgeApiConfig, CurseForgeApiFileResolver, EventEmittingInstanceStorage,
+                FsInstanceStorage, FsPackStorage, FsPlaySessionStorage, FsServerListStorage,
+                FsSnapshotStorage, FsWorldStorage, InstanceEventHandler, ModrinthContentProvider,
             },
-            ContentProviderRegistry, InstanceWatcherServiceImpl,
+            ContentProviderRegistry, DiskUsageService, InstanceLockService,
+            InstanceWatcherServiceImpl,
         },
-        java::infra::FsJavaStorage,
+        java::infra::{AzulJreProvider, FsJavaInstallationService, FsJavaStorage},
         minecraft::infra::{
-            CachedMetadataStorage, MinecraftMetadataResolver, ModrinthMetadataStorage,
+            CachedMetadataStorage, MinecraftDownloadResolver, MinecraftDownloadService,
+            MinecraftMetadataResolver, ModrinthMetadataStorage,
         },
         plugins::{
             infra::{
@@ -49,13 +59,52 @@ pub type UpdaterRegistry = MemoryCapabilityRegistry<UpdaterCapability>;
 
 pub type MinecraftMetadataCache = FileCache<MinecraftMetadataResolver>;
 
+pub type MicrosoftAuthServiceType = MicrosoftAuthClient<ReqwestClient<ProgressServiceType>>;
+pub type YggdrasilAuthServiceType = YggdrasilAuthClient<ReqwestClient<ProgressServiceType>>;
+pub type TokenRefreshServiceType = TokenRefreshService<
+    EncryptedFsCredentialsStorage,
+    MicrosoftAuthServiceType,
+    YggdrasilAuthServiceType,
+>;
+
+pub type CurseForgeFileResolverType = CurseForgeApiFileResolver<ReqwestClient<ProgressServiceType>>;
+
+pub type InstanceStorageType = EventEmittingInstanceStorage<TauriEventEmitter, FsInstanceStorage>;
+pub type MetadataStorageType = CachedMetadataStorage<
+    MinecraftMetadataCache,
+    ModrinthMetadataStorage<ReqwestClient<ProgressServiceType>>,
+>;
+pub type InstanceWatcherServiceType =
+    InstanceWatcherServiceImpl<NotifyFileWatcher<InstanceEventHandler<TauriEventEmitter>>>;
+pub type MinecraftDownloadServiceType = MinecraftDownloadService<
+    ReqwestClient<ProgressServiceType>,
+    ProgressServiceType,
+    FileCache<MinecraftDownloadResolver>,
+    FileCache<MinecraftDownloadResolver>,
+>;
+pub type JreProviderType = AzulJreProvider<ProgressServiceType, ReqwestClient<ProgressServiceType>>;
+pub type CreateInstanceUseCaseType = CreateInstanceUseCase<
+    InstanceStorageType,
+    MetadataStorageType,
+    TauriEventEmitter,
+    MinecraftDownloadServiceType,
+    ProgressServiceType,
+    InstanceWatcherServiceType,
+    FsJavaInstallationService,
+    FsJavaStorage,
+    JreProviderType,
+>;
+
 pub struct LazyLocator {
     state: Arc<LauncherState>,
     app_handle: tauri::AppHandle,
     reqwest_client: Arc<ClientWithMiddleware>,
     request_client: OnceCell<Arc<ReqwestClient<ProgressServiceType>>>,
     api_client: OnceCell<Arc<ReqwestClient<ProgressServiceType>>>,
-    credentials_storage: OnceCell<Arc<FsCredentialsStorage>>,
+    credentials_storage: OnceCell<Arc<EncryptedFsCredentialsStorage>>,
+    microsoft_auth_service: OnceCell<Arc<MicrosoftAuthServiceType>>,
+    yggdrasil_auth_service: OnceCell<Arc<YggdrasilAuthServiceType>>,
+    token_refresh_service: OnceCell<Arc<TokenRefreshServiceType>>,
     settings_storage: OnceCell<Arc<FsSettingsStorage>>,
     process_storage: OnceCell<Arc<InMemoryProcessStorage>>,
     instance_storage:
@@ -70,9 +119,16 @@ pub struct LazyLocator {
         >,
     >,
     pack_storage: OnceCell<Arc<FsPackStorage>>,
+    snapshot_storage: OnceCell<Arc<FsSnapshotStorage>>,
+    world_storage: OnceCell<Arc<FsWorldStorage>>,
+    server_list_storage: OnceCell<Arc<FsServerListStorage>>,
+    play_session_storage: OnceCell<Arc<FsPlaySessionStorage>>,
+    instance_lock_service: OnceCell<Arc<InstanceLockService>>,
+    disk_usage_service: OnceCell<Arc<DiskUsageService>>,
     content_provider_registry: OnceCell<
         Arc<ContentProviderRegistry<ModrinthContentProvider<ReqwestClient<ProgressServiceType>>>>,
     >,
+    curseforge_file_resolver: OnceCell<Arc<CurseForgeFileResolverType>>,
     plugin_settings_storage: OnceCell<Arc<FsPluginSettingsStorage>>,
     plugin_registry: OnceCell<Arc<PluginRegistry<TauriEventEmitter>>>,
     plugin_loader_registry: OnceCell<Arc<PluginLoaderRegistry<ExtismPluginLoader>>>,
@@ -125,13 +181,23 @@ impl LazyLocator {
                     request_client: OnceCell::new(),
                     api_client: OnceCell::new(),
                     credentials_storage: OnceCell::new(),
+                    microsoft_auth_service: OnceCell::new(),
+                    yggdrasil_auth_service: OnceCell::new(),
+                    token_refresh_service: OnceCell::new(),
                     settings_storage: OnceCell::new(),
                     process_storage: OnceCell::new(),
                     instance_storage: OnceCell::new(),
                     java_storage: OnceCell::new(),
                     metadata_storage: OnceCell::new(),
                     pack_storage: OnceCell::new(),
+                    snapshot_storage: OnceCell::new(),
+                    world_storage: OnceCell::new(),
+                    server_list_storage: OnceCell::new(),
+                    play_session_storage: OnceCell::new(),
+                    instance_lock_service: OnceCell::new(),
+                    disk_usage_service: OnceCell::new(),
                     content_provider_registry: OnceCell::new(),
+                    curseforge_file_resolver: OnceCell::new(),
                     plugin_settings_storage: OnceCell::new(),
                     plugin_registry: OnceCell::new(),
                     plugin_loader_registry: OnceCell::new(),
@@ -212,10 +278,10 @@ impl LazyLocator {
             .clone()
     }
 
-    pub async fn get_credentials_storage(&self) -> Arc<FsCredentialsStorage> {
+    pub async fn get_credentials_storage(&self) -> Arc<EncryptedFsCredentialsStorage> {
         self.credentials_storage
             .get_or_init(|| async {
-                Arc::new(FsCredentialsStorage::new(
+                Arc::new(EncryptedFsCredentialsStorage::with_key_file(
                     self.state.location_info.settings_dir(),
                 ))
             })
@@ -223,6 +289,40 @@ impl LazyLocator {
             .clone()
     }
 
+    pub async fn get_microsoft_auth_service(&self) -> Arc<MicrosoftAuthServiceType> {
+        self.microsoft_auth_service
+            .get_or_init(|| async {
+                Arc::new(MicrosoftAuthClient::new(
+                    MicrosoftAuthConfig::default(),
+                    self.get_api_client().await,
+                ))
+            })
+            .await
+            .clone()
+    }
+
+    pub async fn get_yggdrasil_auth_service(&self) -> Arc<YggdrasilAuthServiceType> {
+        self.yggdrasil_auth_service
+            .get_or_init(|| async {
+                Arc::new(YggdrasilAuthClient::new(self.get_api_client().await))
+            })
+            .await
+            .clone()
+    }
+
+    pub async fn get_token_refresh_service(&self) -> Arc<TokenRefreshServiceType> {
+        self.token_refresh_service
+            .get_or_init(|| async {
+                Arc::new(TokenRefreshService::new(
+                    self.get_credentials_storage().await,
+                    self.get_microsoft_auth_service().await,
+                    self.get_yggdrasil_auth_service().await,
+                ))
+            })
+            .await
+            .clone()
+    }
+
     pub async fn get_settings_storage(&self) -> Arc<FsSettingsStorage> {
         self.settings_storage
             .get_or_init(|| async {
@@ -294,6 +394,56 @@ impl LazyLocator {
             .clone()
     }
 
+    pub async fn get_snapshot_storage(&self) -> Arc<FsSnapshotStorage> {
+        self.snapshot_storage
+            .get_or_init(|| async {
+                Arc::new(FsSnapshotStorage::new(self.state.location_info.clone()))
+            })
+            .await
+            .clone()
+    }
+
+    pub async fn get_world_storage(&self) -> Arc<FsWorldStorage> {
+        self.world_storage
+            .get_or_init(|| async {
+                Arc::new(FsWorldStorage::new(self.state.location_info.clone()))
+            })
+            .await
+            .clone()
+    }
+
+    pub async fn get_server_list_storage(&self) -> Arc<FsServerListStorage> {
+        self.server_list_storage
+            .get_or_init(|| async {
+                Arc::new(FsServerListStorage::new(self.state.location_info.clone()))
+            })
+            .await
+            .clone()
+    }
+
+    pub async fn get_play_session_storage(&self) -> Arc<FsPlaySessionStorage> {
+        self.play_session_storage
+            .get_or_init(|| async {
+                Arc::new(FsPlaySessionStorage::new(self.state.location_info.clone()))
+            })
+            .await
+            .clone()
+    }
+
+    pub async fn get_instance_lock_service(&self) -> Arc<InstanceLockService> {
+        self.instance_lock_service
+            .get_or_init(|| async { Arc::new(InstanceLockService::new()) })
+            .await
+            .clone()
+    }
+
+    pub async fn get_disk_usage_service(&self) -> Arc<DiskUsageService> {
+        self.disk_usage_service
+            .get_or_init(|| async { Arc::new(DiskUsageService::new()) })
+            .await
+            .clone()
+    }
+
     pub async fn get_content_provider_registry(
         &self,
     ) -> Arc<ContentProviderRegistry<ModrinthContentProvider<ReqwestClient<ProgressServiceType>>>>
@@ -315,6 +465,18 @@ impl LazyLocator {
             .clone()
     }
 
+    pub async fn get_curseforge_file_resolver(&self) -> Arc<CurseForgeFileResolverType> {
+        self.curseforge_file_resolver
+            .get_or_init(|| async {
+                Arc::new(CurseForgeApiFileResolver::new(
+                    CurseForgeApiConfig::default(),
+                    self.get_api_client().await,
+                ))
+            })
+            .await
+            .clone()
+    }
+
     pub async fn get_plugin_settings_storage(&self) -> Arc<FsPluginSettingsStorage> {
         self.plugin_settings_storage
             .get_or_init(|| async {
@@ -393,6 +555,8 @@ impl LazyLocator {
             .get_or_try_init(|| async {
                 let watcher = NotifyFileWatcher::new(Arc::new(InstanceEventHandler::new(
                     self.get_event_emitter().await,
+                    self.get_disk_usage_service().await,
+                    self.state.location_info.clone(),
                 )))?;
 
                 let service = InstanceWatcherServiceImpl::new(
@@ -10,6 +10,8 @@ pub struct AccountData {
     pub username: String,
     pub account_type: AccountType,
     pub active: bool,
+    pub needs_relogin: bool,
+    pub auth_server_url: Option<String>,
 }
 
 impl From<&Credentials> for AccountData {
@@ -19,6 +21,8 @@ impl From<&Credentials> for AccountData {
             username: credentials.username.to_string(),
             account_type: credentials.account_type(),
             active: credentials.is_active(),
+            needs_relogin: credentials.needs_relogin(),
+            auth_server_url: credentials.auth_server().map(|server| server.url.clone()),
         }
     }
 }
@@ -1,3 +1,5 @@
 mod account_data;
+mod microsoft_device_code;
 
 pub use account_data::*;
+pub use microsoft_device_code::*;
@@ -1,6 +1,6 @@
 use serr::SerializeError;
 
-use crate::{features::auth::AuthDomainError, shared::IoError};
+use crate::{features::auth::AuthDomainError, libs::request_client::RequestError, shared::IoError};
 
 #[derive(Debug, thiserror::Error, SerializeError)]
 pub enum AuthApplicationError {
@@ -9,4 +9,16 @@ pub enum AuthApplicationError {
 
     #[error("Storage failure: {0}")]
     StorageFailure(#[from] IoError),
+
+    #[error("Request failed: {0}")]
+    RequestFailed(#[from] RequestError),
+
+    #[error("Failed to decrypt credentials, wrong key or corrupted file")]
+    DecryptionFailed,
+
+    #[error("Credentials are protected by a passphrase and must be unlocked")]
+    CredentialsLocked,
+
+    #[error("Failed to encrypt credentials: {reason}")]
+    EncryptionFailed { reason: String },
 }
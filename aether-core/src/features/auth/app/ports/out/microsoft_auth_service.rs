@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+
+use crate::features::auth::{
+    AuthApplicationError, DeviceCodePollResult, MicrosoftDeviceCode, MicrosoftToken,
+    MinecraftProfile, MinecraftToken, XboxToken,
+};
+
+#[async_trait]
+pub trait MicrosoftAuthService: Send + Sync {
+    async fn request_device_code(&self) -> Result<MicrosoftDeviceCode, AuthApplicationError>;
+    async fn poll_device_code(
+        &self,
+        device_code: &str,
+    ) -> Result<DeviceCodePollResult, AuthApplicationError>;
+    async fn refresh_microsoft_token(
+        &self,
+        refresh_token: &str,
+    ) -> Result<MicrosoftToken, AuthApplicationError>;
+
+    async fn authenticate_xbox_live(
+        &self,
+        microsoft_access_token: &str,
+    ) -> Result<XboxToken, AuthApplicationError>;
+    async fn authorize_xsts(
+        &self,
+        xbox_live_token: &str,
+    ) -> Result<XboxToken, AuthApplicationError>;
+
+    async fn login_minecraft(
+        &self,
+        xsts: &XboxToken,
+    ) -> Result<MinecraftToken, AuthApplicationError>;
+    async fn get_minecraft_profile(
+        &self,
+        minecraft_access_token: &str,
+    ) -> Result<MinecraftProfile, AuthApplicationError>;
+}
@@ -1,3 +1,7 @@
 mod credentials_storage;
+mod microsoft_auth_service;
+mod yggdrasil_auth_service;
 
 pub use credentials_storage::*;
+pub use microsoft_auth_service::*;
+pub use yggdrasil_auth_service::*;
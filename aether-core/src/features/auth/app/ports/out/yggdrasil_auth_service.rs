@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::features::auth::{AuthApplicationError, YggdrasilSession};
+
+#[async_trait]
+pub trait YggdrasilAuthService: Send + Sync {
+    async fn get_server_metadata(&self, server_url: &str) -> Result<String, AuthApplicationError>;
+
+    async fn authenticate(
+        &self,
+        server_url: &str,
+        username: &str,
+        password: &str,
+        client_token: &str,
+    ) -> Result<YggdrasilSession, AuthApplicationError>;
+    async fn refresh(
+        &self,
+        server_url: &str,
+        access_token: &str,
+        client_token: &str,
+    ) -> Result<YggdrasilSession, AuthApplicationError>;
+    async fn validate(
+        &self,
+        server_url: &str,
+        access_token: &str,
+        client_token: &str,
+    ) -> Result<bool, AuthApplicationError>;
+}
@@ -31,7 +31,7 @@ impl ActiveAccountHelper {
         let mut target_idx = None;
         for (i, credentials) in all.iter_mut().enumerate() {
             if credentials.id() == id {
-                credentials.activate()?;
+                credentials.activate();
                 target_idx = Some(i);
             } else {
                 credentials.deactivate();
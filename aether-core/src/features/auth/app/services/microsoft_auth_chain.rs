@@ -0,0 +1,30 @@
+use crate::features::auth::{
+    AuthApplicationError, MicrosoftAuthService, MicrosoftSession, MicrosoftToken,
+};
+
+/// Exchanges a Microsoft account token for a Minecraft session
+/// going through Xbox Live, XSTS and Minecraft services.
+pub struct MicrosoftAuthChain;
+
+impl MicrosoftAuthChain {
+    pub async fn authenticate(
+        service: &dyn MicrosoftAuthService,
+        microsoft_token: MicrosoftToken,
+    ) -> Result<MicrosoftSession, AuthApplicationError> {
+        let xbox_live = service
+            .authenticate_xbox_live(&microsoft_token.access_token)
+            .await?;
+        let xsts = service.authorize_xsts(&xbox_live.token).await?;
+
+        let minecraft_token = service.login_minecraft(&xsts).await?;
+        let profile = service
+            .get_minecraft_profile(&minecraft_token.access_token)
+            .await?;
+
+        Ok(MicrosoftSession {
+            profile,
+            minecraft_token,
+            refresh_token: microsoft_token.refresh_token,
+        })
+    }
+}
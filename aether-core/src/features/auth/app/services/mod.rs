@@ -1,3 +1,7 @@
 mod active_account_helper;
+mod microsoft_auth_chain;
+mod token_refresh_service;
 
 pub use active_account_helper::*;
+pub use microsoft_auth_chain::*;
+pub use token_refresh_service::*;
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::{
+    features::auth::{
+        AccountType, AuthApplicationError, AuthDomainError, Credentials, CredentialsStorage,
+        MicrosoftAuthChain, MicrosoftAuthService, YggdrasilAuthService,
+    },
+    libs::request_client::RequestError,
+};
+
+/// Tokens expiring sooner than this are refreshed ahead of time.
+const REFRESH_THRESHOLD: Duration = Duration::hours(1);
+
+pub struct TokenRefreshService<
+    CS: CredentialsStorage,
+    MA: MicrosoftAuthService,
+    YA: YggdrasilAuthService,
+> {
+    credentials_storage: Arc<CS>,
+    microsoft_auth_service: Arc<MA>,
+    yggdrasil_auth_service: Arc<YA>,
+    // Refresh tokens are single use, so concurrent refreshes must not race
+    refresh_lock: Mutex<()>,
+}
+
+impl<CS: CredentialsStorage, MA: MicrosoftAuthService, YA: YggdrasilAuthService>
+    TokenRefreshService<CS, MA, YA>
+{
+    pub fn new(
+        credentials_storage: Arc<CS>,
+        microsoft_auth_service: Arc<MA>,
+        yggdrasil_auth_service: Arc<YA>,
+    ) -> Self {
+        Self {
+            credentials_storage,
+            microsoft_auth_service,
+            yggdrasil_auth_service,
+            refresh_lock: Mutex::new(()),
+        }
+    }
+
+    pub async fn refresh_active(&self) -> Result<Option<Credentials>, AuthApplicationError> {
+        let Some(active) = self.credentials_storage.find_active().await? else {
+            return Ok(None);
+        };
+
+        self.refresh_if_needed(active.id()).await.map(Some)
+    }
+
+    pub async fn refresh_if_needed(&self, id: Uuid) -> Result<Credentials, AuthApplicationError> {
+        let _guard = self.refresh_lock.lock().await;
+
+        // Re-read under the lock, a concurrent refresh may have already updated the tokens
+        let mut credentials = self.credentials_storage.get(id).await?;
+
+        // Yggdrasil tokens have no known expiry, so they are validated against the server
+        match credentials.account_type() {
+            AccountType::Offline => return Ok(credentials),
+            AccountType::Microsoft if !credentials.expires_within(REFRESH_THRESHOLD) => {
+                return Ok(credentials)
+            }
+            AccountType::Microsoft | AccountType::Yggdrasil => {}
+        }
+
+        if credentials.needs_relogin() {
+            return Err(AuthDomainError::ReloginRequired { id }.into());
+        }
+
+        let result = match credentials.account_type() {
+            AccountType::Yggdrasil => self.refresh_yggdrasil(&mut credentials).await,
+            _ => self.refresh_microsoft(&mut credentials).await,
+        };
+
+        match result {
+            Ok(()) => Ok(self.credentials_storage.upsert(credentials).await?),
+            // Network failures are transient, keep the account as is
+            Err(AuthApplicationError::RequestFailed(err)) if is_transient(&err) => {
+                Err(AuthApplicationError::RequestFailed(err))
+            }
+            Err(err) => {
+                tracing::warn!("Failed to refresh credentials {id}: {err}");
+
+                credentials.mark_needs_relogin();
+                self.credentials_storage.upsert(credentials).await?;
+
+                Err(AuthDomainError::ReloginRequired { id }.into())
+            }
+        }
+    }
+
+    async fn refresh_microsoft(
+        &self,
+        credentials: &mut Credentials,
+    ) -> Result<(), AuthApplicationError> {
+        let microsoft_token = self
+            .microsoft_auth_service
+            .refresh_microsoft_token(credentials.refresh_token())
+            .await?;
+
+        // The old refresh token is already spent, losing the new one would force a relogin
+        // if the rest of the chain fails
+        credentials.update_refresh_token(microsoft_token.refresh_token.clone());
+        *credentials = self.credentials_storage.upsert(credentials.clone()).await?;
+
+        MicrosoftAuthChain::authenticate(self.microsoft_auth_service.as_ref(), microsoft_token)
+            .await?
+            .apply_to(credentials)?;
+
+        Ok(())
+    }
+
+    async fn refresh_yggdrasil(
+        &self,
+        credentials: &mut Credentials,
+    ) -> Result<(), AuthApplicationError> {
+        let Some(server_url) = credentials.auth_server().map(|server| server.url.clone()) else {
+            return Err(AuthDomainError::YggdrasilAuthFailed {
+                reason: "Authentication server is not set".to_string(),
+            }
+            .into());
+        };
+
+        // Metadata is optional here, stale metadata is better than a failed launch
+        let metadata = self
+            .yggdrasil_auth_service
+            .get_server_metadata(&server_url)
+            .await
+            .ok();
+
+        let is_valid = self
+            .yggdrasil_auth_service
+            .validate(
+                &server_url,
+                credentials.access_token(),
+                credentials.refresh_token(),
+            )
+            .await?;
+
+        let (access_token, client_token) = if is_valid {
+            (
+                credentials.access_token().to_string(),
+                credentials.refresh_token().to_string(),
+            )
+        } else {
+            let session = self
+                .yggdrasil_auth_service
+                .refresh(
+                    &server_url,
+                    credentials.access_token(),
+                    credentials.refresh_token(),
+                )
+                .await?;
+
+            (session.access_token, session.client_token)
+        };
+
+        credentials.update_yggdrasil_session(access_token, client_token, metadata);
+
+        Ok(())
+    }
+}
+
+/// Failures to reach the server, as opposed to the server answering with something unexpected
+fn is_transient(error: &RequestError) -> bool {
+    matches!(
+        error,
+        RequestError::AcquireError(_)
+            | RequestError::RequestSendError(_)
+            | RequestError::MiddlewareError(_)
+    )
+}
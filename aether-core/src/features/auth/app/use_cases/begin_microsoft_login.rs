@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use crate::features::auth::{AuthApplicationError, MicrosoftAuthService, MicrosoftDeviceCode};
+
+pub struct BeginMicrosoftLoginUseCase<MA: MicrosoftAuthService> {
+    microsoft_auth_service: Arc<MA>,
+}
+
+impl<MA: MicrosoftAuthService> BeginMicrosoftLoginUseCase<MA> {
+    pub fn new(microsoft_auth_service: Arc<MA>) -> Self {
+        Self {
+            microsoft_auth_service,
+        }
+    }
+
+    pub async fn execute(&self) -> Result<MicrosoftDeviceCode, AuthApplicationError> {
+        self.microsoft_auth_service.request_device_code().await
+    }
+}
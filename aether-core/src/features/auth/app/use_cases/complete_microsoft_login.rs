@@ -0,0 +1,65 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::time::Instant;
+
+use crate::features::auth::{
+    ActiveAccountHelper, AuthApplicationError, AuthDomainError, CredentialsStorage,
+    DeviceCodePollResult, MicrosoftAuthChain, MicrosoftAuthService, MicrosoftDeviceCode,
+};
+
+use super::super::AccountData;
+
+const SLOW_DOWN_INCREMENT: Duration = Duration::from_secs(5);
+
+pub struct CompleteMicrosoftLoginUseCase<CS: CredentialsStorage, MA: MicrosoftAuthService> {
+    credentials_storage: Arc<CS>,
+    microsoft_auth_service: Arc<MA>,
+}
+
+impl<CS: CredentialsStorage, MA: MicrosoftAuthService> CompleteMicrosoftLoginUseCase<CS, MA> {
+    pub fn new(credentials_storage: Arc<CS>, microsoft_auth_service: Arc<MA>) -> Self {
+        Self {
+            credentials_storage,
+            microsoft_auth_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        device_code: MicrosoftDeviceCode,
+    ) -> Result<AccountData, AuthApplicationError> {
+        let deadline = Instant::now() + Duration::from_secs(device_code.expires_in);
+        let mut interval = Duration::from_secs(device_code.interval);
+
+        let microsoft_token = loop {
+            if Instant::now() >= deadline {
+                return Err(AuthDomainError::DeviceCodeExpired.into());
+            }
+
+            match self
+                .microsoft_auth_service
+                .poll_device_code(&device_code.device_code)
+                .await?
+            {
+                DeviceCodePollResult::Authorized(token) => break token,
+                DeviceCodePollResult::Pending => {}
+                DeviceCodePollResult::SlowDown => interval += SLOW_DOWN_INCREMENT,
+            }
+
+            tokio::time::sleep(interval).await;
+        };
+
+        let credentials =
+            MicrosoftAuthChain::authenticate(self.microsoft_auth_service.as_ref(), microsoft_token)
+                .await?
+                .into_credentials()?;
+
+        let id = credentials.id();
+        self.credentials_storage.upsert(credentials).await?;
+
+        let account =
+            ActiveAccountHelper::set_active(self.credentials_storage.as_ref(), id).await?;
+
+        Ok(AccountData::from(account))
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::features::auth::{
+    ActiveAccountHelper, AuthApplicationError, AuthServer, CredentialsStorage, YggdrasilAuthService,
+};
+
+use super::super::AccountData;
+
+pub struct CreateYggdrasilAccountUseCase<CS: CredentialsStorage, YA: YggdrasilAuthService> {
+    credentials_storage: Arc<CS>,
+    yggdrasil_auth_service: Arc<YA>,
+}
+
+impl<CS: CredentialsStorage, YA: YggdrasilAuthService> CreateYggdrasilAccountUseCase<CS, YA> {
+    pub fn new(credentials_storage: Arc<CS>, yggdrasil_auth_service: Arc<YA>) -> Self {
+        Self {
+            credentials_storage,
+            yggdrasil_auth_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        server_url: String,
+        username: String,
+        password: String,
+    ) -> Result<AccountData, AuthApplicationError> {
+        let server_url = server_url.trim_end_matches('/').to_string();
+
+        let metadata = self
+            .yggdrasil_auth_service
+            .get_server_metadata(&server_url)
+            .await?;
+
+        let client_token = Uuid::new_v4().simple().to_string();
+
+        let session = self
+            .yggdrasil_auth_service
+            .authenticate(&server_url, &username, &password, &client_token)
+            .await?;
+
+        let credentials = session.into_credentials(AuthServer {
+            url: server_url,
+            metadata,
+        })?;
+
+        let id = credentials.id();
+        self.credentials_storage.upsert(credentials).await?;
+
+        let account =
+            ActiveAccountHelper::set_active(self.credentials_storage.as_ref(), id).await?;
+
+        Ok(AccountData::from(account))
+    }
+}
@@ -1,9 +1,15 @@
+mod begin_microsoft_login;
+mod complete_microsoft_login;
 mod create_offline_account;
+mod create_yggdrasil_account;
 mod get_accounts;
 mod logout;
 mod set_active_account;
 
+pub use begin_microsoft_login::*;
+pub use complete_microsoft_login::*;
 pub use create_offline_account::*;
+pub use create_yggdrasil_account::*;
 pub use get_accounts::*;
 pub use logout::*;
 pub use set_active_account::*;
@@ -15,6 +15,27 @@ pub enum AuthDomainError {
     #[error("Invalid username chars")]
     InvalidUsernameChars,
 
-    #[error("Session expired, please login again")]
-    TokenExpired,
+    #[error("Account {id} needs to login again")]
+    ReloginRequired { id: Uuid },
+
+    #[error("Login code expired, please try again")]
+    DeviceCodeExpired,
+
+    #[error("Login was declined by the user")]
+    AuthorizationDeclined,
+
+    #[error("Microsoft authentication failed: {reason}")]
+    MicrosoftAuthFailed { reason: String },
+
+    #[error("Xbox Live authorization failed: {reason}")]
+    XboxAuthorizationFailed { reason: String },
+
+    #[error("This account does not own Minecraft")]
+    MinecraftProfileNotFound,
+
+    #[error("Authentication server error: {reason}")]
+    YggdrasilAuthFailed { reason: String },
+
+    #[error("Authentication server returned no selected profile")]
+    YggdrasilProfileNotSelected,
 }
@@ -2,9 +2,10 @@ use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::features::auth::AuthDomainError;
+use super::{AuthServer, Username};
 
-use super::Username;
+/// Lifetime for tokens that are never expired locally, their validity is checked by the server
+const NEVER_EXPIRES: Duration = Duration::days(365 * 99);
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -16,6 +17,10 @@ pub struct Credentials {
     access_token: String,
     refresh_token: String,
     expires: DateTime<Utc>,
+    #[serde(default)]
+    needs_relogin: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    auth_server: Option<AuthServer>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -23,6 +28,7 @@ pub struct Credentials {
 pub enum AccountType {
     Offline,
     Microsoft,
+    Yggdrasil,
 }
 
 impl Credentials {
@@ -34,7 +40,50 @@ impl Credentials {
             account_type: AccountType::Offline,
             access_token: "null".to_string(),
             refresh_token: "null".to_string(),
-            expires: Utc::now() + Duration::days(365 * 99),
+            expires: Utc::now() + NEVER_EXPIRES,
+            needs_relogin: false,
+            auth_server: None,
+        }
+    }
+
+    pub fn new_microsoft(
+        id: Uuid,
+        username: Username,
+        access_token: String,
+        refresh_token: String,
+        expires_in: i64,
+    ) -> Self {
+        Self {
+            id,
+            username,
+            active: false,
+            account_type: AccountType::Microsoft,
+            access_token,
+            refresh_token,
+            expires: Utc::now() + Duration::seconds(expires_in),
+            needs_relogin: false,
+            auth_server: None,
+        }
+    }
+
+    /// Yggdrasil has no refresh token, the client token is kept in its place
+    pub fn new_yggdrasil(
+        id: Uuid,
+        username: Username,
+        access_token: String,
+        client_token: String,
+        auth_server: AuthServer,
+    ) -> Self {
+        Self {
+            id,
+            username,
+            active: false,
+            account_type: AccountType::Yggdrasil,
+            access_token,
+            refresh_token: client_token,
+            expires: Utc::now() + NEVER_EXPIRES,
+            needs_relogin: false,
+            auth_server: Some(auth_server),
         }
     }
 
@@ -50,23 +99,29 @@ impl Credentials {
     pub fn access_token(&self) -> &str {
         &self.access_token
     }
+    pub fn refresh_token(&self) -> &str {
+        &self.refresh_token
+    }
+    pub fn expires(&self) -> &DateTime<Utc> {
+        &self.expires
+    }
     pub fn is_expired(&self) -> bool {
         Utc::now() > self.expires
     }
+    pub fn expires_within(&self, duration: Duration) -> bool {
+        Utc::now() + duration > self.expires
+    }
+    pub fn needs_relogin(&self) -> bool {
+        self.needs_relogin
+    }
+    pub fn auth_server(&self) -> Option<&AuthServer> {
+        self.auth_server.as_ref()
+    }
 
-    pub fn activate(&mut self) -> Result<(), AuthDomainError> {
-        match self.account_type {
-            AccountType::Microsoft => {
-                if self.is_expired() {
-                    return Err(AuthDomainError::TokenExpired);
-                }
-            }
-            AccountType::Offline => (),
-        };
-
+    /// Expired Microsoft tokens don't block selecting the account,
+    /// they are refreshed before launch
+    pub fn activate(&mut self) {
         self.active = true;
-
-        Ok(())
     }
 
     pub fn deactivate(&mut self) {
@@ -77,5 +132,28 @@ impl Credentials {
         self.access_token = access;
         self.refresh_token = refresh;
         self.expires = Utc::now() + chrono::Duration::seconds(expires_in);
+        self.needs_relogin = false;
+    }
+
+    /// Microsoft refresh tokens are single use, the new one is stored as soon as it is received
+    pub fn update_refresh_token(&mut self, refresh: String) {
+        self.refresh_token = refresh;
+    }
+
+    pub fn update_yggdrasil_session(
+        &mut self,
+        access: String,
+        client: String,
+        metadata: Option<String>,
+    ) {
+        self.update_tokens(access, client, NEVER_EXPIRES.num_seconds());
+
+        if let (Some(auth_server), Some(metadata)) = (self.auth_server.as_mut(), metadata) {
+            auth_server.metadata = metadata;
+        }
+    }
+
+    pub fn mark_needs_relogin(&mut self) {
+        self.needs_relogin = true;
     }
 }
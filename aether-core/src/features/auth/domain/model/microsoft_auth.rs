@@ -0,0 +1,67 @@
+use uuid::Uuid;
+
+use crate::features::auth::AuthDomainError;
+
+use super::{Credentials, Username};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MicrosoftToken {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XboxToken {
+    pub token: String,
+    pub user_hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinecraftToken {
+    pub access_token: String,
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinecraftProfile {
+    pub id: Uuid,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceCodePollResult {
+    Pending,
+    SlowDown,
+    Authorized(MicrosoftToken),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MicrosoftSession {
+    pub profile: MinecraftProfile,
+    pub minecraft_token: MinecraftToken,
+    pub refresh_token: String,
+}
+
+impl MicrosoftSession {
+    pub fn into_credentials(self) -> Result<Credentials, AuthDomainError> {
+        Ok(Credentials::new_microsoft(
+            self.profile.id,
+            Username::parse(&self.profile.name)?,
+            self.minecraft_token.access_token,
+            self.refresh_token,
+            self.minecraft_token.expires_in,
+        ))
+    }
+
+    pub fn apply_to(self, credentials: &mut Credentials) -> Result<(), AuthDomainError> {
+        credentials.username = Username::parse(&self.profile.name)?;
+        credentials.update_tokens(
+            self.minecraft_token.access_token,
+            self.refresh_token,
+            self.minecraft_token.expires_in,
+        );
+
+        Ok(())
+    }
+}
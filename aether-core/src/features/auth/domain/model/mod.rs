@@ -1,5 +1,9 @@
 mod credentials;
+mod microsoft_auth;
 mod username;
+mod yggdrasil;
 
 pub use credentials::*;
+pub use microsoft_auth::*;
 pub use username::*;
+pub use yggdrasil::*;
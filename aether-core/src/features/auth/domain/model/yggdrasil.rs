@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::auth::AuthDomainError;
+
+use super::{Credentials, MinecraftProfile, Username};
+
+/// Third-party Yggdrasil-compatible authentication server.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthServer {
+    /// API root, e.g. `https://example.com/api/yggdrasil`
+    pub url: String,
+    /// Raw JSON returned by the API root, passed to authlib-injector at launch
+    pub metadata: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YggdrasilSession {
+    pub access_token: String,
+    pub client_token: String,
+    pub profile: MinecraftProfile,
+}
+
+impl YggdrasilSession {
+    pub fn into_credentials(self, auth_server: AuthServer) -> Result<Credentials, AuthDomainError> {
+        Ok(Credentials::new_yggdrasil(
+            self.profile.id,
+            Username::parse(&self.profile.name)?,
+            self.access_token,
+            self.client_token,
+            auth_server,
+        ))
+    }
+}
@@ -0,0 +1,488 @@
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use argon2::Argon2;
+use async_trait::async_trait;
+use chacha20poly1305::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng, Payload},
+    Key, XChaCha20Poly1305, XNonce,
+};
+use tokio::{io::AsyncWriteExt, sync::Mutex};
+use uuid::Uuid;
+
+use crate::{
+    features::auth::{AuthApplicationError, AuthDomainError, Credentials, CredentialsStorage},
+    shared::{
+        create_dir_all, read_async, read_json_async, remove_file, rename, IoError, UpdateAction,
+    },
+};
+
+const MAGIC: &[u8; 8] = b"AETHERCR";
+const FORMAT_VERSION: u8 = 1;
+
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const HEADER_LEN: usize = MAGIC.len() + 2 + SALT_LEN + NONCE_LEN;
+
+const KEY_FILE_KDF_ID: u8 = 0;
+const PASSPHRASE_KDF_ID: u8 = 1;
+
+/// Where the credentials encryption key comes from.
+#[derive(Clone)]
+pub enum CredentialsKeySource {
+    /// Random key stored in a file, generated on first use
+    KeyFile(PathBuf),
+    /// Key derived from a user passphrase with Argon2id
+    Passphrase(String),
+}
+
+impl CredentialsKeySource {
+    fn kdf_id(&self) -> u8 {
+        match self {
+            CredentialsKeySource::KeyFile(_) => KEY_FILE_KDF_ID,
+            CredentialsKeySource::Passphrase(_) => PASSPHRASE_KDF_ID,
+        }
+    }
+}
+
+struct DerivedKey {
+    salt: [u8; SALT_LEN],
+    key: [u8; KEY_LEN],
+}
+
+/// Stores credentials encrypted with XChaCha20-Poly1305.
+///
+/// File layout: magic, format version, kdf id, salt, nonce, ciphertext.
+/// The header is authenticated together with the ciphertext.
+/// An existing plaintext `credentials.json` is migrated on first access.
+///
+/// Credentials protected by a passphrase stay locked until [`Self::unlock`] is called,
+/// the mode is read from the file header so no separate setting is needed.
+pub struct EncryptedFsCredentialsStorage {
+    path: PathBuf,
+    plaintext_path: PathBuf,
+    key_file_path: PathBuf,
+    // Guards file access, the key source and caches the last derived key
+    lock: Mutex<KeyState>,
+}
+
+struct KeyState {
+    key_source: CredentialsKeySource,
+    cached: Option<DerivedKey>,
+}
+
+impl EncryptedFsCredentialsStorage {
+    pub fn new(settings_dir: &Path, key_source: CredentialsKeySource) -> Self {
+        let key_file_path = match &key_source {
+            CredentialsKeySource::KeyFile(path) => path.clone(),
+            CredentialsKeySource::Passphrase(_) => settings_dir.join("credentials.key"),
+        };
+
+        Self {
+            path: settings_dir.join("credentials.enc"),
+            plaintext_path: settings_dir.join("credentials.json"),
+            key_file_path,
+            lock: Mutex::new(KeyState {
+                key_source,
+                cached: None,
+            }),
+        }
+    }
+
+    pub fn with_key_file(settings_dir: &Path) -> Self {
+        Self::new(
+            settings_dir,
+            CredentialsKeySource::KeyFile(settings_dir.join("credentials.key")),
+        )
+    }
+
+    /// Whether stored credentials need a passphrase that was not provided yet
+    pub async fn is_locked(&self) -> Result<bool, AuthApplicationError> {
+        let state = self.lock.lock().await;
+
+        Ok(self.read_kdf_id().await? == Some(PASSPHRASE_KDF_ID)
+            && state.key_source.kdf_id() != PASSPHRASE_KDF_ID)
+    }
+
+    /// Provides the passphrase for credentials protected by one.
+    /// The previous key source is kept if the passphrase is wrong.
+    pub async fn unlock(&self, passphrase: String) -> Result<(), AuthApplicationError> {
+        let mut state = self.lock.lock().await;
+
+        // Nothing to unlock, trying would migrate plaintext credentials to the passphrase
+        if self.read_kdf_id().await? != Some(PASSPHRASE_KDF_ID) {
+            return Ok(());
+        }
+
+        let mut unlocked = KeyState {
+            key_source: CredentialsKeySource::Passphrase(passphrase),
+            cached: None,
+        };
+        self.read_locked(&mut unlocked).await?;
+
+        *state = unlocked;
+
+        Ok(())
+    }
+
+    /// Re-encrypts stored credentials with a passphrase, or with the key file when `None`
+    pub async fn set_passphrase(
+        &self,
+        passphrase: Option<String>,
+    ) -> Result<(), AuthApplicationError> {
+        let mut state = self.lock.lock().await;
+
+        let data = self.read_locked(&mut state).await?;
+
+        let mut updated = KeyState {
+            key_source: match passphrase {
+                Some(passphrase) => CredentialsKeySource::Passphrase(passphrase),
+                None => CredentialsKeySource::KeyFile(self.key_file_path.clone()),
+            },
+            cached: None,
+        };
+        self.write_locked(&mut updated, &data).await?;
+
+        *state = updated;
+
+        tracing::info!("Changed credentials encryption key");
+
+        Ok(())
+    }
+
+    async fn read_kdf_id(&self) -> Result<Option<u8>, AuthApplicationError> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+
+        Ok(read_async(&self.path).await?.get(MAGIC.len() + 1).copied())
+    }
+
+    async fn derive_key(
+        key_source: &CredentialsKeySource,
+        salt: [u8; SALT_LEN],
+    ) -> Result<DerivedKey, AuthApplicationError> {
+        let key = match key_source {
+            CredentialsKeySource::KeyFile(path) => Self::read_or_create_key_file(path).await?,
+            CredentialsKeySource::Passphrase(passphrase) => {
+                let passphrase = passphrase.clone();
+
+                tokio::task::spawn_blocking(move || {
+                    let mut key = [0u8; KEY_LEN];
+                    Argon2::default()
+                        .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+                        .map(|_| key)
+                })
+                .await
+                .map_err(|err| AuthApplicationError::EncryptionFailed {
+                    reason: err.to_string(),
+                })?
+                .map_err(|err| AuthApplicationError::EncryptionFailed {
+                    reason: err.to_string(),
+                })?
+            }
+        };
+
+        Ok(DerivedKey { salt, key })
+    }
+
+    async fn read_or_create_key_file(path: &Path) -> Result<[u8; KEY_LEN], AuthApplicationError> {
+        if path.exists() {
+            return read_async(path)
+                .await?
+                .try_into()
+                .map_err(|_| AuthApplicationError::DecryptionFailed);
+        }
+
+        let mut key = [0u8; KEY_LEN];
+        OsRng.fill_bytes(&mut key);
+
+        write_private_file(path, &key).await?;
+
+        Ok(key)
+    }
+
+    async fn get_key(
+        state: &mut KeyState,
+        salt: Option<[u8; SALT_LEN]>,
+    ) -> Result<[u8; KEY_LEN], AuthApplicationError> {
+        match (state.cached.as_ref(), salt) {
+            (Some(derived), None) => return Ok(derived.key),
+            (Some(derived), Some(salt)) if derived.salt == salt => return Ok(derived.key),
+            _ => {}
+        }
+
+        let salt = salt.unwrap_or_else(|| {
+            let mut salt = [0u8; SALT_LEN];
+            OsRng.fill_bytes(&mut salt);
+            salt
+        });
+
+        let derived = Self::derive_key(&state.key_source, salt).await?;
+        let key = derived.key;
+        state.cached = Some(derived);
+
+        Ok(key)
+    }
+
+    async fn read_locked(
+        &self,
+        state: &mut KeyState,
+    ) -> Result<Vec<Credentials>, AuthApplicationError> {
+        if !self.path.exists() {
+            return self.migrate_plaintext(state).await;
+        }
+
+        let bytes = read_async(&self.path).await?;
+
+        if bytes.len() < HEADER_LEN
+            || &bytes[..MAGIC.len()] != MAGIC
+            || bytes[MAGIC.len()] != FORMAT_VERSION
+        {
+            return Err(AuthApplicationError::DecryptionFailed);
+        }
+
+        if bytes[MAGIC.len() + 1] != state.key_source.kdf_id() {
+            return Err(if bytes[MAGIC.len() + 1] == PASSPHRASE_KDF_ID {
+                AuthApplicationError::CredentialsLocked
+            } else {
+                AuthApplicationError::DecryptionFailed
+            });
+        }
+
+        let (header, ciphertext) = bytes.split_at(HEADER_LEN);
+        let salt_start = MAGIC.len() + 2;
+        let nonce_start = salt_start + SALT_LEN;
+
+        let mut salt = [0u8; SALT_LEN];
+        salt.copy_from_slice(&header[salt_start..nonce_start]);
+
+        let key = Self::get_key(state, Some(salt)).await?;
+
+        let plaintext = XChaCha20Poly1305::new(Key::from_slice(&key))
+            .decrypt(
+                XNonce::from_slice(&header[nonce_start..]),
+                Payload {
+                    msg: ciphertext,
+                    aad: header,
+                },
+            )
+            .map_err(|_| AuthApplicationError::DecryptionFailed)?;
+
+        serde_json::from_slice(&plaintext)
+            .map_err(|err| IoError::DeserializationError(err.to_string()).into())
+    }
+
+    async fn write_locked(
+        &self,
+        state: &mut KeyState,
+        data: &[Credentials],
+    ) -> Result<(), AuthApplicationError> {
+        let key = Self::get_key(state, None).await?;
+        let salt = state
+            .cached
+            .as_ref()
+            .map(|derived| derived.salt)
+            .unwrap_or_default();
+
+        let plaintext =
+            serde_json::to_vec(data).map_err(|err| IoError::SerializationError(err.to_string()))?;
+
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+        let mut header = Vec::with_capacity(HEADER_LEN);
+        header.extend_from_slice(MAGIC);
+        header.push(FORMAT_VERSION);
+        header.push(state.key_source.kdf_id());
+        header.extend_from_slice(&salt);
+        header.extend_from_slice(&nonce);
+
+        let ciphertext = XChaCha20Poly1305::new(Key::from_slice(&key))
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: &plaintext,
+                    aad: &header,
+                },
+            )
+            .map_err(|err| AuthApplicationError::EncryptionFailed {
+                reason: err.to_string(),
+            })?;
+
+        header.extend_from_slice(&ciphertext);
+        write_private_file(&self.path, &header).await?;
+
+        Ok(())
+    }
+
+    async fn migrate_plaintext(
+        &self,
+        state: &mut KeyState,
+    ) -> Result<Vec<Credentials>, AuthApplicationError> {
+        if !self.plaintext_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let credentials: Vec<Credentials> = read_json_async(&self.plaintext_path).await?;
+        self.write_locked(state, &credentials).await?;
+        remove_file(&self.plaintext_path).await?;
+
+        tracing::info!("Migrated plaintext credentials to encrypted storage");
+
+        Ok(credentials)
+    }
+
+    async fn update<F, R>(&self, f: F) -> Result<R, AuthApplicationError>
+    where
+        F: FnOnce(&mut Vec<Credentials>) -> UpdateAction<R>,
+    {
+        let mut state = self.lock.lock().await;
+
+        let mut data = self.read_locked(&mut state).await?;
+
+        match f(&mut data) {
+            UpdateAction::Save(result) => {
+                self.write_locked(&mut state, &data).await?;
+                Ok(result)
+            }
+            UpdateAction::NoChanges(result) => Ok(result),
+        }
+    }
+
+    async fn read_all(&self) -> Result<Vec<Credentials>, AuthApplicationError> {
+        let mut state = self.lock.lock().await;
+        self.read_locked(&mut state).await
+    }
+}
+
+/// Writes a file only the current user can read. The data goes to a temporary file first,
+/// so the key is never readable by others and a crash never leaves a truncated file behind.
+async fn write_private_file(path: &Path, data: &[u8]) -> Result<(), IoError> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).await?;
+    }
+
+    let mut tmp_path = OsString::from(path.as_os_str());
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+
+    // A leftover from an interrupted write may have other permissions
+    if tmp_path.exists() {
+        remove_file(&tmp_path).await?;
+    }
+
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    let mut file = options
+        .open(&tmp_path)
+        .await
+        .map_err(|err| IoError::with_path(err, &tmp_path))?;
+
+    file.write_all(data)
+        .await
+        .map_err(|err| IoError::with_path(err, &tmp_path))?;
+    file.sync_all()
+        .await
+        .map_err(|err| IoError::with_path(err, &tmp_path))?;
+    drop(file);
+
+    rename(&tmp_path, path).await
+}
+
+#[async_trait]
+impl CredentialsStorage for EncryptedFsCredentialsStorage {
+    async fn list(&self) -> Result<Vec<Credentials>, AuthApplicationError> {
+        self.read_all().await
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Credentials, AuthApplicationError> {
+        let list = self.read_all().await?;
+
+        list.into_iter()
+            .find(|x| x.id() == id)
+            .ok_or(AuthApplicationError::Domain(
+                AuthDomainError::CredentialsNotFound { id },
+            ))
+    }
+
+    async fn upsert(&self, credentials: Credentials) -> Result<Credentials, AuthApplicationError> {
+        self.update(|list| {
+            if let Some(existing) = list.iter_mut().find(|c| c.id() == credentials.id()) {
+                if existing == &credentials {
+                    return UpdateAction::NoChanges(credentials);
+                }
+                *existing = credentials.clone();
+            } else {
+                list.push(credentials.clone());
+            }
+            UpdateAction::Save(credentials)
+        })
+        .await
+    }
+
+    async fn upsert_all(
+        &self,
+        credentials_list: Vec<Credentials>,
+    ) -> Result<(), AuthApplicationError> {
+        self.update(|current| {
+            let mut changed = false;
+
+            for new_item in credentials_list {
+                if let Some(existing) = current.iter_mut().find(|c| c.id() == new_item.id()) {
+                    if existing != &new_item {
+                        *existing = new_item;
+                        changed = true;
+                    }
+                } else {
+                    current.push(new_item);
+                    changed = true;
+                }
+            }
+
+            if changed {
+                UpdateAction::Save(())
+            } else {
+                UpdateAction::NoChanges(())
+            }
+        })
+        .await
+    }
+
+    async fn remove(&self, id: Uuid) -> Result<(), AuthApplicationError> {
+        let found = self
+            .update(|list| {
+                let prev_len = list.len();
+                list.retain(|c| c.id() != id);
+
+                if list.len() < prev_len {
+                    UpdateAction::Save(true)
+                } else {
+                    UpdateAction::NoChanges(false)
+                }
+            })
+            .await?;
+
+        if !found {
+            return Err(AuthApplicationError::Domain(
+                AuthDomainError::CredentialsNotFound { id },
+            ));
+        }
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<(), AuthApplicationError> {
+        let mut state = self.lock.lock().await;
+        self.write_locked(&mut state, &[]).await
+    }
+
+    async fn find_active(&self) -> Result<Option<Credentials>, AuthApplicationError> {
+        let list = self.read_all().await?;
+        Ok(list.into_iter().find(|x| x.is_active()))
+    }
+}
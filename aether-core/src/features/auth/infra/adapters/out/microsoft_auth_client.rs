@@ -0,0 +1,404 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    features::auth::{
+        AuthApplicationError, AuthDomainError, DeviceCodePollResult, MicrosoftAuthService,
+        MicrosoftDeviceCode, MicrosoftToken, MinecraftProfile, MinecraftToken, XboxToken,
+    },
+    libs::request_client::{Request, RequestClient, RequestClientExt},
+};
+
+const MICROSOFT_SCOPE: &str = "XboxLive.signin offline_access";
+const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// Base URLs of every service taking part in the Microsoft login chain.
+/// Override them to run the chain against a local stub server.
+#[derive(Debug, Clone)]
+pub struct MicrosoftAuthEndpoints {
+    pub microsoft_oauth_url: String,
+    pub xbox_live_url: String,
+    pub xsts_url: String,
+    pub minecraft_services_url: String,
+}
+
+impl Default for MicrosoftAuthEndpoints {
+    fn default() -> Self {
+        Self {
+            microsoft_oauth_url: "https://login.microsoftonline.com/consumers/oauth2/v2.0"
+                .to_string(),
+            xbox_live_url: "https://user.auth.xboxlive.com".to_string(),
+            xsts_url: "https://xsts.auth.xboxlive.com".to_string(),
+            minecraft_services_url: "https://api.minecraftservices.com".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MicrosoftAuthConfig {
+    pub client_id: String,
+    pub endpoints: MicrosoftAuthEndpoints,
+}
+
+impl Default for MicrosoftAuthConfig {
+    fn default() -> Self {
+        Self {
+            client_id: option_env!("AETHER_MICROSOFT_CLIENT_ID")
+                .unwrap_or_default()
+                .to_string(),
+            endpoints: MicrosoftAuthEndpoints::default(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DeviceCodeForm<'a> {
+    client_id: &'a str,
+    scope: &'a str,
+}
+
+#[derive(Serialize)]
+struct DeviceTokenForm<'a> {
+    grant_type: &'a str,
+    client_id: &'a str,
+    device_code: &'a str,
+}
+
+#[derive(Serialize)]
+struct RefreshTokenForm<'a> {
+    grant_type: &'a str,
+    client_id: &'a str,
+    refresh_token: &'a str,
+    scope: &'a str,
+}
+
+#[derive(Deserialize)]
+struct DeviceCodeResponse {
+    user_code: String,
+    device_code: String,
+    verification_uri: String,
+    expires_in: u64,
+    interval: u64,
+    message: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OAuthTokenResponse {
+    Success {
+        access_token: String,
+        refresh_token: String,
+        expires_in: i64,
+    },
+    Error {
+        error: String,
+        error_description: Option<String>,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum XboxResponse {
+    Success {
+        #[serde(rename = "Token")]
+        token: String,
+        #[serde(rename = "DisplayClaims")]
+        display_claims: XboxDisplayClaims,
+    },
+    Error {
+        #[serde(rename = "XErr")]
+        xerr: u64,
+    },
+}
+
+#[derive(Deserialize)]
+struct XboxDisplayClaims {
+    xui: Vec<XboxUserInfo>,
+}
+
+#[derive(Deserialize)]
+struct XboxUserInfo {
+    uhs: String,
+}
+
+#[derive(Deserialize)]
+struct MinecraftLoginResponse {
+    access_token: String,
+    expires_in: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MinecraftProfileResponse {
+    Success { id: Uuid, name: String },
+    Error { error: String },
+}
+
+pub struct MicrosoftAuthClient<RC: RequestClient> {
+    config: MicrosoftAuthConfig,
+    request_client: Arc<RC>,
+}
+
+impl<RC: RequestClient> MicrosoftAuthClient<RC> {
+    pub fn new(config: MicrosoftAuthConfig, request_client: Arc<RC>) -> Self {
+        Self {
+            config,
+            request_client,
+        }
+    }
+
+    fn form_request<T: Serialize>(url: String, form: &T) -> Result<Request, AuthApplicationError> {
+        let body =
+            serde_qs::to_string(form).map_err(|err| AuthDomainError::MicrosoftAuthFailed {
+                reason: err.to_string(),
+            })?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        );
+
+        Ok(Request::post(url)
+            .with_headers(headers)
+            .with_body(body.into_bytes()))
+    }
+
+    fn json_request(url: String, body: serde_json::Value) -> Request {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+
+        Request::post(url)
+            .with_headers(headers)
+            .with_body(body.to_string().into_bytes())
+    }
+
+    fn parse_xbox_response(response: XboxResponse) -> Result<XboxToken, AuthApplicationError> {
+        match response {
+            XboxResponse::Success {
+                token,
+                display_claims,
+            } => {
+                let user_hash = display_claims
+                    .xui
+                    .into_iter()
+                    .next()
+                    .map(|info| info.uhs)
+                    .ok_or_else(|| AuthDomainError::XboxAuthorizationFailed {
+                        reason: "User hash is missing".to_string(),
+                    })?;
+
+                Ok(XboxToken { token, user_hash })
+            }
+            XboxResponse::Error { xerr } => Err(AuthDomainError::XboxAuthorizationFailed {
+                reason: get_xbox_error_reason(xerr),
+            }
+            .into()),
+        }
+    }
+}
+
+#[async_trait]
+impl<RC: RequestClient> MicrosoftAuthService for MicrosoftAuthClient<RC> {
+    async fn request_device_code(&self) -> Result<MicrosoftDeviceCode, AuthApplicationError> {
+        let request = Self::form_request(
+            format!("{}/devicecode", self.config.endpoints.microsoft_oauth_url),
+            &DeviceCodeForm {
+                client_id: &self.config.client_id,
+                scope: MICROSOFT_SCOPE,
+            },
+        )?;
+
+        let response: DeviceCodeResponse = self.request_client.fetch_json(request).await?;
+
+        Ok(MicrosoftDeviceCode {
+            user_code: response.user_code,
+            device_code: response.device_code,
+            verification_uri: response.verification_uri,
+            expires_in: response.expires_in,
+            interval: response.interval,
+            message: response.message,
+        })
+    }
+
+    async fn poll_device_code(
+        &self,
+        device_code: &str,
+    ) -> Result<DeviceCodePollResult, AuthApplicationError> {
+        let request = Self::form_request(
+            format!("{}/token", self.config.endpoints.microsoft_oauth_url),
+            &DeviceTokenForm {
+                grant_type: DEVICE_CODE_GRANT_TYPE,
+                client_id: &self.config.client_id,
+                device_code,
+            },
+        )?;
+
+        let response: OAuthTokenResponse = self.request_client.fetch_json(request).await?;
+
+        match response {
+            OAuthTokenResponse::Success {
+                access_token,
+                refresh_token,
+                expires_in,
+            } => Ok(DeviceCodePollResult::Authorized(MicrosoftToken {
+                access_token,
+                refresh_token,
+                expires_in,
+            })),
+            OAuthTokenResponse::Error {
+                error,
+                error_description,
+            } => match error.as_str() {
+                "authorization_pending" => Ok(DeviceCodePollResult::Pending),
+                "slow_down" => Ok(DeviceCodePollResult::SlowDown),
+                "expired_token" => Err(AuthDomainError::DeviceCodeExpired.into()),
+                "authorization_declined" => Err(AuthDomainError::AuthorizationDeclined.into()),
+                _ => Err(AuthDomainError::MicrosoftAuthFailed {
+                    reason: error_description.unwrap_or(error),
+                }
+                .into()),
+            },
+        }
+    }
+
+    async fn refresh_microsoft_token(
+        &self,
+        refresh_token: &str,
+    ) -> Result<MicrosoftToken, AuthApplicationError> {
+        let request = Self::form_request(
+            format!("{}/token", self.config.endpoints.microsoft_oauth_url),
+            &RefreshTokenForm {
+                grant_type: "refresh_token",
+                client_id: &self.config.client_id,
+                refresh_token,
+                scope: MICROSOFT_SCOPE,
+            },
+        )?;
+
+        match self.request_client.fetch_json(request).await? {
+            OAuthTokenResponse::Success {
+                access_token,
+                refresh_token,
+                expires_in,
+            } => Ok(MicrosoftToken {
+                access_token,
+                refresh_token,
+                expires_in,
+            }),
+            OAuthTokenResponse::Error {
+                error,
+                error_description,
+            } => Err(AuthDomainError::MicrosoftAuthFailed {
+                reason: error_description.unwrap_or(error),
+            }
+            .into()),
+        }
+    }
+
+    async fn authenticate_xbox_live(
+        &self,
+        microsoft_access_token: &str,
+    ) -> Result<XboxToken, AuthApplicationError> {
+        let request = Self::json_request(
+            format!("{}/user/authenticate", self.config.endpoints.xbox_live_url),
+            json!({
+                "Properties": {
+                    "AuthMethod": "RPS",
+                    "SiteName": "user.auth.xboxlive.com",
+                    "RpsTicket": format!("d={microsoft_access_token}"),
+                },
+                "RelyingParty": "http://auth.xboxlive.com",
+                "TokenType": "JWT",
+            }),
+        );
+
+        Self::parse_xbox_response(self.request_client.fetch_json(request).await?)
+    }
+
+    async fn authorize_xsts(
+        &self,
+        xbox_live_token: &str,
+    ) -> Result<XboxToken, AuthApplicationError> {
+        let request = Self::json_request(
+            format!("{}/xsts/authorize", self.config.endpoints.xsts_url),
+            json!({
+                "Properties": {
+                    "SandboxId": "RETAIL",
+                    "UserTokens": [xbox_live_token],
+                },
+                "RelyingParty": "rp://api.minecraftservices.com/",
+                "TokenType": "JWT",
+            }),
+        );
+
+        Self::parse_xbox_response(self.request_client.fetch_json(request).await?)
+    }
+
+    async fn login_minecraft(
+        &self,
+        xsts: &XboxToken,
+    ) -> Result<MinecraftToken, AuthApplicationError> {
+        let request = Self::json_request(
+            format!(
+                "{}/authentication/login_with_xbox",
+                self.config.endpoints.minecraft_services_url
+            ),
+            json!({
+                "identityToken": format!("XBL3.0 x={};{}", xsts.user_hash, xsts.token),
+            }),
+        );
+
+        let response: MinecraftLoginResponse = self.request_client.fetch_json(request).await?;
+
+        Ok(MinecraftToken {
+            access_token: response.access_token,
+            expires_in: response.expires_in,
+        })
+    }
+
+    async fn get_minecraft_profile(
+        &self,
+        minecraft_access_token: &str,
+    ) -> Result<MinecraftProfile, AuthApplicationError> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {minecraft_access_token}")).map_err(|err| {
+                AuthDomainError::MicrosoftAuthFailed {
+                    reason: err.to_string(),
+                }
+            })?,
+        );
+
+        let request = Request::get(format!(
+            "{}/minecraft/profile",
+            self.config.endpoints.minecraft_services_url
+        ))
+        .with_headers(headers);
+
+        match self.request_client.fetch_json(request).await? {
+            MinecraftProfileResponse::Success { id, name } => Ok(MinecraftProfile { id, name }),
+            MinecraftProfileResponse::Error { error } => {
+                tracing::debug!("Failed to get Minecraft profile: {error}");
+                Err(AuthDomainError::MinecraftProfileNotFound.into())
+            }
+        }
+    }
+}
+
+fn get_xbox_error_reason(xerr: u64) -> String {
+    match xerr {
+        2148916233 => "This Microsoft account does not have an Xbox profile".to_string(),
+        2148916235 => "Xbox Live is not available in your country".to_string(),
+        2148916236 | 2148916237 => "This account needs adult verification".to_string(),
+        2148916238 => "This account is a child account and must be added to a family".to_string(),
+        _ => format!("Unknown error code {xerr}"),
+    }
+}
@@ -1,3 +1,9 @@
+mod encrypted_fs_credentials_storage;
 mod fs_credentials_storage;
+mod microsoft_auth_client;
+mod yggdrasil_auth_client;
 
+pub use encrypted_fs_credentials_storage::*;
 pub use fs_credentials_storage::FsCredentialsStorage;
+pub use microsoft_auth_client::*;
+pub use yggdrasil_auth_client::*;
@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use serde::Deserialize;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    features::auth::{
+        AuthApplicationError, AuthDomainError, MinecraftProfile, YggdrasilAuthService,
+        YggdrasilSession,
+    },
+    libs::request_client::{Request, RequestClient, RequestClientExt},
+};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum YggdrasilResponse {
+    Success {
+        #[serde(rename = "accessToken")]
+        access_token: String,
+        #[serde(rename = "clientToken")]
+        client_token: String,
+        #[serde(rename = "selectedProfile")]
+        selected_profile: Option<YggdrasilProfile>,
+    },
+    Error {
+        error: String,
+        #[serde(rename = "errorMessage")]
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Deserialize)]
+struct YggdrasilProfile {
+    id: Uuid,
+    name: String,
+}
+
+/// Client for Yggdrasil-compatible authentication servers (authlib-injector API).
+pub struct YggdrasilAuthClient<RC: RequestClient> {
+    request_client: Arc<RC>,
+}
+
+impl<RC: RequestClient> YggdrasilAuthClient<RC> {
+    pub fn new(request_client: Arc<RC>) -> Self {
+        Self { request_client }
+    }
+
+    fn json_request(url: String, body: serde_json::Value) -> Request {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        Request::post(url)
+            .with_headers(headers)
+            .with_body(body.to_string().into_bytes())
+    }
+
+    fn parse_session(
+        response: YggdrasilResponse,
+    ) -> Result<YggdrasilSession, AuthApplicationError> {
+        match response {
+            YggdrasilResponse::Success {
+                access_token,
+                client_token,
+                selected_profile,
+            } => {
+                let profile =
+                    selected_profile.ok_or(AuthDomainError::YggdrasilProfileNotSelected)?;
+
+                Ok(YggdrasilSession {
+                    access_token,
+                    client_token,
+                    profile: MinecraftProfile {
+                        id: profile.id,
+                        name: profile.name,
+                    },
+                })
+            }
+            YggdrasilResponse::Error {
+                error,
+                error_message,
+            } => Err(AuthDomainError::YggdrasilAuthFailed {
+                reason: error_message.unwrap_or(error),
+            }
+            .into()),
+        }
+    }
+}
+
+#[async_trait]
+impl<RC: RequestClient> YggdrasilAuthService for YggdrasilAuthClient<RC> {
+    async fn get_server_metadata(&self, server_url: &str) -> Result<String, AuthApplicationError> {
+        let bytes = self
+            .request_client
+            .fetch_bytes(Request::get(server_url))
+            .await?;
+
+        // Make sure the server answered with JSON before passing it to authlib-injector
+        let metadata: serde_json::Value =
+            serde_json::from_slice(&bytes).map_err(|_| AuthDomainError::YggdrasilAuthFailed {
+                reason: format!("{server_url} is not a Yggdrasil API root"),
+            })?;
+
+        Ok(metadata.to_string())
+    }
+
+    async fn authenticate(
+        &self,
+        server_url: &str,
+        username: &str,
+        password: &str,
+        client_token: &str,
+    ) -> Result<YggdrasilSession, AuthApplicationError> {
+        let request = Self::json_request(
+            format!("{server_url}/authserver/authenticate"),
+            json!({
+                "agent": { "name": "Minecraft", "version": 1 },
+                "username": username,
+                "password": password,
+                "clientToken": client_token,
+                "requestUser": false,
+            }),
+        );
+
+        Self::parse_session(self.request_client.fetch_json(request).await?)
+    }
+
+    async fn refresh(
+        &self,
+        server_url: &str,
+        access_token: &str,
+        client_token: &str,
+    ) -> Result<YggdrasilSession, AuthApplicationError> {
+        let request = Self::json_request(
+            format!("{server_url}/authserver/refresh"),
+            json!({
+                "accessToken": access_token,
+                "clientToken": client_token,
+                "requestUser": false,
+            }),
+        );
+
+        Self::parse_session(self.request_client.fetch_json(request).await?)
+    }
+
+    async fn validate(
+        &self,
+        server_url: &str,
+        access_token: &str,
+        client_token: &str,
+    ) -> Result<bool, AuthApplicationError> {
+        let request = Self::json_request(
+            format!("{server_url}/authserver/validate"),
+            json!({
+                "accessToken": access_token,
+                "clientToken": client_token,
+            }),
+        );
+
+        // Valid tokens are answered with 204 No Content, invalid ones with an error body
+        let bytes = self.request_client.fetch_bytes(request).await?;
+
+        Ok(bytes.is_empty())
+    }
+}
@@ -6,4 +6,4 @@ pub use app::*;
 pub use domain::*;
 
 #[cfg(test)]
-mod tests;
+pub mod tests;
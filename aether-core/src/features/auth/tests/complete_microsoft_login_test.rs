@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::features::auth::{
+    AccountType, AuthApplicationError, AuthDomainError, CompleteMicrosoftLoginUseCase,
+    CredentialsStorage, DeviceCodePollResult,
+};
+
+use super::fixtures::{CredentialsBuilder, MockCredentialsStorage, MockMicrosoftAuthService};
+
+#[tokio::test]
+async fn test_complete_microsoft_login_saves_and_activates_account() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let offline = CredentialsBuilder::new("OfflineUser")
+        .with_active(true)
+        .build();
+    storage.upsert(offline.clone()).await.unwrap();
+
+    let profile_id = Uuid::new_v4();
+    let auth_service = Arc::new(MockMicrosoftAuthService::new(profile_id, "OnlineUser"));
+
+    let use_case = CompleteMicrosoftLoginUseCase::new(storage.clone(), auth_service);
+
+    let account = use_case
+        .execute(MockMicrosoftAuthService::device_code())
+        .await
+        .unwrap();
+
+    assert_eq!(account.id, profile_id);
+    assert_eq!(account.username, "OnlineUser");
+    assert_eq!(account.account_type, AccountType::Microsoft);
+    assert!(account.active);
+
+    let saved = storage.get(profile_id).await.unwrap();
+    assert_eq!(saved.access_token(), "minecraft-access");
+    assert!(!storage.get(offline.id()).await.unwrap().is_active());
+}
+
+#[tokio::test]
+async fn test_complete_microsoft_login_waits_for_authorization() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let profile_id = Uuid::new_v4();
+    let auth_service = Arc::new(
+        MockMicrosoftAuthService::new(profile_id, "OnlineUser").with_poll_results(vec![
+            DeviceCodePollResult::Pending,
+            DeviceCodePollResult::Pending,
+            DeviceCodePollResult::Authorized(MockMicrosoftAuthService::microsoft_token()),
+        ]),
+    );
+
+    let use_case = CompleteMicrosoftLoginUseCase::new(storage.clone(), auth_service);
+
+    let account = use_case
+        .execute(MockMicrosoftAuthService::device_code())
+        .await
+        .unwrap();
+
+    assert_eq!(account.id, profile_id);
+}
+
+#[tokio::test]
+async fn test_complete_microsoft_login_fails_when_code_expires() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let auth_service = Arc::new(
+        MockMicrosoftAuthService::new(Uuid::new_v4(), "OnlineUser")
+            .with_poll_results(vec![DeviceCodePollResult::Pending]),
+    );
+
+    let use_case = CompleteMicrosoftLoginUseCase::new(storage.clone(), auth_service);
+
+    let mut device_code = MockMicrosoftAuthService::device_code();
+    device_code.expires_in = 0;
+
+    let result = use_case.execute(device_code).await;
+
+    assert!(matches!(
+        result,
+        Err(AuthApplicationError::Domain(
+            AuthDomainError::DeviceCodeExpired
+        ))
+    ));
+    assert!(storage.list().await.unwrap().is_empty());
+}
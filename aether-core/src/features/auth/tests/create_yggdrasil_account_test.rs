@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::features::auth::{AccountType, CreateYggdrasilAccountUseCase, CredentialsStorage};
+
+use super::fixtures::{MockCredentialsStorage, MockYggdrasilAuthService, MOCK_YGGDRASIL_METADATA};
+
+#[tokio::test]
+async fn test_create_yggdrasil_account() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let profile_id = Uuid::new_v4();
+    let auth_service = Arc::new(MockYggdrasilAuthService::new(
+        profile_id, "SkinUser", "secret",
+    ));
+
+    let use_case = CreateYggdrasilAccountUseCase::new(storage.clone(), auth_service);
+
+    let account = use_case
+        .execute(
+            "https://auth.example.com/api/yggdrasil/".to_string(),
+            "SkinUser".to_string(),
+            "secret".to_string(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(account.id, profile_id);
+    assert_eq!(account.account_type, AccountType::Yggdrasil);
+    assert_eq!(
+        account.auth_server_url.as_deref(),
+        Some("https://auth.example.com/api/yggdrasil")
+    );
+    assert!(account.active);
+
+    let saved = storage.get(profile_id).await.unwrap();
+    assert_eq!(saved.access_token(), "yggdrasil-access");
+    assert_eq!(
+        saved.auth_server().unwrap().metadata,
+        MOCK_YGGDRASIL_METADATA
+    );
+}
+
+#[tokio::test]
+async fn test_create_yggdrasil_account_with_wrong_password() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let auth_service = Arc::new(MockYggdrasilAuthService::new(
+        Uuid::new_v4(),
+        "SkinUser",
+        "secret",
+    ));
+
+    let use_case = CreateYggdrasilAccountUseCase::new(storage.clone(), auth_service);
+
+    let result = use_case
+        .execute(
+            "https://auth.example.com/api/yggdrasil".to_string(),
+            "SkinUser".to_string(),
+            "wrong".to_string(),
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert!(storage.list().await.unwrap().is_empty());
+}
@@ -0,0 +1,131 @@
+use crate::{
+    features::auth::{
+        infra::{CredentialsKeySource, EncryptedFsCredentialsStorage},
+        AuthApplicationError, CredentialsStorage,
+    },
+    shared::write_json_async,
+};
+
+use super::fixtures::CredentialsBuilder;
+
+#[tokio::test]
+async fn test_encrypted_storage_roundtrip() {
+    let dir = tempfile::tempdir().unwrap();
+    let storage = EncryptedFsCredentialsStorage::with_key_file(dir.path());
+
+    let credentials = CredentialsBuilder::new("User1").build();
+    storage.upsert(credentials.clone()).await.unwrap();
+
+    let raw = std::fs::read(dir.path().join("credentials.enc")).unwrap();
+    assert!(!raw.windows(5).any(|window| window == b"User1"));
+
+    let reopened = EncryptedFsCredentialsStorage::with_key_file(dir.path());
+    assert_eq!(reopened.list().await.unwrap(), vec![credentials]);
+}
+
+#[tokio::test]
+async fn test_encrypted_storage_migrates_plaintext() {
+    let dir = tempfile::tempdir().unwrap();
+    let credentials = CredentialsBuilder::new("User1").build();
+    write_json_async(
+        dir.path().join("credentials.json"),
+        vec![credentials.clone()],
+    )
+    .await
+    .unwrap();
+
+    let storage = EncryptedFsCredentialsStorage::with_key_file(dir.path());
+
+    assert_eq!(storage.list().await.unwrap(), vec![credentials]);
+    assert!(!dir.path().join("credentials.json").exists());
+    assert!(dir.path().join("credentials.enc").exists());
+}
+
+#[tokio::test]
+async fn test_encrypted_storage_wrong_passphrase() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let storage = EncryptedFsCredentialsStorage::new(
+        dir.path(),
+        CredentialsKeySource::Passphrase("correct horse".to_string()),
+    );
+    storage
+        .upsert(CredentialsBuilder::new("User1").build())
+        .await
+        .unwrap();
+
+    let wrong = EncryptedFsCredentialsStorage::new(
+        dir.path(),
+        CredentialsKeySource::Passphrase("battery staple".to_string()),
+    );
+
+    assert!(matches!(
+        wrong.list().await,
+        Err(AuthApplicationError::DecryptionFailed)
+    ));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_encrypted_storage_files_are_private() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let storage = EncryptedFsCredentialsStorage::with_key_file(dir.path());
+    storage
+        .upsert(CredentialsBuilder::new("User1").build())
+        .await
+        .unwrap();
+
+    for file in ["credentials.key", "credentials.enc"] {
+        let mode = std::fs::metadata(dir.path().join(file))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600, "{file}");
+    }
+
+    let leftovers: Vec<_> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().ends_with(".tmp"))
+        .collect();
+    assert!(leftovers.is_empty());
+}
+
+#[tokio::test]
+async fn test_encrypted_storage_passphrase_lock_and_unlock() {
+    let dir = tempfile::tempdir().unwrap();
+    let credentials = CredentialsBuilder::new("User1").build();
+
+    let storage = EncryptedFsCredentialsStorage::with_key_file(dir.path());
+    storage.upsert(credentials.clone()).await.unwrap();
+    storage
+        .set_passphrase(Some("correct horse".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(storage.list().await.unwrap(), vec![credentials.clone()]);
+
+    let reopened = EncryptedFsCredentialsStorage::with_key_file(dir.path());
+    assert!(reopened.is_locked().await.unwrap());
+    assert!(matches!(
+        reopened.list().await,
+        Err(AuthApplicationError::CredentialsLocked)
+    ));
+
+    assert!(matches!(
+        reopened.unlock("battery staple".to_string()).await,
+        Err(AuthApplicationError::DecryptionFailed)
+    ));
+    assert!(reopened.is_locked().await.unwrap());
+
+    reopened.unlock("correct horse".to_string()).await.unwrap();
+    assert!(!reopened.is_locked().await.unwrap());
+    assert_eq!(reopened.list().await.unwrap(), vec![credentials.clone()]);
+
+    reopened.set_passphrase(None).await.unwrap();
+
+    let key_file = EncryptedFsCredentialsStorage::with_key_file(dir.path());
+    assert!(!key_file.is_locked().await.unwrap());
+    assert_eq!(key_file.list().await.unwrap(), vec![credentials]);
+}
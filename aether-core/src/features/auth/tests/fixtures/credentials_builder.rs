@@ -1,4 +1,4 @@
-use crate::features::auth::{AccountType, Credentials, Username};
+use crate::features::auth::{AccountType, AuthServer, Credentials, Username};
 use uuid::Uuid;
 
 /// Builder for constructing test Credentials with customizable fields.
@@ -8,6 +8,7 @@ pub struct CredentialsBuilder {
     username: String,
     active: bool,
     account_type: AccountType,
+    expires_in: i64,
 }
 
 impl CredentialsBuilder {
@@ -17,6 +18,7 @@ impl CredentialsBuilder {
             username: username.to_string(),
             active: false,
             account_type: AccountType::Offline,
+            expires_in: 86400,
         }
     }
 
@@ -30,19 +32,44 @@ impl CredentialsBuilder {
         self
     }
 
+    pub fn with_microsoft(mut self, expires_in: i64) -> Self {
+        self.account_type = AccountType::Microsoft;
+        self.expires_in = expires_in;
+        self
+    }
+
+    pub fn with_yggdrasil(mut self) -> Self {
+        self.account_type = AccountType::Yggdrasil;
+        self
+    }
+
     pub fn build(self) -> Credentials {
         let mut credentials = match self.account_type {
             AccountType::Offline => Credentials::new_offline(
                 self.id,
                 Username::parse(&self.username).expect("Invalid username in test builder"),
             ),
-            AccountType::Microsoft => panic!("Unsupported account type"),
+            AccountType::Microsoft => Credentials::new_microsoft(
+                self.id,
+                Username::parse(&self.username).expect("Invalid username in test builder"),
+                "access".to_string(),
+                "refresh".to_string(),
+                self.expires_in,
+            ),
+            AccountType::Yggdrasil => Credentials::new_yggdrasil(
+                self.id,
+                Username::parse(&self.username).expect("Invalid username in test builder"),
+                "access".to_string(),
+                "client".to_string(),
+                AuthServer {
+                    url: "https://auth.example.com/api/yggdrasil".to_string(),
+                    metadata: "{}".to_string(),
+                },
+            ),
         };
 
         if self.active {
-            credentials
-                .activate()
-                .expect("Failed to activate credentials in builder");
+            credentials.activate();
         }
 
         credentials
@@ -0,0 +1,182 @@
+use crate::features::auth::*;
+use crate::libs::request_client::RequestError;
+use async_trait::async_trait;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tokio::sync::Semaphore;
+use uuid::Uuid;
+
+/// Request failures the mock can simulate
+#[derive(Debug, Clone, Copy)]
+pub enum MockRequestFailure {
+    Network,
+    InvalidResponse,
+}
+
+impl MockRequestFailure {
+    async fn to_error(self) -> AuthApplicationError {
+        let error = match self {
+            // Acquiring a closed semaphore is the simplest way to get a non parse error
+            Self::Network => {
+                let semaphore = Semaphore::new(0);
+                semaphore.close();
+                RequestError::from(semaphore.acquire().await.unwrap_err())
+            }
+            Self::InvalidResponse => {
+                RequestError::from(serde_json::from_str::<()>("<html>").unwrap_err())
+            }
+        };
+
+        AuthApplicationError::RequestFailed(error)
+    }
+}
+
+/// Mock of the Microsoft login chain.
+/// Device code polls are answered from a queue, the last answer is repeated.
+pub struct MockMicrosoftAuthService {
+    profile: MinecraftProfile,
+    poll_results: Mutex<VecDeque<DeviceCodePollResult>>,
+    refresh_fails: bool,
+    refresh_failure: Option<MockRequestFailure>,
+    xbox_live_failure: Option<MockRequestFailure>,
+}
+
+impl MockMicrosoftAuthService {
+    pub fn new(profile_id: Uuid, profile_name: &str) -> Self {
+        Self {
+            profile: MinecraftProfile {
+                id: profile_id,
+                name: profile_name.to_string(),
+            },
+            poll_results: Mutex::new(VecDeque::from([DeviceCodePollResult::Authorized(
+                Self::microsoft_token(),
+            )])),
+            refresh_fails: false,
+            refresh_failure: None,
+            xbox_live_failure: None,
+        }
+    }
+
+    pub fn with_failing_refresh(mut self) -> Self {
+        self.refresh_fails = true;
+        self
+    }
+
+    pub fn with_refresh_failure(mut self, failure: MockRequestFailure) -> Self {
+        self.refresh_failure = Some(failure);
+        self
+    }
+
+    pub fn with_xbox_live_failure(mut self, failure: MockRequestFailure) -> Self {
+        self.xbox_live_failure = Some(failure);
+        self
+    }
+
+    pub fn with_poll_results(self, poll_results: Vec<DeviceCodePollResult>) -> Self {
+        *self.poll_results.lock().unwrap() = poll_results.into();
+        self
+    }
+
+    pub fn device_code() -> MicrosoftDeviceCode {
+        MicrosoftDeviceCode {
+            user_code: "ABCD1234".to_string(),
+            device_code: "device-code".to_string(),
+            verification_uri: "https://www.microsoft.com/link".to_string(),
+            expires_in: 900,
+            interval: 0,
+            message: None,
+        }
+    }
+
+    pub fn microsoft_token() -> MicrosoftToken {
+        MicrosoftToken {
+            access_token: "microsoft-access".to_string(),
+            refresh_token: "microsoft-refresh".to_string(),
+            expires_in: 3600,
+        }
+    }
+}
+
+#[async_trait]
+impl MicrosoftAuthService for MockMicrosoftAuthService {
+    async fn request_device_code(&self) -> Result<MicrosoftDeviceCode, AuthApplicationError> {
+        Ok(Self::device_code())
+    }
+
+    async fn poll_device_code(
+        &self,
+        _device_code: &str,
+    ) -> Result<DeviceCodePollResult, AuthApplicationError> {
+        let mut poll_results = self.poll_results.lock().unwrap();
+
+        let result = if poll_results.len() > 1 {
+            poll_results.pop_front()
+        } else {
+            poll_results.front().cloned()
+        };
+
+        result.ok_or(AuthApplicationError::Domain(
+            AuthDomainError::DeviceCodeExpired,
+        ))
+    }
+
+    async fn refresh_microsoft_token(
+        &self,
+        _refresh_token: &str,
+    ) -> Result<MicrosoftToken, AuthApplicationError> {
+        if self.refresh_fails {
+            return Err(AuthApplicationError::Domain(
+                AuthDomainError::MicrosoftAuthFailed {
+                    reason: "invalid_grant".to_string(),
+                },
+            ));
+        }
+
+        if let Some(failure) = self.refresh_failure {
+            return Err(failure.to_error().await);
+        }
+
+        Ok(Self::microsoft_token())
+    }
+
+    async fn authenticate_xbox_live(
+        &self,
+        _microsoft_access_token: &str,
+    ) -> Result<XboxToken, AuthApplicationError> {
+        if let Some(failure) = self.xbox_live_failure {
+            return Err(failure.to_error().await);
+        }
+
+        Ok(XboxToken {
+            token: "xbox-live".to_string(),
+            user_hash: "user-hash".to_string(),
+        })
+    }
+
+    async fn authorize_xsts(
+        &self,
+        _xbox_live_token: &str,
+    ) -> Result<XboxToken, AuthApplicationError> {
+        Ok(XboxToken {
+            token: "xsts".to_string(),
+            user_hash: "user-hash".to_string(),
+        })
+    }
+
+    async fn login_minecraft(
+        &self,
+        _xsts: &XboxToken,
+    ) -> Result<MinecraftToken, AuthApplicationError> {
+        Ok(MinecraftToken {
+            access_token: "minecraft-access".to_string(),
+            expires_in: 86400,
+        })
+    }
+
+    async fn get_minecraft_profile(
+        &self,
+        _minecraft_access_token: &str,
+    ) -> Result<MinecraftProfile, AuthApplicationError> {
+        Ok(self.profile.clone())
+    }
+}
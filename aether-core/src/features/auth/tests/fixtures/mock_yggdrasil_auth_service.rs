@@ -0,0 +1,81 @@
+use crate::features::auth::*;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+pub const MOCK_YGGDRASIL_METADATA: &str = r#"{"meta":{"serverName":"Mock"}}"#;
+
+/// Mock Yggdrasil server accepting a single username/password pair.
+pub struct MockYggdrasilAuthService {
+    profile: MinecraftProfile,
+    password: String,
+    tokens_valid: bool,
+}
+
+impl MockYggdrasilAuthService {
+    pub fn new(profile_id: Uuid, profile_name: &str, password: &str) -> Self {
+        Self {
+            profile: MinecraftProfile {
+                id: profile_id,
+                name: profile_name.to_string(),
+            },
+            password: password.to_string(),
+            tokens_valid: true,
+        }
+    }
+
+    pub fn with_invalid_tokens(mut self) -> Self {
+        self.tokens_valid = false;
+        self
+    }
+
+    fn session(&self, client_token: &str) -> YggdrasilSession {
+        YggdrasilSession {
+            access_token: "yggdrasil-access".to_string(),
+            client_token: client_token.to_string(),
+            profile: self.profile.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl YggdrasilAuthService for MockYggdrasilAuthService {
+    async fn get_server_metadata(&self, _server_url: &str) -> Result<String, AuthApplicationError> {
+        Ok(MOCK_YGGDRASIL_METADATA.to_string())
+    }
+
+    async fn authenticate(
+        &self,
+        _server_url: &str,
+        username: &str,
+        password: &str,
+        client_token: &str,
+    ) -> Result<YggdrasilSession, AuthApplicationError> {
+        if username != self.profile.name || password != self.password {
+            return Err(AuthApplicationError::Domain(
+                AuthDomainError::YggdrasilAuthFailed {
+                    reason: "Invalid credentials".to_string(),
+                },
+            ));
+        }
+
+        Ok(self.session(client_token))
+    }
+
+    async fn refresh(
+        &self,
+        _server_url: &str,
+        _access_token: &str,
+        client_token: &str,
+    ) -> Result<YggdrasilSession, AuthApplicationError> {
+        Ok(self.session(client_token))
+    }
+
+    async fn validate(
+        &self,
+        _server_url: &str,
+        _access_token: &str,
+        _client_token: &str,
+    ) -> Result<bool, AuthApplicationError> {
+        Ok(self.tokens_valid)
+    }
+}
@@ -1,5 +1,9 @@
 mod credentials_builder;
 mod mock_credentials_storage;
+mod mock_microsoft_auth_service;
+mod mock_yggdrasil_auth_service;
 
 pub use credentials_builder::*;
 pub use mock_credentials_storage::*;
+pub use mock_microsoft_auth_service::*;
+pub use mock_yggdrasil_auth_service::*;
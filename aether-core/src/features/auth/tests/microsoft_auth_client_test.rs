@@ -0,0 +1,223 @@
+use std::{sync::Arc, time::Duration};
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    features::auth::{
+        infra::{MicrosoftAuthClient, MicrosoftAuthConfig, MicrosoftAuthEndpoints},
+        AuthApplicationError, AuthDomainError, CompleteMicrosoftLoginUseCase, CredentialsStorage,
+        DeviceCodePollResult, MicrosoftAuthService,
+    },
+    libs::request_client::tests::fixtures::{StubHttpServer, StubRequestClient},
+};
+
+use super::fixtures::MockCredentialsStorage;
+
+fn create_client(server: &StubHttpServer) -> MicrosoftAuthClient<StubRequestClient> {
+    MicrosoftAuthClient::new(
+        MicrosoftAuthConfig {
+            client_id: "client-id".to_string(),
+            endpoints: MicrosoftAuthEndpoints {
+                microsoft_oauth_url: format!("{}/oauth", server.url()),
+                xbox_live_url: format!("{}/xbl", server.url()),
+                xsts_url: format!("{}/xsts", server.url()),
+                minecraft_services_url: format!("{}/mc", server.url()),
+            },
+        },
+        server.client(),
+    )
+}
+
+fn respond_device_code(server: &StubHttpServer, interval: u64) {
+    server.respond_json(
+        "/oauth/devicecode",
+        json!({
+            "user_code": "ABCD-1234",
+            "device_code": "device-code",
+            "verification_uri": "https://microsoft.com/link",
+            "expires_in": 900,
+            "interval": interval,
+            "message": "Enter the code",
+        }),
+    );
+}
+
+fn respond_token_error(server: &StubHttpServer, error: &str) {
+    server.respond(
+        "/oauth/token",
+        400,
+        json!({ "error": error, "error_description": format!("{error} description") }).to_string(),
+    );
+}
+
+fn respond_token(server: &StubHttpServer) {
+    server.respond_json(
+        "/oauth/token",
+        json!({
+            "access_token": "microsoft-access",
+            "refresh_token": "microsoft-refresh",
+            "expires_in": 3600,
+        }),
+    );
+}
+
+fn respond_login_chain(server: &StubHttpServer, profile_id: Uuid) {
+    server.respond_json(
+        "/xbl/user/authenticate",
+        json!({ "Token": "xbl-token", "DisplayClaims": { "xui": [{ "uhs": "user-hash" }] } }),
+    );
+    server.respond_json(
+        "/xsts/xsts/authorize",
+        json!({ "Token": "xsts-token", "DisplayClaims": { "xui": [{ "uhs": "user-hash" }] } }),
+    );
+    server.respond_json(
+        "/mc/authentication/login_with_xbox",
+        json!({ "access_token": "minecraft-access", "expires_in": 86400 }),
+    );
+    server.respond_json(
+        "/mc/minecraft/profile",
+        json!({ "id": profile_id.simple().to_string(), "name": "OnlineUser" }),
+    );
+}
+
+#[tokio::test]
+async fn test_request_device_code_parses_response() {
+    let server = StubHttpServer::start().await;
+    respond_device_code(&server, 5);
+
+    let device_code = create_client(&server).request_device_code().await.unwrap();
+
+    assert_eq!(device_code.user_code, "ABCD-1234");
+    assert_eq!(device_code.device_code, "device-code");
+    assert_eq!(device_code.verification_uri, "https://microsoft.com/link");
+    assert_eq!(device_code.expires_in, 900);
+    assert_eq!(device_code.interval, 5);
+    assert_eq!(device_code.message.as_deref(), Some("Enter the code"));
+
+    let requests = server.requests("/oauth/devicecode");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+
+    let form = String::from_utf8(requests[0].body.clone()).unwrap();
+    assert!(form.contains("client_id=client-id"));
+    assert!(form.contains("scope="));
+}
+
+#[tokio::test]
+async fn test_poll_device_code_maps_oauth_errors() {
+    let server = StubHttpServer::start().await;
+    let client = create_client(&server);
+
+    respond_token_error(&server, "authorization_pending");
+    respond_token_error(&server, "slow_down");
+    respond_token_error(&server, "authorization_declined");
+    respond_token_error(&server, "expired_token");
+
+    assert!(matches!(
+        client.poll_device_code("device-code").await,
+        Ok(DeviceCodePollResult::Pending)
+    ));
+    assert!(matches!(
+        client.poll_device_code("device-code").await,
+        Ok(DeviceCodePollResult::SlowDown)
+    ));
+    assert!(matches!(
+        client.poll_device_code("device-code").await,
+        Err(AuthApplicationError::Domain(
+            AuthDomainError::AuthorizationDeclined
+        ))
+    ));
+    assert!(matches!(
+        client.poll_device_code("device-code").await,
+        Err(AuthApplicationError::Domain(
+            AuthDomainError::DeviceCodeExpired
+        ))
+    ));
+
+    let form = String::from_utf8(server.requests("/oauth/token")[0].body.clone()).unwrap();
+    assert!(form.contains("device_code=device-code"));
+    assert!(form.contains("grant_type=urn"));
+}
+
+#[tokio::test]
+async fn test_complete_login_polls_until_authorized() {
+    let server = StubHttpServer::start().await;
+    let client = Arc::new(create_client(&server));
+    let profile_id = Uuid::new_v4();
+
+    respond_device_code(&server, 1);
+    respond_token_error(&server, "authorization_pending");
+    respond_token_error(&server, "authorization_pending");
+    respond_token(&server);
+    respond_login_chain(&server, profile_id);
+
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let device_code = client.request_device_code().await.unwrap();
+
+    let account = CompleteMicrosoftLoginUseCase::new(storage.clone(), client)
+        .execute(device_code)
+        .await
+        .unwrap();
+
+    assert_eq!(account.id, profile_id);
+    assert_eq!(account.username, "OnlineUser");
+    assert!(account.active);
+
+    let credentials = storage.get(profile_id).await.unwrap();
+    assert_eq!(credentials.access_token(), "minecraft-access");
+
+    let polls = server.requests("/oauth/token");
+    assert_eq!(polls.len(), 3);
+    for pair in polls.windows(2) {
+        assert!(pair[1].received - pair[0].received >= Duration::from_secs(1));
+    }
+
+    let login = String::from_utf8(
+        server.requests("/mc/authentication/login_with_xbox")[0]
+            .body
+            .clone(),
+    )
+    .unwrap();
+    assert!(login.contains("XBL3.0 x=user-hash;xsts-token"));
+}
+
+#[tokio::test]
+async fn test_complete_login_slows_down_polling() {
+    let server = StubHttpServer::start().await;
+    let client = Arc::new(create_client(&server));
+    let profile_id = Uuid::new_v4();
+
+    respond_device_code(&server, 0);
+    respond_token_error(&server, "authorization_pending");
+    respond_token_error(&server, "slow_down");
+    respond_token(&server);
+    respond_login_chain(&server, profile_id);
+
+    let device_code = client.request_device_code().await.unwrap();
+
+    CompleteMicrosoftLoginUseCase::new(Arc::new(MockCredentialsStorage::new()), client)
+        .execute(device_code)
+        .await
+        .unwrap();
+
+    let polls = server.requests("/oauth/token");
+    assert_eq!(polls.len(), 3);
+    assert!(polls[1].received - polls[0].received < Duration::from_secs(5));
+    assert!(polls[2].received - polls[1].received >= Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn test_login_chain_reports_xbox_errors() {
+    let server = StubHttpServer::start().await;
+    server.respond_json("/xsts/xsts/authorize", json!({ "XErr": 2148916233u64 }));
+
+    let result = create_client(&server).authorize_xsts("xbl-token").await;
+
+    assert!(matches!(
+        result,
+        Err(AuthApplicationError::Domain(
+            AuthDomainError::XboxAuthorizationFailed { reason }
+        )) if reason.contains("Xbox profile")
+    ));
+}
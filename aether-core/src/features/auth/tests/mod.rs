@@ -1,5 +1,10 @@
+mod complete_microsoft_login_test;
 mod create_offline_account_test;
-mod fixtures;
+mod create_yggdrasil_account_test;
+mod encrypted_fs_credentials_storage_test;
+pub mod fixtures;
 mod get_accounts_test;
 mod logout_test;
+mod microsoft_auth_client_test;
 mod set_active_account_test;
+mod token_refresh_service_test;
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::features::auth::{CredentialsStorage, SetActiveAccountUseCase};
+use crate::features::auth::{ActiveAccountHelper, CredentialsStorage, SetActiveAccountUseCase};
 
 use super::fixtures::{CredentialsBuilder, MockCredentialsStorage};
 
@@ -73,3 +73,49 @@ async fn test_set_active_nonexistent_account() {
     let result = use_case.execute(nonexistent_id).await;
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_set_active_expired_microsoft_account() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+
+    let cred_id = Uuid::new_v4();
+    storage
+        .upsert(
+            CredentialsBuilder::new("User1")
+                .with_id(cred_id)
+                .with_microsoft(-60)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let use_case = SetActiveAccountUseCase::new(storage.clone());
+
+    // Tokens are refreshed before launch, selecting the account must not need them
+    let result = use_case.execute(cred_id).await.unwrap();
+    assert!(result.active);
+    assert!(storage.get(cred_id).await.unwrap().is_expired());
+}
+
+#[tokio::test]
+async fn test_ensure_active_falls_back_to_expired_account() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+
+    let cred_id = Uuid::new_v4();
+    storage
+        .upsert(
+            CredentialsBuilder::new("User1")
+                .with_id(cred_id)
+                .with_microsoft(-60)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let account = ActiveAccountHelper::ensure_active(storage.as_ref())
+        .await
+        .unwrap();
+
+    assert_eq!(account.id(), cred_id);
+    assert!(account.is_active());
+}
@@ -0,0 +1,242 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::features::auth::{
+    AuthApplicationError, AuthDomainError, AuthServer, Credentials, CredentialsStorage,
+    TokenRefreshService, Username,
+};
+
+use super::fixtures::{
+    CredentialsBuilder, MockCredentialsStorage, MockMicrosoftAuthService, MockRequestFailure,
+    MockYggdrasilAuthService, MOCK_YGGDRASIL_METADATA,
+};
+
+#[tokio::test]
+async fn test_refresh_expired_microsoft_account() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let id = Uuid::new_v4();
+    storage
+        .upsert(
+            CredentialsBuilder::new("OnlineUser")
+                .with_id(id)
+                .with_microsoft(-60)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let service = TokenRefreshService::new(
+        storage.clone(),
+        Arc::new(MockMicrosoftAuthService::new(id, "OnlineUser")),
+        Arc::new(MockYggdrasilAuthService::new(id, "OnlineUser", "secret")),
+    );
+
+    let refreshed = service.refresh_if_needed(id).await.unwrap();
+
+    assert!(!refreshed.is_expired());
+    assert_eq!(refreshed.access_token(), "minecraft-access");
+    assert_eq!(refreshed.refresh_token(), "microsoft-refresh");
+    assert_eq!(storage.get(id).await.unwrap(), refreshed);
+}
+
+#[tokio::test]
+async fn test_refresh_skips_fresh_and_offline_accounts() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let online = CredentialsBuilder::new("OnlineUser")
+        .with_microsoft(86400)
+        .build();
+    let offline = CredentialsBuilder::new("OfflineUser").build();
+    storage
+        .upsert_all(vec![online.clone(), offline.clone()])
+        .await
+        .unwrap();
+
+    let service = TokenRefreshService::new(
+        storage.clone(),
+        Arc::new(MockMicrosoftAuthService::new(online.id(), "OnlineUser").with_failing_refresh()),
+        Arc::new(MockYggdrasilAuthService::new(
+            online.id(),
+            "OnlineUser",
+            "secret",
+        )),
+    );
+
+    assert_eq!(
+        service.refresh_if_needed(online.id()).await.unwrap(),
+        online
+    );
+    assert_eq!(
+        service.refresh_if_needed(offline.id()).await.unwrap(),
+        offline
+    );
+}
+
+#[tokio::test]
+async fn test_failed_refresh_marks_account_for_relogin() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let id = Uuid::new_v4();
+    storage
+        .upsert(
+            CredentialsBuilder::new("OnlineUser")
+                .with_id(id)
+                .with_microsoft(-60)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let service = TokenRefreshService::new(
+        storage.clone(),
+        Arc::new(MockMicrosoftAuthService::new(id, "OnlineUser").with_failing_refresh()),
+        Arc::new(MockYggdrasilAuthService::new(id, "OnlineUser", "secret")),
+    );
+
+    let result = service.refresh_if_needed(id).await;
+
+    assert!(matches!(
+        result,
+        Err(AuthApplicationError::Domain(
+            AuthDomainError::ReloginRequired { .. }
+        ))
+    ));
+    assert!(storage.get(id).await.unwrap().needs_relogin());
+}
+
+#[tokio::test]
+async fn test_rotated_refresh_token_survives_failed_chain() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let id = Uuid::new_v4();
+    storage
+        .upsert(
+            CredentialsBuilder::new("OnlineUser")
+                .with_id(id)
+                .with_microsoft(-60)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let service = TokenRefreshService::new(
+        storage.clone(),
+        Arc::new(
+            MockMicrosoftAuthService::new(id, "OnlineUser")
+                .with_xbox_live_failure(MockRequestFailure::Network),
+        ),
+        Arc::new(MockYggdrasilAuthService::new(id, "OnlineUser", "secret")),
+    );
+
+    let result = service.refresh_if_needed(id).await;
+
+    assert!(matches!(
+        result,
+        Err(AuthApplicationError::RequestFailed(_))
+    ));
+
+    let saved = storage.get(id).await.unwrap();
+    assert_eq!(saved.refresh_token(), "microsoft-refresh");
+    assert!(!saved.needs_relogin());
+}
+
+#[tokio::test]
+async fn test_invalid_refresh_response_marks_account_for_relogin() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let id = Uuid::new_v4();
+    storage
+        .upsert(
+            CredentialsBuilder::new("OnlineUser")
+                .with_id(id)
+                .with_microsoft(-60)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let service = TokenRefreshService::new(
+        storage.clone(),
+        Arc::new(
+            MockMicrosoftAuthService::new(id, "OnlineUser")
+                .with_refresh_failure(MockRequestFailure::InvalidResponse),
+        ),
+        Arc::new(MockYggdrasilAuthService::new(id, "OnlineUser", "secret")),
+    );
+
+    let result = service.refresh_if_needed(id).await;
+
+    assert!(matches!(
+        result,
+        Err(AuthApplicationError::Domain(
+            AuthDomainError::ReloginRequired { .. }
+        ))
+    ));
+    assert!(storage.get(id).await.unwrap().needs_relogin());
+}
+
+#[tokio::test]
+async fn test_network_failure_keeps_account_usable() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let id = Uuid::new_v4();
+    storage
+        .upsert(
+            CredentialsBuilder::new("OnlineUser")
+                .with_id(id)
+                .with_microsoft(-60)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let service = TokenRefreshService::new(
+        storage.clone(),
+        Arc::new(
+            MockMicrosoftAuthService::new(id, "OnlineUser")
+                .with_refresh_failure(MockRequestFailure::Network),
+        ),
+        Arc::new(MockYggdrasilAuthService::new(id, "OnlineUser", "secret")),
+    );
+
+    let result = service.refresh_if_needed(id).await;
+
+    assert!(matches!(
+        result,
+        Err(AuthApplicationError::RequestFailed(_))
+    ));
+
+    let saved = storage.get(id).await.unwrap();
+    assert_eq!(saved.refresh_token(), "refresh");
+    assert!(!saved.needs_relogin());
+}
+
+#[tokio::test]
+async fn test_refresh_invalid_yggdrasil_session() {
+    let storage = Arc::new(MockCredentialsStorage::new());
+    let id = Uuid::new_v4();
+    storage
+        .upsert(Credentials::new_yggdrasil(
+            id,
+            Username::parse("SkinUser").unwrap(),
+            "stale-access".to_string(),
+            "client".to_string(),
+            AuthServer {
+                url: "https://auth.example.com/api/yggdrasil".to_string(),
+                metadata: "{}".to_string(),
+            },
+        ))
+        .await
+        .unwrap();
+
+    let service = TokenRefreshService::new(
+        storage.clone(),
+        Arc::new(MockMicrosoftAuthService::new(id, "SkinUser")),
+        Arc::new(MockYggdrasilAuthService::new(id, "SkinUser", "secret").with_invalid_tokens()),
+    );
+
+    let refreshed = service.refresh_if_needed(id).await.unwrap();
+
+    assert_eq!(refreshed.access_token(), "yggdrasil-access");
+    assert_eq!(refreshed.refresh_token(), "client");
+    assert_eq!(
+        refreshed.auth_server().unwrap().metadata,
+        MOCK_YGGDRASIL_METADATA
+    );
+}
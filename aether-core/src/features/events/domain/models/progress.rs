@@ -39,6 +39,10 @@ pub enum ProgressEventType {
         instance_id: String,
         instance_name: String,
     },
+    InstanceSnapshot {
+        instance_id: String,
+        instance_name: String,
+    },
     ZipExtract {
         instance_path: String,
         instance_name: String,
@@ -3,3 +3,6 @@ pub mod infra;
 pub mod utils;
 
 pub use domain::*;
+
+#[cfg(test)]
+pub mod tests;
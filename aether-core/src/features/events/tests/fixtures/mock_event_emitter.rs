@@ -0,0 +1,57 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::features::events::{
+    EventEmitter, EventError, InstanceEvent, InstanceEventType, LauncherEvent,
+};
+
+/// Records emitted events instead of sending them to the frontend.
+#[derive(Default)]
+pub struct MockEventEmitter {
+    events: Mutex<Vec<(String, serde_json::Value)>>,
+}
+
+impl MockEventEmitter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn has_instance_event(&self, instance_id: &str, event: InstanceEventType) -> bool {
+        let expected = serde_json::to_value(InstanceEvent {
+            instance_id: instance_id.to_string(),
+            event,
+        })
+        .unwrap();
+
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(name, payload)| name == LauncherEvent::Instance.as_str() && *payload == expected)
+    }
+}
+
+#[async_trait]
+impl EventEmitter for MockEventEmitter {
+    async fn emit<P: Serialize + Clone + Send>(
+        &self,
+        event: &str,
+        payload: P,
+    ) -> Result<(), EventError> {
+        let payload = serde_json::to_value(payload)
+            .map_err(|err| EventError::SerializeError(anyhow::Error::from(err)))?;
+        self.events
+            .lock()
+            .unwrap()
+            .push((event.to_string(), payload));
+        Ok(())
+    }
+
+    fn listen<F, T>(&self, _event: impl Into<String>, _handler: F)
+    where
+        F: Fn(String) + Send + 'static,
+    {
+    }
+}
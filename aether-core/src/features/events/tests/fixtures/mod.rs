@@ -0,0 +1,3 @@
+mod mock_event_emitter;
+
+pub use mock_event_emitter::*;
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use crate::{
     features::{
         events::{EventEmitter, EventEmitterExt, InstanceEventType},
-        instance::InstanceError,
+        instance::{InstanceError, InstanceLockService, InstanceOperation},
         settings::LocationInfo,
     },
     shared::rename,
@@ -45,13 +45,19 @@ impl ChangeContentState {
 pub struct ChangeContentStateUseCase<E: EventEmitter> {
     event_emitter: Arc<E>,
     location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
 }
 
 impl<E: EventEmitter> ChangeContentStateUseCase<E> {
-    pub fn new(event_emitter: Arc<E>, location_info: Arc<LocationInfo>) -> Self {
+    pub fn new(
+        event_emitter: Arc<E>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
         Self {
             event_emitter,
             location_info,
+            instance_lock_service,
         }
     }
 
@@ -62,6 +68,10 @@ impl<E: EventEmitter> ChangeContentStateUseCase<E> {
             action,
         } = input;
 
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ChangeContentState)?;
+
         match action {
             ContentStateAction::Enable => {
                 self.enable_many(&instance_id, content_paths.as_slice())
@@ -8,7 +8,10 @@ use log::debug;
 use crate::{
     features::{
         events::{EventEmitter, EventEmitterExt, InstanceEventType},
-        instance::{ContentType, InstanceError, PackFile, PackStorage},
+        instance::{
+            ContentType, InstanceError, InstanceLockService, InstanceOperation, PackFile,
+            PackStorage,
+        },
         settings::LocationInfo,
     },
     shared::{read_async, sha1_async, IoError},
@@ -46,6 +49,7 @@ pub struct ImportContentUseCase<E: EventEmitter, PS: PackStorage> {
     event_emitter: Arc<E>,
     pack_storage: Arc<PS>,
     location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
 }
 
 impl<E: EventEmitter, PS: PackStorage> ImportContentUseCase<E, PS> {
@@ -53,11 +57,13 @@ impl<E: EventEmitter, PS: PackStorage> ImportContentUseCase<E, PS> {
         event_emitter: Arc<E>,
         pack_storage: Arc<PS>,
         location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
     ) -> Self {
         Self {
             event_emitter,
             pack_storage,
             location_info,
+            instance_lock_service,
         }
     }
 
@@ -154,6 +160,10 @@ impl<E: EventEmitter, PS: PackStorage> ImportContentUseCase<E, PS> {
             source_paths,
         } = input;
 
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ImportContent)?;
+
         let (content_paths, pack_files) = self
             .prepare_import_data(&instance_id, content_type, source_paths.as_slice())
             .await?;
@@ -148,6 +148,7 @@ impl<PS: PackStorage> ListContentUseCase<PS> {
             disabled: file_name.ends_with(".disabled"),
             instance_relative_path: original_path,
             update: pack_file.update,
+            download_url: pack_file.download.map(|download| download.url),
         }))
     }
 }
@@ -6,7 +6,7 @@ use std::{
 use crate::{
     features::{
         events::{EventEmitter, EventEmitterExt, InstanceEventType},
-        instance::{InstanceError, PackStorage},
+        instance::{InstanceError, InstanceLockService, InstanceOperation, PackStorage},
         settings::LocationInfo,
     },
     shared::remove_file,
@@ -37,6 +37,7 @@ pub struct RemoveContentUseCase<E, PS: PackStorage> {
     event_emitter: Arc<E>,
     pack_storage: Arc<PS>,
     location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
 }
 
 impl<E: EventEmitter, PS: PackStorage> RemoveContentUseCase<E, PS> {
@@ -44,11 +45,13 @@ impl<E: EventEmitter, PS: PackStorage> RemoveContentUseCase<E, PS> {
         event_emitter: Arc<E>,
         pack_storage: Arc<PS>,
         location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
     ) -> Self {
         Self {
             event_emitter,
             pack_storage,
             location_info,
+            instance_lock_service,
         }
     }
 
@@ -68,6 +71,10 @@ impl<E: EventEmitter, PS: PackStorage> RemoveContentUseCase<E, PS> {
             content_paths,
         } = input;
 
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::RemoveContent)?;
+
         let instance_dir = self.location_info.instance_dir(&instance_id);
 
         for content_path in content_paths.iter() {
@@ -0,0 +1,157 @@
+use std::{path::Path, sync::Arc};
+
+use futures::{StreamExt, TryStreamExt};
+use log::warn;
+
+use crate::features::instance::{
+    app::ListContentUseCase, ContentProvider, ContentProviderRegistry, ContentType, ContentUpdate,
+    ContentVersion, ContentVersionType, Instance, InstanceError, InstanceStorage, PackStorage,
+};
+
+const CONCURRENT_REQUESTS: usize = 8;
+
+pub struct CheckContentUpdatesUseCase<IS: InstanceStorage, PS: PackStorage, CP: ContentProvider> {
+    instance_storage: Arc<IS>,
+    list_content_use_case: Arc<ListContentUseCase<PS>>,
+    pack_storage: Arc<PS>,
+    provider_registry: Arc<ContentProviderRegistry<CP>>,
+}
+
+impl<IS: InstanceStorage, PS: PackStorage, CP: ContentProvider>
+    CheckContentUpdatesUseCase<IS, PS, CP>
+{
+    pub fn new(
+        instance_storage: Arc<IS>,
+        list_content_use_case: Arc<ListContentUseCase<PS>>,
+        pack_storage: Arc<PS>,
+        provider_registry: Arc<ContentProviderRegistry<CP>>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            list_content_use_case,
+            pack_storage,
+            provider_registry,
+        }
+    }
+
+    /// Checks every installed content file for a newer version
+    pub async fn execute(&self, instance_id: String) -> Result<Vec<ContentUpdate>, InstanceError> {
+        let instance = self.instance_storage.get(&instance_id).await?;
+
+        let mut content_paths: Vec<String> = self
+            .list_content_use_case
+            .execute(instance_id)
+            .await?
+            .into_iter()
+            .map(|(_, content)| content.content_path)
+            .collect();
+
+        content_paths.sort();
+
+        self.check_contents(&instance, &content_paths).await
+    }
+
+    pub async fn check_contents(
+        &self,
+        instance: &Instance,
+        content_paths: &[String],
+    ) -> Result<Vec<ContentUpdate>, InstanceError> {
+        futures::stream::iter(content_paths)
+            .map(|content_path| self.check_content(instance, content_path))
+            .buffered(CONCURRENT_REQUESTS)
+            .try_collect()
+            .await
+    }
+
+    async fn check_content(
+        &self,
+        instance: &Instance,
+        content_path: &str,
+    ) -> Result<ContentUpdate, InstanceError> {
+        let pack_file = self
+            .pack_storage
+            .get_pack_file(&instance.id, content_path)
+            .await?;
+
+        let content_type = ContentType::get_from_parent_folder(Path::new(content_path)).ok_or(
+            InstanceError::ContentFilename {
+                path: content_path.into(),
+            },
+        )?;
+
+        let provider_id = pack_file.update_provider;
+        let update_data = provider_id
+            .as_ref()
+            .zip(pack_file.update)
+            .and_then(|(provider_id, mut update)| update.remove(provider_id));
+
+        let mut content_update = ContentUpdate {
+            content_path: content_path.to_owned(),
+            content_type,
+            name: pack_file.name,
+            provider: provider_id.clone(),
+            installed_version: None,
+            latest_version: None,
+            update_available: false,
+        };
+
+        let (Some(provider_id), Some(update_data)) = (provider_id, update_data) else {
+            return Ok(content_update);
+        };
+
+        let Ok(provider) = self.provider_registry.get(&provider_id) else {
+            return Ok(content_update);
+        };
+
+        content_update.installed_version = provider.get_update_data_version(&update_data);
+
+        // Only mods depend on the loader, other content is matched by game version
+        let loader =
+            (content_type == ContentType::Mod).then(|| instance.loader.as_str().to_owned());
+
+        let versions = match provider
+            .list_compatible_versions(&update_data, &instance.game_version, &loader)
+            .await
+        {
+            Ok(versions) => versions,
+            Err(err) => {
+                warn!("Failed to check updates of \"{}\": {}", content_path, err);
+                return Ok(content_update);
+            }
+        };
+
+        content_update.latest_version =
+            get_latest_version(versions, content_update.installed_version.as_deref());
+        content_update.update_available = content_update
+            .latest_version
+            .as_ref()
+            .is_some_and(|version| Some(&version.id) != content_update.installed_version.as_ref());
+
+        Ok(content_update)
+    }
+}
+
+/// First listed version that is at least as stable and as recent as the installed one,
+/// only releases are offered when the installed version isn't listed
+fn get_latest_version(
+    versions: Vec<ContentVersion>,
+    installed_version: Option<&str>,
+) -> Option<ContentVersion> {
+    let Some(installed) = installed_version
+        .and_then(|installed_version| {
+            versions
+                .iter()
+                .find(|version| version.id == installed_version)
+        })
+        .cloned()
+    else {
+        return versions
+            .into_iter()
+            .find(|version| version.version_type == ContentVersionType::Release);
+    };
+
+    versions.into_iter().find(|version| {
+        version.version_type >= installed.version_type
+            && version.date_published >= installed.date_published
+    })
+}
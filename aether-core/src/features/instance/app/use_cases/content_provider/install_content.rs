@@ -1,20 +1,26 @@
 use std::sync::Arc;
 
 use crate::features::instance::{
-    ContentInstallParams, ContentProvider, ContentProviderRegistry, InstanceError, PackFile,
-    PackStorage,
+    ContentFile, ContentInstallParams, ContentProvider, ContentProviderRegistry, InstanceError,
+    InstanceLockService, InstanceOperation, PackFile, PackFileDownload, PackStorage,
 };
 
 pub struct InstallContentUseCase<PS: PackStorage, CP: ContentProvider> {
     pack_storage: Arc<PS>,
     provider_registry: Arc<ContentProviderRegistry<CP>>,
+    instance_lock_service: Arc<InstanceLockService>,
 }
 
 impl<PS: PackStorage, CP: ContentProvider> InstallContentUseCase<PS, CP> {
-    pub fn new(pack_storage: Arc<PS>, provider_registry: Arc<ContentProviderRegistry<CP>>) -> Self {
+    pub fn new(
+        pack_storage: Arc<PS>,
+        provider_registry: Arc<ContentProviderRegistry<CP>>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
         Self {
             pack_storage,
             provider_registry,
+            instance_lock_service,
         }
     }
 
@@ -22,7 +28,20 @@ impl<PS: PackStorage, CP: ContentProvider> InstallContentUseCase<PS, CP> {
         &self,
         instance_id: String,
         install_params: ContentInstallParams,
-    ) -> Result<(), InstanceError> {
+    ) -> Result<ContentFile, InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::InstallContent)?;
+
+        self.perform_install(instance_id, install_params).await
+    }
+
+    /// Installs without taking the instance lock, the caller must hold it
+    pub async fn perform_install(
+        &self,
+        instance_id: String,
+        install_params: ContentInstallParams,
+    ) -> Result<ContentFile, InstanceError> {
         let provider = self
             .provider_registry
             .get(&install_params.provider.to_string())?;
@@ -36,16 +55,22 @@ impl<PS: PackStorage, CP: ContentProvider> InstallContentUseCase<PS, CP> {
                 &PackFile {
                     name: instance_file.name.clone(),
                     file_name: instance_file.filename.clone(),
-                    hash: instance_file.hash,
-                    download: None,
+                    download: instance_file
+                        .download_url
+                        .clone()
+                        .map(|url| PackFileDownload {
+                            hash: instance_file.hash.clone(),
+                            url,
+                        }),
+                    hash: instance_file.hash.clone(),
                     option: None,
                     side: None,
                     update_provider: Some(install_params.provider.to_owned()),
-                    update: instance_file.update,
+                    update: instance_file.update.clone(),
                 },
             )
             .await?;
 
-        Ok(())
+        Ok(instance_file)
     }
 }
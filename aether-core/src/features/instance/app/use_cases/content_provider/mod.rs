@@ -1,9 +1,13 @@
+mod check_content_updates;
 mod get_provider_metadata;
 mod install_content;
 mod list_providers;
 mod search_content;
+mod update_contents;
 
+pub use check_content_updates::*;
 pub use get_provider_metadata::*;
 pub use install_content::*;
 pub use list_providers::*;
 pub use search_content::*;
+pub use update_contents::*;
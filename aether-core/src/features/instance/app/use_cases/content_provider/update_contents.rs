@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use log::{info, warn};
+
+use crate::{
+    features::{
+        events::{EventEmitter, EventEmitterExt, InstanceEventType, ProgressService},
+        instance::{
+            app::{CreateSnapshotUseCase, InstallContentUseCase},
+            ContentInstallParams, ContentProvider, ContentType, ContentUpdate, Instance,
+            InstanceError, InstanceInstallStage, InstanceLockService, InstanceOperation,
+            InstanceStorage, PackStorage, SnapshotReason, SnapshotStorage,
+        },
+        settings::LocationInfo,
+    },
+    shared::{remove_file, rename},
+};
+
+use super::CheckContentUpdatesUseCase;
+
+pub struct UpdateContentsUseCase<
+    E: EventEmitter,
+    IS: InstanceStorage,
+    PS: PackStorage,
+    CP: ContentProvider,
+    SS: SnapshotStorage,
+    PGS: ProgressService,
+> {
+    event_emitter: Arc<E>,
+    instance_storage: Arc<IS>,
+    pack_storage: Arc<PS>,
+    check_content_updates_use_case: Arc<CheckContentUpdatesUseCase<IS, PS, CP>>,
+    install_content_use_case: Arc<InstallContentUseCase<PS, CP>>,
+    create_snapshot_use_case: Arc<CreateSnapshotUseCase<IS, SS, PGS>>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<
+        E: EventEmitter,
+        IS: InstanceStorage,
+        PS: PackStorage,
+        CP: ContentProvider,
+        SS: SnapshotStorage,
+        PGS: ProgressService,
+    > UpdateContentsUseCase<E, IS, PS, CP, SS, PGS>
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        event_emitter: Arc<E>,
+        instance_storage: Arc<IS>,
+        pack_storage: Arc<PS>,
+        check_content_updates_use_case: Arc<CheckContentUpdatesUseCase<IS, PS, CP>>,
+        install_content_use_case: Arc<InstallContentUseCase<PS, CP>>,
+        create_snapshot_use_case: Arc<CreateSnapshotUseCase<IS, SS, PGS>>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            event_emitter,
+            instance_storage,
+            pack_storage,
+            check_content_updates_use_case,
+            install_content_use_case,
+            create_snapshot_use_case,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    /// Updates content to the newest compatible versions, returns the updated content paths
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        content_paths: Vec<String>,
+    ) -> Result<Vec<String>, InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::UpdateContent)?;
+
+        let instance = self.instance_storage.get(&instance_id).await?;
+
+        if matches!(
+            instance.install_stage,
+            InstanceInstallStage::Installing | InstanceInstallStage::PackInstalling
+        ) {
+            return Err(InstanceError::InstanceStillInstalling { instance_id });
+        }
+
+        let updates: Vec<ContentUpdate> = self
+            .check_content_updates_use_case
+            .check_contents(&instance, &content_paths)
+            .await?
+            .into_iter()
+            .filter(|update| update.update_available)
+            .collect();
+
+        if updates.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        info!(
+            "Updating {} content files of instance \"{}\"",
+            updates.len(),
+            instance_id
+        );
+
+        self.create_snapshot_use_case
+            .execute_auto(&instance_id, SnapshotReason::BeforeContentUpdate)
+            .await?;
+
+        // A failed update leaves the old file in place
+        let mut updated_contents = Vec::new();
+        for update in updates {
+            match self.update_content(&instance, &update).await {
+                Ok(()) => updated_contents.push(update.content_path),
+                Err(err) => warn!("Failed to update \"{}\": {}", update.content_path, err),
+            }
+        }
+
+        if !updated_contents.is_empty() {
+            self.event_emitter
+                .emit_instance_safe(instance_id, InstanceEventType::Edited)
+                .await;
+        }
+
+        Ok(updated_contents)
+    }
+
+    async fn update_content(
+        &self,
+        instance: &Instance,
+        update: &ContentUpdate,
+    ) -> Result<(), InstanceError> {
+        let (Some(provider), Some(version)) = (&update.provider, &update.latest_version) else {
+            return Ok(());
+        };
+
+        // Update data carries the project id the provider needs to install a version
+        let provider_data = self
+            .pack_storage
+            .get_pack_file(&instance.id, &update.content_path)
+            .await?
+            .update
+            .and_then(|mut update_data| update_data.remove(provider));
+
+        let instance_dir = self.location_info.instance_dir(&instance.id);
+        let was_disabled = instance_dir
+            .join(format!("{}.disabled", update.content_path))
+            .exists();
+
+        let installed = self
+            .install_content_use_case
+            .perform_install(
+                instance.id.clone(),
+                ContentInstallParams {
+                    game_version: instance.game_version.clone(),
+                    loader: (update.content_type == ContentType::Mod)
+                        .then(|| instance.loader.as_str().to_owned()),
+                    content_type: update.content_type,
+                    content_version: Some(version.id.clone()),
+                    provider: provider.clone(),
+                    provider_data,
+                },
+            )
+            .await?;
+
+        let installed_path = instance_dir.join(&installed.content_path);
+
+        // Old file may be disabled or already overwritten by the new one
+        for old_path in [
+            instance_dir.join(&update.content_path),
+            instance_dir.join(format!("{}.disabled", update.content_path)),
+        ] {
+            if old_path != installed_path && old_path.exists() {
+                remove_file(&old_path).await?;
+            }
+        }
+
+        if installed.content_path != update.content_path {
+            self.pack_storage
+                .remove_pack_file(&instance.id, &update.content_path)
+                .await?;
+        }
+
+        // Providers install files enabled
+        if was_disabled {
+            rename(
+                &installed_path,
+                instance_dir.join(format!("{}.disabled", installed.content_path)),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,314 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use log::{info, warn};
+
+use crate::{
+    features::{
+        instance::{
+            DiskUsageService, GarbageEntry, GarbageKind, GarbageRemoveFailure, GarbageReport,
+            InstanceError, InstanceLockService, InstanceOperation, InstanceStorage,
+        },
+        java::{app::JavaApplicationError, Java, JavaStorage, JavaStorageError},
+        minecraft::{
+            app::{GetVersionFilesUseCase, MinecraftApplicationError},
+            utils::AUTHLIB_INJECTOR_PATH,
+            MetadataStorage, MinecraftDownloader,
+        },
+        settings::LocationInfo,
+    },
+    shared::{read_dir, remove_dir_all, remove_file, IoError},
+};
+
+/// Disk usage request id of the collection, `cancel_disk_usage` with it stops measuring garbage
+pub const GARBAGE_REQUEST_ID: &str = "collect_metadata_garbage";
+
+pub struct CollectMetadataGarbageUseCase<
+    IS: InstanceStorage,
+    MS: MetadataStorage,
+    MD: MinecraftDownloader,
+    JS: JavaStorage,
+> {
+    instance_storage: Arc<IS>,
+    java_storage: Arc<JS>,
+    get_version_files_use_case: Arc<GetVersionFilesUseCase<MS, MD>>,
+    disk_usage_service: Arc<DiskUsageService>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<IS: InstanceStorage, MS: MetadataStorage, MD: MinecraftDownloader, JS: JavaStorage>
+    CollectMetadataGarbageUseCase<IS, MS, MD, JS>
+{
+    pub fn new(
+        instance_storage: Arc<IS>,
+        java_storage: Arc<JS>,
+        get_version_files_use_case: Arc<GetVersionFilesUseCase<MS, MD>>,
+        disk_usage_service: Arc<DiskUsageService>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            java_storage,
+            get_version_files_use_case,
+            disk_usage_service,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    /// Lists metadata no instance references, without `dry_run` it's deleted
+    pub async fn execute(&self, dry_run: bool) -> Result<GarbageReport, InstanceError> {
+        let instances = self.instance_storage.list().await?;
+
+        // Instances can't be installed while their files are collected
+        let mut locks = Vec::new();
+        if !dry_run {
+            for instance in &instances {
+                locks.push(
+                    self.instance_lock_service
+                        .try_lock(&instance.id, InstanceOperation::CollectGarbage)?,
+                );
+            }
+        }
+
+        let mut referenced = HashSet::new();
+        let mut java_versions = HashSet::new();
+        let mut java_paths = Vec::new();
+
+        // A version that can't be resolved fails the whole collection, its files would be lost
+        for instance in &instances {
+            let version_files = self
+                .get_version_files_use_case
+                .execute(
+                    instance.game_version.clone(),
+                    instance.loader,
+                    instance.loader_version.clone(),
+                )
+                .await?;
+
+            referenced.extend(version_files.files);
+
+            match &instance.java_path {
+                Some(java_path) => java_paths.push(PathBuf::from(java_path)),
+                None => {
+                    java_versions.insert(version_files.java_version);
+                }
+            }
+        }
+
+        let installed_java = self.java_storage.list().await.map_err(map_java_error)?;
+
+        java_paths.extend(
+            installed_java
+                .iter()
+                .filter(|java| java_versions.contains(&java.major_version()))
+                .map(|java| PathBuf::from(java.path())),
+        );
+
+        let candidates = self.get_candidates(&java_paths).await?;
+
+        let mut report = GarbageReport::default();
+        let request = self.disk_usage_service.start_request(GARBAGE_REQUEST_ID);
+
+        for (kind, path) in candidates {
+            if is_referenced(&path, &referenced) {
+                continue;
+            }
+
+            let size = self.disk_usage_service.get_size(&path, &request).await?;
+
+            report.size += size;
+            report.entries.push(GarbageEntry {
+                kind,
+                path: path.to_string_lossy().to_string(),
+                size,
+            });
+        }
+
+        if dry_run {
+            return Ok(report);
+        }
+
+        info!(
+            "Removing {} unreferenced metadata entries ({} bytes)",
+            report.entries.len(),
+            report.size
+        );
+
+        let mut reclaimed = 0;
+
+        // One locked or vanished entry shouldn't keep the rest on disk
+        for entry in &report.entries {
+            match self.remove_entry(entry, &installed_java).await {
+                Ok(()) => reclaimed += entry.size,
+                Err(err) => {
+                    warn!("Failed to remove \"{}\": {}", entry.path, err);
+                    report.failed.push(GarbageRemoveFailure {
+                        path: entry.path.clone(),
+                        error: err.to_string(),
+                    });
+                }
+            }
+        }
+
+        self.disk_usage_service
+            .invalidate(&self.location_info.metadata_dir());
+        self.disk_usage_service
+            .invalidate(&self.location_info.java_dir());
+
+        report.reclaimed = Some(reclaimed);
+
+        Ok(report)
+    }
+
+    async fn remove_entry(
+        &self,
+        entry: &GarbageEntry,
+        installed_java: &[Java],
+    ) -> Result<(), InstanceError> {
+        let path = Path::new(&entry.path);
+
+        match entry.kind {
+            GarbageKind::Library => {
+                remove_file(path).await?;
+                remove_empty_parents(path, &self.location_info.libraries_dir()).await;
+            }
+            GarbageKind::AssetObject => {
+                remove_file(path).await?;
+                remove_empty_parents(path, &self.location_info.objects_dir()).await;
+            }
+            GarbageKind::AssetIndex => remove_file(path).await?,
+            GarbageKind::Java => {
+                remove_dir_all(path).await?;
+
+                for java in installed_java
+                    .iter()
+                    .filter(|java| Path::new(java.path()).starts_with(path))
+                {
+                    self.java_storage
+                        .remove(java.major_version())
+                        .await
+                        .map_err(map_java_error)?;
+                }
+            }
+            GarbageKind::Version | GarbageKind::Natives | GarbageKind::LegacyAssets => {
+                remove_dir_all(path).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Paths that may be collected, Java runtimes in use are left out here
+    async fn get_candidates(
+        &self,
+        java_paths: &[PathBuf],
+    ) -> Result<Vec<(GarbageKind, PathBuf)>, InstanceError> {
+        let mut candidates = Vec::new();
+
+        for path in list_dir(&self.location_info.versions_dir()).await? {
+            candidates.push((GarbageKind::Version, path));
+        }
+
+        for path in list_dir(&self.location_info.natives_dir()).await? {
+            candidates.push((GarbageKind::Natives, path));
+        }
+
+        let authlib_injector_path = self
+            .location_info
+            .libraries_dir()
+            .join(AUTHLIB_INJECTOR_PATH);
+
+        for path in list_files(&self.location_info.libraries_dir()).await? {
+            if path != authlib_injector_path {
+                candidates.push((GarbageKind::Library, path));
+            }
+        }
+
+        for path in list_files(&self.location_info.assets_index_dir()).await? {
+            candidates.push((GarbageKind::AssetIndex, path));
+        }
+
+        for path in list_files(&self.location_info.objects_dir()).await? {
+            candidates.push((GarbageKind::AssetObject, path));
+        }
+
+        let legacy_assets_dir = self.location_info.legacy_assets_dir();
+        if legacy_assets_dir.is_dir() {
+            candidates.push((GarbageKind::LegacyAssets, legacy_assets_dir));
+        }
+
+        // Only folders, the java storage file lives next to the runtimes
+        for path in list_dir(&self.location_info.java_dir()).await? {
+            if path.is_dir() && !java_paths.iter().any(|java| java.starts_with(&path)) {
+                candidates.push((GarbageKind::Java, path));
+            }
+        }
+
+        Ok(candidates)
+    }
+}
+
+fn map_java_error(err: JavaStorageError) -> MinecraftApplicationError {
+    MinecraftApplicationError::JavaError(JavaApplicationError::Storage(err))
+}
+
+/// Referenced folders keep everything inside them
+fn is_referenced(path: &Path, referenced: &HashSet<PathBuf>) -> bool {
+    path.ancestors()
+        .any(|ancestor| referenced.contains(ancestor))
+}
+
+/// Stops at the first folder that still has entries
+async fn remove_empty_parents(path: &Path, root: &Path) {
+    for parent in path.ancestors().skip(1) {
+        if parent == root || !parent.starts_with(root) {
+            break;
+        }
+
+        if tokio::fs::remove_dir(parent).await.is_err() {
+            break;
+        }
+    }
+}
+
+async fn list_dir(path: &Path) -> Result<Vec<PathBuf>, InstanceError> {
+    if !path.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths = Vec::new();
+    let mut entries = read_dir(path).await?;
+
+    while let Some(entry) = entries.next_entry().await.map_err(IoError::from)? {
+        paths.push(entry.path());
+    }
+
+    paths.sort();
+
+    Ok(paths)
+}
+
+async fn list_files(path: &Path) -> Result<Vec<PathBuf>, InstanceError> {
+    let mut files = Vec::new();
+    let mut dirs = vec![path.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        for path in list_dir(&dir).await? {
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+
+    Ok(files)
+}
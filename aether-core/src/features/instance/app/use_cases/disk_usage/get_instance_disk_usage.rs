@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use crate::{
+    features::{
+        instance::{
+            ContentDiskUsage, ContentType, DiskUsageService, InstanceDiskUsage, InstanceError,
+            InstanceStorage,
+        },
+        settings::LocationInfo,
+    },
+    shared::{read_dir, IoError},
+};
+
+pub struct GetInstanceDiskUsageUseCase<IS: InstanceStorage> {
+    instance_storage: Arc<IS>,
+    disk_usage_service: Arc<DiskUsageService>,
+    location_info: Arc<LocationInfo>,
+}
+
+impl<IS: InstanceStorage> GetInstanceDiskUsageUseCase<IS> {
+    pub fn new(
+        instance_storage: Arc<IS>,
+        disk_usage_service: Arc<DiskUsageService>,
+        location_info: Arc<LocationInfo>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            disk_usage_service,
+            location_info,
+        }
+    }
+
+    /// Content folders are watched and their sizes cached, `refresh` drops the cached sizes.
+    /// `cancel` with `request_id` stops the calculation
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        refresh: bool,
+        request_id: &str,
+    ) -> Result<InstanceDiskUsage, InstanceError> {
+        // Fails for unknown instances instead of reporting an empty folder
+        self.instance_storage.get(&instance_id).await?;
+
+        let request = self.disk_usage_service.start_request(request_id);
+        let instance_dir = self.location_info.instance_dir(&instance_id);
+
+        if refresh {
+            self.disk_usage_service.invalidate(&instance_dir);
+        }
+
+        let mut usage = InstanceDiskUsage {
+            instance_id,
+            total: 0,
+            content: ContentType::iterator()
+                .map(|content_type| ContentDiskUsage {
+                    content_type,
+                    size: 0,
+                })
+                .collect(),
+            saves: 0,
+            logs: 0,
+            screenshots: 0,
+            other: 0,
+        };
+
+        let mut entries = read_dir(&instance_dir).await?;
+
+        while let Some(entry) = entries.next_entry().await.map_err(IoError::from)? {
+            let path = entry.path();
+            let file_name = entry.file_name();
+
+            if let Some(content) = usage
+                .content
+                .iter_mut()
+                .find(|content| file_name == content.content_type.get_folder())
+            {
+                let size = self.disk_usage_service.get_size(&path, &request).await?;
+                usage.total += size;
+                content.size += size;
+                continue;
+            }
+
+            // Saves, logs and everything else change without watcher events, a cached size would go stale
+            let size = self
+                .disk_usage_service
+                .get_size_uncached(&path, &request)
+                .await?;
+            usage.total += size;
+
+            match file_name.to_str() {
+                Some("saves") => usage.saves += size,
+                Some("logs") => usage.logs += size,
+                Some("screenshots") => usage.screenshots += size,
+                _ => usage.other += size,
+            }
+        }
+
+        Ok(usage)
+    }
+}
@@ -0,0 +1,219 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{
+    features::{
+        instance::{
+            DiskUsageRequest, DiskUsageService, Instance, InstanceError, InstanceStorage,
+            JavaDiskUsage, MetadataDiskUsage, VersionDiskUsage,
+        },
+        java::{app::JavaApplicationError, JavaStorage},
+        minecraft::{app::MinecraftApplicationError, utils::get_compatible_java_version},
+        settings::LocationInfo,
+    },
+    shared::{read_dir, read_json_async, IoError},
+};
+
+pub struct GetMetadataDiskUsageUseCase<IS: InstanceStorage, JS: JavaStorage> {
+    instance_storage: Arc<IS>,
+    java_storage: Arc<JS>,
+    disk_usage_service: Arc<DiskUsageService>,
+    location_info: Arc<LocationInfo>,
+}
+
+impl<IS: InstanceStorage, JS: JavaStorage> GetMetadataDiskUsageUseCase<IS, JS> {
+    pub fn new(
+        instance_storage: Arc<IS>,
+        java_storage: Arc<JS>,
+        disk_usage_service: Arc<DiskUsageService>,
+        location_info: Arc<LocationInfo>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            java_storage,
+            disk_usage_service,
+            location_info,
+        }
+    }
+
+    /// Shared folders aren't watched, `refresh` drops their cached sizes.
+    /// `cancel` with `request_id` stops the calculation
+    pub async fn execute(
+        &self,
+        refresh: bool,
+        request_id: &str,
+    ) -> Result<MetadataDiskUsage, InstanceError> {
+        let request = self.disk_usage_service.start_request(request_id);
+
+        if refresh {
+            self.disk_usage_service
+                .invalidate(&self.location_info.metadata_dir());
+            self.disk_usage_service
+                .invalidate(&self.location_info.cache_dir());
+        }
+
+        let instances = self.instance_storage.list().await?;
+
+        let versions = self.get_versions_usage(&instances, &request).await?;
+        let java = self.get_java_usage(&instances, &request).await?;
+
+        let libraries = self
+            .disk_usage_service
+            .get_size(&self.location_info.libraries_dir(), &request)
+            .await?;
+        let assets = self
+            .disk_usage_service
+            .get_size(&self.location_info.assets_dir(), &request)
+            .await?
+            + self
+                .disk_usage_service
+                .get_size(&self.location_info.legacy_assets_dir(), &request)
+                .await?;
+        let natives = self
+            .disk_usage_service
+            .get_size(&self.location_info.natives_dir(), &request)
+            .await?;
+
+        let java_dir = self.location_info.java_dir();
+        let mut caches = 0;
+
+        for path in list_dir(&self.location_info.cache_dir()).await? {
+            if path != java_dir {
+                caches += self.disk_usage_service.get_size(&path, &request).await?;
+            }
+        }
+
+        let total = versions.iter().map(|version| version.size).sum::<u64>()
+            + java.iter().map(|java| java.size).sum::<u64>()
+            + libraries
+            + assets
+            + natives
+            + caches;
+
+        Ok(MetadataDiskUsage {
+            total,
+            versions,
+            libraries,
+            assets,
+            natives,
+            java,
+            caches,
+        })
+    }
+
+    async fn get_versions_usage(
+        &self,
+        instances: &[Instance],
+        request: &DiskUsageRequest,
+    ) -> Result<Vec<VersionDiskUsage>, InstanceError> {
+        let mut versions = Vec::new();
+
+        for path in list_dir(&self.location_info.versions_dir()).await? {
+            let Some(version_id) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+
+            versions.push(VersionDiskUsage {
+                version_id: version_id.to_owned(),
+                size: self.disk_usage_service.get_size(&path, request).await?,
+                instances: instances
+                    .iter()
+                    .filter(|instance| is_version_of_instance(version_id, instance))
+                    .map(|instance| instance.id.clone())
+                    .collect(),
+            });
+        }
+
+        Ok(versions)
+    }
+
+    async fn get_java_usage(
+        &self,
+        instances: &[Instance],
+        request: &DiskUsageRequest,
+    ) -> Result<Vec<JavaDiskUsage>, InstanceError> {
+        let installed_java = self.java_storage.list().await.map_err(|err| {
+            MinecraftApplicationError::JavaError(JavaApplicationError::Storage(err))
+        })?;
+
+        let mut required_java = Vec::with_capacity(instances.len());
+        for instance in instances {
+            required_java.push(self.get_required_java_version(instance).await);
+        }
+
+        let mut runtimes = Vec::new();
+
+        for runtime_dir in list_dir(&self.location_info.java_dir()).await? {
+            let Some(name) = runtime_dir.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+
+            let major_version = installed_java
+                .iter()
+                .find(|java| Path::new(java.path()).starts_with(&runtime_dir))
+                .map(|java| java.major_version());
+
+            let instances = instances
+                .iter()
+                .zip(&required_java)
+                .filter(|(instance, required_version)| match &instance.java_path {
+                    Some(java_path) => Path::new(java_path).starts_with(&runtime_dir),
+                    None => major_version.is_some() && **required_version == major_version,
+                })
+                .map(|(instance, _)| instance.id.clone())
+                .collect();
+
+            runtimes.push(JavaDiskUsage {
+                name: name.to_owned(),
+                major_version,
+                size: self
+                    .disk_usage_service
+                    .get_size(&runtime_dir, request)
+                    .await?,
+                instances,
+            });
+        }
+
+        Ok(runtimes)
+    }
+
+    /// Read from the downloaded version info, `None` if the instance wasn't installed yet
+    async fn get_required_java_version(&self, instance: &Instance) -> Option<u32> {
+        let version_info_path = self
+            .location_info
+            .version_dir(&instance.game_version)
+            .join(format!("{}.json", instance.game_version));
+
+        read_json_async::<daedalus::minecraft::VersionInfo>(version_info_path)
+            .await
+            .ok()
+            .map(|version_info| get_compatible_java_version(&version_info))
+    }
+}
+
+/// Loader versions are stored as `{game_version}-{loader_version}`
+fn is_version_of_instance(version_id: &str, instance: &Instance) -> bool {
+    version_id == instance.game_version
+        || version_id
+            .strip_prefix(&instance.game_version)
+            .is_some_and(|suffix| suffix.starts_with('-'))
+}
+
+async fn list_dir(path: &Path) -> Result<Vec<PathBuf>, InstanceError> {
+    if !path.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths = Vec::new();
+    let mut entries = read_dir(path).await?;
+
+    while let Some(entry) = entries.next_entry().await.map_err(IoError::from)? {
+        paths.push(entry.path());
+    }
+
+    paths.sort();
+
+    Ok(paths)
+}
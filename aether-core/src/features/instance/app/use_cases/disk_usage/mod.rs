@@ -0,0 +1,7 @@
+mod collect_metadata_garbage;
+mod get_instance_disk_usage;
+mod get_metadata_disk_usage;
+
+pub use collect_metadata_garbage::*;
+pub use get_instance_disk_usage::*;
+pub use get_metadata_disk_usage::*;
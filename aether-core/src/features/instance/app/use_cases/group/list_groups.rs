@@ -0,0 +1,48 @@
+use std::{collections::BTreeSet, sync::Arc};
+
+use crate::features::instance::{InstanceError, InstanceStorage};
+
+pub struct ListGroupsUseCase<IS> {
+    instance_storage: Arc<IS>,
+}
+
+impl<IS: InstanceStorage> ListGroupsUseCase<IS> {
+    pub fn new(instance_storage: Arc<IS>) -> Self {
+        Self { instance_storage }
+    }
+
+    /// Returns group names used by at least one instance, sorted
+    pub async fn execute(&self) -> Result<Vec<String>, InstanceError> {
+        let groups: BTreeSet<String> = self
+            .instance_storage
+            .list()
+            .await?
+            .into_iter()
+            .filter_map(|instance| instance.group)
+            .collect();
+
+        Ok(groups.into_iter().collect())
+    }
+}
+
+pub fn validate_group_name(group: &str) -> Result<(), InstanceError> {
+    if group.trim().is_empty() {
+        return Err(InstanceError::ValidationError {
+            field: "group".to_owned(),
+            reason: "group cannot be empty".to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Trims tags and drops empty and repeated ones, keeping the first occurrence order
+pub fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+
+    tags.iter()
+        .map(|tag| tag.trim())
+        .filter(|tag| !tag.is_empty() && seen.insert(tag.to_owned()))
+        .map(ToOwned::to_owned)
+        .collect()
+}
@@ -0,0 +1,9 @@
+mod list_groups;
+mod query_instances;
+mod remove_group;
+mod rename_group;
+
+pub use list_groups::*;
+pub use query_instances::*;
+pub use remove_group::*;
+pub use rename_group::*;
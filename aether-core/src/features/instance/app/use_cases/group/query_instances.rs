@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use crate::features::instance::{Instance, InstanceError, InstanceQuery, InstanceStorage};
+
+pub struct QueryInstancesUseCase<IS> {
+    instance_storage: Arc<IS>,
+}
+
+impl<IS: InstanceStorage> QueryInstancesUseCase<IS> {
+    pub fn new(instance_storage: Arc<IS>) -> Self {
+        Self { instance_storage }
+    }
+
+    pub async fn execute(&self, query: InstanceQuery) -> Result<Vec<Instance>, InstanceError> {
+        Ok(query.apply(self.instance_storage.list().await?))
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::features::instance::{InstanceError, InstanceStorage, InstanceStorageExt};
+
+pub struct RemoveGroupUseCase<IS> {
+    instance_storage: Arc<IS>,
+}
+
+impl<IS: InstanceStorage> RemoveGroupUseCase<IS> {
+    pub fn new(instance_storage: Arc<IS>) -> Self {
+        Self { instance_storage }
+    }
+
+    /// Ungroups every member, the instances themselves are kept.
+    /// Returns ids of the updated instances
+    pub async fn execute(&self, group: String) -> Result<Vec<String>, InstanceError> {
+        let member_ids: Vec<String> = self
+            .instance_storage
+            .list()
+            .await?
+            .into_iter()
+            .filter(|instance| instance.group.as_ref() == Some(&group))
+            .map(|instance| instance.id)
+            .collect();
+
+        if member_ids.is_empty() {
+            return Err(InstanceError::GroupNotFound { group });
+        }
+
+        self.instance_storage
+            .upsert_all_with(
+                &member_ids,
+                |instance| {
+                    // Moved to another group since it was listed
+                    if instance.group.as_ref() == Some(&group) {
+                        instance.group = None;
+                        instance.modified = Utc::now();
+                    }
+                    Ok(())
+                },
+                |instance| {
+                    if instance.group.is_none() {
+                        instance.group = Some(group.clone());
+                        instance.modified = Utc::now();
+                    }
+                    Ok(())
+                },
+            )
+            .await?;
+
+        Ok(member_ids)
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::features::instance::{InstanceError, InstanceStorage, InstanceStorageExt};
+
+use super::validate_group_name;
+
+pub struct RenameGroupUseCase<IS> {
+    instance_storage: Arc<IS>,
+}
+
+impl<IS: InstanceStorage> RenameGroupUseCase<IS> {
+    pub fn new(instance_storage: Arc<IS>) -> Self {
+        Self { instance_storage }
+    }
+
+    /// Moves every member to the new group, returns ids of the updated instances
+    pub async fn execute(
+        &self,
+        group: String,
+        new_group: String,
+    ) -> Result<Vec<String>, InstanceError> {
+        validate_group_name(&new_group)?;
+        let new_group = new_group.trim().to_owned();
+
+        let member_ids: Vec<String> = self
+            .instance_storage
+            .list()
+            .await?
+            .into_iter()
+            .filter(|instance| instance.group.as_ref() == Some(&group))
+            .map(|instance| instance.id)
+            .collect();
+
+        if member_ids.is_empty() {
+            return Err(InstanceError::GroupNotFound { group });
+        }
+
+        self.instance_storage
+            .upsert_all_with(
+                &member_ids,
+                |instance| {
+                    // Moved to another group since it was listed
+                    if instance.group.as_ref() == Some(&group) {
+                        instance.group = Some(new_group.clone());
+                        instance.modified = Utc::now();
+                    }
+                    Ok(())
+                },
+                |instance| {
+                    if instance.group.as_ref() == Some(&new_group) {
+                        instance.group = Some(group.clone());
+                        instance.modified = Utc::now();
+                    }
+                    Ok(())
+                },
+            )
+            .await?;
+
+        Ok(member_ids)
+    }
+}
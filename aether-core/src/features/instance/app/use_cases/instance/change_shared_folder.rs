@@ -0,0 +1,225 @@
+use std::{path::Path, sync::Arc};
+
+use log::info;
+
+use crate::{
+    features::{
+        instance::{
+            InstanceError, InstanceLockService, InstanceOperation, InstanceStorage,
+            InstanceStorageExt, InstanceWatcherService, PackFile, PackStorage, SharedFolder,
+        },
+        settings::LocationInfo,
+    },
+    shared::{
+        copy_dir_all, create_dir_all, create_dir_link, read_async, read_dir, remove_dir_all,
+        remove_dir_link, rename, IoError,
+    },
+};
+
+pub struct ChangeSharedFolderUseCase<IS, PS, IWS> {
+    instance_storage: Arc<IS>,
+    pack_storage: Arc<PS>,
+    instance_watcher_service: Arc<IWS>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<IS: InstanceStorage, PS: PackStorage, IWS: InstanceWatcherService>
+    ChangeSharedFolderUseCase<IS, PS, IWS>
+{
+    pub fn new(
+        instance_storage: Arc<IS>,
+        pack_storage: Arc<PS>,
+        instance_watcher_service: Arc<IWS>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            pack_storage,
+            instance_watcher_service,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        shared_folder: SharedFolder,
+        shared: bool,
+    ) -> Result<(), InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ChangeSharedFolder)?;
+
+        let link = self
+            .location_info
+            .instance_dir(&instance_id)
+            .join(shared_folder.get_folder());
+
+        if link.is_symlink() != shared {
+            info!(
+                "Changing folder \"{}\" of instance \"{}\" to shared: {}",
+                shared_folder.get_folder(),
+                instance_id,
+                shared
+            );
+
+            let pack_files = self.get_pack_files(&instance_id, shared_folder).await?;
+
+            if shared {
+                self.link_folder(&instance_id, shared_folder, &link, &pack_files)
+                    .await?;
+            } else {
+                self.unlink_folder(&instance_id, shared_folder, &link, &pack_files)
+                    .await?;
+            }
+
+            self.instance_watcher_service
+                .watch_instance(&instance_id)
+                .await?;
+        }
+
+        self.instance_storage
+            .upsert_with(&instance_id, |instance| {
+                instance
+                    .shared_folders
+                    .retain(|folder| *folder != shared_folder);
+                if shared {
+                    instance.shared_folders.push(shared_folder);
+                }
+                Ok(())
+            })
+            .await
+    }
+
+    /// Moves the instance files into the shared folder and replaces the folder with a link
+    async fn link_folder(
+        &self,
+        instance_id: &str,
+        shared_folder: SharedFolder,
+        link: &Path,
+        pack_files: &[(String, PackFile)],
+    ) -> Result<(), InstanceError> {
+        let shared_dir = self.location_info.shared_folder(shared_folder.get_folder());
+        create_dir_all(&shared_dir).await?;
+
+        if link.exists() {
+            let mut moved_files = Vec::new();
+            let mut conflicts = Vec::new();
+
+            let mut entries = read_dir(link).await?;
+            while let Some(entry) = entries.next_entry().await.map_err(IoError::from)? {
+                let target = shared_dir.join(entry.file_name());
+
+                if !target.exists() {
+                    moved_files.push((entry.path(), target));
+                } else if !is_same_file(&entry.path(), &target).await? {
+                    conflicts.push(entry.file_name().to_string_lossy().to_string());
+                }
+            }
+
+            // Checked before moving anything, so a conflict leaves the instance untouched
+            if !conflicts.is_empty() {
+                return Err(InstanceError::SharedFolderConflict {
+                    folder: shared_folder.get_folder().to_owned(),
+                    files: conflicts,
+                });
+            }
+
+            for (source, target) in moved_files {
+                rename(&source, &target).await?;
+            }
+
+            // Only files identical to the shared ones are left, they are already shared
+            remove_dir_all(link).await?;
+        }
+
+        // Metadata is removed while the folder still belongs to the instance
+        let content_paths: Vec<String> = pack_files.iter().map(|(path, _)| path.clone()).collect();
+        self.pack_storage
+            .remove_pack_file_many(instance_id, &content_paths)
+            .await?;
+
+        create_dir_link(&shared_dir, link).await?;
+
+        self.restore_pack_files(instance_id, pack_files).await
+    }
+
+    /// Replaces the link with a copy of the shared folder, the shared folder itself is kept
+    async fn unlink_folder(
+        &self,
+        instance_id: &str,
+        shared_folder: SharedFolder,
+        link: &Path,
+        pack_files: &[(String, PackFile)],
+    ) -> Result<(), InstanceError> {
+        let shared_dir = self.location_info.shared_folder(shared_folder.get_folder());
+
+        remove_dir_link(link).await?;
+
+        if shared_dir.exists() {
+            let folder = link.to_path_buf();
+            tokio::task::spawn_blocking(move || copy_dir_all(&shared_dir, &folder))
+                .await
+                .map_err(|err| IoError::IoError(std::io::Error::other(err)))??;
+        } else {
+            create_dir_all(link).await?;
+        }
+
+        self.restore_pack_files(instance_id, pack_files).await
+    }
+
+    async fn get_pack_files(
+        &self,
+        instance_id: &str,
+        shared_folder: SharedFolder,
+    ) -> Result<Vec<(String, PackFile)>, InstanceError> {
+        if shared_folder.get_content_type().is_none() {
+            return Ok(Vec::new());
+        }
+
+        let pack = self.pack_storage.get_pack(instance_id).await?;
+
+        let mut pack_files = Vec::new();
+        for entry in pack.files {
+            if entry.file.split('/').next() != Some(shared_folder.get_folder()) {
+                continue;
+            }
+
+            let pack_file = self
+                .pack_storage
+                .get_pack_file(instance_id, &entry.file)
+                .await?;
+            pack_files.push((entry.file, pack_file));
+        }
+
+        Ok(pack_files)
+    }
+
+    async fn restore_pack_files(
+        &self,
+        instance_id: &str,
+        pack_files: &[(String, PackFile)],
+    ) -> Result<(), InstanceError> {
+        if pack_files.is_empty() {
+            return Ok(());
+        }
+
+        let (content_paths, pack_files): (Vec<String>, Vec<PackFile>) =
+            pack_files.iter().cloned().unzip();
+
+        self.pack_storage
+            .update_pack_file_many(instance_id, &content_paths, &pack_files)
+            .await
+    }
+}
+
+async fn is_same_file(first: &Path, second: &Path) -> Result<bool, IoError> {
+    if !first.is_file() || !second.is_file() {
+        return Ok(false);
+    }
+
+    Ok(read_async(first).await? == read_async(second).await?)
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use futures::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::features::{
+    instance::{
+        app::ListContentUseCase, ContentCompatibility, ContentCompatibilityStatus, ContentFile,
+        ContentProvider, ContentProviderRegistry, ContentType, InstanceError, PackStorage,
+    },
+    minecraft::{LoaderVersionPreference, ModLoader},
+};
+
+const CONCURRENT_REQUESTS: usize = 8;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceMigration {
+    pub game_version: String,
+    pub mod_loader: ModLoader,
+    pub loader_version: Option<LoaderVersionPreference>,
+}
+
+pub struct CheckInstanceMigrationUseCase<PS: PackStorage, CP: ContentProvider> {
+    list_content_use_case: Arc<ListContentUseCase<PS>>,
+    pack_storage: Arc<PS>,
+    provider_registry: Arc<ContentProviderRegistry<CP>>,
+}
+
+impl<PS: PackStorage, CP: ContentProvider> CheckInstanceMigrationUseCase<PS, CP> {
+    pub fn new(
+        list_content_use_case: Arc<ListContentUseCase<PS>>,
+        pack_storage: Arc<PS>,
+        provider_registry: Arc<ContentProviderRegistry<CP>>,
+    ) -> Self {
+        Self {
+            list_content_use_case,
+            pack_storage,
+            provider_registry,
+        }
+    }
+
+    /// Checks every installed content file against the migration target
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        migration: &InstanceMigration,
+    ) -> Result<Vec<ContentCompatibility>, InstanceError> {
+        let mut contents: Vec<ContentFile> = self
+            .list_content_use_case
+            .execute(instance_id.clone())
+            .await?
+            .into_iter()
+            .map(|(_, content)| content)
+            .collect();
+
+        contents.sort_by(|a, b| a.content_path.cmp(&b.content_path));
+
+        futures::stream::iter(contents)
+            .map(|content| self.check_content(&instance_id, content, migration))
+            .buffered(CONCURRENT_REQUESTS)
+            .try_collect()
+            .await
+    }
+
+    async fn check_content(
+        &self,
+        instance_id: &str,
+        content: ContentFile,
+        migration: &InstanceMigration,
+    ) -> Result<ContentCompatibility, InstanceError> {
+        let pack_file = self
+            .pack_storage
+            .get_pack_file(instance_id, &content.content_path)
+            .await?;
+
+        let provider_id = pack_file.update_provider;
+
+        let status = match (&provider_id, &pack_file.update) {
+            (Some(provider_id), Some(update)) => match update.get(provider_id) {
+                Some(update_data) => {
+                    self.get_status(provider_id, update_data, content.content_type, migration)
+                        .await
+                }
+                None => ContentCompatibilityStatus::Unknown,
+            },
+            _ => ContentCompatibilityStatus::Unknown,
+        };
+
+        Ok(ContentCompatibility {
+            content_path: content.content_path,
+            content_type: content.content_type,
+            name: content.name,
+            provider: provider_id,
+            status,
+        })
+    }
+
+    async fn get_status(
+        &self,
+        provider_id: &str,
+        update_data: &serde_json::Value,
+        content_type: ContentType,
+        migration: &InstanceMigration,
+    ) -> ContentCompatibilityStatus {
+        let Ok(provider) = self.provider_registry.get(provider_id) else {
+            return ContentCompatibilityStatus::Unknown;
+        };
+
+        // Only mods depend on the loader, other content is matched by game version
+        let loader =
+            (content_type == ContentType::Mod).then(|| migration.mod_loader.as_str().to_owned());
+
+        let versions = match provider
+            .list_compatible_versions(update_data, &migration.game_version, &loader)
+            .await
+        {
+            Ok(versions) => versions,
+            Err(err) => {
+                return ContentCompatibilityStatus::CheckFailed {
+                    error: err.to_string(),
+                };
+            }
+        };
+
+        let installed_version = provider.get_update_data_version(update_data);
+
+        if versions
+            .iter()
+            .any(|version| Some(&version.id) == installed_version.as_ref())
+        {
+            return ContentCompatibilityStatus::Compatible;
+        }
+
+        match versions.into_iter().next() {
+            Some(version) => ContentCompatibilityStatus::UpdateAvailable { version },
+            None => ContentCompatibilityStatus::Incompatible,
+        }
+    }
+}
@@ -195,6 +195,9 @@ fn build_instance(
         id: sanitized_name.to_owned(),
         name: name.to_owned(),
         icon_path: icon_path.as_ref().map(ToOwned::to_owned),
+        group: None,
+        tags: Vec::new(),
+        favorite: false,
         install_stage: InstanceInstallStage::NotInstalled,
         game_version: game_version.to_owned(),
         loader: mod_loader,
@@ -205,6 +208,8 @@ fn build_instance(
         memory: None,
         force_fullscreen: None,
         game_resolution: None,
+        game_options: None,
+        account_id: None,
         created: Utc::now(),
         modified: Utc::now(),
         last_played: None,
@@ -212,6 +217,8 @@ fn build_instance(
         recent_time_played: 0,
         hooks: Hooks::default(),
         pack_info: pack_info.clone(),
+        snapshot_policy: None,
+        shared_folders: Vec::new(),
     }
 }
 
@@ -224,7 +231,7 @@ async fn create_unique_instance_dir(
     Ok((instance_path, sanitized_name))
 }
 
-fn create_unique_instance_path(name: &str, base_dir: &Path) -> (PathBuf, String) {
+pub fn create_unique_instance_path(name: &str, base_dir: &Path) -> (PathBuf, String) {
     let base_sanitized_name = sanitize_instance_name(name);
 
     let mut sanitized_name = base_sanitized_name.clone();
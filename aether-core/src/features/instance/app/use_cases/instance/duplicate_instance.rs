@@ -0,0 +1,255 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use chrono::Utc;
+use log::{debug, error, info};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    features::{
+        instance::{
+            ContentType, Instance, InstanceError, InstanceInstallStage, InstanceLockService,
+            InstanceOperation, InstanceStorage, InstanceWatcherService, SharedFolder,
+        },
+        settings::LocationInfo,
+    },
+    shared::{create_dir_link, remove_file, IoError},
+};
+
+use super::create_unique_instance_path;
+
+const SAVES_FOLDER: &str = "saves";
+const LOGS_FOLDER: &str = "logs";
+const CRASH_REPORTS_FOLDER: &str = "crash-reports";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateInstanceOptions {
+    /// Hardlink content files (mods, resource packs, etc.) instead of copying them
+    #[serde(default)]
+    pub hardlink_content: bool,
+    #[serde(default)]
+    pub exclude_saves: bool,
+    /// Skip `logs/` and `crash-reports/`
+    #[serde(default)]
+    pub exclude_logs: bool,
+    #[serde(default)]
+    pub reset_playtime: bool,
+    #[serde(default)]
+    pub reset_pack_info: bool,
+}
+
+pub struct DuplicateInstanceUseCase<IS, IWS> {
+    instance_storage: Arc<IS>,
+    instance_watcher_service: Arc<IWS>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<IS: InstanceStorage, IWS: InstanceWatcherService> DuplicateInstanceUseCase<IS, IWS> {
+    pub fn new(
+        instance_storage: Arc<IS>,
+        instance_watcher_service: Arc<IWS>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            instance_watcher_service,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        new_name: String,
+        options: DuplicateInstanceOptions,
+    ) -> Result<String, InstanceError> {
+        // Source files must not change while they are copied
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::Duplicate)?;
+
+        let instance = self.instance_storage.get(&instance_id).await?;
+
+        if matches!(
+            instance.install_stage,
+            InstanceInstallStage::Installing | InstanceInstallStage::PackInstalling
+        ) {
+            return Err(InstanceError::InstanceStillInstalling { instance_id });
+        }
+
+        let source_dir = self.location_info.instance_dir(&instance_id);
+        let (target_dir, new_id) =
+            create_unique_instance_path(&new_name, &self.location_info.instances_dir());
+
+        info!(
+            "Duplicating instance \"{}\" to \"{:?}\"",
+            instance_id, target_dir
+        );
+
+        let new_instance = build_duplicate(&instance, &new_id, &new_name, &options);
+
+        match self
+            .setup_duplicate(source_dir, target_dir, &new_instance, options)
+            .await
+        {
+            Ok(()) => {
+                info!("Instance \"{}\" duplicated as \"{}\"", instance_id, new_id);
+                Ok(new_id)
+            }
+            Err(err) => {
+                error!("Failed to duplicate instance \"{}\": {}", instance_id, err);
+
+                if let Err(cleanup_err) = self.instance_storage.remove(&new_id).await {
+                    error!("Failed to cleanup instance: {}", cleanup_err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn setup_duplicate(
+        &self,
+        source_dir: PathBuf,
+        target_dir: PathBuf,
+        new_instance: &Instance,
+        options: DuplicateInstanceOptions,
+    ) -> Result<(), InstanceError> {
+        let linked_folders: Vec<SharedFolder> = SharedFolder::iterator()
+            .filter(|shared_folder| {
+                source_dir.join(shared_folder.get_folder()).is_symlink()
+                    && !is_excluded(Path::new(shared_folder.get_folder()), &options)
+            })
+            .collect();
+
+        let reset_playtime = options.reset_playtime;
+        let copy_target_dir = target_dir.clone();
+        tokio::task::spawn_blocking(move || {
+            copy_instance_dir(&source_dir, &source_dir, &copy_target_dir, &options)
+        })
+        .await
+        .map_err(|err| IoError::IoError(std::io::Error::other(err)))??;
+
+        // Recorded sessions would bring the reset play time back on the next recount
+        if reset_playtime {
+            let sessions_file = self.location_info.instance_sessions_file(&new_instance.id);
+            if sessions_file.exists() {
+                remove_file(&sessions_file).await?;
+            }
+        }
+
+        // Shared folders stay shared in the copy instead of being copied
+        for shared_folder in linked_folders {
+            let folder = shared_folder.get_folder();
+            create_dir_link(
+                self.location_info.shared_folder(folder),
+                target_dir.join(folder),
+            )
+            .await?;
+        }
+
+        // Overwrites the copied instance.json with the new id and name
+        self.instance_storage.upsert(new_instance).await?;
+
+        self.instance_watcher_service
+            .watch_instance(&new_instance.id)
+            .await
+    }
+}
+
+fn build_duplicate(
+    instance: &Instance,
+    new_id: &str,
+    new_name: &str,
+    options: &DuplicateInstanceOptions,
+) -> Instance {
+    let mut new_instance = instance.clone();
+
+    new_instance.id = new_id.to_owned();
+    new_instance.name = new_name.to_owned();
+    new_instance.created = Utc::now();
+    new_instance.modified = Utc::now();
+
+    // An unfinished install can't be trusted in the copy either
+    if new_instance.install_stage != InstanceInstallStage::Installed {
+        new_instance.install_stage = InstanceInstallStage::NotInstalled;
+    }
+
+    if options.reset_playtime {
+        new_instance.last_played = None;
+        new_instance.time_played = 0;
+        new_instance.recent_time_played = 0;
+    }
+
+    if options.reset_pack_info {
+        new_instance.pack_info = None;
+    }
+
+    new_instance
+}
+
+fn is_excluded(relative_path: &Path, options: &DuplicateInstanceOptions) -> bool {
+    let Some(top_folder) = relative_path.iter().next().and_then(|name| name.to_str()) else {
+        return false;
+    };
+
+    match top_folder {
+        SAVES_FOLDER => options.exclude_saves,
+        LOGS_FOLDER | CRASH_REPORTS_FOLDER => options.exclude_logs,
+        _ => false,
+    }
+}
+
+fn copy_instance_dir(
+    source_root: &Path,
+    dir: &Path,
+    target_root: &Path,
+    options: &DuplicateInstanceOptions,
+) -> Result<(), IoError> {
+    let relative_dir = dir.strip_prefix(source_root).unwrap_or(Path::new(""));
+    let target_dir = target_root.join(relative_dir);
+    std::fs::create_dir_all(&target_dir).map_err(|e| IoError::with_path(e, &target_dir))?;
+
+    for entry in std::fs::read_dir(dir).map_err(|e| IoError::with_path(e, dir))? {
+        let entry = entry?;
+        let path = entry.path();
+        let relative_path = relative_dir.join(entry.file_name());
+
+        if is_excluded(&relative_path, options) {
+            continue;
+        }
+
+        let file_type = entry.file_type()?;
+
+        // Links are recreated by the caller
+        if file_type.is_symlink() {
+            continue;
+        }
+
+        if file_type.is_dir() {
+            copy_instance_dir(source_root, &path, target_root, options)?;
+            continue;
+        }
+
+        let target_path = target_root.join(&relative_path);
+
+        // Providers replace content files instead of writing in place, so updating one
+        // instance doesn't change the other
+        if options.hardlink_content && ContentType::get_from_parent_folder(&relative_path).is_some()
+        {
+            match std::fs::hard_link(&path, &target_path) {
+                Ok(()) => continue,
+                Err(err) => debug!("Failed to hardlink {:?}, copying instead: {}", path, err),
+            }
+        }
+
+        std::fs::copy(&path, &target_path).map_err(|e| IoError::with_path(e, &path))?;
+    }
+
+    Ok(())
+}
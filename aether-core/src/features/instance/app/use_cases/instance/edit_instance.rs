@@ -2,10 +2,15 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::features::{
-    instance::{Instance, InstanceError, InstanceStorage},
-    settings::{app::EditHooks, MemorySettings, WindowSize},
+    instance::{
+        app::{normalize_tags, validate_group_name},
+        Instance, InstanceError, InstanceLockService, InstanceOperation, InstanceStorage,
+        SnapshotPolicy,
+    },
+    settings::{app::EditHooks, GameOptions, MemorySettings, WindowSize},
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -17,6 +22,14 @@ pub struct EditInstance {
         skip_serializing_if = "Option::is_none",
         with = "::serde_with::rust::double_option"
     )]
+    pub group: Option<Option<String>>,
+    pub tags: Option<Vec<String>>,
+    pub favorite: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
     pub java_path: Option<Option<String>>,
     #[serde(
         default,
@@ -41,17 +54,45 @@ pub struct EditInstance {
         skip_serializing_if = "Option::is_none",
         with = "::serde_with::rust::double_option"
     )]
+    pub force_fullscreen: Option<Option<bool>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
     pub game_resolution: Option<Option<WindowSize>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    pub game_options: Option<Option<GameOptions>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    pub account_id: Option<Option<Uuid>>,
     pub hooks: Option<EditHooks>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    pub snapshot_policy: Option<Option<SnapshotPolicy>>,
 }
 
 pub struct EditInstanceUseCase<IS> {
     instance_storage: Arc<IS>,
+    instance_lock_service: Arc<InstanceLockService>,
 }
 
 impl<IS: InstanceStorage> EditInstanceUseCase<IS> {
-    pub fn new(instance_storage: Arc<IS>) -> Self {
-        Self { instance_storage }
+    pub fn new(instance_storage: Arc<IS>, instance_lock_service: Arc<InstanceLockService>) -> Self {
+        Self {
+            instance_storage,
+            instance_lock_service,
+        }
     }
 
     pub async fn execute(
@@ -61,6 +102,11 @@ impl<IS: InstanceStorage> EditInstanceUseCase<IS> {
     ) -> Result<Instance, InstanceError> {
         validate_edit(&edit_instance)?;
 
+        // Other operations rewrite instance.json from their own copy and would drop the edit
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::Edit)?;
+
         let mut instance = self.instance_storage.get(&instance_id).await?;
         apply_edit_changes(&mut instance, &edit_instance);
         self.instance_storage.upsert(&instance).await?;
@@ -71,18 +117,37 @@ impl<IS: InstanceStorage> EditInstanceUseCase<IS> {
 fn apply_edit_changes(instance: &mut Instance, edit_instance: &EditInstance) {
     let EditInstance {
         name,
+        group,
+        tags,
+        favorite,
         java_path,
         launch_args,
         env_vars,
         memory,
+        force_fullscreen,
         game_resolution,
+        game_options,
+        account_id,
         hooks,
+        snapshot_policy,
     } = edit_instance;
 
     if let Some(name) = name {
         instance.name = name.clone();
     }
 
+    if let Some(group) = group {
+        instance.group = group.as_ref().map(|group| group.trim().to_owned());
+    }
+
+    if let Some(tags) = tags {
+        instance.tags = normalize_tags(tags);
+    }
+
+    if let Some(favorite) = favorite {
+        instance.favorite = *favorite;
+    }
+
     if let Some(java_path) = java_path {
         instance.java_path = java_path.clone();
     }
@@ -99,14 +164,30 @@ fn apply_edit_changes(instance: &mut Instance, edit_instance: &EditInstance) {
         instance.memory = *mem;
     }
 
+    if let Some(force_fullscreen) = force_fullscreen {
+        instance.force_fullscreen = *force_fullscreen;
+    }
+
     if let Some(res) = game_resolution {
         instance.game_resolution = *res;
     }
 
+    if let Some(game_options) = game_options {
+        instance.game_options = game_options.clone();
+    }
+
+    if let Some(account_id) = account_id {
+        instance.account_id = *account_id;
+    }
+
     if let Some(hooks) = hooks {
         hooks.apply_to(&mut instance.hooks);
     }
 
+    if let Some(snapshot_policy) = snapshot_policy {
+        instance.snapshot_policy = snapshot_policy.clone();
+    }
+
     instance.modified = Utc::now();
 }
 
@@ -115,6 +196,10 @@ fn validate_edit(edit: &EditInstance) -> Result<(), InstanceError> {
         validate_name(name)?;
     }
 
+    if let Some(Some(group)) = &edit.group {
+        validate_group_name(group)?;
+    }
+
     Ok(())
 }
 
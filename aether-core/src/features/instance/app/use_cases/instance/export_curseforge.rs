@@ -0,0 +1,169 @@
+use std::{collections::BTreeSet, path::PathBuf, sync::Arc};
+
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+
+use crate::features::{
+    instance::{
+        app::ListContentUseCase,
+        utils::{collect_files_async, validate_include_folders, write_pack_archive},
+        CurseForgeManifest, CurseForgeManifestFile, CurseForgeMinecraft, CurseForgeUpdateData,
+        Instance, InstanceError, InstanceStorage, PackStorage, CURSEFORGE_MANIFEST_FILE,
+        CURSEFORGE_MANIFEST_TYPE, CURSEFORGE_MANIFEST_VERSION, CURSEFORGE_OVERRIDES_DIR,
+    },
+    minecraft::{app::MinecraftApplicationError, LoaderVersionResolver, MetadataStorage},
+    settings::LocationInfo,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportCurseForgeOptions {
+    pub output_path: PathBuf,
+    pub version: String,
+    pub name: Option<String>,
+    pub author: Option<String>,
+    /// Instance folders copied into `overrides/`, e.g. `config`
+    pub include_folders: Vec<String>,
+}
+
+pub struct ExportCurseForgeUseCase<IS: InstanceStorage, PS: PackStorage, MS: MetadataStorage> {
+    instance_storage: Arc<IS>,
+    pack_storage: Arc<PS>,
+    list_content_use_case: Arc<ListContentUseCase<PS>>,
+    loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+    location_info: Arc<LocationInfo>,
+}
+
+impl<IS: InstanceStorage, PS: PackStorage, MS: MetadataStorage>
+    ExportCurseForgeUseCase<IS, PS, MS>
+{
+    pub fn new(
+        instance_storage: Arc<IS>,
+        pack_storage: Arc<PS>,
+        list_content_use_case: Arc<ListContentUseCase<PS>>,
+        loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+        location_info: Arc<LocationInfo>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            pack_storage,
+            list_content_use_case,
+            loader_version_resolver,
+            location_info,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        options: ExportCurseForgeOptions,
+    ) -> Result<(), InstanceError> {
+        validate_include_folders(&options.include_folders)?;
+
+        let instance = self.instance_storage.get(&instance_id).await?;
+        let instance_dir = self.location_info.instance_dir(&instance_id);
+
+        info!(
+            "Exporting instance \"{}\" as CurseForge modpack to {:?}",
+            instance_id, options.output_path
+        );
+
+        let minecraft = self.get_minecraft(&instance).await?;
+
+        let mut files = Vec::new();
+        let mut referenced_paths = BTreeSet::new();
+        let mut overrides = BTreeSet::new();
+
+        let contents = self
+            .list_content_use_case
+            .execute(instance_id.clone())
+            .await?;
+
+        for (_, content) in contents {
+            if content.disabled {
+                continue;
+            }
+
+            let pack_file = self
+                .pack_storage
+                .get_pack_file(&instance_id, &content.content_path)
+                .await?;
+
+            // Only files installed from CurseForge can be referenced by id
+            match CurseForgeUpdateData::from_pack_file(&pack_file) {
+                Some(update_data) => {
+                    files.push(CurseForgeManifestFile {
+                        project_id: update_data.project_id,
+                        file_id: update_data.file_id,
+                        required: !pack_file
+                            .option
+                            .as_ref()
+                            .is_some_and(|option| option.optional),
+                    });
+                    referenced_paths.insert(content.instance_relative_path);
+                }
+                None => {
+                    overrides.insert(content.instance_relative_path);
+                }
+            }
+        }
+
+        for folder in &options.include_folders {
+            overrides.extend(
+                collect_files_async(instance_dir.clone(), instance_dir.join(folder)).await?,
+            );
+        }
+
+        overrides.retain(|path| !referenced_paths.contains(path));
+
+        files.sort_by_key(|file| (file.project_id, file.file_id));
+
+        let manifest = CurseForgeManifest {
+            minecraft,
+            manifest_type: CURSEFORGE_MANIFEST_TYPE.to_owned(),
+            manifest_version: CURSEFORGE_MANIFEST_VERSION,
+            name: options.name.unwrap_or(instance.name),
+            version: options.version,
+            author: options.author.unwrap_or_default(),
+            files,
+            overrides: CURSEFORGE_OVERRIDES_DIR.to_owned(),
+        };
+
+        debug!(
+            "Writing CurseForge modpack with {} files and {} overrides",
+            manifest.files.len(),
+            overrides.len()
+        );
+
+        write_pack_archive(
+            options.output_path,
+            CURSEFORGE_MANIFEST_FILE,
+            manifest,
+            CURSEFORGE_OVERRIDES_DIR.to_owned(),
+            instance_dir,
+            overrides,
+        )
+        .await
+    }
+
+    async fn get_minecraft(
+        &self,
+        instance: &Instance,
+    ) -> Result<CurseForgeMinecraft, InstanceError> {
+        let loader_version = self
+            .loader_version_resolver
+            .resolve(
+                &instance.game_version,
+                &instance.loader,
+                instance.loader_version.as_ref(),
+            )
+            .await
+            .map_err(MinecraftApplicationError::Domain)?;
+
+        Ok(CurseForgeMinecraft::new(
+            instance.game_version.clone(),
+            instance.loader,
+            loader_version.as_ref().map(|version| version.id.as_str()),
+        ))
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use bytes::Bytes;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    features::{
+        instance::{
+            app::ListContentUseCase,
+            utils::{
+                collect_files_async, to_pack_loader_version, validate_include_folders,
+                write_pack_archive,
+            },
+            Instance, InstanceError, InstanceStorage, MrpackEnv, MrpackEnvSupport, MrpackFile,
+            MrpackIndex, PackFile, PackStorage, MRPACK_FORMAT_VERSION, MRPACK_INDEX_FILE,
+            MRPACK_MINECRAFT_DEPENDENCY, MRPACK_OVERRIDES_DIR,
+        },
+        minecraft::{app::MinecraftApplicationError, LoaderVersionResolver, MetadataStorage},
+        settings::LocationInfo,
+    },
+    shared::{read_async, sha1_async, sha512_async},
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMrpackOptions {
+    pub output_path: PathBuf,
+    pub version_id: String,
+    pub name: Option<String>,
+    pub summary: Option<String>,
+    /// Instance folders copied into `overrides/`, e.g. `config`
+    pub include_folders: Vec<String>,
+}
+
+pub struct ExportMrpackUseCase<IS: InstanceStorage, PS: PackStorage, MS: MetadataStorage> {
+    instance_storage: Arc<IS>,
+    pack_storage: Arc<PS>,
+    list_content_use_case: Arc<ListContentUseCase<PS>>,
+    loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+    location_info: Arc<LocationInfo>,
+}
+
+impl<IS: InstanceStorage, PS: PackStorage, MS: MetadataStorage> ExportMrpackUseCase<IS, PS, MS> {
+    pub fn new(
+        instance_storage: Arc<IS>,
+        pack_storage: Arc<PS>,
+        list_content_use_case: Arc<ListContentUseCase<PS>>,
+        loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+        location_info: Arc<LocationInfo>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            pack_storage,
+            list_content_use_case,
+            loader_version_resolver,
+            location_info,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        options: ExportMrpackOptions,
+    ) -> Result<(), InstanceError> {
+        validate_include_folders(&options.include_folders)?;
+
+        let instance = self.instance_storage.get(&instance_id).await?;
+        let instance_dir = self.location_info.instance_dir(&instance_id);
+
+        info!(
+            "Exporting instance \"{}\" to {:?}",
+            instance_id, options.output_path
+        );
+
+        let dependencies = self.get_dependencies(&instance).await?;
+
+        let mut files = Vec::new();
+        let mut overrides = BTreeSet::new();
+
+        let contents = self
+            .list_content_use_case
+            .execute(instance_id.clone())
+            .await?;
+
+        for (_, content) in contents {
+            if content.disabled {
+                continue;
+            }
+
+            let pack_file = self
+                .pack_storage
+                .get_pack_file(&instance_id, &content.content_path)
+                .await?;
+
+            // Disabled content is skipped, so this is also the path the pack installs to
+            let path = content.instance_relative_path;
+
+            if pack_file.download.is_some() {
+                files.push(
+                    to_mrpack_file(path.clone(), &instance_dir.join(&path), &pack_file).await?,
+                );
+            } else {
+                overrides.insert(path);
+            }
+        }
+
+        for folder in &options.include_folders {
+            overrides.extend(
+                collect_files_async(instance_dir.clone(), instance_dir.join(folder)).await?,
+            );
+        }
+
+        // Files with a download link must not be duplicated in overrides
+        for file in &files {
+            overrides.remove(&file.path);
+        }
+
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let index = MrpackIndex {
+            format_version: MRPACK_FORMAT_VERSION,
+            game: "minecraft".to_owned(),
+            version_id: options.version_id,
+            name: options.name.unwrap_or(instance.name),
+            summary: options.summary,
+            files,
+            dependencies,
+        };
+
+        debug!(
+            "Writing mrpack with {} files and {} overrides",
+            index.files.len(),
+            overrides.len()
+        );
+
+        write_pack_archive(
+            options.output_path,
+            MRPACK_INDEX_FILE,
+            index,
+            MRPACK_OVERRIDES_DIR.to_owned(),
+            instance_dir,
+            overrides,
+        )
+        .await
+    }
+
+    async fn get_dependencies(
+        &self,
+        instance: &Instance,
+    ) -> Result<HashMap<String, String>, InstanceError> {
+        let mut dependencies = HashMap::from([(
+            MRPACK_MINECRAFT_DEPENDENCY.to_owned(),
+            instance.game_version.clone(),
+        )]);
+
+        let Some(dependency_id) = MrpackIndex::loader_dependency_id(instance.loader) else {
+            return Ok(dependencies);
+        };
+
+        let loader_version = self
+            .loader_version_resolver
+            .resolve(
+                &instance.game_version,
+                &instance.loader,
+                instance.loader_version.as_ref(),
+            )
+            .await
+            .map_err(MinecraftApplicationError::Domain)?;
+
+        if let Some(loader_version) = loader_version {
+            dependencies.insert(
+                dependency_id.to_owned(),
+                to_pack_loader_version(&instance.game_version, &loader_version.id),
+            );
+        }
+
+        Ok(dependencies)
+    }
+}
+
+/// Both hashes come from the file on disk, the stored hash may predate edits or repairs
+async fn to_mrpack_file(
+    path: String,
+    file_path: &Path,
+    pack_file: &PackFile,
+) -> Result<MrpackFile, InstanceError> {
+    let bytes = Bytes::from(read_async(file_path).await?);
+    let file_size = bytes.len() as u64;
+
+    let sha1 = sha1_async(bytes.clone()).await.map_err(|error| {
+        debug!("Failed to compute sha1: {error}");
+        InstanceError::HashConstructError
+    })?;
+    let sha512 = sha512_async(bytes).await.map_err(|error| {
+        debug!("Failed to compute sha512: {error}");
+        InstanceError::HashConstructError
+    })?;
+
+    Ok(MrpackFile {
+        path,
+        hashes: HashMap::from([("sha1".to_owned(), sha1), ("sha512".to_owned(), sha512)]),
+        env: get_env(pack_file),
+        downloads: pack_file
+            .download
+            .iter()
+            .map(|download| download.url.clone())
+            .collect(),
+        file_size,
+    })
+}
+
+fn get_env(pack_file: &PackFile) -> Option<MrpackEnv> {
+    let optional = pack_file
+        .option
+        .as_ref()
+        .is_some_and(|option| option.optional);
+
+    let support = if optional {
+        MrpackEnvSupport::Optional
+    } else {
+        MrpackEnvSupport::Required
+    };
+
+    match pack_file.side.as_deref() {
+        Some("client") => Some(MrpackEnv {
+            client: support,
+            server: MrpackEnvSupport::Unsupported,
+        }),
+        Some("server") => Some(MrpackEnv {
+            client: MrpackEnvSupport::Unsupported,
+            server: support,
+        }),
+        _ if optional => Some(MrpackEnv {
+            client: support,
+            server: support,
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,250 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use bytes::Bytes;
+use log::{debug, info};
+use path_slash::PathBufExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    features::{
+        instance::{
+            app::ListContentUseCase,
+            utils::{
+                collect_files_async, compute_packwiz_hash, to_pack_loader_version,
+                validate_include_folders,
+            },
+            ContentType, Instance, InstanceError, InstanceStorage, PackStorage, PackwizHashFormat,
+            PackwizIndex, PackwizIndexFile, PackwizIndexRef, PackwizMetafile, PackwizPack,
+            PACKWIZ_INDEX_FILE, PACKWIZ_METAFILE_EXTENSION, PACKWIZ_PACK_FILE, PACKWIZ_PACK_FORMAT,
+        },
+        minecraft::{
+            app::MinecraftApplicationError, LoaderVersionResolver, MetadataStorage, ModLoader,
+        },
+        settings::LocationInfo,
+    },
+    shared::{read_async, remove_file, write_async, IoError},
+};
+
+const PACKWIZ_HASH_FORMAT: PackwizHashFormat = PackwizHashFormat::Sha256;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportPackwizOptions {
+    /// Directory where `pack.toml` is written, usually a git repository
+    pub output_dir: PathBuf,
+    pub version: Option<String>,
+    pub name: Option<String>,
+    pub author: Option<String>,
+    /// Instance folders stored in the pack as is, e.g. `config`
+    pub include_folders: Vec<String>,
+}
+
+pub struct ExportPackwizUseCase<IS: InstanceStorage, PS: PackStorage, MS: MetadataStorage> {
+    instance_storage: Arc<IS>,
+    pack_storage: Arc<PS>,
+    list_content_use_case: Arc<ListContentUseCase<PS>>,
+    loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+    location_info: Arc<LocationInfo>,
+}
+
+impl<IS: InstanceStorage, PS: PackStorage, MS: MetadataStorage> ExportPackwizUseCase<IS, PS, MS> {
+    pub fn new(
+        instance_storage: Arc<IS>,
+        pack_storage: Arc<PS>,
+        list_content_use_case: Arc<ListContentUseCase<PS>>,
+        loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+        location_info: Arc<LocationInfo>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            pack_storage,
+            list_content_use_case,
+            loader_version_resolver,
+            location_info,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        options: ExportPackwizOptions,
+    ) -> Result<(), InstanceError> {
+        validate_include_folders(&options.include_folders)?;
+
+        let instance = self.instance_storage.get(&instance_id).await?;
+        let instance_dir = self.location_info.instance_dir(&instance_id);
+
+        info!(
+            "Exporting instance \"{}\" as packwiz pack to {:?}",
+            instance_id, options.output_dir
+        );
+
+        let versions = self.get_versions(&instance).await?;
+
+        // Pack relative path to file content, sorted to keep the index stable
+        let mut pack_files: BTreeMap<String, (Bytes, bool)> = BTreeMap::new();
+        let mut referenced_paths = BTreeSet::new();
+        let mut plain_files = BTreeSet::new();
+
+        let contents = self
+            .list_content_use_case
+            .execute(instance_id.clone())
+            .await?;
+
+        for (_, content) in contents {
+            if content.disabled {
+                continue;
+            }
+
+            let pack_file = self
+                .pack_storage
+                .get_pack_file(&instance_id, &content.content_path)
+                .await?;
+
+            match PackwizMetafile::from_pack_file(&pack_file) {
+                Some(metafile) => {
+                    let metafile_path = get_metafile_path(&content.content_path);
+                    pack_files.insert(metafile_path, (to_toml_bytes(&metafile)?, true));
+                    referenced_paths.insert(content.instance_relative_path);
+                }
+                None => {
+                    plain_files.insert(content.instance_relative_path);
+                }
+            }
+        }
+
+        for folder in &options.include_folders {
+            plain_files.extend(
+                collect_files_async(instance_dir.clone(), instance_dir.join(folder)).await?,
+            );
+        }
+
+        for path in plain_files.difference(&referenced_paths) {
+            let bytes = read_async(instance_dir.join(path)).await?;
+            pack_files.insert(path.clone(), (Bytes::from(bytes), false));
+        }
+
+        remove_stale_metafiles(&options.output_dir, &pack_files).await?;
+
+        let mut index = PackwizIndex {
+            hash_format: PACKWIZ_HASH_FORMAT,
+            files: Vec::with_capacity(pack_files.len()),
+        };
+
+        for (path, (bytes, metafile)) in pack_files {
+            index.files.push(PackwizIndexFile {
+                file: path.clone(),
+                hash: compute_packwiz_hash(PACKWIZ_HASH_FORMAT, bytes.clone()).await?,
+                hash_format: None,
+                alias: None,
+                metafile,
+                preserve: false,
+            });
+
+            write_async(options.output_dir.join(path), &bytes).await?;
+        }
+
+        debug!("Writing packwiz index with {} files", index.files.len());
+
+        let index_bytes = to_toml_bytes(&index)?;
+        write_async(options.output_dir.join(PACKWIZ_INDEX_FILE), &index_bytes).await?;
+
+        let pack = PackwizPack {
+            name: options.name.unwrap_or(instance.name),
+            author: options.author,
+            version: options.version,
+            description: None,
+            pack_format: Some(PACKWIZ_PACK_FORMAT.to_owned()),
+            index: PackwizIndexRef {
+                file: PACKWIZ_INDEX_FILE.to_owned(),
+                hash_format: PACKWIZ_HASH_FORMAT,
+                hash: compute_packwiz_hash(PACKWIZ_HASH_FORMAT, index_bytes).await?,
+            },
+            versions,
+        };
+
+        write_async(
+            options.output_dir.join(PACKWIZ_PACK_FILE),
+            to_toml_bytes(&pack)?,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_versions(
+        &self,
+        instance: &Instance,
+    ) -> Result<BTreeMap<String, String>, InstanceError> {
+        let mut versions =
+            BTreeMap::from([("minecraft".to_owned(), instance.game_version.clone())]);
+
+        if instance.loader == ModLoader::Vanilla {
+            return Ok(versions);
+        }
+
+        let loader_version = self
+            .loader_version_resolver
+            .resolve(
+                &instance.game_version,
+                &instance.loader,
+                instance.loader_version.as_ref(),
+            )
+            .await
+            .map_err(MinecraftApplicationError::Domain)?;
+
+        if let Some(loader_version) = loader_version {
+            versions.insert(
+                instance.loader.as_str().to_owned(),
+                to_pack_loader_version(&instance.game_version, &loader_version.id),
+            );
+        }
+
+        Ok(versions)
+    }
+}
+
+/// `mods/sodium.jar` is described by `mods/sodium.pw.toml`
+fn get_metafile_path(content_path: &str) -> String {
+    PathBuf::from(content_path)
+        .with_extension(PACKWIZ_METAFILE_EXTENSION)
+        .to_slash_lossy()
+        .to_string()
+}
+
+/// Removes metafiles left by a previous export for content the instance no longer has.
+/// Only content folders are walked, metafiles are never written anywhere else
+async fn remove_stale_metafiles(
+    output_dir: &Path,
+    pack_files: &BTreeMap<String, (Bytes, bool)>,
+) -> Result<(), InstanceError> {
+    let metafile_suffix = format!(".{PACKWIZ_METAFILE_EXTENSION}");
+
+    for content_type in ContentType::iterator() {
+        let existing_files = collect_files_async(
+            output_dir.to_path_buf(),
+            output_dir.join(content_type.get_folder()),
+        )
+        .await?;
+
+        for path in existing_files
+            .iter()
+            .filter(|path| path.ends_with(&metafile_suffix) && !pack_files.contains_key(*path))
+        {
+            debug!("Removing stale packwiz metafile \"{}\"", path);
+            remove_file(output_dir.join(path)).await?;
+        }
+    }
+
+    Ok(())
+}
+
+fn to_toml_bytes<T: Serialize>(value: &T) -> Result<Bytes, InstanceError> {
+    toml::to_string(value)
+        .map(Bytes::from)
+        .map_err(|err| IoError::SerializationError(err.to_string()).into())
+}
@@ -0,0 +1,222 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use futures::{StreamExt, TryStreamExt};
+use log::{debug, error, info};
+
+use crate::{
+    features::{
+        events::{EventEmitter, ProgressService},
+        instance::{
+            utils::{
+                extract_pack_overrides, install_imported_pack, is_inner_relative_path,
+                read_pack_manifest, PACK_CONCURRENT_DOWNLOADS,
+            },
+            CurseForgeFileResolver, CurseForgeManifest, CurseForgeManifestResolver, InstanceError,
+            InstanceLockService, InstanceOperation, InstanceStorage, InstanceWatcherService,
+            PackFile, PackStorage, ResolvedPackFile, CURSEFORGE_MANIFEST_FILE,
+        },
+        java::{JavaInstallationService, JavaStorage, JreProvider},
+        minecraft::{MetadataStorage, MinecraftDownloader},
+        settings::LocationInfo,
+    },
+    libs::request_client::{Request, RequestClient},
+    shared::{sha1_async, write_async},
+};
+
+use super::{CreateInstanceUseCase, NewInstance};
+
+pub struct ImportCurseForgeUseCase<
+    IS: InstanceStorage,
+    MS: MetadataStorage,
+    E: EventEmitter,
+    MD: MinecraftDownloader,
+    PS: ProgressService,
+    IWS: InstanceWatcherService,
+    JIS: JavaInstallationService,
+    JS: JavaStorage,
+    JP: JreProvider,
+    PST: PackStorage,
+    CFR: CurseForgeFileResolver,
+    RC: RequestClient,
+> {
+    instance_storage: Arc<IS>,
+    create_instance_use_case: Arc<CreateInstanceUseCase<IS, MS, E, MD, PS, IWS, JIS, JS, JP>>,
+    instance_watcher_service: Arc<IWS>,
+    pack_storage: Arc<PST>,
+    manifest_resolver: Arc<CurseForgeManifestResolver<CFR>>,
+    request_client: Arc<RC>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<
+        IS: InstanceStorage,
+        MS: MetadataStorage,
+        E: EventEmitter,
+        MD: MinecraftDownloader,
+        PS: ProgressService,
+        IWS: InstanceWatcherService,
+        JIS: JavaInstallationService,
+        JS: JavaStorage,
+        JP: JreProvider,
+        PST: PackStorage,
+        CFR: CurseForgeFileResolver,
+        RC: RequestClient,
+    > ImportCurseForgeUseCase<IS, MS, E, MD, PS, IWS, JIS, JS, JP, PST, CFR, RC>
+{
+    pub fn new(
+        instance_storage: Arc<IS>,
+        create_instance_use_case: Arc<CreateInstanceUseCase<IS, MS, E, MD, PS, IWS, JIS, JS, JP>>,
+        instance_watcher_service: Arc<IWS>,
+        pack_storage: Arc<PST>,
+        manifest_resolver: Arc<CurseForgeManifestResolver<CFR>>,
+        request_client: Arc<RC>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            create_instance_use_case,
+            instance_watcher_service,
+            pack_storage,
+            manifest_resolver,
+            request_client,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    pub async fn execute(&self, path: PathBuf) -> Result<String, InstanceError> {
+        let manifest: CurseForgeManifest =
+            read_pack_manifest(path.clone(), CURSEFORGE_MANIFEST_FILE).await?;
+
+        if !is_inner_relative_path(&manifest.overrides) {
+            return Err(InstanceError::InvalidModpack {
+                reason: format!("invalid overrides folder \"{}\"", manifest.overrides),
+            });
+        }
+
+        info!(
+            "Importing CurseForge modpack \"{}\" from {:?}",
+            manifest.name, path
+        );
+
+        // Resolve files first, so packs with blocked downloads don't leave an empty instance
+        let files = self.manifest_resolver.resolve(&manifest).await?;
+
+        let (mod_loader, loader_version) = manifest.minecraft.loader();
+
+        let instance_id = self
+            .create_instance_use_case
+            .execute(NewInstance {
+                name: manifest.name.clone(),
+                game_version: manifest.minecraft.version.clone(),
+                mod_loader,
+                loader_version,
+                icon_path: None,
+                skip_install_instance: Some(true),
+                pack_info: None,
+            })
+            .await?;
+
+        // Held until the pack is installed, so the half imported instance isn't launched or changed
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ImportPack)?;
+
+        let install = self.install_pack(&instance_id, path, &manifest, files);
+
+        match install_imported_pack(
+            self.instance_storage.as_ref(),
+            self.instance_watcher_service.as_ref(),
+            &instance_id,
+            install,
+        )
+        .await
+        {
+            Ok(()) => {
+                info!(
+                    "CurseForge modpack \"{}\" imported as \"{}\"",
+                    manifest.name, instance_id
+                );
+                Ok(instance_id)
+            }
+            Err(err) => {
+                error!(
+                    "Failed to import CurseForge modpack \"{}\": {}",
+                    manifest.name, err
+                );
+                Err(err)
+            }
+        }
+    }
+
+    async fn install_pack(
+        &self,
+        instance_id: &str,
+        path: PathBuf,
+        manifest: &CurseForgeManifest,
+        files: Vec<ResolvedPackFile>,
+    ) -> Result<(), InstanceError> {
+        let instance_dir = self.location_info.instance_dir(instance_id);
+
+        let downloaded: Vec<(String, PackFile)> = futures::stream::iter(files)
+            .map(|file| self.download_file(&instance_dir, file))
+            .buffer_unordered(PACK_CONCURRENT_DOWNLOADS)
+            .try_collect()
+            .await?;
+
+        let (content_paths, pack_files): (Vec<String>, Vec<PackFile>) =
+            downloaded.into_iter().unzip();
+
+        self.pack_storage
+            .update_pack_file_many(instance_id, &content_paths, &pack_files)
+            .await?;
+
+        extract_pack_overrides(path, vec![manifest.overrides.clone()], instance_dir).await
+    }
+
+    async fn download_file(
+        &self,
+        instance_dir: &Path,
+        file: ResolvedPackFile,
+    ) -> Result<(String, PackFile), InstanceError> {
+        let ResolvedPackFile {
+            content_path,
+            mut pack_file,
+        } = file;
+
+        let Some(download) = pack_file.download.as_mut() else {
+            return Err(InstanceError::CurseForgeDownloadBlocked {
+                files: vec![pack_file.file_name],
+            });
+        };
+
+        let mut request = Request::get(&download.url);
+        if !download.hash.is_empty() {
+            request = request.with_sha1(&download.hash);
+        }
+
+        let bytes = self
+            .request_client
+            .fetch_bytes(request)
+            .await
+            .map_err(|err| InstanceError::ContentDownloadError(err.to_string()))?;
+
+        // Not every CurseForge file has a sha1, so it is computed from the content
+        if download.hash.is_empty() {
+            download.hash = sha1_async(bytes.clone()).await.map_err(|error| {
+                debug!("Failed to compute sha1: {error}");
+                InstanceError::HashConstructError
+            })?;
+            pack_file.hash = download.hash.clone();
+        }
+
+        write_async(instance_dir.join(&content_path), &bytes).await?;
+
+        Ok((content_path, pack_file))
+    }
+}
@@ -0,0 +1,284 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use futures::{StreamExt, TryStreamExt};
+use log::{error, info, warn};
+
+use crate::{
+    features::{
+        events::{EventEmitter, ProgressService},
+        instance::{
+            utils::{
+                extract_pack_overrides, install_imported_pack, is_inner_relative_path,
+                read_pack_manifest, to_metadata_loader_version, PACK_CONCURRENT_DOWNLOADS,
+            },
+            ContentProvider, ContentProviderRegistry, InstanceError, InstanceLockService,
+            InstanceOperation, InstanceStorage, InstanceWatcherService, MrpackEnvSupport,
+            MrpackFile, MrpackIndex, PackFile, PackFileDownload, PackFileOption, PackStorage,
+            MRPACK_CLIENT_OVERRIDES_DIR, MRPACK_INDEX_FILE, MRPACK_OVERRIDES_DIR,
+        },
+        java::{JavaInstallationService, JavaStorage, JreProvider},
+        minecraft::{LoaderVersionPreference, MetadataStorage, MinecraftDownloader},
+        settings::LocationInfo,
+    },
+    libs::request_client::{Request, RequestClient},
+    shared::write_async,
+};
+
+use super::{CreateInstanceUseCase, NewInstance};
+
+pub struct ImportMrpackUseCase<
+    IS: InstanceStorage,
+    MS: MetadataStorage,
+    E: EventEmitter,
+    MD: MinecraftDownloader,
+    PS: ProgressService,
+    IWS: InstanceWatcherService,
+    JIS: JavaInstallationService,
+    JS: JavaStorage,
+    JP: JreProvider,
+    PST: PackStorage,
+    CP: ContentProvider,
+    RC: RequestClient,
+> {
+    instance_storage: Arc<IS>,
+    create_instance_use_case: Arc<CreateInstanceUseCase<IS, MS, E, MD, PS, IWS, JIS, JS, JP>>,
+    instance_watcher_service: Arc<IWS>,
+    pack_storage: Arc<PST>,
+    provider_registry: Arc<ContentProviderRegistry<CP>>,
+    request_client: Arc<RC>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<
+        IS: InstanceStorage,
+        MS: MetadataStorage,
+        E: EventEmitter,
+        MD: MinecraftDownloader,
+        PS: ProgressService,
+        IWS: InstanceWatcherService,
+        JIS: JavaInstallationService,
+        JS: JavaStorage,
+        JP: JreProvider,
+        PST: PackStorage,
+        CP: ContentProvider,
+        RC: RequestClient,
+    > ImportMrpackUseCase<IS, MS, E, MD, PS, IWS, JIS, JS, JP, PST, CP, RC>
+{
+    pub fn new(
+        instance_storage: Arc<IS>,
+        create_instance_use_case: Arc<CreateInstanceUseCase<IS, MS, E, MD, PS, IWS, JIS, JS, JP>>,
+        instance_watcher_service: Arc<IWS>,
+        pack_storage: Arc<PST>,
+        provider_registry: Arc<ContentProviderRegistry<CP>>,
+        request_client: Arc<RC>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            create_instance_use_case,
+            instance_watcher_service,
+            pack_storage,
+            provider_registry,
+            request_client,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    pub async fn execute(&self, path: PathBuf) -> Result<String, InstanceError> {
+        let index: MrpackIndex = read_pack_manifest(path.clone(), MRPACK_INDEX_FILE).await?;
+
+        let game_version = index
+            .game_version()
+            .ok_or_else(|| InstanceError::InvalidModpack {
+                reason: "minecraft dependency is missing".to_owned(),
+            })?
+            .clone();
+
+        let (mod_loader, loader_version) = index.loader();
+        let loader_version = loader_version.map(|version| {
+            LoaderVersionPreference::Exact(to_metadata_loader_version(
+                mod_loader,
+                &game_version,
+                version,
+            ))
+        });
+
+        info!("Importing modpack \"{}\" from {:?}", index.name, path);
+
+        let instance_id = self
+            .create_instance_use_case
+            .execute(NewInstance {
+                name: index.name.clone(),
+                game_version,
+                mod_loader,
+                loader_version,
+                icon_path: None,
+                skip_install_instance: Some(true),
+                pack_info: None,
+            })
+            .await?;
+
+        // Held until the pack is installed, so the half imported instance isn't launched or changed
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ImportPack)?;
+
+        let install = self.install_pack(&instance_id, path, &index);
+
+        match install_imported_pack(
+            self.instance_storage.as_ref(),
+            self.instance_watcher_service.as_ref(),
+            &instance_id,
+            install,
+        )
+        .await
+        {
+            Ok(()) => {
+                info!("Modpack \"{}\" imported as \"{}\"", index.name, instance_id);
+                Ok(instance_id)
+            }
+            Err(err) => {
+                error!("Failed to import modpack \"{}\": {}", index.name, err);
+                Err(err)
+            }
+        }
+    }
+
+    async fn install_pack(
+        &self,
+        instance_id: &str,
+        path: PathBuf,
+        index: &MrpackIndex,
+    ) -> Result<(), InstanceError> {
+        let instance_dir = self.location_info.instance_dir(instance_id);
+
+        let files: Vec<&MrpackFile> = index
+            .files
+            .iter()
+            .filter(|file| file.is_client_supported())
+            .collect();
+
+        if let Some(file) = files
+            .iter()
+            .find(|file| !is_inner_relative_path(&file.path))
+        {
+            return Err(InstanceError::InvalidModpack {
+                reason: format!("\"{}\" points outside of the instance directory", file.path),
+            });
+        }
+
+        let downloaded: Vec<(String, PackFile)> = futures::stream::iter(files)
+            .map(|file| self.download_file(&instance_dir, file))
+            .buffer_unordered(PACK_CONCURRENT_DOWNLOADS)
+            .try_collect()
+            .await?;
+
+        let (content_paths, pack_files): (Vec<String>, Vec<PackFile>) =
+            downloaded.into_iter().unzip();
+
+        self.pack_storage
+            .update_pack_file_many(instance_id, &content_paths, &pack_files)
+            .await?;
+
+        // Client overrides are applied last so they take precedence
+        extract_pack_overrides(
+            path,
+            vec![
+                MRPACK_OVERRIDES_DIR.to_owned(),
+                MRPACK_CLIENT_OVERRIDES_DIR.to_owned(),
+            ],
+            instance_dir,
+        )
+        .await
+    }
+
+    async fn download_file(
+        &self,
+        instance_dir: &Path,
+        file: &MrpackFile,
+    ) -> Result<(String, PackFile), InstanceError> {
+        let sha1 = file
+            .hashes
+            .get("sha1")
+            .ok_or_else(|| InstanceError::InvalidModpack {
+                reason: format!("\"{}\" has no sha1 hash", file.path),
+            })?;
+
+        let mut last_error = None;
+
+        for url in &file.downloads {
+            match self
+                .request_client
+                .fetch_bytes(Request::get(url).with_sha1(sha1))
+                .await
+            {
+                Ok(bytes) => {
+                    write_async(instance_dir.join(&file.path), &bytes).await?;
+                    return Ok((file.path.clone(), self.to_pack_file(file, url, sha1)));
+                }
+                Err(err) => {
+                    warn!("Failed to download \"{}\" from {}: {}", file.path, url, err);
+                    last_error = Some(err.to_string());
+                }
+            }
+        }
+
+        Err(InstanceError::ContentDownloadError(
+            last_error.unwrap_or_else(|| format!("No download urls for \"{}\"", file.path)),
+        ))
+    }
+
+    fn to_pack_file(&self, file: &MrpackFile, url: &str, sha1: &str) -> PackFile {
+        let file_name = Path::new(&file.path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| file.path.clone());
+
+        let (update_provider, update) = self
+            .provider_registry
+            .find_update_data_by_download_url(url)
+            .map(|(provider_id, update_data)| {
+                (
+                    Some(provider_id.clone()),
+                    Some(HashMap::from([(provider_id, update_data)])),
+                )
+            })
+            .unwrap_or_default();
+
+        let (side, option) = match file.env {
+            Some(env) => (
+                match env.server {
+                    MrpackEnvSupport::Unsupported => Some("client".to_owned()),
+                    _ => None,
+                },
+                (env.client == MrpackEnvSupport::Optional).then_some(PackFileOption {
+                    optional: true,
+                    default: Some(true),
+                    description: None,
+                }),
+            ),
+            None => (None, None),
+        };
+
+        PackFile {
+            file_name,
+            name: None,
+            hash: sha1.to_owned(),
+            download: Some(PackFileDownload {
+                hash: sha1.to_owned(),
+                url: url.to_owned(),
+            }),
+            option,
+            side,
+            update_provider,
+            update,
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use bytes::Bytes;
+use futures::{StreamExt, TryStreamExt};
+use log::{debug, error, info};
+use path_slash::PathBufExt;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    features::{
+        events::{EventEmitter, ProgressService},
+        instance::{
+            utils::{
+                compute_packwiz_hash, install_imported_pack, is_inner_relative_path,
+                to_metadata_loader_version, verify_packwiz_hash, PACK_CONCURRENT_DOWNLOADS,
+            },
+            CurseForgeFileResolver, InstanceError, InstanceLockService, InstanceOperation,
+            InstanceStorage, InstanceWatcherService, PackFile, PackStorage, PackwizHashFormat,
+            PackwizIndex, PackwizIndexFile, PackwizMetafile, PackwizPack, PACKWIZ_PACK_FILE,
+        },
+        java::{JavaInstallationService, JavaStorage, JreProvider},
+        minecraft::{LoaderVersionPreference, MetadataStorage, MinecraftDownloader},
+        settings::LocationInfo,
+    },
+    libs::request_client::{Request, RequestClient},
+    shared::{read_async, write_async},
+};
+
+use super::{CreateInstanceUseCase, NewInstance};
+
+pub struct ImportPackwizUseCase<
+    IS: InstanceStorage,
+    MS: MetadataStorage,
+    E: EventEmitter,
+    MD: MinecraftDownloader,
+    PS: ProgressService,
+    IWS: InstanceWatcherService,
+    JIS: JavaInstallationService,
+    JS: JavaStorage,
+    JP: JreProvider,
+    PST: PackStorage,
+    CFR: CurseForgeFileResolver,
+    RC: RequestClient,
+> {
+    instance_storage: Arc<IS>,
+    create_instance_use_case: Arc<CreateInstanceUseCase<IS, MS, E, MD, PS, IWS, JIS, JS, JP>>,
+    instance_watcher_service: Arc<IWS>,
+    pack_storage: Arc<PST>,
+    curseforge_file_resolver: Arc<CFR>,
+    request_client: Arc<RC>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<
+        IS: InstanceStorage,
+        MS: MetadataStorage,
+        E: EventEmitter,
+        MD: MinecraftDownloader,
+        PS: ProgressService,
+        IWS: InstanceWatcherService,
+        JIS: JavaInstallationService,
+        JS: JavaStorage,
+        JP: JreProvider,
+        PST: PackStorage,
+        CFR: CurseForgeFileResolver,
+        RC: RequestClient,
+    > ImportPackwizUseCase<IS, MS, E, MD, PS, IWS, JIS, JS, JP, PST, CFR, RC>
+{
+    pub fn new(
+        instance_storage: Arc<IS>,
+        create_instance_use_case: Arc<CreateInstanceUseCase<IS, MS, E, MD, PS, IWS, JIS, JS, JP>>,
+        instance_watcher_service: Arc<IWS>,
+        pack_storage: Arc<PST>,
+        curseforge_file_resolver: Arc<CFR>,
+        request_client: Arc<RC>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            create_instance_use_case,
+            instance_watcher_service,
+            pack_storage,
+            curseforge_file_resolver,
+            request_client,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    /// Imports a packwiz repository, `path` is either `pack.toml` or its directory
+    pub async fn execute(&self, path: PathBuf) -> Result<String, InstanceError> {
+        let pack_path = if path.is_dir() {
+            path.join(PACKWIZ_PACK_FILE)
+        } else {
+            path
+        };
+        let pack_dir = pack_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        let pack: PackwizPack = parse_toml(&read_async(&pack_path).await?, PACKWIZ_PACK_FILE)?;
+
+        if !is_inner_relative_path(&pack.index.file) {
+            return Err(InstanceError::InvalidModpack {
+                reason: format!("invalid index file \"{}\"", pack.index.file),
+            });
+        }
+
+        let index_path = pack_dir.join(&pack.index.file);
+        let index_bytes = Bytes::from(read_async(&index_path).await?);
+        verify_packwiz_hash(
+            pack.index.hash_format,
+            &pack.index.hash,
+            index_bytes.clone(),
+            &pack.index.file,
+        )
+        .await?;
+        let index: PackwizIndex = parse_toml(&index_bytes, &pack.index.file)?;
+
+        let game_version = pack
+            .game_version()
+            .ok_or_else(|| InstanceError::InvalidModpack {
+                reason: "minecraft version is missing".to_owned(),
+            })?
+            .clone();
+
+        let (mod_loader, loader_version) = pack.loader();
+        let loader_version = loader_version.map(|version| {
+            LoaderVersionPreference::Exact(to_metadata_loader_version(
+                mod_loader,
+                &game_version,
+                version,
+            ))
+        });
+
+        info!(
+            "Importing packwiz pack \"{}\" from {:?}",
+            pack.name, pack_dir
+        );
+
+        let instance_id = self
+            .create_instance_use_case
+            .execute(NewInstance {
+                name: pack.name.clone(),
+                game_version,
+                mod_loader,
+                loader_version,
+                icon_path: None,
+                skip_install_instance: Some(true),
+                pack_info: None,
+            })
+            .await?;
+
+        // Index paths are relative to the index file
+        let index_dir = index_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or(pack_dir);
+
+        // Held until the pack is installed, so the half imported instance isn't launched or changed
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ImportPack)?;
+
+        let install = self.install_pack(&instance_id, &index_dir, &index);
+
+        match install_imported_pack(
+            self.instance_storage.as_ref(),
+            self.instance_watcher_service.as_ref(),
+            &instance_id,
+            install,
+        )
+        .await
+        {
+            Ok(()) => {
+                info!(
+                    "Packwiz pack \"{}\" imported as \"{}\"",
+                    pack.name, instance_id
+                );
+                Ok(instance_id)
+            }
+            Err(err) => {
+                error!("Failed to import packwiz pack \"{}\": {}", pack.name, err);
+                Err(err)
+            }
+        }
+    }
+
+    async fn install_pack(
+        &self,
+        instance_id: &str,
+        index_dir: &Path,
+        index: &PackwizIndex,
+    ) -> Result<(), InstanceError> {
+        let instance_dir = self.location_info.instance_dir(instance_id);
+
+        if let Some(file) = index.files.iter().find(|file| {
+            !is_inner_relative_path(&file.file)
+                || !file.alias.as_deref().is_none_or(is_inner_relative_path)
+        }) {
+            return Err(InstanceError::InvalidModpack {
+                reason: format!("\"{}\" points outside of the pack directory", file.file),
+            });
+        }
+
+        let (metafiles, files): (Vec<&PackwizIndexFile>, Vec<&PackwizIndexFile>) =
+            index.files.iter().partition(|file| file.metafile);
+
+        for file in files {
+            let bytes = Bytes::from(read_async(index_dir.join(&file.file)).await?);
+            verify_packwiz_hash(
+                file.hash_format.unwrap_or(index.hash_format),
+                &file.hash,
+                bytes.clone(),
+                &file.file,
+            )
+            .await?;
+
+            let target = file.alias.as_ref().unwrap_or(&file.file);
+            write_async(instance_dir.join(target), &bytes).await?;
+        }
+
+        let downloaded: Vec<Option<(String, PackFile)>> = futures::stream::iter(metafiles)
+            .map(|file| self.install_metafile(index_dir, &instance_dir, index, file))
+            .buffer_unordered(PACK_CONCURRENT_DOWNLOADS)
+            .try_collect()
+            .await?;
+
+        let (content_paths, pack_files): (Vec<String>, Vec<PackFile>) =
+            downloaded.into_iter().flatten().unzip();
+
+        self.pack_storage
+            .update_pack_file_many(instance_id, &content_paths, &pack_files)
+            .await
+    }
+
+    async fn install_metafile(
+        &self,
+        index_dir: &Path,
+        instance_dir: &Path,
+        index: &PackwizIndex,
+        file: &PackwizIndexFile,
+    ) -> Result<Option<(String, PackFile)>, InstanceError> {
+        let bytes = Bytes::from(read_async(index_dir.join(&file.file)).await?);
+        verify_packwiz_hash(
+            file.hash_format.unwrap_or(index.hash_format),
+            &file.hash,
+            bytes.clone(),
+            &file.file,
+        )
+        .await?;
+        let metafile: PackwizMetafile = parse_toml(&bytes, &file.file)?;
+
+        if !metafile.is_client_supported() {
+            return Ok(None);
+        }
+
+        if !is_inner_relative_path(&metafile.filename) || metafile.filename.contains('/') {
+            return Err(InstanceError::InvalidModpack {
+                reason: format!("invalid file name \"{}\"", metafile.filename),
+            });
+        }
+
+        // Downloaded file is placed next to its metafile
+        let content_path = PathBuf::from(file.alias.as_ref().unwrap_or(&file.file))
+            .with_file_name(&metafile.filename)
+            .to_slash_lossy()
+            .to_string();
+
+        let url = self.get_download_url(&metafile).await?;
+
+        let bytes = self
+            .request_client
+            .fetch_bytes(Request::get(&url))
+            .await
+            .map_err(|err| InstanceError::ContentDownloadError(err.to_string()))?;
+
+        verify_packwiz_hash(
+            metafile.download.hash_format,
+            &metafile.download.hash,
+            bytes.clone(),
+            &metafile.filename,
+        )
+        .await?;
+
+        let sha1 = match metafile.download.hash_format {
+            PackwizHashFormat::Sha1 => metafile.download.hash.to_lowercase(),
+            _ => compute_packwiz_hash(PackwizHashFormat::Sha1, bytes.clone()).await?,
+        };
+
+        write_async(instance_dir.join(&content_path), &bytes).await?;
+
+        Ok(Some((content_path, metafile.to_pack_file(url, sha1))))
+    }
+
+    async fn get_download_url(&self, metafile: &PackwizMetafile) -> Result<String, InstanceError> {
+        if let Some(url) = &metafile.download.url {
+            return Ok(url.clone());
+        }
+
+        // `metadata:curseforge` files only reference the CurseForge project and file
+        let Some(curseforge) = metafile
+            .update
+            .as_ref()
+            .and_then(|update| update.curseforge.as_ref())
+        else {
+            return Err(InstanceError::InvalidModpack {
+                reason: format!("\"{}\" has no download url", metafile.filename),
+            });
+        };
+
+        debug!(
+            "Resolving download url of \"{}\" through CurseForge",
+            metafile.filename
+        );
+
+        self.curseforge_file_resolver
+            .resolve(curseforge.project_id, curseforge.file_id)
+            .await?
+            .download_url
+            .ok_or_else(|| InstanceError::CurseForgeDownloadBlocked {
+                files: vec![metafile.filename.clone()],
+            })
+    }
+}
+
+fn parse_toml<T: DeserializeOwned>(bytes: &[u8], file: &str) -> Result<T, InstanceError> {
+    std::str::from_utf8(bytes)
+        .map_err(|err| err.to_string())
+        .and_then(|content| toml::from_str(content).map_err(|err| err.to_string()))
+        .map_err(|reason| InstanceError::InvalidModpack {
+            reason: format!("failed to parse \"{file}\": {reason}"),
+        })
+}
@@ -2,7 +2,10 @@ use std::sync::Arc;
 
 use crate::features::{
     events::{ProgressEventType, ProgressService, ProgressServiceExt},
-    instance::{Instance, InstanceError, InstanceInstallStage, InstanceStorage},
+    instance::{
+        Instance, InstanceError, InstanceInstallStage, InstanceLockService, InstanceOperation,
+        InstanceStorage,
+    },
     java::{JavaInstallationService, JavaStorage, JreProvider},
     minecraft::{
         app::{InstallMinecraftParams, InstallMinecraftUseCase},
@@ -24,6 +27,7 @@ pub struct InstallInstanceUseCase<
     install_minecraft_use_case: Arc<InstallMinecraftUseCase<MS, MD, PS, JIS, JS, JP>>,
     progress_service: Arc<PS>,
     location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
 }
 
 impl<
@@ -41,12 +45,14 @@ impl<
         install_minecraft_use_case: Arc<InstallMinecraftUseCase<MS, MD, PS, JIS, JS, JP>>,
         progress_service: Arc<PS>,
         location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
     ) -> Self {
         Self {
             instance_storage,
             install_minecraft_use_case,
             progress_service,
             location_info,
+            instance_lock_service,
         }
     }
 
@@ -79,6 +85,19 @@ impl<
     }
 
     pub async fn execute(&self, instance_id: String, force: bool) -> Result<(), InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::Install)?;
+
+        self.perform_install(instance_id, force).await
+    }
+
+    /// Installs without taking the instance lock, the caller must hold it
+    pub async fn perform_install(
+        &self,
+        instance_id: String,
+        force: bool,
+    ) -> Result<(), InstanceError> {
         let mut instance = self.instance_storage.get(&instance_id).await?;
 
         instance.install_stage = InstanceInstallStage::Installing;
@@ -1,22 +1,26 @@
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use crate::{
     features::{
         auth::Credentials,
         events::{EventEmitter, ProgressService},
         instance::{
-            Instance, InstanceError, InstanceInstallStage, InstanceStorage, InstanceStorageExt,
+            Instance, InstanceError, InstanceInstallStage, InstanceLockService, InstanceOperation,
+            InstanceStorage, InstanceStorageExt, PlaySessionStorage,
         },
         java::{JavaInstallationService, JavaStorage, JreProvider},
         minecraft::{
             app::{GetMinecraftLaunchCommandParams, GetMinecraftLaunchCommandUseCase},
-            LaunchSettings, MetadataStorage, MinecraftDownloader,
+            LaunchSettings, MetadataStorage, MinecraftDownloader, QuickPlayTarget,
         },
         process::{
             app::{GetProcessMetadataByInstanceIdUseCase, StartProcessUseCase},
             MinecraftProcessMetadata, ProcessStorage,
         },
-        settings::{DefaultInstanceSettings, DefaultInstanceSettingsStorage, Hooks, LocationInfo},
+        settings::{
+            DefaultInstanceSettings, DefaultInstanceSettingsStorage, GameOptionOverride,
+            GameOptionsApplyMode, Hooks, LocationInfo,
+        },
     },
     shared::{IoError, SerializableCommand},
 };
@@ -34,6 +38,7 @@ pub struct LaunchInstanceUseCase<
     JIS: JavaInstallationService,
     JS: JavaStorage,
     JP: JreProvider,
+    PSS: PlaySessionStorage,
 > {
     instance_storage: Arc<IS>,
     default_instance_settings_storage: Arc<GISS>,
@@ -41,7 +46,8 @@ pub struct LaunchInstanceUseCase<
     get_process_by_instance_id_use_case: Arc<GetProcessMetadataByInstanceIdUseCase<PS>>,
     install_instance_use_case: Arc<InstallInstanceUseCase<IS, MS, MD, PGS, JIS, JS, JP>>,
     get_minecraft_launch_command_use_case: GetMinecraftLaunchCommandUseCase<MS, MD, JIS, JS>,
-    start_process_use_case: Arc<StartProcessUseCase<E, PS, IS>>,
+    start_process_use_case: Arc<StartProcessUseCase<E, PS, IS, PSS>>,
+    instance_lock_service: Arc<InstanceLockService>,
 }
 
 impl<
@@ -55,7 +61,8 @@ impl<
         JIS: JavaInstallationService,
         JS: JavaStorage,
         JP: JreProvider,
-    > LaunchInstanceUseCase<IS, MS, PS, GISS, E, MD, PGS, JIS, JS, JP>
+        PSS: PlaySessionStorage + 'static,
+    > LaunchInstanceUseCase<IS, MS, PS, GISS, E, MD, PGS, JIS, JS, JP, PSS>
 {
     pub fn new(
         instance_storage: Arc<IS>,
@@ -64,7 +71,8 @@ impl<
         get_process_by_instance_id_use_case: Arc<GetProcessMetadataByInstanceIdUseCase<PS>>,
         install_instance_use_case: Arc<InstallInstanceUseCase<IS, MS, MD, PGS, JIS, JS, JP>>,
         get_minecraft_launch_command_use_case: GetMinecraftLaunchCommandUseCase<MS, MD, JIS, JS>,
-        start_process_use_case: Arc<StartProcessUseCase<E, PS, IS>>,
+        start_process_use_case: Arc<StartProcessUseCase<E, PS, IS, PSS>>,
+        instance_lock_service: Arc<InstanceLockService>,
     ) -> Self {
         Self {
             instance_storage,
@@ -74,6 +82,7 @@ impl<
             install_instance_use_case,
             get_minecraft_launch_command_use_case,
             start_process_use_case,
+            instance_lock_service,
         }
     }
 
@@ -115,14 +124,45 @@ impl<
                     .cloned()
                     .or_else(|| settings.hooks().post_exit().cloned()),
             ),
+
+            game_options: Self::resolve_game_options(instance, settings),
         }
     }
 
+    fn resolve_game_options(
+        instance: &Instance,
+        settings: &DefaultInstanceSettings,
+    ) -> BTreeMap<String, GameOptionOverride> {
+        let mut game_options = settings.game_options().to_overrides();
+
+        if let Some(instance_game_options) = &instance.game_options {
+            game_options.extend(instance_game_options.to_overrides());
+        }
+
+        if let Some(force_fullscreen) = instance.force_fullscreen {
+            game_options.insert(
+                "fullscreen".to_owned(),
+                GameOptionOverride {
+                    value: force_fullscreen.to_string(),
+                    apply_mode: GameOptionsApplyMode::Always,
+                },
+            );
+        }
+
+        game_options
+    }
+
     pub async fn execute(
         &self,
         instance_id: String,
         credentials: Credentials,
+        quick_play: Option<QuickPlayTarget>,
     ) -> Result<MinecraftProcessMetadata, InstanceError> {
+        // Held until the process is started, the running game keeps its own mark until it exits
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::Launch)?;
+
         let settings = self.default_instance_settings_storage.get().await?;
         let instance = self.instance_storage.get(&instance_id).await?;
 
@@ -152,7 +192,7 @@ impl<
 
         if instance.install_stage != InstanceInstallStage::Installed {
             self.install_instance_use_case
-                .execute(instance_id, false)
+                .perform_install(instance_id, false)
                 .await?;
         }
 
@@ -199,6 +239,8 @@ impl<
         //     }
         // }
 
+        let account_id = credentials.id();
+
         let command = self
             .get_minecraft_launch_command_use_case
             .execute(
@@ -208,6 +250,7 @@ impl<
                     loader_version: instance.loader_version.clone(),
                     launch_dir: instance_path,
                     java_path: instance.java_path.clone(),
+                    quick_play,
                 },
                 launch_settings.clone(),
                 credentials,
@@ -221,12 +264,17 @@ impl<
             })
             .await?;
 
+        // Released by the process manager once the game exits
+        let running_guard = self.instance_lock_service.mark_running(&instance.id);
+
         let metadata = self
             .start_process_use_case
             .execute(
                 instance.id.clone(),
                 command,
+                Some(account_id),
                 launch_settings.hooks.post_exit().cloned(),
+                running_guard,
             )
             .await;
 
@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
 use crate::features::{
-    auth::{ActiveAccountHelper, CredentialsStorage},
+    auth::{
+        ActiveAccountHelper, AuthApplicationError, AuthDomainError, Credentials,
+        CredentialsStorage, MicrosoftAuthService, TokenRefreshService, YggdrasilAuthService,
+    },
     events::{EventEmitter, ProgressService},
-    instance::{InstanceError, InstanceStorage},
+    instance::{Instance, InstanceError, InstanceStorage, PlaySessionStorage},
     java::{JavaInstallationService, JavaStorage, JreProvider},
-    minecraft::{MetadataStorage, MinecraftDownloader},
+    minecraft::{MetadataStorage, MinecraftDownloader, QuickPlayTarget},
     process::{MinecraftProcessMetadata, ProcessStorage},
     settings::DefaultInstanceSettingsStorage,
 };
@@ -17,6 +20,8 @@ pub struct LaunchInstanceWithActiveAccountUseCase<
     MS: MetadataStorage,
     PS: ProcessStorage,
     CS: CredentialsStorage,
+    MA: MicrosoftAuthService,
+    YA: YggdrasilAuthService,
     GISS: DefaultInstanceSettingsStorage,
     E: EventEmitter,
     MD: MinecraftDownloader,
@@ -24,9 +29,12 @@ pub struct LaunchInstanceWithActiveAccountUseCase<
     JIS: JavaInstallationService,
     JS: JavaStorage,
     JP: JreProvider,
+    PSS: PlaySessionStorage,
 > {
+    instance_storage: Arc<IS>,
     credentials_storage: Arc<CS>,
-    launch_instance_use_case: LaunchInstanceUseCase<IS, MS, PS, GISS, E, MD, PGS, JIS, JS, JP>,
+    token_refresh_service: Arc<TokenRefreshService<CS, MA, YA>>,
+    launch_instance_use_case: LaunchInstanceUseCase<IS, MS, PS, GISS, E, MD, PGS, JIS, JS, JP, PSS>,
 }
 
 impl<
@@ -34,6 +42,8 @@ impl<
         MS: MetadataStorage,
         PS: ProcessStorage + 'static,
         CS: CredentialsStorage,
+        MA: MicrosoftAuthService,
+        YA: YggdrasilAuthService,
         GISS: DefaultInstanceSettingsStorage,
         E: EventEmitter + 'static,
         MD: MinecraftDownloader,
@@ -41,10 +51,29 @@ impl<
         JIS: JavaInstallationService,
         JS: JavaStorage,
         JP: JreProvider,
-    > LaunchInstanceWithActiveAccountUseCase<IS, MS, PS, CS, GISS, E, MD, PGS, JIS, JS, JP>
+        PSS: PlaySessionStorage + 'static,
+    >
+    LaunchInstanceWithActiveAccountUseCase<
+        IS,
+        MS,
+        PS,
+        CS,
+        MA,
+        YA,
+        GISS,
+        E,
+        MD,
+        PGS,
+        JIS,
+        JS,
+        JP,
+        PSS,
+    >
 {
     pub fn new(
+        instance_storage: Arc<IS>,
         credentials_storage: Arc<CS>,
+        token_refresh_service: Arc<TokenRefreshService<CS, MA, YA>>,
         launch_with_credentials_use_case: LaunchInstanceUseCase<
             IS,
             MS,
@@ -56,10 +85,13 @@ impl<
             JIS,
             JS,
             JP,
+            PSS,
         >,
     ) -> Self {
         Self {
+            instance_storage,
             credentials_storage,
+            token_refresh_service,
             launch_instance_use_case: launch_with_credentials_use_case,
         }
     }
@@ -67,12 +99,42 @@ impl<
     pub async fn execute(
         &self,
         instance_id: String,
+        quick_play: Option<QuickPlayTarget>,
     ) -> Result<MinecraftProcessMetadata, InstanceError> {
-        let default_account =
-            ActiveAccountHelper::ensure_active(self.credentials_storage.as_ref()).await?;
+        let instance = self.instance_storage.get(&instance_id).await?;
+
+        let account =
+            resolve_instance_account(self.credentials_storage.as_ref(), &instance).await?;
+        let account = self
+            .token_refresh_service
+            .refresh_if_needed(account.id())
+            .await?;
 
         self.launch_instance_use_case
-            .execute(instance_id, default_account)
+            .execute(instance_id, account, quick_play)
             .await
     }
 }
+
+/// Returns the account pinned to the instance, falls back to the active account
+/// when the instance has none
+pub async fn resolve_instance_account<CS: CredentialsStorage>(
+    credentials_storage: &CS,
+    instance: &Instance,
+) -> Result<Credentials, InstanceError> {
+    let Some(account_id) = instance.account_id else {
+        let account = ActiveAccountHelper::ensure_active(credentials_storage).await?;
+        return Ok(account);
+    };
+
+    match credentials_storage.get(account_id).await {
+        Ok(account) => Ok(account),
+        Err(AuthApplicationError::Domain(AuthDomainError::CredentialsNotFound { .. })) => {
+            Err(InstanceError::PinnedAccountNotFound {
+                instance_id: instance.id.clone(),
+                account_id,
+            })
+        }
+        Err(err) => Err(err.into()),
+    }
+}
@@ -0,0 +1,279 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    features::{
+        events::ProgressService,
+        instance::{
+            app::{CreateSnapshotUseCase, InstallContentUseCase},
+            ContentCompatibility, ContentCompatibilityStatus, ContentInstallParams,
+            ContentProvider, ContentType, ContentUpdateResult, ContentUpdateStatus, InstanceError,
+            InstanceInstallStage, InstanceLockService, InstanceOperation, InstanceStorage,
+            InstanceStorageExt, PackStorage, SnapshotReason, SnapshotStorage,
+        },
+        minecraft::{
+            app::MinecraftApplicationError, LoaderVersionPreference, LoaderVersionResolver,
+            MetadataStorage, ModLoader,
+        },
+        settings::LocationInfo,
+    },
+    shared::remove_file,
+};
+
+use super::{CheckInstanceMigrationUseCase, InstanceMigration};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceMigrationReport {
+    pub game_version: String,
+    pub mod_loader: ModLoader,
+    pub loader_version: Option<LoaderVersionPreference>,
+    /// Compatibility of content before the migration
+    pub contents: Vec<ContentCompatibility>,
+    /// Outcome of every attempted content update
+    pub content_updates: Vec<ContentUpdateResult>,
+}
+
+pub struct MigrateInstanceUseCase<
+    IS: InstanceStorage,
+    MS: MetadataStorage,
+    PS: PackStorage,
+    CP: ContentProvider,
+    SS: SnapshotStorage,
+    PGS: ProgressService,
+> {
+    instance_storage: Arc<IS>,
+    pack_storage: Arc<PS>,
+    loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+    check_instance_migration_use_case: Arc<CheckInstanceMigrationUseCase<PS, CP>>,
+    install_content_use_case: Arc<InstallContentUseCase<PS, CP>>,
+    create_snapshot_use_case: Arc<CreateSnapshotUseCase<IS, SS, PGS>>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<
+        IS: InstanceStorage,
+        MS: MetadataStorage,
+        PS: PackStorage,
+        CP: ContentProvider,
+        SS: SnapshotStorage,
+        PGS: ProgressService,
+    > MigrateInstanceUseCase<IS, MS, PS, CP, SS, PGS>
+{
+    pub fn new(
+        instance_storage: Arc<IS>,
+        pack_storage: Arc<PS>,
+        loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+        check_instance_migration_use_case: Arc<CheckInstanceMigrationUseCase<PS, CP>>,
+        install_content_use_case: Arc<InstallContentUseCase<PS, CP>>,
+        create_snapshot_use_case: Arc<CreateSnapshotUseCase<IS, SS, PGS>>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            pack_storage,
+            loader_version_resolver,
+            check_instance_migration_use_case,
+            install_content_use_case,
+            create_snapshot_use_case,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    /// Switches the instance to another game version or loader, it has to be reinstalled after
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        migration: InstanceMigration,
+        update_content: bool,
+    ) -> Result<InstanceMigrationReport, InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::Migrate)?;
+
+        let instance = self.instance_storage.get(&instance_id).await?;
+
+        if matches!(
+            instance.install_stage,
+            InstanceInstallStage::Installing | InstanceInstallStage::PackInstalling
+        ) {
+            return Err(InstanceError::InstanceStillInstalling { instance_id });
+        }
+
+        let loader_version = self.resolve_loader_version(&migration).await?;
+        let migration = InstanceMigration {
+            loader_version,
+            ..migration
+        };
+
+        info!(
+            "Migrating instance \"{}\" to {} {} ({:?})",
+            instance_id,
+            migration.game_version,
+            migration.mod_loader.as_str(),
+            migration.loader_version
+        );
+
+        let contents = self
+            .check_instance_migration_use_case
+            .execute(instance_id.clone(), &migration)
+            .await?;
+
+        self.create_snapshot_use_case
+            .execute_auto(&instance_id, SnapshotReason::BeforeMigration)
+            .await?;
+
+        let target = migration.clone();
+        self.instance_storage
+            .upsert_with(&instance_id, |instance| {
+                instance.game_version = target.game_version;
+                instance.loader = target.mod_loader;
+                instance.loader_version = target.loader_version;
+                instance.install_stage = InstanceInstallStage::NotInstalled;
+                instance.modified = Utc::now();
+                Ok(())
+            })
+            .await?;
+
+        let content_updates = if update_content {
+            self.update_contents(&instance_id, &migration, &contents)
+                .await
+        } else {
+            Vec::new()
+        };
+
+        Ok(InstanceMigrationReport {
+            game_version: migration.game_version,
+            mod_loader: migration.mod_loader,
+            loader_version: migration.loader_version,
+            contents,
+            content_updates,
+        })
+    }
+
+    // Same validation as on instance creation
+    async fn resolve_loader_version(
+        &self,
+        migration: &InstanceMigration,
+    ) -> Result<Option<LoaderVersionPreference>, InstanceError> {
+        if migration.mod_loader == ModLoader::Vanilla {
+            return Ok(None);
+        }
+
+        if migration.loader_version.is_some() {
+            self.loader_version_resolver
+                .resolve(
+                    &migration.game_version,
+                    &migration.mod_loader,
+                    migration.loader_version.as_ref(),
+                )
+                .await
+                .map_err(MinecraftApplicationError::Domain)?;
+
+            return Ok(migration.loader_version.clone());
+        }
+
+        Ok(self
+            .loader_version_resolver
+            .try_get_default(&migration.game_version, &migration.mod_loader)
+            .await
+            .map_err(MinecraftApplicationError::Domain)?)
+    }
+
+    /// Installs compatible versions, a failed update leaves the old file in place
+    async fn update_contents(
+        &self,
+        instance_id: &str,
+        migration: &InstanceMigration,
+        contents: &[ContentCompatibility],
+    ) -> Vec<ContentUpdateResult> {
+        let mut content_updates = Vec::new();
+
+        for content in contents {
+            let ContentCompatibilityStatus::UpdateAvailable { version } = &content.status else {
+                continue;
+            };
+
+            let status = match self
+                .update_content(instance_id, migration, content, &version.id)
+                .await
+            {
+                Ok(()) => ContentUpdateStatus::Updated,
+                Err(err) => {
+                    warn!("Failed to update \"{}\": {}", content.content_path, err);
+                    ContentUpdateStatus::Failed {
+                        error: err.to_string(),
+                    }
+                }
+            };
+
+            content_updates.push(ContentUpdateResult {
+                content_path: content.content_path.clone(),
+                status,
+            });
+        }
+
+        content_updates
+    }
+
+    async fn update_content(
+        &self,
+        instance_id: &str,
+        migration: &InstanceMigration,
+        content: &ContentCompatibility,
+        version_id: &str,
+    ) -> Result<(), InstanceError> {
+        let Some(provider) = content.provider.clone() else {
+            return Ok(());
+        };
+
+        // Update data carries the project id the provider needs to install a version
+        let provider_data = self
+            .pack_storage
+            .get_pack_file(instance_id, &content.content_path)
+            .await?
+            .update
+            .and_then(|mut update| update.remove(&provider));
+
+        let installed = self
+            .install_content_use_case
+            .perform_install(
+                instance_id.to_owned(),
+                ContentInstallParams {
+                    game_version: migration.game_version.clone(),
+                    loader: (content.content_type == ContentType::Mod)
+                        .then(|| migration.mod_loader.as_str().to_owned()),
+                    content_type: content.content_type,
+                    content_version: Some(version_id.to_owned()),
+                    provider,
+                    provider_data,
+                },
+            )
+            .await?;
+
+        if installed.content_path != content.content_path {
+            let instance_dir = self.location_info.instance_dir(instance_id);
+
+            // Old file may be disabled
+            for old_path in [
+                instance_dir.join(&content.content_path),
+                instance_dir.join(format!("{}.disabled", content.content_path)),
+            ] {
+                if old_path.exists() {
+                    remove_file(&old_path).await?;
+                }
+            }
+            self.pack_storage
+                .remove_pack_file(instance_id, &content.content_path)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
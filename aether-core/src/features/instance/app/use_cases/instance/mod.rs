@@ -1,21 +1,43 @@
+mod change_shared_folder;
+mod check_instance_migration;
 mod create_instance;
+mod duplicate_instance;
 mod edit_instance;
+mod export_curseforge;
+mod export_mrpack;
+mod export_packwiz;
 mod get_instance;
+mod import_curseforge;
 mod import_instance;
+mod import_mrpack;
+mod import_packwiz;
 mod install_instance;
 mod launch_instance;
 mod launch_instance_with_active_account;
 mod list_instances;
+mod migrate_instance;
 mod remove_instance;
 mod update_instance;
+mod verify_instance;
 
+pub use change_shared_folder::*;
+pub use check_instance_migration::*;
 pub use create_instance::*;
+pub use duplicate_instance::*;
 pub use edit_instance::*;
+pub use export_curseforge::*;
+pub use export_mrpack::*;
+pub use export_packwiz::*;
 pub use get_instance::*;
+pub use import_curseforge::*;
 pub use import_instance::*;
+pub use import_mrpack::*;
+pub use import_packwiz::*;
 pub use install_instance::*;
 pub use launch_instance::*;
 pub use launch_instance_with_active_account::*;
 pub use list_instances::*;
+pub use migrate_instance::*;
 pub use remove_instance::*;
 pub use update_instance::*;
+pub use verify_instance::*;
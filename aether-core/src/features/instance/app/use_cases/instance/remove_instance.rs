@@ -1,21 +1,33 @@
 use std::sync::Arc;
 
-use crate::features::instance::{InstanceError, InstanceStorage, InstanceWatcherService};
+use crate::features::instance::{
+    InstanceError, InstanceLockService, InstanceOperation, InstanceStorage, InstanceWatcherService,
+};
 
 pub struct RemoveInstanceUseCase<IS, IWS> {
     instance_storage: Arc<IS>,
     instance_watcher_service: Arc<IWS>,
+    instance_lock_service: Arc<InstanceLockService>,
 }
 
 impl<IS: InstanceStorage, IWS: InstanceWatcherService> RemoveInstanceUseCase<IS, IWS> {
-    pub fn new(instance_storage: Arc<IS>, instance_watcher_service: Arc<IWS>) -> Self {
+    pub fn new(
+        instance_storage: Arc<IS>,
+        instance_watcher_service: Arc<IWS>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
         Self {
             instance_storage,
             instance_watcher_service,
+            instance_lock_service,
         }
     }
 
     pub async fn execute(&self, instance_id: String) -> Result<(), InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::Remove)?;
+
         self.instance_watcher_service
             .unwatch_instance(&instance_id)
             .await?;
@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
 use crate::features::{
-    events::EventEmitter,
+    events::{EventEmitter, ProgressService},
     instance::{
-        InstanceError, InstanceInstallStage, InstanceStorage, InstanceStorageExt, PackInfo,
+        app::CreateSnapshotUseCase, InstanceError, InstanceInstallStage, InstanceLockService,
+        InstanceOperation, InstanceStorage, InstanceStorageExt, PackInfo, SnapshotReason,
+        SnapshotStorage,
     },
     plugins::{
         CapabilityRegistry, PluginInstanceExt, PluginRegistry, PluginState, UpdaterCapability,
@@ -14,30 +16,51 @@ pub struct UpdateInstanceUseCase<
     IS: InstanceStorage,
     E: EventEmitter,
     UR: CapabilityRegistry<UpdaterCapability>,
+    SS: SnapshotStorage,
+    PS: ProgressService,
 > {
     instance_storage: Arc<IS>,
     plugin_registry: Arc<PluginRegistry<E>>,
     importers_registry: Arc<UR>,
+    create_snapshot_use_case: Arc<CreateSnapshotUseCase<IS, SS, PS>>,
+    instance_lock_service: Arc<InstanceLockService>,
 }
 
-impl<IS: InstanceStorage, E: EventEmitter, UR: CapabilityRegistry<UpdaterCapability>>
-    UpdateInstanceUseCase<IS, E, UR>
+impl<
+        IS: InstanceStorage,
+        E: EventEmitter,
+        UR: CapabilityRegistry<UpdaterCapability>,
+        SS: SnapshotStorage,
+        PS: ProgressService,
+    > UpdateInstanceUseCase<IS, E, UR, SS, PS>
 {
     pub fn new(
         instance_storage: Arc<IS>,
         plugin_registry: Arc<PluginRegistry<E>>,
         importers_registry: Arc<UR>,
+        create_snapshot_use_case: Arc<CreateSnapshotUseCase<IS, SS, PS>>,
+        instance_lock_service: Arc<InstanceLockService>,
     ) -> Self {
         Self {
             instance_storage,
             plugin_registry,
             importers_registry,
+            create_snapshot_use_case,
+            instance_lock_service,
         }
     }
 
     pub async fn execute(&self, instance_id: String) -> Result<(), InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::Update)?;
+
         let original_stage = self.instance_storage.get(&instance_id).await?.install_stage;
 
+        self.create_snapshot_use_case
+            .execute_auto(&instance_id, SnapshotReason::BeforeUpdate)
+            .await?;
+
         let result = self.perform_update(&instance_id).await;
 
         self.instance_storage
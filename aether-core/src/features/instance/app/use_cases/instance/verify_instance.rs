@@ -0,0 +1,333 @@
+use std::{collections::HashSet, path::Path, sync::Arc};
+
+use log::{error, info, warn};
+use path_slash::PathBufExt;
+
+use crate::{
+    features::{
+        instance::{
+            app::InstallContentUseCase, ContentInstallParams, ContentProvider,
+            ContentProviderRegistry, ContentType, Instance, InstanceError, InstanceInstallStage,
+            InstanceIntegrityReport, InstanceLockService, InstanceOperation, InstanceStorage,
+            IntegrityFile, PackStorage,
+        },
+        java::{JavaInstallationService, JavaStorage},
+        minecraft::{
+            app::{VerifyMinecraftParams, VerifyMinecraftUseCase},
+            utils::get_file_integrity_status,
+            FileIntegrityStatus, MetadataStorage, MinecraftDownloader,
+        },
+        settings::LocationInfo,
+    },
+    shared::{read_dir, remove_file, rename, IoError},
+};
+
+/// Suffix of the broken content copy kept while it's downloaded again
+const REPAIR_BACKUP_SUFFIX: &str = ".repair-backup";
+
+pub struct VerifyInstanceUseCase<
+    IS: InstanceStorage,
+    PS: PackStorage,
+    CP: ContentProvider,
+    MS: MetadataStorage,
+    MD: MinecraftDownloader,
+    JIS: JavaInstallationService,
+    JS: JavaStorage,
+> {
+    instance_storage: Arc<IS>,
+    pack_storage: Arc<PS>,
+    provider_registry: Arc<ContentProviderRegistry<CP>>,
+    verify_minecraft_use_case: Arc<VerifyMinecraftUseCase<MS, MD, JIS, JS>>,
+    install_content_use_case: Arc<InstallContentUseCase<PS, CP>>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<
+        IS: InstanceStorage,
+        PS: PackStorage,
+        CP: ContentProvider,
+        MS: MetadataStorage,
+        MD: MinecraftDownloader,
+        JIS: JavaInstallationService,
+        JS: JavaStorage,
+    > VerifyInstanceUseCase<IS, PS, CP, MS, MD, JIS, JS>
+{
+    pub fn new(
+        instance_storage: Arc<IS>,
+        pack_storage: Arc<PS>,
+        provider_registry: Arc<ContentProviderRegistry<CP>>,
+        verify_minecraft_use_case: Arc<VerifyMinecraftUseCase<MS, MD, JIS, JS>>,
+        install_content_use_case: Arc<InstallContentUseCase<PS, CP>>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            pack_storage,
+            provider_registry,
+            verify_minecraft_use_case,
+            install_content_use_case,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    /// Checks game files and pack files, with `repair` broken ones are downloaded again
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        repair: bool,
+    ) -> Result<InstanceIntegrityReport, InstanceError> {
+        let operation = if repair {
+            InstanceOperation::Repair
+        } else {
+            InstanceOperation::Verify
+        };
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, operation)?;
+
+        let instance = self.instance_storage.get(&instance_id).await?;
+
+        if instance.install_stage != InstanceInstallStage::Installed {
+            return Err(InstanceError::InstanceStillInstalling { instance_id });
+        }
+
+        info!("Verifying instance \"{}\"", instance_id);
+
+        let mut report = InstanceIntegrityReport::default();
+
+        let game_verification = self
+            .verify_minecraft_use_case
+            .execute(
+                VerifyMinecraftParams {
+                    game_version: instance.game_version.clone(),
+                    loader: instance.loader,
+                    loader_version: instance.loader_version.clone(),
+                    java_path: instance.java_path.clone(),
+                },
+                repair,
+            )
+            .await?;
+
+        for issue in game_verification.issues {
+            let file = IntegrityFile::Game {
+                kind: issue.kind,
+                name: issue.name,
+            };
+
+            if game_verification.repaired {
+                report.repaired.push(file.clone());
+            }
+
+            match issue.status {
+                FileIntegrityStatus::Missing => report.missing.push(file),
+                FileIntegrityStatus::Corrupted => report.corrupted.push(file),
+            }
+        }
+
+        let instance_dir = self.location_info.instance_dir(&instance_id);
+        let content_paths: HashSet<String> = self
+            .pack_storage
+            .get_pack(&instance_id)
+            .await?
+            .files
+            .into_iter()
+            .map(|entry| entry.file)
+            .collect();
+
+        for content_path in &content_paths {
+            let Some(status) = self
+                .get_content_status(&instance_id, &instance_dir, content_path)
+                .await?
+            else {
+                continue;
+            };
+
+            let file = IntegrityFile::Content {
+                content_path: content_path.clone(),
+            };
+
+            if repair {
+                match self
+                    .repair_content(&instance, &instance_dir, content_path)
+                    .await
+                {
+                    Ok(()) => report.repaired.push(file.clone()),
+                    Err(err) => warn!("Failed to repair \"{}\": {}", content_path, err),
+                }
+            }
+
+            match status {
+                FileIntegrityStatus::Missing => report.missing.push(file),
+                FileIntegrityStatus::Corrupted => report.corrupted.push(file),
+            }
+        }
+
+        report.untracked = get_untracked_contents(&instance_dir, &content_paths).await?;
+
+        Ok(report)
+    }
+
+    async fn get_content_status(
+        &self,
+        instance_id: &str,
+        instance_dir: &Path,
+        content_path: &str,
+    ) -> Result<Option<FileIntegrityStatus>, InstanceError> {
+        let pack_file = self
+            .pack_storage
+            .get_pack_file(instance_id, content_path)
+            .await?;
+
+        let path = get_content_file_path(instance_dir, content_path);
+
+        Ok(get_file_integrity_status(&path, Some(&pack_file.hash)).await?)
+    }
+
+    /// Installs the recorded version again through its content provider
+    async fn repair_content(
+        &self,
+        instance: &Instance,
+        instance_dir: &Path,
+        content_path: &str,
+    ) -> Result<(), InstanceError> {
+        let pack_file = self
+            .pack_storage
+            .get_pack_file(&instance.id, content_path)
+            .await?;
+
+        let content_type = ContentType::get_from_parent_folder(Path::new(content_path));
+        let (Some(provider_id), Some(content_type)) = (pack_file.update_provider, content_type)
+        else {
+            return Err(InstanceError::ContentNotRepairable {
+                content_path: content_path.to_owned(),
+            });
+        };
+
+        let provider = self.provider_registry.get(&provider_id)?;
+        let provider_data = pack_file
+            .update
+            .and_then(|mut update| update.remove(&provider_id));
+
+        // Without the recorded version the provider would install whatever release is newest
+        let Some(content_version) = provider_data
+            .as_ref()
+            .and_then(|data| provider.get_update_data_version(data))
+        else {
+            return Err(InstanceError::ContentNotRepairable {
+                content_path: content_path.to_owned(),
+            });
+        };
+
+        let disabled_path = instance_dir.join(format!("{content_path}.disabled"));
+        let was_disabled = disabled_path.exists();
+        let broken_path = if was_disabled {
+            disabled_path
+        } else {
+            instance_dir.join(content_path)
+        };
+        let backup_path = instance_dir.join(format!("{content_path}{REPAIR_BACKUP_SUFFIX}"));
+
+        // Broken copy stays aside until the new one is downloaded, a failed download puts it back
+        if broken_path.exists() {
+            rename(&broken_path, &backup_path).await?;
+        }
+
+        let installed = match self
+            .install_content_use_case
+            .perform_install(
+                instance.id.clone(),
+                ContentInstallParams {
+                    game_version: instance.game_version.clone(),
+                    loader: (content_type == ContentType::Mod)
+                        .then(|| instance.loader.as_str().to_owned()),
+                    content_type,
+                    content_version: Some(content_version),
+                    provider: provider_id,
+                    provider_data,
+                },
+            )
+            .await
+        {
+            Ok(installed) => installed,
+            Err(err) => {
+                if backup_path.exists() {
+                    if let Err(restore_err) = rename(&backup_path, &broken_path).await {
+                        error!("Failed to put back \"{}\": {}", content_path, restore_err);
+                    }
+                }
+                return Err(err);
+            }
+        };
+
+        if backup_path.exists() {
+            remove_file(&backup_path).await?;
+        }
+
+        // Providers install files enabled
+        if was_disabled {
+            rename(
+                instance_dir.join(&installed.content_path),
+                instance_dir.join(format!("{}.disabled", installed.content_path)),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Disabled files are stored with the `.disabled` suffix
+fn get_content_file_path(instance_dir: &Path, content_path: &str) -> std::path::PathBuf {
+    let path = instance_dir.join(content_path);
+
+    if path.exists() {
+        return path;
+    }
+
+    instance_dir.join(format!("{content_path}.disabled"))
+}
+
+async fn get_untracked_contents(
+    instance_dir: &Path,
+    content_paths: &HashSet<String>,
+) -> Result<Vec<String>, InstanceError> {
+    let mut untracked = Vec::new();
+
+    for content_type in ContentType::iterator() {
+        let content_dir = instance_dir.join(content_type.get_folder());
+
+        if !content_dir.exists() {
+            continue;
+        }
+
+        let mut entries = read_dir(&content_dir).await?;
+
+        while let Some(entry) = entries.next_entry().await.map_err(IoError::from)? {
+            let path = entry.path();
+
+            if !path.is_file() {
+                continue;
+            }
+
+            let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+
+            let content_path = std::path::PathBuf::from(content_type.get_folder())
+                .join(file_name.trim_end_matches(".disabled"))
+                .to_slash_lossy()
+                .to_string();
+
+            if !content_paths.contains(&content_path) {
+                untracked.push(content_path);
+            }
+        }
+    }
+
+    untracked.sort();
+
+    Ok(untracked)
+}
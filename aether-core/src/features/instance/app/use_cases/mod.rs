@@ -1,7 +1,19 @@
 mod content;
 mod content_provider;
+mod disk_usage;
+mod group;
 mod instance;
+mod playtime;
+mod server;
+mod snapshot;
+mod world;
 
 pub use content::*;
 pub use content_provider::*;
+pub use disk_usage::*;
+pub use group::*;
 pub use instance::*;
+pub use playtime::*;
+pub use server::*;
+pub use snapshot::*;
+pub use world::*;
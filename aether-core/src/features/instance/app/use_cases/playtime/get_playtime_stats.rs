@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use chrono::FixedOffset;
+
+use crate::features::instance::{
+    aggregate_playtime, InstanceError, PlaySessionStorage, PlaytimePeriod, PlaytimeStats,
+};
+
+pub struct GetPlaytimeStatsUseCase<PSS> {
+    play_session_storage: Arc<PSS>,
+}
+
+impl<PSS: PlaySessionStorage> GetPlaytimeStatsUseCase<PSS> {
+    pub fn new(play_session_storage: Arc<PSS>) -> Self {
+        Self {
+            play_session_storage,
+        }
+    }
+
+    /// `utc_offset_minutes` sets where days and weeks start, e.g. `120` for UTC+2
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        period: PlaytimePeriod,
+        utc_offset_minutes: i32,
+    ) -> Result<Vec<PlaytimeStats>, InstanceError> {
+        let offset = utc_offset_minutes
+            .checked_mul(60)
+            .and_then(FixedOffset::east_opt)
+            .ok_or_else(|| InstanceError::ValidationError {
+                field: "utc_offset_minutes".to_owned(),
+                reason: "offset must be within a day".to_owned(),
+            })?;
+
+        let sessions = self.play_session_storage.list(&instance_id).await?;
+
+        Ok(aggregate_playtime(&sessions, period, offset))
+    }
+}
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use crate::features::instance::{InstanceError, PlaySession, PlaySessionStorage};
+
+pub struct ListPlaySessionsUseCase<PSS> {
+    play_session_storage: Arc<PSS>,
+}
+
+impl<PSS: PlaySessionStorage> ListPlaySessionsUseCase<PSS> {
+    pub fn new(play_session_storage: Arc<PSS>) -> Self {
+        Self {
+            play_session_storage,
+        }
+    }
+
+    pub async fn execute(&self, instance_id: String) -> Result<Vec<PlaySession>, InstanceError> {
+        self.play_session_storage.list(&instance_id).await
+    }
+}
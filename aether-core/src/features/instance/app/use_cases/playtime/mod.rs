@@ -0,0 +1,7 @@
+mod get_playtime_stats;
+mod list_play_sessions;
+mod record_play_session;
+
+pub use get_playtime_stats::*;
+pub use list_play_sessions::*;
+pub use record_play_session::*;
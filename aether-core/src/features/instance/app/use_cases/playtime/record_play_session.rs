@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::features::instance::{
+    get_recent_playtime, InstanceError, InstanceStorage, InstanceStorageExt, PlaySession,
+    PlaySessionStorage,
+};
+
+pub struct RecordPlaySessionUseCase<IS, PSS> {
+    instance_storage: Arc<IS>,
+    play_session_storage: Arc<PSS>,
+}
+
+impl<IS: InstanceStorage, PSS: PlaySessionStorage> RecordPlaySessionUseCase<IS, PSS> {
+    pub fn new(instance_storage: Arc<IS>, play_session_storage: Arc<PSS>) -> Self {
+        Self {
+            instance_storage,
+            play_session_storage,
+        }
+    }
+
+    /// Adds the session to the history and recomputes `recent_time_played`
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        session: PlaySession,
+    ) -> Result<(), InstanceError> {
+        self.play_session_storage.add(&instance_id, session).await?;
+
+        let sessions = self.play_session_storage.list(&instance_id).await?;
+        let recent_time_played = get_recent_playtime(&sessions, Utc::now());
+
+        self.instance_storage
+            .upsert_with(&instance_id, |instance| {
+                instance.recent_time_played = recent_time_played;
+                Ok(())
+            })
+            .await
+    }
+}
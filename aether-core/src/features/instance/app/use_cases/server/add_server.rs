@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::features::instance::{
+    InstanceError, InstanceLockService, InstanceOperation, ServerEntry, ServerListStorage,
+};
+
+pub struct AddServerUseCase<SLS: ServerListStorage> {
+    server_list_storage: Arc<SLS>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<SLS: ServerListStorage> AddServerUseCase<SLS> {
+    pub fn new(
+        server_list_storage: Arc<SLS>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            server_list_storage,
+            instance_lock_service,
+        }
+    }
+
+    /// Inserts the server at `index` or at the end of the list
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        server: ServerEntry,
+        index: Option<usize>,
+    ) -> Result<Vec<ServerEntry>, InstanceError> {
+        validate_server_address(&server.address)?;
+
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ChangeServers)?;
+
+        let mut servers = self.server_list_storage.get(&instance_id).await?;
+
+        let index = index.unwrap_or(servers.len()).min(servers.len());
+        servers.insert(index, server);
+
+        self.server_list_storage.set(&instance_id, &servers).await?;
+
+        Ok(servers)
+    }
+}
+
+pub fn validate_server_address(address: &str) -> Result<(), InstanceError> {
+    if address.trim().is_empty() {
+        return Err(InstanceError::ValidationError {
+            field: "address".to_owned(),
+            reason: "address cannot be empty".to_owned(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::features::instance::{
+    InstanceError, InstanceLockService, InstanceOperation, ResourcePackPolicy, ServerEntry,
+    ServerListStorage,
+};
+
+use super::validate_server_address;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditServer {
+    pub name: Option<String>,
+    pub address: Option<String>,
+    pub resource_pack_policy: Option<ResourcePackPolicy>,
+}
+
+pub struct EditServerUseCase<SLS: ServerListStorage> {
+    server_list_storage: Arc<SLS>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<SLS: ServerListStorage> EditServerUseCase<SLS> {
+    pub fn new(
+        server_list_storage: Arc<SLS>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            server_list_storage,
+            instance_lock_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        index: usize,
+        edit_server: EditServer,
+    ) -> Result<Vec<ServerEntry>, InstanceError> {
+        if let Some(address) = &edit_server.address {
+            validate_server_address(address)?;
+        }
+
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ChangeServers)?;
+
+        let mut servers = self.server_list_storage.get(&instance_id).await?;
+
+        let Some(server) = servers.get_mut(index) else {
+            return Err(InstanceError::ServerNotFound { instance_id, index });
+        };
+
+        let EditServer {
+            name,
+            address,
+            resource_pack_policy,
+        } = edit_server;
+
+        if let Some(name) = name {
+            server.name = name;
+        }
+
+        if let Some(address) = address {
+            // Icon belongs to the old server
+            if address != server.address {
+                server.icon = None;
+            }
+            server.address = address;
+        }
+
+        if let Some(resource_pack_policy) = resource_pack_policy {
+            server.resource_pack_policy = resource_pack_policy;
+        }
+
+        self.server_list_storage.set(&instance_id, &servers).await?;
+
+        Ok(servers)
+    }
+}
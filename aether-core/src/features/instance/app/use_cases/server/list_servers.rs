@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use crate::features::instance::{InstanceError, ServerEntry, ServerListStorage};
+
+pub struct ListServersUseCase<SLS: ServerListStorage> {
+    server_list_storage: Arc<SLS>,
+}
+
+impl<SLS: ServerListStorage> ListServersUseCase<SLS> {
+    pub fn new(server_list_storage: Arc<SLS>) -> Self {
+        Self {
+            server_list_storage,
+        }
+    }
+
+    pub async fn execute(&self, instance_id: String) -> Result<Vec<ServerEntry>, InstanceError> {
+        self.server_list_storage.get(&instance_id).await
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use log::info;
+
+use crate::features::instance::{
+    InstanceError, InstanceLockService, InstanceOperation, ServerEntry, ServerListStorage,
+};
+
+use super::validate_server_address;
+
+pub struct MergeServersUseCase<SLS: ServerListStorage> {
+    server_list_storage: Arc<SLS>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<SLS: ServerListStorage> MergeServersUseCase<SLS> {
+    pub fn new(
+        server_list_storage: Arc<SLS>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            server_list_storage,
+            instance_lock_service,
+        }
+    }
+
+    /// Appends servers missing from the list, entries with a known address are left as the user
+    /// configured them
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        new_servers: Vec<ServerEntry>,
+    ) -> Result<Vec<ServerEntry>, InstanceError> {
+        for server in &new_servers {
+            validate_server_address(&server.address)?;
+        }
+
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ChangeServers)?;
+
+        let mut servers = self.server_list_storage.get(&instance_id).await?;
+        let servers_count = servers.len();
+
+        for server in new_servers {
+            if !servers
+                .iter()
+                .any(|existing| is_same_address(&existing.address, &server.address))
+            {
+                servers.push(server);
+            }
+        }
+
+        if servers.len() != servers_count {
+            info!(
+                "Merged {} servers into instance \"{}\"",
+                servers.len() - servers_count,
+                instance_id
+            );
+
+            self.server_list_storage.set(&instance_id, &servers).await?;
+        }
+
+        Ok(servers)
+    }
+}
+
+fn is_same_address(a: &str, b: &str) -> bool {
+    a.trim().eq_ignore_ascii_case(b.trim())
+}
@@ -0,0 +1,15 @@
+mod add_server;
+mod edit_server;
+mod list_servers;
+mod merge_servers;
+mod move_server;
+mod ping_server;
+mod remove_server;
+
+pub use add_server::*;
+pub use edit_server::*;
+pub use list_servers::*;
+pub use merge_servers::*;
+pub use move_server::*;
+pub use ping_server::*;
+pub use remove_server::*;
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use crate::features::instance::{
+    InstanceError, InstanceLockService, InstanceOperation, ServerEntry, ServerListStorage,
+};
+
+pub struct MoveServerUseCase<SLS: ServerListStorage> {
+    server_list_storage: Arc<SLS>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<SLS: ServerListStorage> MoveServerUseCase<SLS> {
+    pub fn new(
+        server_list_storage: Arc<SLS>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            server_list_storage,
+            instance_lock_service,
+        }
+    }
+
+    /// Moves the server at `from` so it ends up at `to`
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        from: usize,
+        to: usize,
+    ) -> Result<Vec<ServerEntry>, InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ChangeServers)?;
+
+        let mut servers = self.server_list_storage.get(&instance_id).await?;
+
+        if let Some(index) = [from, to].into_iter().find(|index| *index >= servers.len()) {
+            return Err(InstanceError::ServerNotFound { instance_id, index });
+        }
+
+        let server = servers.remove(from);
+        servers.insert(to, server);
+
+        self.server_list_storage.set(&instance_id, &servers).await?;
+
+        Ok(servers)
+    }
+}
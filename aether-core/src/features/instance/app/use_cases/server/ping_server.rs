@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::features::instance::{InstanceError, ServerPinger, ServerStatus};
+
+use super::validate_server_address;
+
+pub struct PingServerUseCase<SP: ServerPinger> {
+    server_pinger: Arc<SP>,
+}
+
+impl<SP: ServerPinger> PingServerUseCase<SP> {
+    pub fn new(server_pinger: Arc<SP>) -> Self {
+        Self { server_pinger }
+    }
+
+    pub async fn execute(&self, address: String) -> Result<ServerStatus, InstanceError> {
+        validate_server_address(&address)?;
+
+        self.server_pinger.ping(&address).await
+    }
+}
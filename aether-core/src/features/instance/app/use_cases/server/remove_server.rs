@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::features::instance::{
+    InstanceError, InstanceLockService, InstanceOperation, ServerEntry, ServerListStorage,
+};
+
+pub struct RemoveServerUseCase<SLS: ServerListStorage> {
+    server_list_storage: Arc<SLS>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<SLS: ServerListStorage> RemoveServerUseCase<SLS> {
+    pub fn new(
+        server_list_storage: Arc<SLS>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            server_list_storage,
+            instance_lock_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        index: usize,
+    ) -> Result<Vec<ServerEntry>, InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ChangeServers)?;
+
+        let mut servers = self.server_list_storage.get(&instance_id).await?;
+
+        if index >= servers.len() {
+            return Err(InstanceError::ServerNotFound { instance_id, index });
+        }
+
+        servers.remove(index);
+
+        self.server_list_storage.set(&instance_id, &servers).await?;
+
+        Ok(servers)
+    }
+}
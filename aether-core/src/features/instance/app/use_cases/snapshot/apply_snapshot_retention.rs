@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use log::info;
+
+use crate::features::instance::{
+    InstanceError, InstanceLockService, InstanceOperation, InstanceStorage, SnapshotRetention,
+    SnapshotStorage,
+};
+
+pub struct ApplySnapshotRetentionUseCase<IS: InstanceStorage, SS: SnapshotStorage> {
+    instance_storage: Arc<IS>,
+    snapshot_storage: Arc<SS>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<IS: InstanceStorage, SS: SnapshotStorage> ApplySnapshotRetentionUseCase<IS, SS> {
+    pub fn new(
+        instance_storage: Arc<IS>,
+        snapshot_storage: Arc<SS>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            snapshot_storage,
+            instance_lock_service,
+        }
+    }
+
+    /// Removes snapshots not kept by `retention` or by the instance policy if it's `None`,
+    /// returns ids of removed snapshots. Refused while another operation may be using them
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        retention: Option<SnapshotRetention>,
+    ) -> Result<Vec<String>, InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ApplySnapshotRetention)?;
+
+        self.apply(instance_id, retention).await
+    }
+
+    /// Applies the instance policy, the calling operation already holds the instance lock
+    pub async fn execute_auto(&self, instance_id: String) -> Result<Vec<String>, InstanceError> {
+        self.apply(instance_id, None).await
+    }
+
+    async fn apply(
+        &self,
+        instance_id: String,
+        retention: Option<SnapshotRetention>,
+    ) -> Result<Vec<String>, InstanceError> {
+        let retention = match retention {
+            Some(retention) => retention,
+            None => {
+                let instance = self.instance_storage.get(&instance_id).await?;
+
+                match instance.snapshot_policy.and_then(|policy| policy.retention) {
+                    Some(retention) => retention,
+                    None => return Ok(Vec::new()),
+                }
+            }
+        };
+
+        let snapshots = self.snapshot_storage.list(&instance_id).await?;
+        let expired = retention.get_expired(&snapshots, Utc::now());
+
+        for snapshot_id in &expired {
+            self.snapshot_storage
+                .remove(&instance_id, snapshot_id)
+                .await?;
+        }
+
+        if !expired.is_empty() {
+            info!(
+                "Removed {} expired snapshots of instance \"{}\"",
+                expired.len(),
+                instance_id
+            );
+        }
+
+        Ok(expired)
+    }
+}
@@ -0,0 +1,202 @@
+use std::{collections::BTreeSet, future::Future, sync::Arc};
+
+use log::{info, warn};
+use path_slash::PathExt;
+
+use crate::features::{
+    events::{ProgressBarId, ProgressEventType, ProgressService, ProgressServiceExt},
+    instance::{
+        utils::collect_files_async, ContentType, InstanceError, InstanceLockService,
+        InstanceOperation, InstanceSnapshot, InstanceStorage, SnapshotProgressFn, SnapshotReason,
+        SnapshotStorage, SNAPSHOT_FOLDERS,
+    },
+    settings::LocationInfo,
+};
+
+use super::ApplySnapshotRetentionUseCase;
+
+/// Part of the progress bar taken by archiving, the rest is left for finishing
+const ARCHIVE_PROGRESS: f64 = 0.9;
+
+pub struct CreateSnapshotUseCase<IS: InstanceStorage, SS: SnapshotStorage, PS: ProgressService> {
+    instance_storage: Arc<IS>,
+    snapshot_storage: Arc<SS>,
+    apply_snapshot_retention_use_case: Arc<ApplySnapshotRetentionUseCase<IS, SS>>,
+    progress_service: Arc<PS>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<IS: InstanceStorage, SS: SnapshotStorage, PS: ProgressService>
+    CreateSnapshotUseCase<IS, SS, PS>
+{
+    pub fn new(
+        instance_storage: Arc<IS>,
+        snapshot_storage: Arc<SS>,
+        apply_snapshot_retention_use_case: Arc<ApplySnapshotRetentionUseCase<IS, SS>>,
+        progress_service: Arc<PS>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            snapshot_storage,
+            apply_snapshot_retention_use_case,
+            progress_service,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    /// Takes a snapshot on its own, so no other operation changes files while archiving
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        reason: SnapshotReason,
+    ) -> Result<InstanceSnapshot, InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::CreateSnapshot)?;
+
+        self.create(instance_id, reason).await
+    }
+
+    /// Takes a snapshot only if the instance policy asks for automatic snapshots,
+    /// the calling operation already holds the instance lock
+    pub async fn execute_auto(
+        &self,
+        instance_id: &str,
+        reason: SnapshotReason,
+    ) -> Result<Option<InstanceSnapshot>, InstanceError> {
+        let instance = self.instance_storage.get(instance_id).await?;
+
+        if !instance
+            .snapshot_policy
+            .is_some_and(|policy| policy.auto_snapshot)
+        {
+            return Ok(None);
+        }
+
+        self.create(instance_id.to_owned(), reason).await.map(Some)
+    }
+
+    async fn create(
+        &self,
+        instance_id: String,
+        reason: SnapshotReason,
+    ) -> Result<InstanceSnapshot, InstanceError> {
+        let instance = self.instance_storage.get(&instance_id).await?;
+        let instance_dir = self.location_info.instance_dir(&instance_id);
+
+        let mut files = BTreeSet::new();
+        let folders = SNAPSHOT_FOLDERS
+            .into_iter()
+            .chain(ContentType::iterator().map(|content_type| content_type.get_folder()));
+
+        for folder in folders {
+            let folder_dir = instance_dir.join(folder);
+
+            // Shared folders belong to every instance linking them, not to this snapshot
+            if folder_dir.is_symlink() {
+                continue;
+            }
+
+            files.extend(collect_files_async(instance_dir.clone(), folder_dir).await?);
+        }
+
+        // Play sessions are history like the play time, restoring a snapshot must not rewind them
+        if let Ok(sessions_file) = self
+            .location_info
+            .instance_sessions_file(&instance_id)
+            .strip_prefix(&instance_dir)
+        {
+            files.remove(&sessions_file.to_slash_lossy().to_string());
+        }
+
+        info!(
+            "Creating snapshot of instance \"{}\" with {} files ({:?})",
+            instance_id,
+            files.len(),
+            reason
+        );
+
+        let progress_bar = self
+            .progress_service
+            .init_progress_safe(
+                ProgressEventType::InstanceSnapshot {
+                    instance_id: instance.id.clone(),
+                    instance_name: instance.name.clone(),
+                },
+                1.0,
+                "Creating snapshot".to_string(),
+            )
+            .await;
+
+        let result = run_with_snapshot_progress(
+            self.progress_service.as_ref(),
+            progress_bar.as_ref(),
+            |on_progress| {
+                self.snapshot_storage
+                    .create(&instance_id, reason, instance_dir, files, on_progress)
+            },
+        )
+        .await;
+
+        if let Some(progress_bar) = progress_bar {
+            // Completes the bar regardless of accumulated increments
+            self.progress_service
+                .emit_progress_safe(&progress_bar, 1.0, Some("Snapshot created"))
+                .await;
+        }
+
+        let snapshot = result?;
+
+        if instance
+            .snapshot_policy
+            .is_some_and(|policy| policy.retention.is_some())
+        {
+            // Snapshot is already taken, failed cleanup shouldn't fail it
+            if let Err(err) = self
+                .apply_snapshot_retention_use_case
+                .execute_auto(instance_id)
+                .await
+            {
+                warn!("Failed to apply snapshot retention: {}", err);
+            }
+        }
+
+        Ok(snapshot)
+    }
+}
+
+/// Runs a snapshot storage operation while forwarding its progress to the progress bar
+pub async fn run_with_snapshot_progress<PS, F, T>(
+    progress_service: &PS,
+    progress_bar: Option<&ProgressBarId>,
+    operation: impl FnOnce(SnapshotProgressFn) -> F,
+) -> T
+where
+    PS: ProgressService,
+    F: Future<Output = T>,
+{
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel();
+
+    // Channel closes once the storage drops the callback
+    let operation = operation(Box::new(move |increment| {
+        let _ = progress_tx.send(increment);
+    }));
+
+    let report = async {
+        while let Some(increment) = progress_rx.recv().await {
+            if let Some(progress_bar) = progress_bar {
+                progress_service
+                    .emit_progress_safe(progress_bar, increment * ARCHIVE_PROGRESS, None)
+                    .await;
+            }
+        }
+    };
+
+    let (result, ()) = tokio::join!(operation, report);
+
+    result
+}
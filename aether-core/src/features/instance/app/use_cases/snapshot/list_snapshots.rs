@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::features::instance::{InstanceError, InstanceSnapshot, SnapshotStorage};
+
+pub struct ListSnapshotsUseCase<SS: SnapshotStorage> {
+    snapshot_storage: Arc<SS>,
+}
+
+impl<SS: SnapshotStorage> ListSnapshotsUseCase<SS> {
+    pub fn new(snapshot_storage: Arc<SS>) -> Self {
+        Self { snapshot_storage }
+    }
+
+    pub async fn execute(
+        &self,
+        instance_id: String,
+    ) -> Result<Vec<InstanceSnapshot>, InstanceError> {
+        self.snapshot_storage.list(&instance_id).await
+    }
+}
@@ -0,0 +1,11 @@
+mod apply_snapshot_retention;
+mod create_snapshot;
+mod list_snapshots;
+mod remove_snapshot;
+mod restore_snapshot;
+
+pub use apply_snapshot_retention::*;
+pub use create_snapshot::*;
+pub use list_snapshots::*;
+pub use remove_snapshot::*;
+pub use restore_snapshot::*;
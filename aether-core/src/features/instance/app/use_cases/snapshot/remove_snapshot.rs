@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::features::instance::{InstanceError, SnapshotStorage};
+
+pub struct RemoveSnapshotUseCase<SS: SnapshotStorage> {
+    snapshot_storage: Arc<SS>,
+}
+
+impl<SS: SnapshotStorage> RemoveSnapshotUseCase<SS> {
+    pub fn new(snapshot_storage: Arc<SS>) -> Self {
+        Self { snapshot_storage }
+    }
+
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        snapshot_id: String,
+    ) -> Result<(), InstanceError> {
+        self.snapshot_storage
+            .remove(&instance_id, &snapshot_id)
+            .await
+    }
+}
@@ -0,0 +1,264 @@
+use std::{path::Path, sync::Arc};
+
+use chrono::Utc;
+use log::{error, info};
+
+use crate::{
+    features::{
+        events::{ProgressEventType, ProgressService, ProgressServiceExt},
+        instance::{
+            ContentType, InstanceError, InstanceInstallStage, InstanceLockService,
+            InstanceOperation, InstanceStorage, InstanceStorageExt, SnapshotStorage,
+            SNAPSHOT_FOLDERS,
+        },
+        settings::LocationInfo,
+    },
+    shared::{create_dir_all, remove_dir_all, remove_file, rename, IoError},
+};
+
+use super::run_with_snapshot_progress;
+
+const RESTORE_FOLDER: &str = ".restore";
+const BACKUP_FOLDER: &str = ".restore-backup";
+
+pub struct RestoreSnapshotUseCase<IS: InstanceStorage, SS: SnapshotStorage, PS: ProgressService> {
+    instance_storage: Arc<IS>,
+    snapshot_storage: Arc<SS>,
+    progress_service: Arc<PS>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<IS: InstanceStorage, SS: SnapshotStorage, PS: ProgressService>
+    RestoreSnapshotUseCase<IS, SS, PS>
+{
+    pub fn new(
+        instance_storage: Arc<IS>,
+        snapshot_storage: Arc<SS>,
+        progress_service: Arc<PS>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            snapshot_storage,
+            progress_service,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    /// Replaces snapshot folders of the instance with the snapshot content, play time is kept
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        snapshot_id: String,
+    ) -> Result<(), InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::RestoreSnapshot)?;
+
+        let instance = self.instance_storage.get(&instance_id).await?;
+
+        if matches!(
+            instance.install_stage,
+            InstanceInstallStage::Installing | InstanceInstallStage::PackInstalling
+        ) {
+            return Err(InstanceError::InstanceStillInstalling { instance_id });
+        }
+
+        // Fails early if the snapshot doesn't exist
+        self.snapshot_storage
+            .get(&instance_id, &snapshot_id)
+            .await?;
+
+        info!(
+            "Restoring snapshot \"{}\" of instance \"{}\"",
+            snapshot_id, instance_id
+        );
+
+        let progress_bar = self
+            .progress_service
+            .init_progress_safe(
+                ProgressEventType::InstanceSnapshot {
+                    instance_id: instance.id.clone(),
+                    instance_name: instance.name.clone(),
+                },
+                1.0,
+                "Restoring snapshot".to_string(),
+            )
+            .await;
+
+        // Extracted next to the other snapshots first, so a broken archive leaves the instance intact
+        let restore_dir = self
+            .location_info
+            .instance_snapshots_dir(&instance_id)
+            .join(RESTORE_FOLDER);
+
+        if restore_dir.exists() {
+            remove_dir_all(&restore_dir).await?;
+        }
+
+        let result = run_with_snapshot_progress(
+            self.progress_service.as_ref(),
+            progress_bar.as_ref(),
+            |on_progress| {
+                self.snapshot_storage.extract(
+                    &instance_id,
+                    &snapshot_id,
+                    restore_dir.clone(),
+                    on_progress,
+                )
+            },
+        )
+        .await;
+
+        let result = match result {
+            Ok(()) => self.replace_folders(&instance_id).await,
+            Err(err) => Err(err),
+        };
+
+        if restore_dir.exists() {
+            if let Err(err) = remove_dir_all(&restore_dir).await {
+                error!("Failed to cleanup restored snapshot: {}", err);
+            }
+        }
+
+        if let Some(progress_bar) = progress_bar {
+            self.progress_service
+                .emit_progress_safe(&progress_bar, 1.0, Some("Snapshot restored"))
+                .await;
+        }
+
+        result?;
+
+        // Restored instance.json may describe another version, play time stays current
+        self.instance_storage
+            .upsert_with(&instance_id, |restored| {
+                if restored.game_version != instance.game_version
+                    || restored.loader != instance.loader
+                    || restored.loader_version != instance.loader_version
+                {
+                    restored.install_stage = InstanceInstallStage::NotInstalled;
+                } else {
+                    restored.install_stage = instance.install_stage;
+                }
+
+                restored.last_played = instance.last_played;
+                restored.time_played = instance.time_played;
+                restored.recent_time_played = instance.recent_time_played;
+                restored.snapshot_policy = instance.snapshot_policy;
+                restored.shared_folders = instance.shared_folders.clone();
+                restored.modified = Utc::now();
+                Ok(())
+            })
+            .await
+    }
+
+    /// Swaps every folder with its restored copy, current folders are moved aside first
+    /// and put back if any swap fails
+    async fn replace_folders(&self, instance_id: &str) -> Result<(), InstanceError> {
+        let instance_dir = self.location_info.instance_dir(instance_id);
+        let snapshots_dir = self.location_info.instance_snapshots_dir(instance_id);
+        let restore_dir = snapshots_dir.join(RESTORE_FOLDER);
+        let backup_dir = snapshots_dir.join(BACKUP_FOLDER);
+
+        // Older snapshots still carry play sessions, the current ones are kept like the play time
+        let sessions_file = self.location_info.instance_sessions_file(instance_id);
+        if let Ok(relative_path) = sessions_file.strip_prefix(&instance_dir) {
+            keep_current_file(&sessions_file, &restore_dir.join(relative_path)).await?;
+        }
+
+        if backup_dir.exists() {
+            remove_dir_all(&backup_dir).await?;
+        }
+        create_dir_all(&backup_dir).await?;
+
+        let folders = SNAPSHOT_FOLDERS
+            .into_iter()
+            .chain(ContentType::iterator().map(|content_type| content_type.get_folder()));
+
+        let mut swapped_folders = Vec::new();
+        let mut result = Ok(());
+
+        for folder in folders {
+            let target = instance_dir.join(folder);
+
+            // Shared folders aren't part of snapshots and must not be replaced
+            if target.is_symlink() {
+                continue;
+            }
+
+            swapped_folders.push(folder);
+
+            if let Err(err) =
+                swap_folder(&target, &restore_dir.join(folder), &backup_dir.join(folder)).await
+            {
+                result = Err(err);
+                break;
+            }
+        }
+
+        let mut rollback_failed = false;
+        if result.is_err() {
+            for folder in swapped_folders.into_iter().rev() {
+                if let Err(err) =
+                    rollback_folder(&instance_dir.join(folder), &backup_dir.join(folder)).await
+                {
+                    error!("Failed to put back folder \"{}\": {}", folder, err);
+                    rollback_failed = true;
+                }
+            }
+        }
+
+        // Folders that couldn't be put back stay in the backup
+        if !rollback_failed {
+            if let Err(err) = remove_dir_all(&backup_dir).await {
+                error!("Failed to cleanup replaced folders: {}", err);
+            }
+        }
+
+        Ok(result?)
+    }
+}
+
+async fn keep_current_file(current: &Path, restored: &Path) -> Result<(), IoError> {
+    if restored.exists() {
+        remove_file(restored).await?;
+    }
+
+    if current.exists() {
+        if let Some(parent) = restored.parent() {
+            create_dir_all(parent).await?;
+        }
+        tokio::fs::copy(current, restored)
+            .await
+            .map_err(|err| IoError::with_path(err, current))?;
+    }
+
+    Ok(())
+}
+
+async fn swap_folder(target: &Path, source: &Path, backup: &Path) -> Result<(), IoError> {
+    if target.exists() {
+        rename(target, backup).await?;
+    }
+
+    if source.exists() {
+        rename(source, target).await?;
+    }
+
+    Ok(())
+}
+
+async fn rollback_folder(target: &Path, backup: &Path) -> Result<(), IoError> {
+    if target.exists() {
+        remove_dir_all(target).await?;
+    }
+
+    if backup.exists() {
+        rename(backup, target).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use log::{error, info};
+
+use crate::{
+    features::{
+        instance::{
+            app::create_unique_instance_path, InstanceError, InstanceLockService,
+            InstanceOperation, InstanceStorage, WorldStorage,
+        },
+        settings::LocationInfo,
+    },
+    shared::{copy_dir_all, remove_dir_all, IoError},
+};
+
+pub struct CopyWorldUseCase<IS: InstanceStorage, WS: WorldStorage> {
+    instance_storage: Arc<IS>,
+    world_storage: Arc<WS>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<IS: InstanceStorage, WS: WorldStorage> CopyWorldUseCase<IS, WS> {
+    pub fn new(
+        instance_storage: Arc<IS>,
+        world_storage: Arc<WS>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            world_storage,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    /// Copies a world into another instance, returns the id of the copy
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        world_id: String,
+        target_instance_id: String,
+    ) -> Result<String, InstanceError> {
+        // Only the target instance is changed
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&target_instance_id, InstanceOperation::ChangeWorlds)?;
+
+        self.world_storage.get(&instance_id, &world_id).await?;
+        // Fails if the target instance doesn't exist
+        self.instance_storage.get(&target_instance_id).await?;
+
+        let source_dir = self
+            .location_info
+            .instance_saves_dir(&instance_id)
+            .join(&world_id);
+        let (target_dir, new_world_id) = create_unique_instance_path(
+            &world_id,
+            &self.location_info.instance_saves_dir(&target_instance_id),
+        );
+
+        info!(
+            "Copying world \"{}\" from instance \"{}\" to \"{}\"",
+            world_id, instance_id, target_instance_id
+        );
+
+        let copy_target_dir = target_dir.clone();
+        let result =
+            tokio::task::spawn_blocking(move || copy_dir_all(&source_dir, &copy_target_dir))
+                .await
+                .map_err(|err| IoError::IoError(std::io::Error::other(err)))
+                .and_then(|result| result);
+
+        if let Err(err) = result {
+            if target_dir.exists() {
+                if let Err(cleanup_err) = remove_dir_all(&target_dir).await {
+                    error!("Failed to cleanup world copy: {}", cleanup_err);
+                }
+            }
+            return Err(err.into());
+        }
+
+        Ok(new_world_id)
+    }
+}
@@ -0,0 +1,44 @@
+use std::{path::PathBuf, sync::Arc};
+
+use log::info;
+
+use crate::features::{
+    instance::{utils::write_world_archive, InstanceError, WorldStorage},
+    settings::LocationInfo,
+};
+
+pub struct ExportWorldUseCase<WS: WorldStorage> {
+    world_storage: Arc<WS>,
+    location_info: Arc<LocationInfo>,
+}
+
+impl<WS: WorldStorage> ExportWorldUseCase<WS> {
+    pub fn new(world_storage: Arc<WS>, location_info: Arc<LocationInfo>) -> Self {
+        Self {
+            world_storage,
+            location_info,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        world_id: String,
+        output_path: PathBuf,
+    ) -> Result<(), InstanceError> {
+        // Fails if the world doesn't exist
+        self.world_storage.get(&instance_id, &world_id).await?;
+
+        let world_dir = self
+            .location_info
+            .instance_saves_dir(&instance_id)
+            .join(&world_id);
+
+        info!(
+            "Exporting world \"{}\" of instance \"{}\" to {:?}",
+            world_id, instance_id, output_path
+        );
+
+        write_world_archive(world_dir, output_path).await
+    }
+}
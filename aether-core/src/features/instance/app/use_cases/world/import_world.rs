@@ -0,0 +1,88 @@
+use std::{path::PathBuf, sync::Arc};
+
+use log::{error, info};
+
+use crate::{
+    features::{
+        instance::{
+            app::create_unique_instance_path,
+            utils::{extract_world_archive, find_world_archive_root},
+            InstanceError, InstanceLockService, InstanceOperation, InstanceStorage, World,
+            WorldStorage,
+        },
+        settings::LocationInfo,
+    },
+    shared::remove_dir_all,
+};
+
+pub struct ImportWorldUseCase<IS: InstanceStorage, WS: WorldStorage> {
+    instance_storage: Arc<IS>,
+    world_storage: Arc<WS>,
+    location_info: Arc<LocationInfo>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<IS: InstanceStorage, WS: WorldStorage> ImportWorldUseCase<IS, WS> {
+    pub fn new(
+        instance_storage: Arc<IS>,
+        world_storage: Arc<WS>,
+        location_info: Arc<LocationInfo>,
+        instance_lock_service: Arc<InstanceLockService>,
+    ) -> Self {
+        Self {
+            instance_storage,
+            world_storage,
+            location_info,
+            instance_lock_service,
+        }
+    }
+
+    /// Imports a zipped world, the archive may contain the world folder or its content
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        path: PathBuf,
+    ) -> Result<World, InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ChangeWorlds)?;
+
+        // Fails if the instance doesn't exist
+        self.instance_storage.get(&instance_id).await?;
+
+        let root = find_world_archive_root(path.clone()).await?;
+
+        let name = root
+            .file_name()
+            .or_else(|| path.file_stem())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "world".to_owned());
+
+        let (target_dir, world_id) = create_unique_instance_path(
+            &name,
+            &self.location_info.instance_saves_dir(&instance_id),
+        );
+
+        info!(
+            "Importing world {:?} into instance \"{}\" as \"{}\"",
+            path, instance_id, world_id
+        );
+
+        let result = match extract_world_archive(path, root, target_dir.clone()).await {
+            Ok(()) => self.world_storage.get(&instance_id, &world_id).await,
+            Err(err) => Err(err),
+        };
+
+        if let Err(err) = &result {
+            error!("Failed to import world \"{}\": {}", world_id, err);
+
+            if target_dir.exists() {
+                if let Err(cleanup_err) = remove_dir_all(&target_dir).await {
+                    error!("Failed to cleanup world: {}", cleanup_err);
+                }
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use crate::features::instance::{InstanceError, World, WorldStorage};
+
+pub struct ListWorldsUseCase<WS: WorldStorage> {
+    world_storage: Arc<WS>,
+}
+
+impl<WS: WorldStorage> ListWorldsUseCase<WS> {
+    pub fn new(world_storage: Arc<WS>) -> Self {
+        Self { world_storage }
+    }
+
+    pub async fn execute(&self, instance_id: String) -> Result<Vec<World>, InstanceError> {
+        self.world_storage.list(&instance_id).await
+    }
+}
@@ -0,0 +1,11 @@
+mod copy_world;
+mod export_world;
+mod import_world;
+mod list_worlds;
+mod remove_world;
+
+pub use copy_world::*;
+pub use export_world::*;
+pub use import_world::*;
+pub use list_worlds::*;
+pub use remove_world::*;
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use log::info;
+
+use crate::features::instance::{
+    InstanceError, InstanceLockService, InstanceOperation, WorldStorage,
+};
+
+pub struct RemoveWorldUseCase<WS: WorldStorage> {
+    world_storage: Arc<WS>,
+    instance_lock_service: Arc<InstanceLockService>,
+}
+
+impl<WS: WorldStorage> RemoveWorldUseCase<WS> {
+    pub fn new(world_storage: Arc<WS>, instance_lock_service: Arc<InstanceLockService>) -> Self {
+        Self {
+            world_storage,
+            instance_lock_service,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        instance_id: String,
+        world_id: String,
+    ) -> Result<(), InstanceError> {
+        let _lock = self
+            .instance_lock_service
+            .try_lock(&instance_id, InstanceOperation::ChangeWorlds)?;
+
+        info!(
+            "Removing world \"{}\" of instance \"{}\"",
+            world_id, instance_id
+        );
+
+        self.world_storage.remove(&instance_id, &world_id).await
+    }
+}
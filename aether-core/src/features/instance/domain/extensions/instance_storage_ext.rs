@@ -11,6 +11,37 @@ pub trait InstanceStorageExt: InstanceStorage {
         update_fn(&mut instance)?;
         self.upsert(&instance).await
     }
+
+    /// Applies `update_fn` to every instance through `upsert_with`, so each one is re-read right
+    /// before its write and concurrent changes to it are kept. The instances aren't written as
+    /// one transaction: if an update fails, `revert_fn` is applied the same way to the instances
+    /// updated before it, a failed revert is only logged
+    async fn upsert_all_with<F, R>(
+        &self,
+        ids: &[String],
+        update_fn: F,
+        revert_fn: R,
+    ) -> Result<(), InstanceError>
+    where
+        F: Fn(&mut Instance) -> Result<(), InstanceError> + Send + Sync,
+        R: Fn(&mut Instance) -> Result<(), InstanceError> + Send + Sync,
+    {
+        for (index, id) in ids.iter().enumerate() {
+            if let Err(err) = self.upsert_with(id, &update_fn).await {
+                for id in ids[..index].iter().rev() {
+                    if let Err(err) = self.upsert_with(id, &revert_fn).await {
+                        tracing::error!(
+                            "Failed to revert instance \"{id}\" after failed update: {err}"
+                        );
+                    }
+                }
+
+                return Err(err);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
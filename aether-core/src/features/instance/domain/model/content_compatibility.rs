@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::ContentType;
+
+/// Ordered from the least to the most stable
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentVersionType {
+    Alpha,
+    Beta,
+    Release,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentVersion {
+    /// Provider specific version id
+    pub id: String,
+    pub name: String,
+    pub version_type: ContentVersionType,
+    pub date_published: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", tag = "status")]
+pub enum ContentCompatibilityStatus {
+    /// Installed version works with the target game version and loader
+    Compatible,
+    /// Installed version doesn't work, but the provider has one that does
+    UpdateAvailable {
+        version: ContentVersion,
+    },
+    Incompatible,
+    /// Provider couldn't be queried
+    CheckFailed {
+        error: String,
+    },
+    /// Content isn't linked to a provider, so it can't be checked
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentCompatibility {
+    pub content_path: String,
+    pub content_type: ContentType,
+    pub name: Option<String>,
+    pub provider: Option<String>,
+    pub status: ContentCompatibilityStatus,
+}
@@ -16,4 +16,5 @@ pub struct ContentFile {
     pub name: Option<String>,
     pub size: u64,
     pub update: Option<HashMap<String, serde_json::Value>>,
+    pub download_url: Option<String>,
 }
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ContentType, ContentVersion};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentUpdate {
+    pub content_path: String,
+    pub content_type: ContentType,
+    pub name: Option<String>,
+    pub provider: Option<String>,
+    /// Provider specific id of the installed version
+    pub installed_version: Option<String>,
+    /// Newest version for the instance game version and loader,
+    /// never less stable or older than the installed one
+    pub latest_version: Option<ContentVersion>,
+    pub update_available: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", tag = "status")]
+pub enum ContentUpdateStatus {
+    Updated,
+    /// Update failed and the old file was left in place
+    Failed {
+        error: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentUpdateResult {
+    pub content_path: String,
+    pub status: ContentUpdateStatus,
+}
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::{
+    instance::utils::{to_metadata_loader_version, to_pack_loader_version},
+    minecraft::{LoaderVersionPreference, ModLoader},
+};
+
+use super::{ContentType, PackFile};
+
+pub const CURSEFORGE_MANIFEST_FILE: &str = "manifest.json";
+pub const CURSEFORGE_MANIFEST_TYPE: &str = "minecraftModpack";
+pub const CURSEFORGE_MANIFEST_VERSION: u32 = 1;
+pub const CURSEFORGE_OVERRIDES_DIR: &str = "overrides";
+/// Key of [`CurseForgeUpdateData`] in [`PackFile::update`]
+pub const CURSEFORGE_UPDATE_KEY: &str = "curseforge";
+
+/// `manifest.json` of a CurseForge modpack
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CurseForgeManifest {
+    pub minecraft: CurseForgeMinecraft,
+    pub manifest_type: String,
+    pub manifest_version: u32,
+    pub name: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub author: String,
+    pub files: Vec<CurseForgeManifestFile>,
+    #[serde(default = "default_overrides_dir")]
+    pub overrides: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CurseForgeMinecraft {
+    pub version: String,
+    #[serde(default)]
+    pub mod_loaders: Vec<CurseForgeModLoader>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CurseForgeModLoader {
+    /// Loader with its version, e.g. `forge-47.2.0`
+    pub id: String,
+    #[serde(default)]
+    pub primary: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CurseForgeManifestFile {
+    #[serde(rename = "projectID")]
+    pub project_id: u64,
+    #[serde(rename = "fileID")]
+    pub file_id: u64,
+    #[serde(default = "default_required")]
+    pub required: bool,
+}
+
+/// File resolved from a `projectID`/`fileID` pair
+#[derive(Debug, Clone)]
+pub struct CurseForgeFile {
+    pub file_name: String,
+    pub name: Option<String>,
+    /// Missing when the author disabled third-party downloads
+    pub download_url: Option<String>,
+    pub sha1: Option<String>,
+    pub content_type: ContentType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CurseForgeUpdateData {
+    pub project_id: u64,
+    pub file_id: u64,
+}
+
+fn default_overrides_dir() -> String {
+    CURSEFORGE_OVERRIDES_DIR.to_owned()
+}
+
+fn default_required() -> bool {
+    true
+}
+
+impl CurseForgeMinecraft {
+    /// Mod loader and its version, vanilla if the pack doesn't use any loader
+    pub fn loader(&self) -> (ModLoader, Option<LoaderVersionPreference>) {
+        let mod_loader = self
+            .mod_loaders
+            .iter()
+            .find(|loader| loader.primary)
+            .or_else(|| self.mod_loaders.first());
+
+        let Some((loader_id, version)) = mod_loader.and_then(|loader| loader.id.split_once('-'))
+        else {
+            return (ModLoader::Vanilla, None);
+        };
+
+        let mod_loader = match loader_id {
+            "forge" => ModLoader::Forge,
+            "neoforge" => ModLoader::NeoForge,
+            "fabric" => ModLoader::Fabric,
+            "quilt" => ModLoader::Quilt,
+            _ => return (ModLoader::Vanilla, None),
+        };
+
+        (
+            mod_loader,
+            Some(LoaderVersionPreference::Exact(to_metadata_loader_version(
+                mod_loader,
+                &self.version,
+                version,
+            ))),
+        )
+    }
+
+    pub fn new(game_version: String, mod_loader: ModLoader, loader_version: Option<&str>) -> Self {
+        let mod_loaders = loader_version
+            .filter(|_| mod_loader != ModLoader::Vanilla)
+            .map(|version| CurseForgeModLoader {
+                id: format!(
+                    "{}-{}",
+                    mod_loader.as_str(),
+                    to_pack_loader_version(&game_version, version)
+                ),
+                primary: true,
+            })
+            .into_iter()
+            .collect();
+
+        Self {
+            version: game_version,
+            mod_loaders,
+        }
+    }
+}
+
+impl CurseForgeUpdateData {
+    pub fn from_pack_file(pack_file: &PackFile) -> Option<Self> {
+        pack_file
+            .update
+            .as_ref()?
+            .get(CURSEFORGE_UPDATE_KEY)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use super::ContentType;
+
+/// Sizes are in bytes
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceDiskUsage {
+    pub instance_id: String,
+    pub total: u64,
+    pub content: Vec<ContentDiskUsage>,
+    pub saves: u64,
+    pub logs: u64,
+    pub screenshots: u64,
+    /// Everything else in the instance folder, including instance metadata
+    pub other: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentDiskUsage {
+    pub content_type: ContentType,
+    pub size: u64,
+}
+
+/// Shared files downloaded for all instances, sizes are in bytes
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataDiskUsage {
+    pub total: u64,
+    pub versions: Vec<VersionDiskUsage>,
+    pub libraries: u64,
+    /// Asset objects, indexes and legacy copies
+    pub assets: u64,
+    pub natives: u64,
+    pub java: Vec<JavaDiskUsage>,
+    /// Cache folder without java runtimes
+    pub caches: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionDiskUsage {
+    pub version_id: String,
+    pub size: u64,
+    pub instances: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct JavaDiskUsage {
+    /// Folder name of the runtime
+    pub name: String,
+    /// Missing if the runtime isn't registered in the java storage
+    pub major_version: Option<u32>,
+    pub size: u64,
+    pub instances: Vec<String>,
+}
@@ -11,6 +11,8 @@ use crate::{
     shared::IoError,
 };
 
+use super::InstanceOperation;
+
 #[derive(Debug, thiserror::Error, SerializeError)]
 pub enum InstanceError {
     #[error("Storage failure: {0}")]
@@ -22,12 +24,24 @@ pub enum InstanceError {
     #[error("Instance \"{instance_id}\" still in installing state")]
     InstanceStillInstalling { instance_id: String },
 
+    #[error("Instance \"{instance_id}\" is busy with \"{operation}\"")]
+    InstanceBusy {
+        instance_id: String,
+        operation: InstanceOperation,
+    },
+
     #[error("Instance \"{instance_id}\" already running with pid \"{process_id}\"")]
     InstanceAlreadyRunning {
         instance_id: String,
         process_id: Uuid,
     },
 
+    #[error("Account \"{account_id}\" pinned to instance \"{instance_id}\" is logged out")]
+    PinnedAccountNotFound {
+        instance_id: String,
+        account_id: Uuid,
+    },
+
     #[error("Prelaunch command error with code: {code}")]
     PrelaunchCommandError { code: i32 },
 
@@ -43,6 +57,12 @@ pub enum InstanceError {
     #[error("Failed to import instance with importer {importer_id}")]
     ImportFailed { importer_id: String },
 
+    #[error("Invalid modpack: {reason}")]
+    InvalidModpack { reason: String },
+
+    #[error("Files can only be downloaded from CurseForge: {files:?}")]
+    CurseForgeDownloadBlocked { files: Vec<String> },
+
     // Update errors
     #[error("Not found pack info in instance")]
     PackInfoNotFound,
@@ -56,6 +76,42 @@ pub enum InstanceError {
     #[error("Unmanaged instance")]
     UnmanagedInstance { instance_id: String },
 
+    // Groups
+    #[error("Group \"{group}\" not found")]
+    GroupNotFound { group: String },
+
+    // Snapshots
+    #[error("Snapshot \"{snapshot_id}\" of instance \"{instance_id}\" not found")]
+    SnapshotNotFound {
+        instance_id: String,
+        snapshot_id: String,
+    },
+
+    // Worlds
+    #[error("World \"{world_id}\" not found in instance \"{instance_id}\"")]
+    WorldNotFound {
+        instance_id: String,
+        world_id: String,
+    },
+
+    #[error("Invalid world: {reason}")]
+    InvalidWorld { reason: String },
+
+    // Servers
+    #[error("Server at position {index} not found in instance \"{instance_id}\"")]
+    ServerNotFound { instance_id: String, index: usize },
+
+    #[error("Failed to ping server \"{address}\": {reason}")]
+    ServerPingFailed { address: String, reason: String },
+
+    // Shared folders
+    #[error("Files in \"{folder}\" differ from the shared ones: {files:?}")]
+    SharedFolderConflict { folder: String, files: Vec<String> },
+
+    // Disk usage
+    #[error("Disk usage calculation was cancelled")]
+    DiskUsageCancelled,
+
     // Content
     #[error("Found duplicate content at {content_path}")]
     ContentDuplication { content_path: String },
@@ -69,6 +125,9 @@ pub enum InstanceError {
     #[error("Not found content for minecraft version \"{game_version}\"")]
     ContentForGameVersionNotFound { game_version: String },
 
+    #[error("Content \"{content_path}\" has no provider version to download it from")]
+    ContentNotRepairable { content_path: String },
+
     // Features errors
     #[error("Settings load error")]
     #[serialize_error]
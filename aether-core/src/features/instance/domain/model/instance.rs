@@ -1,12 +1,13 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::features::{
     minecraft::{LoaderVersionPreference, ModLoader},
-    settings::{Hooks, MemorySettings, WindowSize},
+    settings::{GameOptions, Hooks, MemorySettings, WindowSize},
 };
 
-use super::{InstanceInstallStage, PackInfo};
+use super::{InstanceInstallStage, PackInfo, SharedFolder, SnapshotPolicy};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -16,6 +17,14 @@ pub struct Instance {
     pub name: String,
     pub icon_path: Option<String>,
 
+    // Organization
+    #[serde(default)]
+    pub group: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub favorite: bool,
+
     pub install_stage: InstanceInstallStage,
 
     // Main minecraft metadata
@@ -32,6 +41,13 @@ pub struct Instance {
     pub memory: Option<MemorySettings>,
     pub force_fullscreen: Option<bool>,
     pub game_resolution: Option<WindowSize>,
+    /// Overrides merged over the default game options
+    #[serde(default)]
+    pub game_options: Option<GameOptions>,
+
+    // Account used to launch instead of the active one
+    #[serde(default)]
+    pub account_id: Option<Uuid>,
 
     // Additional information
     pub created: DateTime<Utc>,
@@ -44,4 +60,11 @@ pub struct Instance {
     pub hooks: Hooks,
 
     pub pack_info: Option<PackInfo>,
+
+    #[serde(default)]
+    pub snapshot_policy: Option<SnapshotPolicy>,
+
+    /// Folders linked to the shared folders instead of kept per instance
+    #[serde(default)]
+    pub shared_folders: Vec<SharedFolder>,
 }
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::minecraft::GameFileKind;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IntegrityFile {
+    #[serde(rename_all = "camelCase")]
+    Game { kind: GameFileKind, name: String },
+    /// Pack file of the instance
+    #[serde(rename_all = "camelCase")]
+    Content { content_path: String },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceIntegrityReport {
+    pub missing: Vec<IntegrityFile>,
+    pub corrupted: Vec<IntegrityFile>,
+    /// Files in content folders the pack doesn't know about
+    pub untracked: Vec<String>,
+    /// Broken files downloaded again, empty unless repair was requested
+    pub repaired: Vec<IntegrityFile>,
+}
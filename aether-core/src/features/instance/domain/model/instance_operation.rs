@@ -0,0 +1,92 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Operations that change instance files or launch it, only one runs per instance at a time
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InstanceOperation {
+    Install,
+    Update,
+    Launch,
+    Migrate,
+    Remove,
+    InstallContent,
+    ImportContent,
+    RemoveContent,
+    ChangeContentState,
+    UpdateContent,
+    RestoreSnapshot,
+    Verify,
+    Repair,
+    CollectGarbage,
+    ChangeSharedFolder,
+    Duplicate,
+    Edit,
+    CreateSnapshot,
+    ApplySnapshotRetention,
+    ChangeWorlds,
+    ChangeServers,
+    ImportPack,
+    /// Game process of the instance, set from launch until the process exits
+    Running,
+}
+
+impl InstanceOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InstanceOperation::Install => "install",
+            InstanceOperation::Update => "update",
+            InstanceOperation::Launch => "launch",
+            InstanceOperation::Migrate => "migrate",
+            InstanceOperation::Remove => "remove",
+            InstanceOperation::InstallContent => "install_content",
+            InstanceOperation::ImportContent => "import_content",
+            InstanceOperation::RemoveContent => "remove_content",
+            InstanceOperation::ChangeContentState => "change_content_state",
+            InstanceOperation::UpdateContent => "update_content",
+            InstanceOperation::RestoreSnapshot => "restore_snapshot",
+            InstanceOperation::Verify => "verify",
+            InstanceOperation::Repair => "repair",
+            InstanceOperation::CollectGarbage => "collect_garbage",
+            InstanceOperation::ChangeSharedFolder => "change_shared_folder",
+            InstanceOperation::Duplicate => "duplicate",
+            InstanceOperation::Edit => "edit",
+            InstanceOperation::CreateSnapshot => "create_snapshot",
+            InstanceOperation::ApplySnapshotRetention => "apply_snapshot_retention",
+            InstanceOperation::ChangeWorlds => "change_worlds",
+            InstanceOperation::ChangeServers => "change_servers",
+            InstanceOperation::ImportPack => "import_pack",
+            InstanceOperation::Running => "running",
+        }
+    }
+
+    /// Operations replacing game files, which the running game may hold open or overwrite.
+    /// `Edit` only changes launcher settings of the instance and `CreateSnapshot` only reads
+    /// files, so both are allowed while the game runs
+    pub fn is_blocked_while_running(&self) -> bool {
+        matches!(
+            self,
+            InstanceOperation::Install
+                | InstanceOperation::Update
+                | InstanceOperation::Migrate
+                | InstanceOperation::Remove
+                | InstanceOperation::InstallContent
+                | InstanceOperation::ImportContent
+                | InstanceOperation::RemoveContent
+                | InstanceOperation::ChangeContentState
+                | InstanceOperation::UpdateContent
+                | InstanceOperation::RestoreSnapshot
+                | InstanceOperation::Repair
+                | InstanceOperation::ChangeSharedFolder
+                | InstanceOperation::ChangeWorlds
+                | InstanceOperation::ChangeServers
+        )
+    }
+}
+
+impl fmt::Display for InstanceOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,133 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::features::minecraft::ModLoader;
+
+use super::Instance;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum InstanceSortKey {
+    #[default]
+    LastPlayed,
+    TimePlayed,
+    Name,
+    Created,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    Ascending,
+    #[default]
+    Descending,
+}
+
+/// Filters are combined, unset ones match every instance
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceQuery {
+    pub loader: Option<ModLoader>,
+    pub game_version: Option<String>,
+    /// Whole tag, compared case insensitively like the search
+    pub tag: Option<String>,
+    pub group: Option<String>,
+    /// Only instances whose pack is managed by this plugin
+    pub plugin_id: Option<String>,
+    pub favorite: Option<bool>,
+    /// Case insensitive search in name, group and tags
+    pub search: Option<String>,
+    #[serde(default)]
+    pub sort_by: InstanceSortKey,
+    #[serde(default)]
+    pub sort_order: SortOrder,
+    /// Put favorites before the rest regardless of the sort key
+    #[serde(default)]
+    pub favorites_first: bool,
+}
+
+impl InstanceQuery {
+    pub fn matches(&self, instance: &Instance) -> bool {
+        self.loader.is_none_or(|loader| instance.loader == loader)
+            && self
+                .game_version
+                .as_ref()
+                .is_none_or(|game_version| &instance.game_version == game_version)
+            && self.tag.as_ref().is_none_or(|tag| {
+                let tag = tag.trim().to_lowercase();
+                instance
+                    .tags
+                    .iter()
+                    .any(|instance_tag| instance_tag.to_lowercase() == tag)
+            })
+            && self
+                .group
+                .as_ref()
+                .is_none_or(|group| instance.group.as_ref() == Some(group))
+            && self.plugin_id.as_ref().is_none_or(|plugin_id| {
+                instance
+                    .pack_info
+                    .as_ref()
+                    .is_some_and(|pack_info| &pack_info.plugin_id == plugin_id)
+            })
+            && self
+                .favorite
+                .is_none_or(|favorite| instance.favorite == favorite)
+            && self
+                .search
+                .as_ref()
+                .is_none_or(|search| matches_search(instance, search))
+    }
+
+    /// Filters and sorts instances, ties keep the name order
+    pub fn apply(&self, instances: Vec<Instance>) -> Vec<Instance> {
+        let mut instances: Vec<_> = instances
+            .into_iter()
+            .filter(|instance| self.matches(instance))
+            .collect();
+
+        instances.sort_by(|a, b| {
+            let favorite = if self.favorites_first {
+                b.favorite.cmp(&a.favorite)
+            } else {
+                Ordering::Equal
+            };
+
+            let ordering = match self.sort_by {
+                InstanceSortKey::LastPlayed => a.last_played.cmp(&b.last_played),
+                InstanceSortKey::TimePlayed => a.time_played.cmp(&b.time_played),
+                InstanceSortKey::Name => compare_names(a, b),
+                InstanceSortKey::Created => a.created.cmp(&b.created),
+            };
+
+            let ordering = match self.sort_order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            };
+
+            favorite.then(ordering).then_with(|| compare_names(a, b))
+        });
+
+        instances
+    }
+}
+
+fn matches_search(instance: &Instance, search: &str) -> bool {
+    let search = search.trim().to_lowercase();
+
+    search.is_empty()
+        || instance.name.to_lowercase().contains(&search)
+        || instance
+            .group
+            .as_ref()
+            .is_some_and(|group| group.to_lowercase().contains(&search))
+        || instance
+            .tags
+            .iter()
+            .any(|tag| tag.to_lowercase().contains(&search))
+}
+
+fn compare_names(a: &Instance, b: &Instance) -> Ordering {
+    a.name.to_lowercase().cmp(&b.name.to_lowercase())
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GarbageKind {
+    Version,
+    Natives,
+    Library,
+    AssetIndex,
+    AssetObject,
+    LegacyAssets,
+    Java,
+}
+
+/// File or folder in the metadata dir no instance references
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GarbageEntry {
+    pub kind: GarbageKind,
+    pub path: String,
+    /// In bytes
+    pub size: u64,
+}
+
+/// Entry that stayed on disk, the other entries are still removed
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GarbageRemoveFailure {
+    pub path: String,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GarbageReport {
+    pub entries: Vec<GarbageEntry>,
+    /// Sum of entry sizes in bytes
+    pub size: u64,
+    /// Bytes freed, `None` for a dry run
+    pub reclaimed: Option<u64>,
+    pub failed: Vec<GarbageRemoveFailure>,
+}
@@ -1,17 +1,47 @@
 mod content;
+mod content_compatibility;
 mod content_file;
 mod content_type;
+mod content_update;
+mod curseforge;
+mod disk_usage;
 mod error;
 mod install_stage;
 mod instance;
+mod instance_integrity;
+mod instance_operation;
+mod instance_query;
+mod metadata_garbage;
+mod mrpack;
 mod pack;
 mod pack_info;
+mod packwiz;
+mod play_session;
+mod server;
+mod shared_folder;
+mod snapshot;
+mod world;
 
 pub use content::*;
+pub use content_compatibility::*;
 pub use content_file::*;
 pub use content_type::*;
+pub use content_update::*;
+pub use curseforge::*;
+pub use disk_usage::*;
 pub use error::*;
 pub use install_stage::*;
 pub use instance::*;
+pub use instance_integrity::*;
+pub use instance_operation::*;
+pub use instance_query::*;
+pub use metadata_garbage::*;
+pub use mrpack::*;
 pub use pack::*;
 pub use pack_info::*;
+pub use packwiz::*;
+pub use play_session::*;
+pub use server::*;
+pub use shared_folder::*;
+pub use snapshot::*;
+pub use world::*;
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::features::minecraft::ModLoader;
+
+pub const MRPACK_INDEX_FILE: &str = "modrinth.index.json";
+pub const MRPACK_OVERRIDES_DIR: &str = "overrides";
+pub const MRPACK_CLIENT_OVERRIDES_DIR: &str = "client-overrides";
+pub const MRPACK_FORMAT_VERSION: u32 = 1;
+pub const MRPACK_MINECRAFT_DEPENDENCY: &str = "minecraft";
+
+/// `modrinth.index.json` of a Modrinth modpack
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MrpackIndex {
+    pub format_version: u32,
+    pub game: String,
+    pub version_id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    pub files: Vec<MrpackFile>,
+    pub dependencies: HashMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MrpackFile {
+    pub path: String,
+    pub hashes: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<MrpackEnv>,
+    pub downloads: Vec<String>,
+    pub file_size: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct MrpackEnv {
+    pub client: MrpackEnvSupport,
+    pub server: MrpackEnvSupport,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MrpackEnvSupport {
+    Required,
+    Optional,
+    Unsupported,
+}
+
+impl MrpackIndex {
+    pub fn loader_dependency_id(loader: ModLoader) -> Option<&'static str> {
+        match loader {
+            ModLoader::Vanilla => None,
+            ModLoader::Forge => Some("forge"),
+            ModLoader::NeoForge => Some("neoforge"),
+            ModLoader::Fabric => Some("fabric-loader"),
+            ModLoader::Quilt => Some("quilt-loader"),
+        }
+    }
+
+    pub fn loader_from_dependency_id(dependency_id: &str) -> Option<ModLoader> {
+        match dependency_id {
+            "forge" => Some(ModLoader::Forge),
+            "neoforge" => Some(ModLoader::NeoForge),
+            "fabric-loader" => Some(ModLoader::Fabric),
+            "quilt-loader" => Some(ModLoader::Quilt),
+            _ => None,
+        }
+    }
+
+    pub fn game_version(&self) -> Option<&String> {
+        self.dependencies.get(MRPACK_MINECRAFT_DEPENDENCY)
+    }
+
+    /// Mod loader with its version, vanilla if the pack doesn't depend on any loader
+    pub fn loader(&self) -> (ModLoader, Option<&String>) {
+        self.dependencies
+            .iter()
+            .find_map(|(id, version)| {
+                Self::loader_from_dependency_id(id).map(|loader| (loader, Some(version)))
+            })
+            .unwrap_or((ModLoader::Vanilla, None))
+    }
+}
+
+impl MrpackFile {
+    pub fn is_client_supported(&self) -> bool {
+        !matches!(
+            self.env,
+            Some(MrpackEnv {
+                client: MrpackEnvSupport::Unsupported,
+                ..
+            })
+        )
+    }
+}
@@ -0,0 +1,272 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::features::minecraft::ModLoader;
+
+use super::{
+    CurseForgeUpdateData, PackFile, PackFileDownload, PackFileOption, CURSEFORGE_UPDATE_KEY,
+};
+
+pub const PACKWIZ_PACK_FILE: &str = "pack.toml";
+pub const PACKWIZ_INDEX_FILE: &str = "index.toml";
+pub const PACKWIZ_PACK_FORMAT: &str = "packwiz:1.1.0";
+pub const PACKWIZ_METAFILE_EXTENSION: &str = "pw.toml";
+pub const PACKWIZ_MODRINTH_UPDATE_KEY: &str = "modrinth";
+
+const PACKWIZ_SIDE_BOTH: &str = "both";
+
+/// `pack.toml` at the root of a packwiz repository
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackwizPack {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pack_format: Option<String>,
+    pub index: PackwizIndexRef,
+    /// `minecraft` and loader versions, e.g. `fabric = "0.15.7"`
+    pub versions: BTreeMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackwizIndexRef {
+    pub file: String,
+    pub hash_format: PackwizHashFormat,
+    pub hash: String,
+}
+
+/// `index.toml` listing every file of the pack
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackwizIndex {
+    pub hash_format: PackwizHashFormat,
+    #[serde(default)]
+    pub files: Vec<PackwizIndexFile>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackwizIndexFile {
+    /// Path relative to the index file
+    pub file: String,
+    pub hash: String,
+    /// Overrides the index hash format for this file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash_format: Option<PackwizHashFormat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub metafile: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub preserve: bool,
+}
+
+/// `.pw.toml` describing a file that is downloaded instead of stored in the pack
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackwizMetafile {
+    pub name: String,
+    pub filename: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<String>,
+    pub download: PackwizDownload,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub option: Option<PackwizOption>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update: Option<PackwizUpdate>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackwizDownload {
+    /// Missing for `metadata:curseforge` downloads
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub hash_format: PackwizHashFormat,
+    pub hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackwizOption {
+    pub optional: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PackwizUpdate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modrinth: Option<PackwizModrinthUpdate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub curseforge: Option<PackwizCurseForgeUpdate>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackwizModrinthUpdate {
+    pub mod_id: String,
+    pub version: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct PackwizCurseForgeUpdate {
+    pub file_id: u64,
+    pub project_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PackwizHashFormat {
+    Sha1,
+    Sha256,
+    Sha512,
+    Md5,
+    Murmur2,
+}
+
+// Same shape as the update data stored by the Modrinth content provider
+#[derive(Serialize, Deserialize)]
+struct ModrinthUpdateValue {
+    project_id: String,
+    version: String,
+}
+
+impl PackwizPack {
+    pub fn game_version(&self) -> Option<&String> {
+        self.versions.get("minecraft")
+    }
+
+    pub fn loader(&self) -> (ModLoader, Option<&String>) {
+        [
+            ModLoader::Forge,
+            ModLoader::NeoForge,
+            ModLoader::Fabric,
+            ModLoader::Quilt,
+        ]
+        .into_iter()
+        .find_map(|loader| {
+            self.versions
+                .get(loader.as_str())
+                .map(|version| (loader, Some(version)))
+        })
+        .unwrap_or((ModLoader::Vanilla, None))
+    }
+}
+
+impl PackwizMetafile {
+    /// Metafile for a pack file, `None` if the file can't be downloaded
+    pub fn from_pack_file(pack_file: &PackFile) -> Option<Self> {
+        let download = pack_file.download.as_ref()?;
+
+        let update = pack_file.update.as_ref().map(|update| PackwizUpdate {
+            modrinth: update
+                .get(PACKWIZ_MODRINTH_UPDATE_KEY)
+                .and_then(|value| serde_json::from_value(value.clone()).ok())
+                .map(|data: ModrinthUpdateValue| PackwizModrinthUpdate {
+                    mod_id: data.project_id,
+                    version: data.version,
+                }),
+            curseforge: CurseForgeUpdateData::from_pack_file(pack_file).map(|data| {
+                PackwizCurseForgeUpdate {
+                    file_id: data.file_id,
+                    project_id: data.project_id,
+                }
+            }),
+        });
+
+        Some(Self {
+            name: pack_file
+                .name
+                .clone()
+                .unwrap_or_else(|| pack_file.file_name.clone()),
+            filename: pack_file.file_name.clone(),
+            side: Some(
+                pack_file
+                    .side
+                    .clone()
+                    .unwrap_or_else(|| PACKWIZ_SIDE_BOTH.to_owned()),
+            ),
+            download: PackwizDownload {
+                url: Some(download.url.clone()),
+                hash_format: PackwizHashFormat::Sha1,
+                hash: download.hash.clone(),
+                mode: None,
+            },
+            option: pack_file.option.as_ref().map(|option| PackwizOption {
+                optional: option.optional,
+                default: option.default,
+                description: option.description.clone(),
+            }),
+            update: update
+                .filter(|update| update.modrinth.is_some() || update.curseforge.is_some()),
+        })
+    }
+
+    /// Pack file for a downloaded metafile, `sha1` is the hash of the downloaded file
+    pub fn to_pack_file(&self, url: String, sha1: String) -> PackFile {
+        let mut update = HashMap::new();
+
+        if let Some(modrinth) = self
+            .update
+            .as_ref()
+            .and_then(|update| update.modrinth.as_ref())
+        {
+            if let Ok(value) = serde_json::to_value(ModrinthUpdateValue {
+                project_id: modrinth.mod_id.clone(),
+                version: modrinth.version.clone(),
+            }) {
+                update.insert(PACKWIZ_MODRINTH_UPDATE_KEY.to_owned(), value);
+            }
+        }
+
+        if let Some(curseforge) = self
+            .update
+            .as_ref()
+            .and_then(|update| update.curseforge.as_ref())
+        {
+            if let Ok(value) = serde_json::to_value(CurseForgeUpdateData {
+                project_id: curseforge.project_id,
+                file_id: curseforge.file_id,
+            }) {
+                update.insert(CURSEFORGE_UPDATE_KEY.to_owned(), value);
+            }
+        }
+
+        let update_provider = [PACKWIZ_MODRINTH_UPDATE_KEY, CURSEFORGE_UPDATE_KEY]
+            .into_iter()
+            .find(|key| update.contains_key(*key))
+            .map(str::to_owned);
+
+        PackFile {
+            file_name: self.filename.clone(),
+            name: Some(self.name.clone()),
+            hash: sha1.clone(),
+            download: Some(PackFileDownload { hash: sha1, url }),
+            option: self.option.as_ref().map(|option| PackFileOption {
+                optional: option.optional,
+                default: option.default,
+                description: option.description.clone(),
+            }),
+            side: self.side.clone().filter(|side| side != PACKWIZ_SIDE_BOTH),
+            update_provider,
+            update: (!update.is_empty()).then_some(update),
+        }
+    }
+
+    pub fn is_client_supported(&self) -> bool {
+        self.side.as_deref() != Some("server")
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Days counted into `Instance::recent_time_played`
+pub const RECENT_PLAYTIME_WINDOW_DAYS: i64 = 14;
+
+/// A single run of the game
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaySession {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// In seconds
+    pub duration: u64,
+    /// Missing if the process was lost before it exited
+    pub exit_code: Option<i32>,
+    pub account_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaytimePeriod {
+    Day,
+    /// ISO week starting on Monday
+    Week,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaytimeStats {
+    /// First day of the period in the requested time zone
+    pub period_start: NaiveDate,
+    /// In seconds, sessions crossing a period boundary are split between periods
+    pub duration: u64,
+    /// Sessions started in the period
+    pub sessions: u32,
+}
+
+impl PlaytimePeriod {
+    fn get_start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            PlaytimePeriod::Day => date,
+            PlaytimePeriod::Week => {
+                date - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+        }
+    }
+
+    fn get_length(&self) -> Duration {
+        match self {
+            PlaytimePeriod::Day => Duration::days(1),
+            PlaytimePeriod::Week => Duration::weeks(1),
+        }
+    }
+}
+
+/// Seconds played between `now - RECENT_PLAYTIME_WINDOW_DAYS` and `now`
+pub fn get_recent_playtime(sessions: &[PlaySession], now: DateTime<Utc>) -> u64 {
+    let window_start = now - Duration::days(RECENT_PLAYTIME_WINDOW_DAYS);
+
+    sessions
+        .iter()
+        .map(|session| {
+            let start = session.start.max(window_start);
+            let end = session.end.min(now);
+
+            (end - start).num_seconds().max(0) as u64
+        })
+        .sum()
+}
+
+/// Playtime per period in the given time zone, sorted by period
+pub fn aggregate_playtime(
+    sessions: &[PlaySession],
+    period: PlaytimePeriod,
+    offset: FixedOffset,
+) -> Vec<PlaytimeStats> {
+    let mut stats: BTreeMap<NaiveDate, PlaytimeStats> = BTreeMap::new();
+
+    let get_period_start =
+        |time: DateTime<Utc>| period.get_start(time.with_timezone(&offset).date_naive());
+
+    for session in sessions {
+        let mut cursor = session.start;
+
+        get_stats(&mut stats, get_period_start(cursor)).sessions += 1;
+
+        while cursor < session.end {
+            let period_start = get_period_start(cursor);
+            let next_period = to_utc(period_start, offset) + period.get_length();
+            let chunk_end = next_period.min(session.end);
+
+            get_stats(&mut stats, period_start).duration +=
+                (chunk_end - cursor).num_seconds().max(0) as u64;
+
+            cursor = chunk_end;
+        }
+    }
+
+    stats.into_values().collect()
+}
+
+fn get_stats(
+    stats: &mut BTreeMap<NaiveDate, PlaytimeStats>,
+    period_start: NaiveDate,
+) -> &mut PlaytimeStats {
+    stats.entry(period_start).or_insert_with(|| PlaytimeStats {
+        period_start,
+        duration: 0,
+        sessions: 0,
+    })
+}
+
+/// Midnight of the date in the time zone
+fn to_utc(date: NaiveDate, offset: FixedOffset) -> DateTime<Utc> {
+    (date.and_time(NaiveTime::MIN) - Duration::seconds(offset.local_minus_utc() as i64)).and_utc()
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourcePackPolicy {
+    /// Ask the player when joining
+    #[default]
+    Prompt,
+    Enabled,
+    Disabled,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerEntry {
+    pub name: String,
+    pub address: String,
+    /// Base64 encoded PNG, written by the game after the first ping
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub resource_pack_policy: ResourcePackPolicy,
+    /// Position in `servers.dat` when the list was read, fields the launcher doesn't edit
+    /// are kept by it. New servers have none
+    #[serde(skip)]
+    pub stored_index: Option<usize>,
+}
+
+/// Response of the Server List Ping
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerStatus {
+    /// Plain text of the description, legacy `§` formatting codes are kept
+    pub motd: String,
+    pub version_name: String,
+    pub protocol: i32,
+    pub players_online: i64,
+    pub players_max: i64,
+    /// Names of some online players, if the server shares them
+    pub players_sample: Vec<String>,
+    /// Base64 encoded PNG without the data URL prefix
+    pub favicon: Option<String>,
+    /// Round trip time in milliseconds
+    pub latency: u64,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use super::ContentType;
+
+/// Instance folder that can be linked to a folder shared between instances
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SharedFolder {
+    ResourcePacks,
+    ShaderPacks,
+    Screenshots,
+    Saves,
+}
+
+impl SharedFolder {
+    pub fn get_folder(&self) -> &'static str {
+        match self {
+            SharedFolder::ResourcePacks => ContentType::ResourcePack.get_folder(),
+            SharedFolder::ShaderPacks => ContentType::ShaderPack.get_folder(),
+            SharedFolder::Screenshots => "screenshots",
+            SharedFolder::Saves => "saves",
+        }
+    }
+
+    /// Content type tracked in the pack metadata, if the folder holds content
+    pub fn get_content_type(&self) -> Option<ContentType> {
+        match self {
+            SharedFolder::ResourcePacks => Some(ContentType::ResourcePack),
+            SharedFolder::ShaderPacks => Some(ContentType::ShaderPack),
+            SharedFolder::Screenshots | SharedFolder::Saves => None,
+        }
+    }
+
+    pub fn from_folder(folder: &str) -> Option<Self> {
+        Self::iterator().find(|shared_folder| shared_folder.get_folder() == folder)
+    }
+
+    pub fn iterator() -> impl Iterator<Item = SharedFolder> {
+        [
+            SharedFolder::ResourcePacks,
+            SharedFolder::ShaderPacks,
+            SharedFolder::Screenshots,
+            SharedFolder::Saves,
+        ]
+        .iter()
+        .copied()
+    }
+}
@@ -0,0 +1,78 @@
+use std::{cmp::Reverse, collections::HashSet};
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Instance folders stored in a snapshot besides the content folders
+pub const SNAPSHOT_FOLDERS: [&str; 3] = ["saves", "config", ".metadata"];
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotReason {
+    Manual,
+    BeforeUpdate,
+    BeforeContentUpdate,
+    BeforeMigration,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceSnapshot {
+    pub id: String,
+    pub instance_id: String,
+    pub created: DateTime<Utc>,
+    pub reason: SnapshotReason,
+    /// Archive size in bytes
+    pub size: u64,
+}
+
+impl InstanceSnapshot {
+    /// Ids sort in creation order
+    pub fn get_id(created: &DateTime<Utc>) -> String {
+        created.format("%Y%m%d-%H%M%S-%3f").to_string()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SnapshotRetention {
+    /// Keeps the `count` newest snapshots
+    KeepLast { count: usize },
+    /// Keeps the newest snapshot of each day for the last `days` days
+    KeepDaily { days: u32 },
+}
+
+impl SnapshotRetention {
+    /// Returns ids of snapshots the policy doesn't keep, the newest snapshot is always kept
+    pub fn get_expired(&self, snapshots: &[InstanceSnapshot], now: DateTime<Utc>) -> Vec<String> {
+        let mut snapshots: Vec<&InstanceSnapshot> = snapshots.iter().collect();
+        snapshots.sort_by_key(|snapshot| Reverse(snapshot.created));
+
+        let mut kept_days: HashSet<NaiveDate> = HashSet::new();
+
+        snapshots
+            .into_iter()
+            .enumerate()
+            .filter(|(i, snapshot)| {
+                let expired = match self {
+                    SnapshotRetention::KeepLast { count } => i >= count,
+                    SnapshotRetention::KeepDaily { days } => {
+                        snapshot.created < now - Duration::days(i64::from(*days))
+                            || !kept_days.insert(snapshot.created.date_naive())
+                    }
+                };
+
+                expired && *i > 0
+            })
+            .map(|(_, snapshot)| snapshot.id.clone())
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotPolicy {
+    /// Take a snapshot before updates, content updates and migrations
+    pub auto_snapshot: bool,
+    pub retention: Option<SnapshotRetention>,
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorldGameMode {
+    Survival,
+    Creative,
+    Adventure,
+    Spectator,
+    Unknown,
+}
+
+impl WorldGameMode {
+    pub fn from_game_type(game_type: i32) -> Self {
+        match game_type {
+            0 => WorldGameMode::Survival,
+            1 => WorldGameMode::Creative,
+            2 => WorldGameMode::Adventure,
+            3 => WorldGameMode::Spectator,
+            _ => WorldGameMode::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldDataPack {
+    /// File or folder name inside `datapacks/`
+    pub file_name: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct World {
+    /// Folder name inside `saves/`
+    pub id: String,
+    pub name: String,
+    pub game_mode: WorldGameMode,
+    pub hardcore: bool,
+    pub last_played: Option<DateTime<Utc>>,
+    pub game_version: Option<String>,
+    pub seed: Option<i64>,
+    /// Base64 encoded `icon.png`
+    pub icon: Option<String>,
+    pub data_packs: Vec<WorldDataPack>,
+}
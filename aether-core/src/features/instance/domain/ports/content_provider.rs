@@ -1,7 +1,8 @@
 use async_trait::async_trait;
 
 use crate::features::instance::{
-    ContentFile, ContentInstallParams, ContentSearchParams, ContentSearchResult, InstanceError,
+    ContentFile, ContentInstallParams, ContentSearchParams, ContentSearchResult, ContentVersion,
+    InstanceError,
 };
 
 #[async_trait]
@@ -20,4 +21,19 @@ pub trait ContentProvider: Send + Sync {
     ) -> Result<ContentFile, InstanceError>;
 
     fn get_update_data_id_field(&self) -> String;
+
+    /// Returns the installed version id stored in update data
+    fn get_update_data_version(&self, update_data: &serde_json::Value) -> Option<String>;
+
+    /// Versions of the content behind `update_data` for the game version and loader,
+    /// best match first
+    async fn list_compatible_versions(
+        &self,
+        update_data: &serde_json::Value,
+        game_version: &str,
+        loader: &Option<String>,
+    ) -> Result<Vec<ContentVersion>, InstanceError>;
+
+    /// Returns update data for a file downloaded from this provider, if the url belongs to it
+    fn get_update_data_by_download_url(&self, url: &str) -> Option<serde_json::Value>;
 }
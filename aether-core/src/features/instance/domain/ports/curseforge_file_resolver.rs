@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::features::instance::{CurseForgeFile, InstanceError};
+
+/// Resolves files referenced by `projectID`/`fileID` in CurseForge manifests
+#[async_trait]
+pub trait CurseForgeFileResolver: Send + Sync {
+    async fn resolve(&self, project_id: u64, file_id: u64)
+        -> Result<CurseForgeFile, InstanceError>;
+}
@@ -1,9 +1,21 @@
 mod content_provider;
+mod curseforge_file_resolver;
 mod instance_storage;
 mod instance_watcher_service;
 mod pack_storage;
+mod play_session_storage;
+mod server_list_storage;
+mod server_pinger;
+mod snapshot_storage;
+mod world_storage;
 
 pub use content_provider::*;
+pub use curseforge_file_resolver::*;
 pub use instance_storage::*;
 pub use instance_watcher_service::*;
 pub use pack_storage::*;
+pub use play_session_storage::*;
+pub use server_list_storage::*;
+pub use server_pinger::*;
+pub use snapshot_storage::*;
+pub use world_storage::*;
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::features::instance::{InstanceError, PlaySession};
+
+#[async_trait]
+pub trait PlaySessionStorage: Send + Sync {
+    /// Returns sessions of an instance, oldest first
+    async fn list(&self, instance_id: &str) -> Result<Vec<PlaySession>, InstanceError>;
+
+    async fn add(&self, instance_id: &str, session: PlaySession) -> Result<(), InstanceError>;
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::features::instance::{InstanceError, ServerEntry};
+
+#[async_trait]
+pub trait ServerListStorage: Send + Sync {
+    /// Returns servers in the order shown by the game
+    async fn get(&self, instance_id: &str) -> Result<Vec<ServerEntry>, InstanceError>;
+
+    /// Replaces the server list, data the launcher doesn't know about is kept
+    async fn set(&self, instance_id: &str, servers: &[ServerEntry]) -> Result<(), InstanceError>;
+}
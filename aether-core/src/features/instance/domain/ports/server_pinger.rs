@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::features::instance::{InstanceError, ServerStatus};
+
+#[async_trait]
+pub trait ServerPinger: Send + Sync {
+    /// Queries the status of the server at `address` in the `host[:port]` form
+    async fn ping(&self, address: &str) -> Result<ServerStatus, InstanceError>;
+}
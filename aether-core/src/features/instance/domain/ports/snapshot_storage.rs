@@ -0,0 +1,41 @@
+use std::{collections::BTreeSet, path::PathBuf};
+
+use async_trait::async_trait;
+
+use crate::features::instance::{InstanceError, InstanceSnapshot, SnapshotReason};
+
+/// Called from the archiving thread with the processed fraction since the last call
+pub type SnapshotProgressFn = Box<dyn Fn(f64) + Send + Sync>;
+
+#[async_trait]
+pub trait SnapshotStorage: Send + Sync {
+    /// Lists snapshots of an instance, newest first
+    async fn list(&self, instance_id: &str) -> Result<Vec<InstanceSnapshot>, InstanceError>;
+
+    async fn get(
+        &self,
+        instance_id: &str,
+        snapshot_id: &str,
+    ) -> Result<InstanceSnapshot, InstanceError>;
+
+    /// Archives `files` relative to `source_dir`
+    async fn create(
+        &self,
+        instance_id: &str,
+        reason: SnapshotReason,
+        source_dir: PathBuf,
+        files: BTreeSet<String>,
+        on_progress: SnapshotProgressFn,
+    ) -> Result<InstanceSnapshot, InstanceError>;
+
+    /// Extracts the snapshot into `target_dir`
+    async fn extract(
+        &self,
+        instance_id: &str,
+        snapshot_id: &str,
+        target_dir: PathBuf,
+        on_progress: SnapshotProgressFn,
+    ) -> Result<(), InstanceError>;
+
+    async fn remove(&self, instance_id: &str, snapshot_id: &str) -> Result<(), InstanceError>;
+}
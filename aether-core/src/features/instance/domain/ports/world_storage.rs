@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::features::instance::{InstanceError, World};
+
+#[async_trait]
+pub trait WorldStorage: Send + Sync {
+    /// Lists worlds in `saves/`, most recently played first
+    async fn list(&self, instance_id: &str) -> Result<Vec<World>, InstanceError>;
+
+    async fn get(&self, instance_id: &str, world_id: &str) -> Result<World, InstanceError>;
+
+    async fn remove(&self, instance_id: &str, world_id: &str) -> Result<(), InstanceError>;
+}
@@ -27,6 +27,17 @@ impl<CP: ContentProvider> ContentProviderRegistry<CP> {
             .collect()
     }
 
+    pub fn find_update_data_by_download_url(
+        &self,
+        url: &str,
+    ) -> Option<(String, serde_json::Value)> {
+        self.providers.iter().find_map(|(id, provider)| {
+            provider
+                .get_update_data_by_download_url(url)
+                .map(|update_data| (id.to_string(), update_data))
+        })
+    }
+
     pub fn register(&mut self, id: String, provider: CP) {
         self.providers.insert(id, provider);
     }
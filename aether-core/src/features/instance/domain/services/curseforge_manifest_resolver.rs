@@ -0,0 +1,100 @@
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+use futures::{StreamExt, TryStreamExt};
+use path_slash::PathBufExt;
+
+use crate::features::instance::{
+    CurseForgeFileResolver, CurseForgeManifest, CurseForgeUpdateData, InstanceError, PackFile,
+    PackFileDownload, PackFileOption, CURSEFORGE_UPDATE_KEY,
+};
+
+const CONCURRENT_REQUESTS: usize = 8;
+
+#[derive(Debug, Clone)]
+pub struct ResolvedPackFile {
+    pub content_path: String,
+    pub pack_file: PackFile,
+}
+
+/// Turns manifest entries into pack files with download info
+pub struct CurseForgeManifestResolver<R> {
+    file_resolver: Arc<R>,
+}
+
+impl<R: CurseForgeFileResolver> CurseForgeManifestResolver<R> {
+    pub fn new(file_resolver: Arc<R>) -> Self {
+        Self { file_resolver }
+    }
+
+    pub async fn resolve(
+        &self,
+        manifest: &CurseForgeManifest,
+    ) -> Result<Vec<ResolvedPackFile>, InstanceError> {
+        let files: Vec<(CurseForgeUpdateData, bool, _)> = futures::stream::iter(&manifest.files)
+            .map(|file| async move {
+                self.file_resolver
+                    .resolve(file.project_id, file.file_id)
+                    .await
+                    .map(|resolved| {
+                        (
+                            CurseForgeUpdateData {
+                                project_id: file.project_id,
+                                file_id: file.file_id,
+                            },
+                            file.required,
+                            resolved,
+                        )
+                    })
+            })
+            .buffered(CONCURRENT_REQUESTS)
+            .try_collect()
+            .await?;
+
+        let blocked_files: Vec<String> = files
+            .iter()
+            .filter(|(_, _, file)| file.download_url.is_none())
+            .map(|(_, _, file)| file.file_name.clone())
+            .collect();
+
+        if !blocked_files.is_empty() {
+            return Err(InstanceError::CurseForgeDownloadBlocked {
+                files: blocked_files,
+            });
+        }
+
+        files
+            .into_iter()
+            .map(|(update_data, required, file)| {
+                let content_path = PathBuf::from(file.content_type.get_folder())
+                    .join(&file.file_name)
+                    .to_slash_lossy()
+                    .to_string();
+
+                let update = serde_json::to_value(&update_data).map_err(|_| {
+                    InstanceError::ContentDownloadError("Failed to parse update data".to_owned())
+                })?;
+
+                Ok(ResolvedPackFile {
+                    content_path,
+                    pack_file: PackFile {
+                        file_name: file.file_name,
+                        name: file.name,
+                        hash: file.sha1.clone().unwrap_or_default(),
+                        download: file.download_url.map(|url| PackFileDownload {
+                            hash: file.sha1.unwrap_or_default(),
+                            url,
+                        }),
+                        option: (!required).then_some(PackFileOption {
+                            optional: true,
+                            default: Some(true),
+                            description: None,
+                        }),
+                        side: None,
+                        update_provider: Some(CURSEFORGE_UPDATE_KEY.to_owned()),
+                        update: Some(HashMap::from([(CURSEFORGE_UPDATE_KEY.to_owned(), update)])),
+                    },
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+};
+
+use crate::{
+    features::instance::InstanceError,
+    shared::{read_dir, IoError},
+};
+
+type RequestMap = Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>;
+
+/// Caches sizes of walked folders, entries are dropped by `invalidate`
+#[derive(Default)]
+pub struct DiskUsageService {
+    sizes: Mutex<HashMap<PathBuf, u64>>,
+    /// Cancellation flags of running calculations by request id
+    requests: RequestMap,
+    /// Sizes walked while something was invalidated aren't cached
+    invalidations: AtomicU64,
+}
+
+impl DiskUsageService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a calculation `cancel` can stop by its id, the id is released when the request is dropped
+    pub fn start_request(&self, request_id: &str) -> DiskUsageRequest {
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(request_id.to_owned(), cancelled.clone());
+
+        DiskUsageRequest {
+            requests: self.requests.clone(),
+            request_id: request_id.to_owned(),
+            cancelled,
+        }
+    }
+
+    /// Walks of the request stop at the next entry, other requests keep running
+    pub fn cancel(&self, request_id: &str) {
+        if let Some(cancelled) = self
+            .requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(request_id)
+        {
+            cancelled.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Drops cached sizes of `path`, of folders inside it and of folders containing it
+    pub fn invalidate(&self, path: &Path) {
+        self.invalidations.fetch_add(1, Ordering::SeqCst);
+        self.sizes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|cached, _| !cached.starts_with(path) && !path.starts_with(cached));
+    }
+
+    /// Size of a file or a folder, symlinks are counted as links and not followed
+    pub async fn get_size(
+        &self,
+        path: &Path,
+        request: &DiskUsageRequest,
+    ) -> Result<u64, InstanceError> {
+        request.check_cancelled()?;
+
+        if let Some(size) = self.get_cached(path) {
+            return Ok(size);
+        }
+
+        let invalidations = self.invalidations.load(Ordering::SeqCst);
+        let size = self.walk(path, request).await?;
+
+        if self.invalidations.load(Ordering::SeqCst) == invalidations {
+            self.sizes
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(path.to_path_buf(), size);
+        }
+
+        Ok(size)
+    }
+
+    /// Same as `get_size` for paths nothing invalidates, their size is always walked
+    pub async fn get_size_uncached(
+        &self,
+        path: &Path,
+        request: &DiskUsageRequest,
+    ) -> Result<u64, InstanceError> {
+        request.check_cancelled()?;
+
+        self.walk(path, request).await
+    }
+
+    fn get_cached(&self, path: &Path) -> Option<u64> {
+        self.sizes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(path)
+            .copied()
+    }
+
+    async fn walk(&self, path: &Path, request: &DiskUsageRequest) -> Result<u64, InstanceError> {
+        let metadata = match tokio::fs::symlink_metadata(path).await {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(IoError::with_path(err, path).into()),
+        };
+
+        if !metadata.is_dir() {
+            return Ok(metadata.len());
+        }
+
+        let mut size = 0;
+        let mut dirs = vec![path.to_path_buf()];
+
+        while let Some(dir) = dirs.pop() {
+            let mut entries = read_dir(&dir).await?;
+
+            while let Some(entry) = entries.next_entry().await.map_err(IoError::from)? {
+                request.check_cancelled()?;
+
+                // Entry may be removed while walking
+                let Ok(metadata) = entry.metadata().await else {
+                    continue;
+                };
+
+                if metadata.is_dir() {
+                    dirs.push(entry.path());
+                } else {
+                    size += metadata.len();
+                }
+            }
+        }
+
+        Ok(size)
+    }
+}
+
+/// Running calculation, dropping it forgets its id
+#[must_use]
+pub struct DiskUsageRequest {
+    requests: RequestMap,
+    request_id: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl DiskUsageRequest {
+    fn check_cancelled(&self) -> Result<(), InstanceError> {
+        if self.cancelled.load(Ordering::SeqCst) {
+            return Err(InstanceError::DiskUsageCancelled);
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for DiskUsageRequest {
+    fn drop(&mut self) {
+        let mut requests = self.requests.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // A newer request may have reused the id
+        if requests
+            .get(&self.request_id)
+            .is_some_and(|cancelled| Arc::ptr_eq(cancelled, &self.cancelled))
+        {
+            requests.remove(&self.request_id);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use crate::features::instance::{InstanceError, InstanceOperation};
+
+type LockMap = Arc<Mutex<HashMap<String, InstanceOperation>>>;
+type RunningSet = Arc<Mutex<HashSet<String>>>;
+
+/// In-memory per-instance locks shared by mutating use cases
+#[derive(Default)]
+pub struct InstanceLockService {
+    locks: LockMap,
+    running: RunningSet,
+}
+
+impl InstanceLockService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fails with `InstanceBusy` if another operation holds the instance,
+    /// or if the operation changes files of a running game
+    pub fn try_lock(
+        &self,
+        instance_id: &str,
+        operation: InstanceOperation,
+    ) -> Result<InstanceLockGuard, InstanceError> {
+        if operation.is_blocked_while_running() && self.is_running(instance_id) {
+            return Err(InstanceError::InstanceBusy {
+                instance_id: instance_id.to_owned(),
+                operation: InstanceOperation::Running,
+            });
+        }
+
+        let mut locks = self.locks.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(current) = locks.get(instance_id) {
+            return Err(InstanceError::InstanceBusy {
+                instance_id: instance_id.to_owned(),
+                operation: *current,
+            });
+        }
+
+        locks.insert(instance_id.to_owned(), operation);
+
+        Ok(InstanceLockGuard {
+            locks: self.locks.clone(),
+            instance_id: instance_id.to_owned(),
+        })
+    }
+
+    /// Keeps the instance marked as running until the guard is dropped
+    pub fn mark_running(&self, instance_id: &str) -> InstanceRunningGuard {
+        self.running
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(instance_id.to_owned());
+
+        InstanceRunningGuard {
+            running: self.running.clone(),
+            instance_id: instance_id.to_owned(),
+        }
+    }
+
+    pub fn is_running(&self, instance_id: &str) -> bool {
+        self.running
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(instance_id)
+    }
+
+    pub fn get_operation(&self, instance_id: &str) -> Option<InstanceOperation> {
+        let operation = self
+            .locks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(instance_id)
+            .copied();
+
+        operation.or_else(|| {
+            self.is_running(instance_id)
+                .then_some(InstanceOperation::Running)
+        })
+    }
+}
+
+/// Releases the lock on drop, so it's also released when the holding task panics
+#[must_use]
+pub struct InstanceLockGuard {
+    locks: LockMap,
+    instance_id: String,
+}
+
+impl Drop for InstanceLockGuard {
+    fn drop(&mut self) {
+        self.locks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.instance_id);
+    }
+}
+
+/// Held by the process manager for the lifetime of the game process
+#[must_use]
+pub struct InstanceRunningGuard {
+    running: RunningSet,
+    instance_id: String,
+}
+
+impl Drop for InstanceRunningGuard {
+    fn drop(&mut self) {
+        self.running
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.instance_id);
+    }
+}
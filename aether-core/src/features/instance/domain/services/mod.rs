@@ -1,5 +1,11 @@
 mod content_provider_registry;
+mod curseforge_manifest_resolver;
+mod disk_usage_service;
+mod instance_lock_service;
 mod instance_watcher_service_impl;
 
 pub use content_provider_registry::*;
+pub use curseforge_manifest_resolver::*;
+pub use disk_usage_service::*;
+pub use instance_lock_service::*;
 pub use instance_watcher_service_impl::*;
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{de::Error, Deserialize, Serialize};
 
 use crate::features::{
@@ -8,6 +9,7 @@ use crate::features::{
 use super::get_facet;
 
 pub const MODRINTH_API_URL: &str = "https://api.modrinth.com/v2";
+pub const MODRINTH_CDN_URL: &str = "https://cdn.modrinth.com/data/";
 
 lazy_static::lazy_static! {
     pub static ref DEFAULT_HEADERS: reqwest::header::HeaderMap = {
@@ -80,7 +82,7 @@ pub struct ProjectVersionResponse {
     pub version_number: String,
     pub changelog: String,
     pub changelog_url: Option<serde_json::Value>,
-    pub date_published: String,
+    pub date_published: DateTime<Utc>,
     pub downloads: i64,
     pub version_type: String,
     pub status: String,
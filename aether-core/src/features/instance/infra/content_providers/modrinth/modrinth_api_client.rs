@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{cmp::Ordering, sync::Arc};
 
 use bytes::Bytes;
 
@@ -66,49 +66,45 @@ impl<RC: RequestClient> ModrinthApiClient<RC> {
             .map_err(|err| InstanceError::ContentDownloadError(err.to_string()))
     }
 
-    fn find_best_version(
-        &self,
-        response: &ListProjectsVersionsResponse,
+    fn is_matching_version(
+        version: &ProjectVersionResponse,
         game_version: &str,
         loader: &Option<String>,
-    ) -> Option<ProjectVersionResponse> {
-        response
-            .iter()
-            .filter(|v| {
-                let is_right_game_version = v.game_versions.contains(&game_version.to_string());
-                let is_right_loader = loader
-                    .clone()
-                    .map(|loader| v.loaders.contains(&loader))
-                    .unwrap_or(true);
-
-                is_right_game_version && is_right_loader
-            })
-            .max_by(|a, b| {
-                // Compare by version type (stability)
-                let stability_priority = |version_type: &str| match version_type {
-                    "release" => 3,
-                    "beta" => 2,
-                    "alpha" => 1,
-                    _ => 0,
-                };
-
-                let a_stability = stability_priority(&a.version_type);
-                let b_stability = stability_priority(&b.version_type);
-
-                // Get newest by date if version type equals
-                a_stability
-                    .cmp(&b_stability)
-                    .then(a.date_published.cmp(&b.date_published))
-            })
-            .cloned()
+    ) -> bool {
+        let is_right_game_version = version.game_versions.contains(&game_version.to_string());
+        let is_right_loader = loader
+            .clone()
+            .map(|loader| version.loaders.contains(&loader))
+            .unwrap_or(true);
+
+        is_right_game_version && is_right_loader
     }
 
-    pub async fn get_project_version_for_game_version(
+    fn compare_versions(a: &ProjectVersionResponse, b: &ProjectVersionResponse) -> Ordering {
+        // Compare by version type (stability)
+        let stability_priority = |version_type: &str| match version_type {
+            "release" => 3,
+            "beta" => 2,
+            "alpha" => 1,
+            _ => 0,
+        };
+
+        let a_stability = stability_priority(&a.version_type);
+        let b_stability = stability_priority(&b.version_type);
+
+        // Get newest by date if version type equals
+        a_stability
+            .cmp(&b_stability)
+            .then(a.date_published.cmp(&b.date_published))
+    }
+
+    /// Project versions for the game version and loader, best match first
+    pub async fn list_project_versions(
         &self,
         project_id: &str,
         game_version: &str,
         loader: &Option<String>,
-    ) -> Result<ProjectVersionResponse, InstanceError> {
+    ) -> Result<ListProjectsVersionsResponse, InstanceError> {
         let params = ListProjectVersionsParams {
             loaders: loader.as_ref().map(|l| vec![l.clone()]),
             game_versions: vec![game_version.to_string()],
@@ -125,19 +121,31 @@ impl<RC: RequestClient> ModrinthApiClient<RC> {
             request = request.with_headers(base_headers);
         }
 
-        let response: ListProjectsVersionsResponse = self
+        let mut response: ListProjectsVersionsResponse = self
             .request_client
             .fetch_json_with_progress(request, None)
             .await
             .map_err(|err| InstanceError::ContentDownloadError(err.to_string()))?;
 
-        if let Some(version) = self.find_best_version(&response, game_version, loader) {
-            Ok(version.clone())
-        } else {
-            Err(InstanceError::ContentForGameVersionNotFound {
+        response.retain(|version| Self::is_matching_version(version, game_version, loader));
+        response.sort_by(|a, b| Self::compare_versions(b, a));
+
+        Ok(response)
+    }
+
+    pub async fn get_project_version_for_game_version(
+        &self,
+        project_id: &str,
+        game_version: &str,
+        loader: &Option<String>,
+    ) -> Result<ProjectVersionResponse, InstanceError> {
+        self.list_project_versions(project_id, game_version, loader)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| InstanceError::ContentForGameVersionNotFound {
                 game_version: game_version.to_owned(),
             })
-        }
     }
 
     pub async fn get_file(&self, url: &str) -> Result<Bytes, InstanceError> {
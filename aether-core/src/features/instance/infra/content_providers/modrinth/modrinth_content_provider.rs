@@ -11,18 +11,18 @@ use crate::{
     features::{
         instance::{
             ContentFile, ContentInstallParams, ContentProvider, ContentSearchParams,
-            ContentSearchResult, InstanceError,
+            ContentSearchResult, ContentVersion, InstanceError,
         },
         settings::LocationInfo,
     },
     libs::request_client::RequestClient,
-    shared::write_async,
+    shared::replace_async,
 };
 
 use super::{
-    get_first_file_from_project_version, modrinth_to_content_response, File, ModrinthApiClient,
-    ModrinthProviderData, ModrinthUpdateData, ProjectSearchParams, ProjectVersionResponse,
-    MODRINTH_API_URL,
+    get_content_version_type, get_first_file_from_project_version, get_update_data_from_cdn_url,
+    modrinth_to_content_response, File, ModrinthApiClient, ModrinthProviderData,
+    ModrinthUpdateData, ProjectSearchParams, ProjectVersionResponse, MODRINTH_API_URL,
 };
 
 pub struct ModrinthContentProvider<RC> {
@@ -94,7 +94,9 @@ impl<RC: RequestClient> ModrinthContentProvider<RC> {
         file_path: &Path,
     ) -> Result<(), InstanceError> {
         let file_bytes = self.api.get_file(file_url).await?;
-        Ok(write_async(file_path, &file_bytes).await?)
+
+        // The file may be hardlinked from a duplicated instance
+        Ok(replace_async(file_path, &file_bytes).await?)
     }
 
     fn create_instance_file(
@@ -127,6 +129,7 @@ impl<RC: RequestClient> ModrinthContentProvider<RC> {
                 install_params.provider.clone(),
                 update_data,
             )])),
+            download_url: Some(file.url.clone()),
         })
     }
 
@@ -189,4 +192,41 @@ impl<RC: RequestClient> ContentProvider for ModrinthContentProvider<RC> {
     fn get_update_data_id_field(&self) -> String {
         "project_id".into()
     }
+
+    fn get_update_data_version(&self, update_data: &serde_json::Value) -> Option<String> {
+        serde_json::from_value::<ModrinthUpdateData>(update_data.clone())
+            .ok()
+            .map(|data| data.version)
+    }
+
+    async fn list_compatible_versions(
+        &self,
+        update_data: &serde_json::Value,
+        game_version: &str,
+        loader: &Option<String>,
+    ) -> Result<Vec<ContentVersion>, InstanceError> {
+        let update_data: ModrinthUpdateData =
+            serde_json::from_value(update_data.clone()).map_err(|_| {
+                InstanceError::ContentDownloadError("Failed to parse update data".to_owned())
+            })?;
+
+        let versions = self
+            .api
+            .list_project_versions(&update_data.project_id, game_version, loader)
+            .await?;
+
+        Ok(versions
+            .into_iter()
+            .map(|version| ContentVersion {
+                id: version.id,
+                name: version.name,
+                version_type: get_content_version_type(&version.version_type),
+                date_published: version.date_published,
+            })
+            .collect())
+    }
+
+    fn get_update_data_by_download_url(&self, url: &str) -> Option<serde_json::Value> {
+        get_update_data_from_cdn_url(url).and_then(|data| serde_json::to_value(&data).ok())
+    }
 }
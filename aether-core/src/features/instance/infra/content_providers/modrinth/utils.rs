@@ -1,6 +1,8 @@
-use crate::features::instance::{ContentSearchParams, ContentSearchResult};
+use crate::features::instance::{ContentSearchParams, ContentSearchResult, ContentVersionType};
 
-use super::{File, ProjectSearchResponse, ProjectVersionResponse};
+use super::{
+    File, ModrinthUpdateData, ProjectSearchResponse, ProjectVersionResponse, MODRINTH_CDN_URL,
+};
 
 pub fn modrinth_to_content_response(
     request: &ContentSearchParams,
@@ -39,3 +41,28 @@ pub fn get_first_file_from_project_version(version: &ProjectVersionResponse) ->
         .cloned()
         .or_else(|| version.files.first().cloned())
 }
+
+/// Unknown types are treated as the least stable
+pub fn get_content_version_type(version_type: &str) -> ContentVersionType {
+    match version_type {
+        "release" => ContentVersionType::Release,
+        "beta" => ContentVersionType::Beta,
+        _ => ContentVersionType::Alpha,
+    }
+}
+
+// Download urls look like `https://cdn.modrinth.com/data/{project_id}/versions/{version_id}/{file}`
+pub fn get_update_data_from_cdn_url(url: &str) -> Option<ModrinthUpdateData> {
+    let mut segments = url.strip_prefix(MODRINTH_CDN_URL)?.split('/');
+
+    let project_id = segments.next()?;
+    if segments.next()? != "versions" {
+        return None;
+    }
+    let version = segments.next()?;
+
+    Some(ModrinthUpdateData {
+        project_id: project_id.to_owned(),
+        version: version.to_owned(),
+    })
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{
+    features::instance::{ContentType, CurseForgeFile, CurseForgeFileResolver, InstanceError},
+    libs::request_client::{Request, RequestClient, RequestClientExt},
+};
+
+pub const CURSEFORGE_API_URL: &str = "https://api.curseforge.com/v1";
+
+const SHA1_ALGO: u8 = 1;
+
+// Project class ids used by CurseForge for Minecraft
+const CLASS_RESOURCE_PACK: u64 = 12;
+const CLASS_SHADER_PACK: u64 = 6552;
+const CLASS_DATA_PACK: u64 = 6945;
+
+#[derive(Deserialize)]
+struct DataResponse<T> {
+    data: T,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ModResponse {
+    class_id: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileResponse {
+    display_name: Option<String>,
+    file_name: String,
+    download_url: Option<String>,
+    #[serde(default)]
+    hashes: Vec<FileHash>,
+}
+
+#[derive(Deserialize)]
+struct FileHash {
+    value: String,
+    algo: u8,
+}
+
+#[derive(Debug, Clone)]
+pub struct CurseForgeApiConfig {
+    pub base_url: String,
+    pub api_key: String,
+}
+
+impl Default for CurseForgeApiConfig {
+    fn default() -> Self {
+        Self {
+            base_url: CURSEFORGE_API_URL.to_string(),
+            api_key: option_env!("AETHER_CURSEFORGE_API_KEY")
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
+
+/// Resolves manifest files through the CurseForge API
+pub struct CurseForgeApiFileResolver<RC> {
+    config: CurseForgeApiConfig,
+    request_client: Arc<RC>,
+}
+
+impl<RC: RequestClient> CurseForgeApiFileResolver<RC> {
+    pub fn new(config: CurseForgeApiConfig, request_client: Arc<RC>) -> Self {
+        Self {
+            config,
+            request_client,
+        }
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, InstanceError> {
+        let mut headers = HeaderMap::new();
+        if let Ok(api_key) = HeaderValue::from_str(&self.config.api_key) {
+            headers.insert("x-api-key", api_key);
+        }
+
+        let request = Request::get(format!("{}{path}", self.config.base_url)).with_headers(headers);
+
+        self.request_client
+            .fetch_json::<DataResponse<T>>(request)
+            .await
+            .map(|response| response.data)
+            .map_err(|err| InstanceError::ContentDownloadError(err.to_string()))
+    }
+}
+
+#[async_trait]
+impl<RC: RequestClient> CurseForgeFileResolver for CurseForgeApiFileResolver<RC> {
+    async fn resolve(
+        &self,
+        project_id: u64,
+        file_id: u64,
+    ) -> Result<CurseForgeFile, InstanceError> {
+        let (project, file) = tokio::try_join!(
+            self.get::<ModResponse>(&format!("/mods/{project_id}")),
+            self.get::<FileResponse>(&format!("/mods/{project_id}/files/{file_id}")),
+        )?;
+
+        let content_type = match project.class_id {
+            Some(CLASS_RESOURCE_PACK) => ContentType::ResourcePack,
+            Some(CLASS_SHADER_PACK) => ContentType::ShaderPack,
+            Some(CLASS_DATA_PACK) => ContentType::DataPack,
+            _ => ContentType::Mod,
+        };
+
+        let sha1 = file
+            .hashes
+            .into_iter()
+            .find(|hash| hash.algo == SHA1_ALGO)
+            .map(|hash| hash.value);
+
+        Ok(CurseForgeFile {
+            file_name: file.file_name,
+            name: file.display_name,
+            download_url: file.download_url,
+            sha1,
+            content_type,
+        })
+    }
+}
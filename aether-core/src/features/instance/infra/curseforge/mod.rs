@@ -0,0 +1,3 @@
+mod curseforge_api_file_resolver;
+
+pub use curseforge_api_file_resolver::*;
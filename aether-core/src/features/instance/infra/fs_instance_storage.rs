@@ -4,10 +4,10 @@ use async_trait::async_trait;
 
 use crate::{
     features::{
-        instance::{Instance, InstanceError, InstanceStorage},
+        instance::{Instance, InstanceError, InstanceStorage, SharedFolder},
         settings::LocationInfo,
     },
-    shared::{read_json_async, remove_dir_all, write_json_async},
+    shared::{read_json_async, remove_dir_all, remove_dir_link, write_json_async},
 };
 
 pub struct FsInstanceStorage {
@@ -64,6 +64,15 @@ impl InstanceStorage for FsInstanceStorage {
 
     async fn remove(&self, id: &str) -> Result<(), InstanceError> {
         let path = self.location_info.instance_dir(id);
+
+        // Links are removed first so the shared data is never deleted with the instance
+        for shared_folder in SharedFolder::iterator() {
+            let link = path.join(shared_folder.get_folder());
+            if link.is_symlink() {
+                remove_dir_link(&link).await?;
+            }
+        }
+
         remove_dir_all(&path).await?;
         Ok(())
     }
@@ -1,15 +1,20 @@
 use std::{path::PathBuf, sync::Arc};
 
 use async_trait::async_trait;
+use tokio::sync::Mutex;
 
 use crate::{
     features::{
-        instance::{InstanceError, Pack, PackEntry, PackFile, PackStorage},
+        instance::{InstanceError, Pack, PackEntry, PackFile, PackStorage, SharedFolder},
         settings::LocationInfo,
     },
     shared::{ensure_read_toml_async, read_toml_async, remove_file, write_toml_async},
 };
 
+/// Serializes read-modify-write of pack indexes, the shared pack is changed by every instance
+/// linking a folder, so a lock per storage or per instance isn't enough
+static PACK_INDEX_LOCK: Mutex<()> = Mutex::const_new(());
+
 pub struct FsPackStorage {
     location_info: Arc<LocationInfo>,
 }
@@ -24,22 +29,84 @@ impl FsPackStorage {
     }
 
     fn get_pack_file_path(&self, instance_id: &str, content_path: &str) -> PathBuf {
-        self.location_info
-            .instance_pack_dir(instance_id)
-            .join(content_path)
-            .with_extension("toml")
+        let pack_dir = if self.is_shared(instance_id, content_path) {
+            self.location_info.shared_pack_dir()
+        } else {
+            self.location_info.instance_pack_dir(instance_id)
+        };
+
+        pack_dir.join(content_path).with_extension("toml")
+    }
+
+    fn has_shared_folders(&self, instance_id: &str) -> bool {
+        let instance_dir = self.location_info.instance_dir(instance_id);
+
+        SharedFolder::iterator()
+            .any(|shared_folder| instance_dir.join(shared_folder.get_folder()).is_symlink())
+    }
+
+    /// Content inside a linked folder is tracked in the shared pack instead of the instance one
+    fn is_shared(&self, instance_id: &str, content_path: &str) -> bool {
+        content_path
+            .split('/')
+            .next()
+            .and_then(SharedFolder::from_folder)
+            .is_some_and(|shared_folder| {
+                self.location_info
+                    .instance_dir(instance_id)
+                    .join(shared_folder.get_folder())
+                    .is_symlink()
+            })
+    }
+
+    /// Callers hold `PACK_INDEX_LOCK` from reading the pack until it's written
+    async fn write_pack(&self, instance_id: &str, pack: &Pack) -> Result<(), InstanceError> {
+        let (shared_files, files): (Vec<PackEntry>, Vec<PackEntry>) = pack
+            .files
+            .iter()
+            .cloned()
+            .partition(|entry| self.is_shared(instance_id, &entry.file));
+
+        // Other instances may link other folders, so only the folders linked here are replaced
+        if self.has_shared_folders(instance_id) {
+            let shared_pack_path = self.location_info.shared_pack();
+            let mut shared_pack: Pack = ensure_read_toml_async(&shared_pack_path).await?;
+            shared_pack
+                .files
+                .retain(|entry| !self.is_shared(instance_id, &entry.file));
+            shared_pack.files.extend(shared_files);
+
+            write_toml_async(&shared_pack_path, &shared_pack).await?;
+        }
+
+        write_toml_async(&self.get_pack_path(instance_id), &Pack { files }).await?;
+        Ok(())
     }
 }
 
 #[async_trait]
 impl PackStorage for FsPackStorage {
     async fn get_pack(&self, instance_id: &str) -> Result<Pack, InstanceError> {
-        Ok(ensure_read_toml_async(&self.get_pack_path(instance_id)).await?)
+        let mut pack: Pack = ensure_read_toml_async(&self.get_pack_path(instance_id)).await?;
+
+        if self.has_shared_folders(instance_id) {
+            let shared_pack: Pack =
+                ensure_read_toml_async(&self.location_info.shared_pack()).await?;
+
+            pack.files.extend(
+                shared_pack
+                    .files
+                    .into_iter()
+                    .filter(|entry| self.is_shared(instance_id, &entry.file)),
+            );
+        }
+
+        Ok(pack)
     }
 
     async fn update_pack(&self, instance_id: &str, pack: &Pack) -> Result<(), InstanceError> {
-        write_toml_async(&self.get_pack_path(instance_id), &pack).await?;
-        Ok(())
+        let _lock = PACK_INDEX_LOCK.lock().await;
+        self.write_pack(instance_id, pack).await
     }
 
     async fn get_pack_file(
@@ -83,6 +150,7 @@ impl PackStorage for FsPackStorage {
         }
 
         if !new_pack_file_paths.is_empty() {
+            let _lock = PACK_INDEX_LOCK.lock().await;
             let mut pack = self.get_pack(instance_id).await?;
 
             let pack_entries: Vec<PackEntry> = new_pack_file_paths
@@ -94,7 +162,7 @@ impl PackStorage for FsPackStorage {
 
             pack.files.extend_from_slice(&pack_entries);
 
-            self.update_pack(instance_id, &pack).await?;
+            self.write_pack(instance_id, &pack).await?;
         }
 
         Ok(())
@@ -124,10 +192,11 @@ impl PackStorage for FsPackStorage {
             }
         }
 
+        let _lock = PACK_INDEX_LOCK.lock().await;
         let mut pack = self.get_pack(instance_id).await?;
         pack.files
             .retain(|entry| !success_deleted_content_paths.contains(&&entry.file));
         pack.files.dedup_by_key(|item| item.file.to_string());
-        self.update_pack(instance_id, &pack).await
+        self.write_pack(instance_id, &pack).await
     }
 }
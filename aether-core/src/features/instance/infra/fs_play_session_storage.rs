@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::{
+    features::{
+        instance::{InstanceError, PlaySession, PlaySessionStorage},
+        settings::LocationInfo,
+    },
+    shared::{read_json_async, write_json_async},
+};
+
+pub struct FsPlaySessionStorage {
+    location_info: Arc<LocationInfo>,
+    lock: Mutex<()>,
+}
+
+impl FsPlaySessionStorage {
+    pub fn new(location_info: Arc<LocationInfo>) -> Self {
+        Self {
+            location_info,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Mutex must be locked
+    async fn read(&self, instance_id: &str) -> Result<Vec<PlaySession>, InstanceError> {
+        let path = self.location_info.instance_sessions_file(instance_id);
+
+        if !path.exists() {
+            return Ok(Vec::default());
+        }
+
+        Ok(read_json_async(&path).await?)
+    }
+}
+
+#[async_trait]
+impl PlaySessionStorage for FsPlaySessionStorage {
+    async fn list(&self, instance_id: &str) -> Result<Vec<PlaySession>, InstanceError> {
+        let _guard = self.lock.lock().await;
+        self.read(instance_id).await
+    }
+
+    async fn add(&self, instance_id: &str, session: PlaySession) -> Result<(), InstanceError> {
+        let _guard = self.lock.lock().await;
+
+        let mut sessions = self.read(instance_id).await?;
+        sessions.push(session);
+        sessions.sort_by_key(|session| session.start);
+
+        Ok(write_json_async(
+            self.location_info.instance_sessions_file(instance_id),
+            &sessions,
+        )
+        .await?)
+    }
+}
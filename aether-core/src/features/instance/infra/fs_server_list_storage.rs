@@ -0,0 +1,171 @@
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+
+use crate::{
+    features::{
+        instance::{InstanceError, ResourcePackPolicy, ServerEntry, ServerListStorage},
+        settings::LocationInfo,
+    },
+    shared::{create_dir_all, read_async, rename, IoError},
+};
+
+/// Uncompressed NBT root of `servers.dat`
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct ServersDat {
+    #[serde(default)]
+    servers: Vec<ServersDatEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ServersDatEntry {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    ip: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    icon: Option<String>,
+    /// Missing means the game asks the player
+    #[serde(
+        default,
+        rename = "acceptTextures",
+        skip_serializing_if = "Option::is_none"
+    )]
+    accept_textures: Option<i8>,
+    /// Fields the launcher doesn't edit, e.g. `hidden`
+    #[serde(flatten)]
+    other: BTreeMap<String, fastnbt::Value>,
+}
+
+impl ServersDatEntry {
+    fn from_server_entry(server: &ServerEntry, other: BTreeMap<String, fastnbt::Value>) -> Self {
+        Self {
+            name: server.name.clone(),
+            ip: server.address.clone(),
+            icon: server.icon.clone(),
+            accept_textures: match server.resource_pack_policy {
+                ResourcePackPolicy::Prompt => None,
+                ResourcePackPolicy::Enabled => Some(1),
+                ResourcePackPolicy::Disabled => Some(0),
+            },
+            other,
+        }
+    }
+
+    fn to_server_entry(&self, stored_index: usize) -> ServerEntry {
+        ServerEntry {
+            name: self.name.clone(),
+            address: self.ip.clone(),
+            icon: self.icon.clone(),
+            resource_pack_policy: match self.accept_textures {
+                None => ResourcePackPolicy::Prompt,
+                Some(0) => ResourcePackPolicy::Disabled,
+                Some(_) => ResourcePackPolicy::Enabled,
+            },
+            stored_index: Some(stored_index),
+        }
+    }
+}
+
+pub struct FsServerListStorage {
+    location_info: Arc<LocationInfo>,
+}
+
+impl FsServerListStorage {
+    pub fn new(location_info: Arc<LocationInfo>) -> Self {
+        Self { location_info }
+    }
+}
+
+#[async_trait]
+impl ServerListStorage for FsServerListStorage {
+    async fn get(&self, instance_id: &str) -> Result<Vec<ServerEntry>, InstanceError> {
+        let servers_dat =
+            read_servers_dat(&self.location_info.instance_servers_file(instance_id)).await?;
+
+        Ok(servers_dat
+            .servers
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| entry.to_server_entry(index))
+            .collect())
+    }
+
+    async fn set(&self, instance_id: &str, servers: &[ServerEntry]) -> Result<(), InstanceError> {
+        let path = self.location_info.instance_servers_file(instance_id);
+
+        // Unknown fields follow the entry they were read with, whatever its new position is
+        let mut others: Vec<Option<BTreeMap<String, fastnbt::Value>>> = read_servers_dat(&path)
+            .await?
+            .servers
+            .into_iter()
+            .map(|entry| Some(entry.other))
+            .collect();
+
+        let servers_dat = ServersDat {
+            servers: servers
+                .iter()
+                .map(|server| {
+                    let other = server
+                        .stored_index
+                        .and_then(|index| others.get_mut(index))
+                        .and_then(Option::take)
+                        .unwrap_or_default();
+
+                    ServersDatEntry::from_server_entry(server, other)
+                })
+                .collect(),
+        };
+
+        let bytes = fastnbt::to_bytes(&servers_dat)
+            .map_err(|err| IoError::SerializationError(err.to_string()))?;
+        write_servers_dat(&path, &bytes).await?;
+
+        Ok(())
+    }
+}
+
+async fn read_servers_dat(path: &Path) -> Result<ServersDat, InstanceError> {
+    if !path.exists() {
+        return Ok(ServersDat::default());
+    }
+
+    let bytes = read_async(path).await?;
+
+    Ok(
+        fastnbt::from_bytes(&bytes)
+            .map_err(|err| IoError::DeserializationError(err.to_string()))?,
+    )
+}
+
+/// The game reads the file on its own, so it's replaced at once instead of rewritten in place
+async fn write_servers_dat(path: &Path, bytes: &[u8]) -> Result<(), IoError> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).await?;
+    }
+
+    let mut tmp_path = OsString::from(path.as_os_str());
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+
+    let mut file = tokio::fs::File::create(&tmp_path)
+        .await
+        .map_err(|err| IoError::with_path(err, &tmp_path))?;
+
+    file.write_all(bytes)
+        .await
+        .map_err(|err| IoError::with_path(err, &tmp_path))?;
+    file.sync_all()
+        .await
+        .map_err(|err| IoError::with_path(err, &tmp_path))?;
+    drop(file);
+
+    rename(&tmp_path, path).await
+}
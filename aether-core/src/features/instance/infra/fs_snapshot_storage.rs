@@ -0,0 +1,247 @@
+use std::{
+    cmp::Reverse,
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::{
+    features::{
+        instance::{
+            utils::{get_join_error, get_zip_error},
+            InstanceError, InstanceSnapshot, SnapshotProgressFn, SnapshotReason, SnapshotStorage,
+        },
+        settings::LocationInfo,
+    },
+    shared::{create_dir_all, read_dir, read_json_async, remove_file, write_json_async, IoError},
+};
+
+pub struct FsSnapshotStorage {
+    location_info: Arc<LocationInfo>,
+}
+
+impl FsSnapshotStorage {
+    pub fn new(location_info: Arc<LocationInfo>) -> Self {
+        Self { location_info }
+    }
+
+    fn get_archive_path(&self, instance_id: &str, snapshot_id: &str) -> PathBuf {
+        self.location_info
+            .instance_snapshots_dir(instance_id)
+            .join(format!("{snapshot_id}.zip"))
+    }
+
+    fn get_snapshot_path(&self, instance_id: &str, snapshot_id: &str) -> PathBuf {
+        self.location_info
+            .instance_snapshots_dir(instance_id)
+            .join(format!("{snapshot_id}.json"))
+    }
+}
+
+#[async_trait]
+impl SnapshotStorage for FsSnapshotStorage {
+    async fn list(&self, instance_id: &str) -> Result<Vec<InstanceSnapshot>, InstanceError> {
+        let snapshots_dir = self.location_info.instance_snapshots_dir(instance_id);
+
+        if !snapshots_dir.exists() {
+            return Ok(Vec::default());
+        }
+
+        let mut entries = read_dir(&snapshots_dir).await?;
+        let mut snapshots = Vec::new();
+
+        while let Some(entry) = entries.next_entry().await.map_err(IoError::from)? {
+            let path = entry.path();
+
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+
+            match read_json_async::<InstanceSnapshot>(&path).await {
+                Ok(snapshot) => snapshots.push(snapshot),
+                Err(err) => {
+                    tracing::debug!("Failed to read snapshot {:?}", err)
+                }
+            }
+        }
+
+        snapshots.sort_by_key(|snapshot| Reverse(snapshot.created));
+
+        Ok(snapshots)
+    }
+
+    async fn get(
+        &self,
+        instance_id: &str,
+        snapshot_id: &str,
+    ) -> Result<InstanceSnapshot, InstanceError> {
+        let path = self.get_snapshot_path(instance_id, snapshot_id);
+
+        if !path.exists() {
+            return Err(InstanceError::SnapshotNotFound {
+                instance_id: instance_id.to_owned(),
+                snapshot_id: snapshot_id.to_owned(),
+            });
+        }
+
+        Ok(read_json_async(&path).await?)
+    }
+
+    async fn create(
+        &self,
+        instance_id: &str,
+        reason: SnapshotReason,
+        source_dir: PathBuf,
+        files: BTreeSet<String>,
+        on_progress: SnapshotProgressFn,
+    ) -> Result<InstanceSnapshot, InstanceError> {
+        let created = Utc::now();
+        let snapshot_id = InstanceSnapshot::get_id(&created);
+
+        create_dir_all(self.location_info.instance_snapshots_dir(instance_id)).await?;
+
+        let archive_path = self.get_archive_path(instance_id, &snapshot_id);
+        let output_path = archive_path.clone();
+
+        let result = tokio::task::spawn_blocking(move || {
+            write_archive(&output_path, &source_dir, &files, &on_progress)
+        })
+        .await
+        .map_err(get_join_error)
+        .and_then(|result| result);
+
+        let size = match result {
+            Ok(size) => size,
+            Err(err) => {
+                if archive_path.exists() {
+                    remove_file(&archive_path).await?;
+                }
+                return Err(err.into());
+            }
+        };
+
+        let snapshot = InstanceSnapshot {
+            id: snapshot_id,
+            instance_id: instance_id.to_owned(),
+            created,
+            reason,
+            size,
+        };
+
+        write_json_async(self.get_snapshot_path(instance_id, &snapshot.id), &snapshot).await?;
+
+        Ok(snapshot)
+    }
+
+    async fn extract(
+        &self,
+        instance_id: &str,
+        snapshot_id: &str,
+        target_dir: PathBuf,
+        on_progress: SnapshotProgressFn,
+    ) -> Result<(), InstanceError> {
+        let archive_path = self.get_archive_path(instance_id, snapshot_id);
+
+        if !archive_path.exists() {
+            return Err(InstanceError::SnapshotNotFound {
+                instance_id: instance_id.to_owned(),
+                snapshot_id: snapshot_id.to_owned(),
+            });
+        }
+
+        tokio::task::spawn_blocking(move || {
+            extract_archive(&archive_path, &target_dir, &on_progress)
+        })
+        .await
+        .map_err(get_join_error)??;
+
+        Ok(())
+    }
+
+    async fn remove(&self, instance_id: &str, snapshot_id: &str) -> Result<(), InstanceError> {
+        let snapshot_path = self.get_snapshot_path(instance_id, snapshot_id);
+
+        if !snapshot_path.exists() {
+            return Err(InstanceError::SnapshotNotFound {
+                instance_id: instance_id.to_owned(),
+                snapshot_id: snapshot_id.to_owned(),
+            });
+        }
+
+        // Metadata goes first so a half removed snapshot is never listed
+        remove_file(&snapshot_path).await?;
+
+        let archive_path = self.get_archive_path(instance_id, snapshot_id);
+        if archive_path.exists() {
+            remove_file(&archive_path).await?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_archive(
+    output_path: &Path,
+    source_dir: &Path,
+    files: &BTreeSet<String>,
+    on_progress: &SnapshotProgressFn,
+) -> Result<u64, IoError> {
+    let file =
+        std::fs::File::create(output_path).map_err(|e| IoError::with_path(e, output_path))?;
+
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .large_file(true);
+    let increment = 1.0 / files.len().max(1) as f64;
+
+    for path in files {
+        let source_path = source_dir.join(path);
+        let mut source =
+            std::fs::File::open(&source_path).map_err(|e| IoError::with_path(e, &source_path))?;
+
+        zip.start_file(path, options).map_err(get_zip_error)?;
+        std::io::copy(&mut source, &mut zip).map_err(IoError::from)?;
+
+        on_progress(increment);
+    }
+
+    let file = zip.finish().map_err(get_zip_error)?;
+    let size = file.metadata().map_err(IoError::from)?.len();
+
+    Ok(size)
+}
+
+fn extract_archive(
+    archive_path: &Path,
+    target_dir: &Path,
+    on_progress: &SnapshotProgressFn,
+) -> Result<(), IoError> {
+    let file =
+        std::fs::File::open(archive_path).map_err(|e| IoError::with_path(e, archive_path))?;
+    let mut archive = ZipArchive::new(file).map_err(get_zip_error)?;
+    let increment = 1.0 / archive.len().max(1) as f64;
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(get_zip_error)?;
+
+        if let Some(relative_path) = entry.enclosed_name().filter(|_| !entry.is_dir()) {
+            let target_path = target_dir.join(relative_path);
+            if let Some(parent) = target_path.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| IoError::with_path(e, parent))?;
+            }
+
+            let mut target = std::fs::File::create(&target_path)
+                .map_err(|e| IoError::with_path(e, &target_path))?;
+            std::io::copy(&mut entry, &mut target).map_err(IoError::from)?;
+        }
+
+        on_progress(increment);
+    }
+
+    Ok(())
+}
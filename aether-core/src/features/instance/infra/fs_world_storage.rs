@@ -0,0 +1,154 @@
+use std::{
+    cmp::Reverse,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use base64::{prelude::BASE64_STANDARD, Engine};
+use chrono::DateTime;
+
+use crate::{
+    features::{
+        instance::{
+            utils::{is_inner_relative_path, parse_level_dat, LevelDataPacks, LEVEL_DAT_FILE},
+            InstanceError, World, WorldDataPack, WorldGameMode, WorldStorage,
+        },
+        settings::LocationInfo,
+    },
+    shared::{read_async, read_dir, remove_dir_all, IoError},
+};
+
+const ICON_FILE: &str = "icon.png";
+const DATAPACKS_FOLDER: &str = "datapacks";
+
+pub struct FsWorldStorage {
+    location_info: Arc<LocationInfo>,
+}
+
+impl FsWorldStorage {
+    pub fn new(location_info: Arc<LocationInfo>) -> Self {
+        Self { location_info }
+    }
+
+    fn get_world_dir(&self, instance_id: &str, world_id: &str) -> Result<PathBuf, InstanceError> {
+        let world_dir = self
+            .location_info
+            .instance_saves_dir(instance_id)
+            .join(world_id);
+
+        // World id is a single folder name, anything else can't be a world
+        if !is_inner_relative_path(world_id)
+            || world_id.contains(['/', '\\'])
+            || !world_dir.join(LEVEL_DAT_FILE).exists()
+        {
+            return Err(InstanceError::WorldNotFound {
+                instance_id: instance_id.to_owned(),
+                world_id: world_id.to_owned(),
+            });
+        }
+
+        Ok(world_dir)
+    }
+}
+
+#[async_trait]
+impl WorldStorage for FsWorldStorage {
+    async fn list(&self, instance_id: &str) -> Result<Vec<World>, InstanceError> {
+        let saves_dir = self.location_info.instance_saves_dir(instance_id);
+
+        if !saves_dir.exists() {
+            return Ok(Vec::default());
+        }
+
+        let mut entries = read_dir(&saves_dir).await?;
+        let mut worlds = Vec::new();
+
+        while let Some(entry) = entries.next_entry().await.map_err(IoError::from)? {
+            let world_dir = entry.path();
+
+            if !world_dir.join(LEVEL_DAT_FILE).exists() {
+                continue;
+            }
+
+            let world_id = entry.file_name().to_string_lossy().to_string();
+
+            match read_world(&world_dir, world_id).await {
+                Ok(world) => worlds.push(world),
+                Err(err) => {
+                    tracing::debug!("Failed to read world {:?}: {}", world_dir, err)
+                }
+            }
+        }
+
+        worlds.sort_by_key(|world| Reverse(world.last_played));
+
+        Ok(worlds)
+    }
+
+    async fn get(&self, instance_id: &str, world_id: &str) -> Result<World, InstanceError> {
+        let world_dir = self.get_world_dir(instance_id, world_id)?;
+        read_world(&world_dir, world_id.to_owned()).await
+    }
+
+    async fn remove(&self, instance_id: &str, world_id: &str) -> Result<(), InstanceError> {
+        let world_dir = self.get_world_dir(instance_id, world_id)?;
+        remove_dir_all(&world_dir).await?;
+        Ok(())
+    }
+}
+
+async fn read_world(world_dir: &Path, world_id: String) -> Result<World, InstanceError> {
+    let level_dat = parse_level_dat(&read_async(world_dir.join(LEVEL_DAT_FILE)).await?)?;
+    let level = level_dat.data;
+
+    let icon_path = world_dir.join(ICON_FILE);
+    let icon = if icon_path.exists() {
+        Some(BASE64_STANDARD.encode(read_async(&icon_path).await?))
+    } else {
+        None
+    };
+
+    let data_packs =
+        read_data_packs(&world_dir.join(DATAPACKS_FOLDER), level.data_packs.as_ref()).await?;
+
+    Ok(World {
+        id: world_id,
+        name: level.level_name.clone(),
+        game_mode: WorldGameMode::from_game_type(level.game_type),
+        hardcore: level.hardcore != 0,
+        last_played: DateTime::from_timestamp_millis(level.last_played)
+            .filter(|_| level.last_played > 0),
+        game_version: level.version.as_ref().map(|version| version.name.clone()),
+        seed: level.seed(),
+        icon,
+        data_packs,
+    })
+}
+
+/// Data packs found in the world folder, `level.dat` refers to them as `file/<name>`
+async fn read_data_packs(
+    datapacks_dir: &Path,
+    level_data_packs: Option<&LevelDataPacks>,
+) -> Result<Vec<WorldDataPack>, InstanceError> {
+    if !datapacks_dir.exists() {
+        return Ok(Vec::default());
+    }
+
+    let mut entries = read_dir(datapacks_dir).await?;
+    let mut data_packs = Vec::new();
+
+    while let Some(entry) = entries.next_entry().await.map_err(IoError::from)? {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let pack_id = format!("file/{file_name}");
+
+        data_packs.push(WorldDataPack {
+            enabled: !level_data_packs.is_some_and(|packs| packs.disabled.contains(&pack_id)),
+            file_name,
+        });
+    }
+
+    data_packs.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+
+    Ok(data_packs)
+}
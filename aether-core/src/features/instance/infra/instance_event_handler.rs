@@ -5,17 +5,27 @@ use async_trait::async_trait;
 use crate::features::{
     events::{EventEmitter, EventEmitterExt, InstanceEventType},
     file_watcher::{FileEvent, FileEventHandler, FileWatcherError},
-    instance::InstanceInstallStage,
-    settings::INSTANCES_FOLDER_NAME,
+    instance::{DiskUsageService, InstanceInstallStage},
+    settings::{LocationInfo, INSTANCES_FOLDER_NAME},
 };
 
 pub struct InstanceEventHandler<E: EventEmitter> {
     event_emitter: Arc<E>,
+    disk_usage_service: Arc<DiskUsageService>,
+    location_info: Arc<LocationInfo>,
 }
 
 impl<E: EventEmitter + 'static> InstanceEventHandler<E> {
-    pub fn new(event_emitter: Arc<E>) -> Self {
-        Self { event_emitter }
+    pub fn new(
+        event_emitter: Arc<E>,
+        disk_usage_service: Arc<DiskUsageService>,
+        location_info: Arc<LocationInfo>,
+    ) -> Self {
+        Self {
+            event_emitter,
+            disk_usage_service,
+            location_info,
+        }
     }
 
     fn extract_instance_path(path: &Path) -> Option<String> {
@@ -82,6 +92,9 @@ impl<E: EventEmitter + 'static> FileEventHandler for InstanceEventHandler<E> {
 
                 for event in events.iter() {
                     if let Some(instance_path) = Self::extract_instance_path(&event.path) {
+                        self.disk_usage_service
+                            .invalidate(&self.location_info.instance_dir(&instance_path));
+
                         if Self::is_crash_report(&event.path) {
                             self.crash_task(instance_path.to_string());
                         } else if !visited_profiles.contains(&instance_path) {
@@ -1,11 +1,23 @@
 mod content_providers;
+mod curseforge;
 mod event_emitting_instance_storage;
 mod fs_instance_storage;
 mod fs_pack_storage;
+mod fs_play_session_storage;
+mod fs_server_list_storage;
+mod fs_snapshot_storage;
+mod fs_world_storage;
 mod instance_event_handler;
+mod tcp_server_pinger;
 
 pub use content_providers::*;
+pub use curseforge::*;
 pub use event_emitting_instance_storage::*;
 pub use fs_instance_storage::*;
 pub use fs_pack_storage::*;
+pub use fs_play_session_storage::*;
+pub use fs_server_list_storage::*;
+pub use fs_snapshot_storage::*;
+pub use fs_world_storage::*;
 pub use instance_event_handler::*;
+pub use tcp_server_pinger::*;
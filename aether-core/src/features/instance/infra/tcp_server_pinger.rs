@@ -0,0 +1,209 @@
+use std::{
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use hickory_resolver::TokioResolver;
+use tokio::net::TcpStream;
+
+use crate::features::{
+    instance::{
+        utils::{
+            get_description_text, read_packet, read_string, write_packet, write_string,
+            write_varint, StatusResponse, HANDSHAKE_PACKET_ID, PING_PACKET_ID, STATUS_NEXT_STATE,
+            STATUS_PROTOCOL_VERSION, STATUS_REQUEST_PACKET_ID,
+        },
+        InstanceError, ServerPinger, ServerStatus,
+    },
+    minecraft::utils::{parse_server_address, DEFAULT_SERVER_PORT},
+};
+
+pub const DEFAULT_PING_TIMEOUT: Duration = Duration::from_secs(10);
+
+const FAVICON_PREFIX: &str = "data:image/png;base64,";
+
+/// Server List Ping over TCP, as used by the multiplayer screen of the game
+pub struct TcpServerPinger {
+    timeout: Duration,
+}
+
+impl TcpServerPinger {
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout }
+    }
+
+    /// Uses the `_minecraft._tcp` SRV record when the address has no port
+    async fn resolve(&self, host: &str, port: Option<u16>) -> (String, u16) {
+        if let Some(port) = port {
+            return (host.to_owned(), port);
+        }
+
+        if host.parse::<IpAddr>().is_err() {
+            if let Some(target) = lookup_srv(host).await {
+                return target;
+            }
+        }
+
+        (host.to_owned(), DEFAULT_SERVER_PORT)
+    }
+
+    /// Connects to `target`, the handshake carries the address the player entered like the game
+    /// does, so servers behind an SRV record or a proxy see their own host name
+    async fn query(
+        &self,
+        target: (&str, u16),
+        host: &str,
+        port: u16,
+    ) -> std::io::Result<ServerStatus> {
+        let mut stream = TcpStream::connect(target).await?;
+        stream.set_nodelay(true)?;
+
+        let mut handshake = Vec::new();
+        write_varint(&mut handshake, STATUS_PROTOCOL_VERSION);
+        write_string(&mut handshake, host);
+        handshake.extend_from_slice(&port.to_be_bytes());
+        write_varint(&mut handshake, STATUS_NEXT_STATE);
+
+        write_packet(&mut stream, HANDSHAKE_PACKET_ID, &handshake).await?;
+
+        let started = Instant::now();
+        write_packet(&mut stream, STATUS_REQUEST_PACKET_ID, &[]).await?;
+
+        let (packet_id, data) = read_packet(&mut stream).await?;
+        if packet_id != STATUS_REQUEST_PACKET_ID {
+            return Err(get_unexpected_packet_error(packet_id));
+        }
+        let status_latency = started.elapsed();
+
+        let response: StatusResponse =
+            serde_json::from_str(&read_string(&mut data.as_slice()).await?)
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+
+        // Some servers close the connection instead of answering the ping
+        let latency = match ping(&mut stream).await {
+            Ok(latency) => latency,
+            Err(err) => {
+                tracing::debug!("Server {host}:{port} didn't answer ping: {err}");
+                status_latency
+            }
+        };
+
+        Ok(get_server_status(response, latency))
+    }
+}
+
+impl Default for TcpServerPinger {
+    fn default() -> Self {
+        Self::new(DEFAULT_PING_TIMEOUT)
+    }
+}
+
+#[async_trait]
+impl ServerPinger for TcpServerPinger {
+    async fn ping(&self, address: &str) -> Result<ServerStatus, InstanceError> {
+        let get_error = |reason: String| InstanceError::ServerPingFailed {
+            address: address.to_owned(),
+            reason,
+        };
+
+        let (host, port) =
+            parse_server_address(address).ok_or_else(|| get_error("invalid address".to_owned()))?;
+
+        tokio::time::timeout(self.timeout, async {
+            let (target_host, target_port) = self.resolve(&host, port).await;
+            self.query(
+                (&target_host, target_port),
+                &host,
+                port.unwrap_or(DEFAULT_SERVER_PORT),
+            )
+            .await
+        })
+        .await
+        .map_err(|_| get_error("timed out".to_owned()))?
+        .map_err(|err| get_error(err.to_string()))
+    }
+}
+
+async fn lookup_srv(host: &str) -> Option<(String, u16)> {
+    let resolver = match TokioResolver::builder_tokio() {
+        Ok(builder) => builder.build(),
+        Err(err) => {
+            tracing::debug!("Failed to create DNS resolver: {err}");
+            return None;
+        }
+    };
+
+    let lookup = resolver
+        .srv_lookup(format!("_minecraft._tcp.{host}"))
+        .await
+        .ok()?;
+
+    lookup.iter().min_by_key(|srv| srv.priority()).map(|srv| {
+        (
+            srv.target().to_utf8().trim_end_matches('.').to_owned(),
+            srv.port(),
+        )
+    })
+}
+
+async fn ping(stream: &mut TcpStream) -> std::io::Result<Duration> {
+    let payload = chrono::Utc::now().timestamp_millis();
+
+    let started = Instant::now();
+    write_packet(stream, PING_PACKET_ID, &payload.to_be_bytes()).await?;
+
+    let (packet_id, data) = read_packet(stream).await?;
+    if packet_id != PING_PACKET_ID || data != payload.to_be_bytes() {
+        return Err(get_unexpected_packet_error(packet_id));
+    }
+
+    Ok(started.elapsed())
+}
+
+fn get_server_status(response: StatusResponse, latency: Duration) -> ServerStatus {
+    let (version_name, protocol) = response
+        .version
+        .map(|version| (version.name, version.protocol))
+        .unwrap_or_default();
+
+    let (players_online, players_max, players_sample) = response
+        .players
+        .map(|players| {
+            (
+                players.online,
+                players.max,
+                players
+                    .sample
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|player| player.name)
+                    .collect(),
+            )
+        })
+        .unwrap_or_default();
+
+    ServerStatus {
+        motd: response
+            .description
+            .as_ref()
+            .map(get_description_text)
+            .unwrap_or_default(),
+        version_name,
+        protocol,
+        players_online,
+        players_max,
+        players_sample,
+        favicon: response
+            .favicon
+            .map(|favicon| favicon.trim_start_matches(FAVICON_PREFIX).replace('\n', "")),
+        latency: latency.as_millis() as u64,
+    }
+}
+
+fn get_unexpected_packet_error(packet_id: i32) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("unexpected packet {packet_id:#04x}"),
+    )
+}
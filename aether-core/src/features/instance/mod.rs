@@ -1,5 +1,9 @@
 pub mod app;
 pub mod domain;
 pub mod infra;
+pub mod utils;
 
 pub use domain::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use crate::features::{
+    instance::{
+        ContentType, CurseForgeManifest, CurseForgeManifestFile, CurseForgeManifestResolver,
+        CurseForgeMinecraft, CurseForgeModLoader, CurseForgeUpdateData, InstanceError,
+        CURSEFORGE_UPDATE_KEY,
+    },
+    minecraft::{LoaderVersionPreference, ModLoader},
+};
+
+use super::fixtures::MockCurseForgeFileResolver;
+
+fn manifest(files: Vec<(u64, u64, bool)>) -> CurseForgeManifest {
+    CurseForgeManifest {
+        minecraft: CurseForgeMinecraft {
+            version: "1.20.1".to_string(),
+            mod_loaders: vec![CurseForgeModLoader {
+                id: "forge-47.2.0".to_string(),
+                primary: true,
+            }],
+        },
+        manifest_type: "minecraftModpack".to_string(),
+        manifest_version: 1,
+        name: "Test Pack".to_string(),
+        version: "1.0.0".to_string(),
+        author: "Tester".to_string(),
+        files: files
+            .into_iter()
+            .map(|(project_id, file_id, required)| CurseForgeManifestFile {
+                project_id,
+                file_id,
+                required,
+            })
+            .collect(),
+        overrides: "overrides".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_manifest_files() {
+    let file_resolver = MockCurseForgeFileResolver::new()
+        .with_file(238222, 4712866, "jei-1.20.1.jar", ContentType::Mod)
+        .with_file(457153, 4703480, "Faithful.zip", ContentType::ResourcePack);
+
+    let resolver = CurseForgeManifestResolver::new(Arc::new(file_resolver));
+
+    let files = resolver
+        .resolve(&manifest(vec![
+            (238222, 4712866, true),
+            (457153, 4703480, false),
+        ]))
+        .await
+        .unwrap();
+
+    assert_eq!(files.len(), 2);
+
+    let jei = &files[0];
+    assert_eq!(jei.content_path, "mods/jei-1.20.1.jar");
+    assert_eq!(jei.pack_file.hash, "sha1-4712866");
+    assert!(jei.pack_file.option.is_none());
+    assert_eq!(
+        jei.pack_file.update_provider.as_deref(),
+        Some(CURSEFORGE_UPDATE_KEY)
+    );
+    assert_eq!(
+        CurseForgeUpdateData::from_pack_file(&jei.pack_file),
+        Some(CurseForgeUpdateData {
+            project_id: 238222,
+            file_id: 4712866,
+        })
+    );
+
+    let resource_pack = &files[1];
+    assert_eq!(resource_pack.content_path, "resourcepacks/Faithful.zip");
+    assert!(resource_pack
+        .pack_file
+        .option
+        .as_ref()
+        .is_some_and(|option| option.optional));
+}
+
+#[tokio::test]
+async fn test_resolve_manifest_reports_blocked_downloads() {
+    let file_resolver = MockCurseForgeFileResolver::new()
+        .with_file(238222, 4712866, "jei-1.20.1.jar", ContentType::Mod)
+        .with_blocked_file(32274, 4644453, "journeymap-1.20.1.jar");
+
+    let resolver = CurseForgeManifestResolver::new(Arc::new(file_resolver));
+
+    let result = resolver
+        .resolve(&manifest(vec![
+            (238222, 4712866, true),
+            (32274, 4644453, true),
+        ]))
+        .await;
+
+    match result {
+        Err(InstanceError::CurseForgeDownloadBlocked { files }) => {
+            assert_eq!(files, vec!["journeymap-1.20.1.jar".to_string()]);
+        }
+        other => panic!("Expected blocked download error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_minecraft_loader_from_manifest() {
+    let (mod_loader, loader_version) = manifest(vec![]).minecraft.loader();
+
+    assert_eq!(mod_loader, ModLoader::Forge);
+    assert!(matches!(
+        loader_version,
+        Some(LoaderVersionPreference::Exact(version)) if version == "1.20.1-47.2.0"
+    ));
+}
+
+#[test]
+fn test_minecraft_loader_round_trip() {
+    let minecraft = CurseForgeMinecraft::new(
+        "1.20.1".to_string(),
+        ModLoader::Forge,
+        Some("1.20.1-47.2.0"),
+    );
+
+    assert_eq!(minecraft.mod_loaders.len(), 1);
+    assert_eq!(minecraft.mod_loaders[0].id, "forge-47.2.0");
+
+    let vanilla = CurseForgeMinecraft::new("1.20.1".to_string(), ModLoader::Vanilla, None);
+    assert!(vanilla.mod_loaders.is_empty());
+    assert_eq!(vanilla.loader().0, ModLoader::Vanilla);
+}
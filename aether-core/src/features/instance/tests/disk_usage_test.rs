@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use crate::features::{
+    instance::{
+        app::GetInstanceDiskUsageUseCase, infra::FsInstanceStorage, DiskUsageService,
+        InstanceDiskUsage, InstanceError, InstanceStorage,
+    },
+    settings::LocationInfo,
+};
+
+use super::fixtures::test_instance;
+
+fn content_size(usage: &InstanceDiskUsage) -> u64 {
+    usage.content.iter().map(|content| content.size).sum()
+}
+
+#[tokio::test]
+async fn test_folder_size_includes_nested_files() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("saves/world")).unwrap();
+    std::fs::write(dir.path().join("options.txt"), [0; 10]).unwrap();
+    std::fs::write(dir.path().join("saves/world/level.dat"), [0; 32]).unwrap();
+
+    let service = DiskUsageService::new();
+    let request = service.start_request("test");
+
+    assert_eq!(service.get_size(dir.path(), &request).await.unwrap(), 42);
+    assert_eq!(
+        service
+            .get_size(&dir.path().join("saves"), &request)
+            .await
+            .unwrap(),
+        32
+    );
+}
+
+#[tokio::test]
+async fn test_cached_size_kept_until_invalidated() {
+    let dir = tempfile::tempdir().unwrap();
+    let mods_dir = dir.path().join("mods");
+    std::fs::create_dir_all(&mods_dir).unwrap();
+    std::fs::write(mods_dir.join("a.jar"), [0; 8]).unwrap();
+
+    let service = DiskUsageService::new();
+    let request = service.start_request("test");
+    assert_eq!(service.get_size(&mods_dir, &request).await.unwrap(), 8);
+
+    std::fs::write(mods_dir.join("b.jar"), [0; 8]).unwrap();
+    assert_eq!(service.get_size(&mods_dir, &request).await.unwrap(), 8);
+
+    // Invalidating the parent folder drops the cached child
+    service.invalidate(dir.path());
+    assert_eq!(service.get_size(&mods_dir, &request).await.unwrap(), 16);
+}
+
+#[tokio::test]
+async fn test_missing_path_has_no_size() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let service = DiskUsageService::new();
+    let request = service.start_request("test");
+
+    assert_eq!(
+        service
+            .get_size(&dir.path().join("screenshots"), &request)
+            .await
+            .unwrap(),
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_stops_only_its_request() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("options.txt"), [0; 10]).unwrap();
+
+    let service = DiskUsageService::new();
+    let instance_request = service.start_request("instance");
+    let metadata_request = service.start_request("metadata");
+
+    service.cancel("instance");
+
+    assert!(matches!(
+        service.get_size(dir.path(), &instance_request).await,
+        Err(InstanceError::DiskUsageCancelled)
+    ));
+    assert_eq!(
+        service
+            .get_size(dir.path(), &metadata_request)
+            .await
+            .unwrap(),
+        10
+    );
+
+    // Finished request forgets its id, a new one with the same id isn't cancelled
+    drop(instance_request);
+    service.cancel("instance");
+    let instance_request = service.start_request("instance");
+    assert_eq!(
+        service
+            .get_size_uncached(dir.path(), &instance_request)
+            .await
+            .unwrap(),
+        10
+    );
+}
+
+#[tokio::test]
+async fn test_instance_usage_caches_only_watched_folders() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+    let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+    instance_storage
+        .upsert(&test_instance("survival"))
+        .await
+        .unwrap();
+
+    let instance_dir = location_info.instance_dir("survival");
+    std::fs::create_dir_all(instance_dir.join("mods")).unwrap();
+    std::fs::create_dir_all(instance_dir.join("saves/world")).unwrap();
+    std::fs::write(instance_dir.join("mods/a.jar"), [0; 8]).unwrap();
+    std::fs::write(instance_dir.join("saves/world/level.dat"), [0; 32]).unwrap();
+
+    let use_case = GetInstanceDiskUsageUseCase::new(
+        instance_storage,
+        Arc::new(DiskUsageService::new()),
+        location_info,
+    );
+    let get_usage = |refresh| use_case.execute("survival".to_owned(), refresh, "test");
+
+    let usage = get_usage(false).await.unwrap();
+    assert_eq!(usage.saves, 32);
+
+    std::fs::write(instance_dir.join("saves/world/region.mca"), [0; 16]).unwrap();
+    std::fs::write(instance_dir.join("mods/b.jar"), [0; 8]).unwrap();
+
+    // Saves aren't watched and are walked every time, content waits for a watcher event
+    let usage = get_usage(false).await.unwrap();
+    assert_eq!(usage.saves, 48);
+    assert_eq!(content_size(&usage), 8);
+
+    let usage = get_usage(true).await.unwrap();
+    assert_eq!(content_size(&usage), 16);
+}
@@ -0,0 +1,265 @@
+use std::{path::Path, sync::Arc};
+
+use crate::{
+    features::{
+        instance::{
+            app::{DuplicateInstanceOptions, DuplicateInstanceUseCase},
+            infra::FsInstanceStorage,
+            InstanceError, InstanceInstallStage, InstanceLockService, InstanceOperation,
+            InstanceStorage, PackInfo,
+        },
+        settings::LocationInfo,
+    },
+    shared::replace_async,
+};
+
+use super::fixtures::{test_instance, NoopInstanceWatcherService};
+
+struct Setup {
+    _dir: tempfile::TempDir,
+    location_info: Arc<LocationInfo>,
+    instance_storage: Arc<FsInstanceStorage>,
+    lock_service: Arc<InstanceLockService>,
+}
+
+impl Setup {
+    async fn new() -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let location_info = Arc::new(LocationInfo::new(
+            dir.path().to_path_buf(),
+            dir.path().to_path_buf(),
+        ));
+        let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+
+        let mut instance = test_instance("survival");
+        instance.time_played = 3600;
+        instance.recent_time_played = 600;
+        instance.pack_info = Some(PackInfo {
+            plugin_id: "modrinth".to_owned(),
+            modpack_id: "pack".to_owned(),
+            version: "1.0.0".to_owned(),
+        });
+        instance_storage.upsert(&instance).await.unwrap();
+
+        let instance_dir = location_info.instance_dir("survival");
+        for path in [
+            "mods/sodium.jar",
+            "config/sodium.json",
+            "saves/World/level.dat",
+            "logs/latest.log",
+            "crash-reports/crash.txt",
+            ".metadata/sessions.json",
+        ] {
+            write_file(&instance_dir, path);
+        }
+
+        Self {
+            _dir: dir,
+            location_info,
+            instance_storage,
+            lock_service: Arc::new(InstanceLockService::new()),
+        }
+    }
+
+    async fn duplicate(
+        &self,
+        new_name: &str,
+        options: DuplicateInstanceOptions,
+    ) -> Result<String, InstanceError> {
+        DuplicateInstanceUseCase::new(
+            self.instance_storage.clone(),
+            Arc::new(NoopInstanceWatcherService),
+            self.location_info.clone(),
+            self.lock_service.clone(),
+        )
+        .execute("survival".to_owned(), new_name.to_owned(), options)
+        .await
+    }
+}
+
+fn write_file(instance_dir: &Path, path: &str) {
+    let path = instance_dir.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, "content").unwrap();
+}
+
+#[tokio::test]
+async fn test_duplicate_copies_everything_by_default() {
+    let setup = Setup::new().await;
+
+    let new_id = setup
+        .duplicate("Copy", DuplicateInstanceOptions::default())
+        .await
+        .unwrap();
+
+    let new_dir = setup.location_info.instance_dir(&new_id);
+    for path in [
+        "mods/sodium.jar",
+        "config/sodium.json",
+        "saves/World/level.dat",
+        "logs/latest.log",
+        "crash-reports/crash.txt",
+    ] {
+        assert!(new_dir.join(path).exists(), "{path} must be copied");
+    }
+
+    // Copied instance.json is rewritten with the new identity, the rest is kept
+    let copy = setup.instance_storage.get(&new_id).await.unwrap();
+    assert_eq!(copy.id, new_id);
+    assert_eq!(copy.name, "Copy");
+    assert_eq!(copy.time_played, 3600);
+    assert!(copy.pack_info.is_some());
+    assert!(setup.location_info.instance_sessions_file(&new_id).exists());
+
+    let source = setup.instance_storage.get("survival").await.unwrap();
+    assert_eq!(source.name, "survival");
+}
+
+#[tokio::test]
+async fn test_duplicate_applies_options() {
+    let setup = Setup::new().await;
+
+    let new_id = setup
+        .duplicate(
+            "Copy",
+            DuplicateInstanceOptions {
+                hardlink_content: true,
+                exclude_saves: true,
+                exclude_logs: true,
+                reset_playtime: true,
+                reset_pack_info: true,
+            },
+        )
+        .await
+        .unwrap();
+
+    let new_dir = setup.location_info.instance_dir(&new_id);
+    assert!(new_dir.join("mods/sodium.jar").exists());
+    assert!(new_dir.join("config/sodium.json").exists());
+    assert!(!new_dir.join("saves").exists());
+    assert!(!new_dir.join("logs").exists());
+    assert!(!new_dir.join("crash-reports").exists());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        let metadata = std::fs::metadata(new_dir.join("mods/sodium.jar")).unwrap();
+        assert_eq!(metadata.nlink(), 2);
+
+        // Only content files are shared between instances
+        let metadata = std::fs::metadata(new_dir.join("config/sodium.json")).unwrap();
+        assert_eq!(metadata.nlink(), 1);
+    }
+
+    assert!(!setup.location_info.instance_sessions_file(&new_id).exists());
+    assert!(setup
+        .location_info
+        .instance_sessions_file("survival")
+        .exists());
+
+    let copy = setup.instance_storage.get(&new_id).await.unwrap();
+    assert_eq!(copy.time_played, 0);
+    assert_eq!(copy.recent_time_played, 0);
+    assert!(copy.last_played.is_none());
+    assert!(copy.pack_info.is_none());
+
+    let source = setup.instance_storage.get("survival").await.unwrap();
+    assert_eq!(source.time_played, 3600);
+    assert!(source.pack_info.is_some());
+}
+
+#[tokio::test]
+async fn test_replacing_hardlinked_content_keeps_source() {
+    let setup = Setup::new().await;
+
+    let new_id = setup
+        .duplicate(
+            "Copy",
+            DuplicateInstanceOptions {
+                hardlink_content: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    // Same write content providers use when updating a file
+    let new_dir = setup.location_info.instance_dir(&new_id);
+    replace_async(new_dir.join("mods/sodium.jar"), "updated")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(new_dir.join("mods/sodium.jar")).unwrap(),
+        "updated"
+    );
+    assert_eq!(
+        std::fs::read_to_string(
+            setup
+                .location_info
+                .instance_dir("survival")
+                .join("mods/sodium.jar")
+        )
+        .unwrap(),
+        "content"
+    );
+}
+
+#[tokio::test]
+async fn test_duplicate_picks_unique_path_and_id() {
+    let setup = Setup::new().await;
+
+    let first = setup
+        .duplicate("survival", DuplicateInstanceOptions::default())
+        .await
+        .unwrap();
+    let second = setup
+        .duplicate("survival", DuplicateInstanceOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(first, "survival-1");
+    assert_eq!(second, "survival-2");
+
+    let copy = setup.instance_storage.get(&second).await.unwrap();
+    assert_eq!(copy.id, "survival-2");
+    assert_eq!(copy.name, "survival");
+    assert_eq!(setup.instance_storage.list().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_duplicate_refuses_busy_or_installing_instance() {
+    let setup = Setup::new().await;
+
+    let lock = setup
+        .lock_service
+        .try_lock("survival", InstanceOperation::Install)
+        .unwrap();
+    let result = setup
+        .duplicate("Copy", DuplicateInstanceOptions::default())
+        .await;
+    assert!(matches!(
+        result,
+        Err(InstanceError::InstanceBusy {
+            operation: InstanceOperation::Install,
+            ..
+        })
+    ));
+    drop(lock);
+
+    let mut instance = setup.instance_storage.get("survival").await.unwrap();
+    instance.install_stage = InstanceInstallStage::PackInstalling;
+    setup.instance_storage.upsert(&instance).await.unwrap();
+
+    let result = setup
+        .duplicate("Copy", DuplicateInstanceOptions::default())
+        .await;
+    assert!(matches!(
+        result,
+        Err(InstanceError::InstanceStillInstalling { .. })
+    ));
+
+    assert_eq!(setup.instance_storage.list().await.unwrap().len(), 1);
+    assert_eq!(setup.lock_service.get_operation("survival"), None);
+}
@@ -0,0 +1,156 @@
+use std::{io::Read, path::Path, sync::Arc};
+
+use crate::{
+    features::{
+        instance::{
+            app::{ExportMrpackOptions, ExportMrpackUseCase, ListContentUseCase},
+            infra::{FsInstanceStorage, FsPackStorage},
+            InstanceStorage, PackFile, PackFileDownload, PackStorage, MRPACK_INDEX_FILE,
+        },
+        minecraft::LoaderVersionResolver,
+        settings::LocationInfo,
+    },
+    shared::sha512_async,
+};
+
+use super::fixtures::{test_instance, UnavailableMetadataStorage};
+
+fn pack_file(file_name: &str, download_url: Option<&str>) -> PackFile {
+    PackFile {
+        file_name: file_name.to_owned(),
+        name: None,
+        hash: format!("{file_name}-sha1"),
+        download: download_url.map(|url| PackFileDownload {
+            hash: format!("{file_name}-sha1"),
+            url: url.to_owned(),
+        }),
+        option: None,
+        side: None,
+        update_provider: None,
+        update: None,
+    }
+}
+
+fn write_file(instance_dir: &Path, path: &str, content: &str) {
+    let path = instance_dir.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+fn read_archive(path: &Path) -> (serde_json::Value, Vec<String>) {
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+
+    let mut index = String::new();
+    archive
+        .by_name(MRPACK_INDEX_FILE)
+        .unwrap()
+        .read_to_string(&mut index)
+        .unwrap();
+
+    let mut names: Vec<String> = archive.file_names().map(ToOwned::to_owned).collect();
+    names.sort();
+
+    (serde_json::from_str(&index).unwrap(), names)
+}
+
+#[tokio::test]
+async fn test_export_mrpack_links_downloads_and_packs_overrides() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+    let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+    let pack_storage = Arc::new(FsPackStorage::new(location_info.clone()));
+
+    instance_storage
+        .upsert(&test_instance("survival"))
+        .await
+        .unwrap();
+
+    let instance_dir = location_info.instance_dir("survival");
+    write_file(&instance_dir, "mods/sodium.jar", "sodium");
+    write_file(&instance_dir, "mods/local.jar", "local");
+    write_file(&instance_dir, "mods/disabled.jar.disabled", "disabled");
+    write_file(&instance_dir, "config/sodium.json", "{}");
+    write_file(&instance_dir, "logs/latest.log", "log");
+
+    pack_storage
+        .update_pack_file(
+            "survival",
+            "mods/sodium.jar",
+            &pack_file("sodium.jar", Some("https://cdn.modrinth.com/sodium.jar")),
+        )
+        .await
+        .unwrap();
+    pack_storage
+        .update_pack_file(
+            "survival",
+            "mods/disabled.jar",
+            &pack_file(
+                "disabled.jar",
+                Some("https://cdn.modrinth.com/disabled.jar"),
+            ),
+        )
+        .await
+        .unwrap();
+
+    let output_path = dir.path().join("survival.mrpack");
+    ExportMrpackUseCase::new(
+        instance_storage,
+        pack_storage.clone(),
+        Arc::new(ListContentUseCase::new(pack_storage, location_info.clone())),
+        Arc::new(LoaderVersionResolver::new(Arc::new(
+            UnavailableMetadataStorage,
+        ))),
+        location_info,
+    )
+    .execute(
+        "survival".to_owned(),
+        ExportMrpackOptions {
+            output_path: output_path.clone(),
+            version_id: "1.0.0".to_owned(),
+            name: None,
+            summary: Some("Summary".to_owned()),
+            include_folders: vec!["config".to_owned()],
+        },
+    )
+    .await
+    .unwrap();
+
+    let (index, names) = read_archive(&output_path);
+
+    assert_eq!(index["formatVersion"], 1);
+    assert_eq!(index["game"], "minecraft");
+    assert_eq!(index["versionId"], "1.0.0");
+    assert_eq!(index["name"], "survival");
+    assert_eq!(index["summary"], "Summary");
+    assert_eq!(
+        index["dependencies"],
+        serde_json::json!({ "minecraft": "1.20.1" })
+    );
+
+    // Only enabled files with a download link are referenced, everything else is bundled
+    let files = index["files"].as_array().unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0]["path"], "mods/sodium.jar");
+    assert_eq!(files[0]["hashes"]["sha1"], "sodium.jar-sha1");
+    assert_eq!(
+        files[0]["hashes"]["sha512"],
+        sha512_async(b"sodium".to_vec()).await.unwrap()
+    );
+    assert_eq!(
+        files[0]["downloads"],
+        serde_json::json!(["https://cdn.modrinth.com/sodium.jar"])
+    );
+    assert_eq!(files[0]["fileSize"], 6);
+
+    assert_eq!(
+        names,
+        vec![
+            MRPACK_INDEX_FILE,
+            "overrides/config/sodium.json",
+            "overrides/mods/local.jar",
+        ]
+    );
+}
@@ -0,0 +1,191 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+
+use crate::{
+    features::{
+        instance::{
+            ContentFile, ContentInstallParams, ContentProvider, ContentSearchParams,
+            ContentSearchResult, ContentVersion, ContentVersionType, InstanceError,
+        },
+        settings::LocationInfo,
+    },
+    shared::replace_async,
+};
+
+/// Content provider owning every download url under `download_url_prefix`
+pub struct MockContentProvider {
+    download_url_prefix: String,
+    location_info: Option<Arc<LocationInfo>>,
+    versions: HashMap<String, Vec<ContentVersion>>,
+    failing_installs: HashSet<String>,
+}
+
+impl MockContentProvider {
+    pub fn new(download_url_prefix: &str) -> Self {
+        Self {
+            download_url_prefix: download_url_prefix.to_owned(),
+            location_info: None,
+            versions: HashMap::new(),
+            failing_installs: HashSet::new(),
+        }
+    }
+
+    /// Lets `install` write `<project id>-<version>.jar` into instance content folders
+    pub fn with_location_info(mut self, location_info: Arc<LocationInfo>) -> Self {
+        self.location_info = Some(location_info);
+        self
+    }
+
+    /// Compatible release versions of a project, best match first and each one
+    /// published a day after the next. Listing versions of any other project fails
+    pub fn with_versions(self, project_id: &str, versions: &[&str]) -> Self {
+        let versions: Vec<(&str, ContentVersionType, i64)> = versions
+            .iter()
+            .enumerate()
+            .map(|(index, version)| {
+                (
+                    *version,
+                    ContentVersionType::Release,
+                    (versions.len() - index) as i64,
+                )
+            })
+            .collect();
+
+        self.with_typed_versions(project_id, &versions)
+    }
+
+    /// Compatible versions of a project as `(version, type, day published)`, best match first
+    pub fn with_typed_versions(
+        mut self,
+        project_id: &str,
+        versions: &[(&str, ContentVersionType, i64)],
+    ) -> Self {
+        self.versions.insert(
+            project_id.to_owned(),
+            versions
+                .iter()
+                .map(|(version, version_type, day)| ContentVersion {
+                    id: (*version).to_owned(),
+                    name: format!("{project_id} {version}"),
+                    version_type: *version_type,
+                    date_published: DateTime::<Utc>::UNIX_EPOCH + Duration::days(*day),
+                })
+                .collect(),
+        );
+        self
+    }
+
+    pub fn with_failing_install(mut self, project_id: &str) -> Self {
+        self.failing_installs.insert(project_id.to_owned());
+        self
+    }
+}
+
+#[async_trait]
+impl ContentProvider for MockContentProvider {
+    fn get_name(&self) -> String {
+        "Mock".to_owned()
+    }
+
+    async fn search(
+        &self,
+        _search_content: &ContentSearchParams,
+    ) -> Result<ContentSearchResult, InstanceError> {
+        Err(InstanceError::ContentDownloadError(
+            "search is not supported".to_owned(),
+        ))
+    }
+
+    async fn install(
+        &self,
+        instance_id: &str,
+        install_params: &ContentInstallParams,
+    ) -> Result<ContentFile, InstanceError> {
+        let location_info = self.location_info.as_ref().ok_or_else(|| {
+            InstanceError::ContentDownloadError("install is not supported".to_owned())
+        })?;
+
+        let project_id = install_params
+            .provider_data
+            .as_ref()
+            .and_then(|data| data["project_id"].as_str())
+            .ok_or_else(|| InstanceError::ContentDownloadError("no project id".to_owned()))?;
+        let version = install_params
+            .content_version
+            .as_deref()
+            .ok_or_else(|| InstanceError::ContentDownloadError("no version".to_owned()))?;
+
+        if self.failing_installs.contains(project_id) {
+            return Err(InstanceError::ContentDownloadError(format!(
+                "failed to download {project_id} {version}"
+            )));
+        }
+
+        let filename = format!("{project_id}-{version}.jar");
+        let content_path = format!("{}/{}", install_params.content_type.get_folder(), filename);
+        let bytes = format!("{project_id} {version}").into_bytes();
+
+        replace_async(
+            location_info.instance_dir(instance_id).join(&content_path),
+            &bytes,
+        )
+        .await?;
+
+        Ok(ContentFile {
+            content_path: content_path.clone(),
+            content_type: install_params.content_type,
+            disabled: false,
+            filename: filename.clone(),
+            hash: String::new(),
+            instance_relative_path: content_path,
+            name: Some(project_id.to_owned()),
+            size: bytes.len() as u64,
+            update: Some(HashMap::from([(
+                install_params.provider.clone(),
+                json!({ "project_id": project_id, "version": version }),
+            )])),
+            download_url: Some(format!(
+                "{}/{}/{}/{}",
+                self.download_url_prefix, project_id, version, filename
+            )),
+        })
+    }
+
+    fn get_update_data_id_field(&self) -> String {
+        "project_id".to_owned()
+    }
+
+    fn get_update_data_version(&self, update_data: &serde_json::Value) -> Option<String> {
+        update_data["version"].as_str().map(ToOwned::to_owned)
+    }
+
+    async fn list_compatible_versions(
+        &self,
+        update_data: &serde_json::Value,
+        _game_version: &str,
+        _loader: &Option<String>,
+    ) -> Result<Vec<ContentVersion>, InstanceError> {
+        update_data["project_id"]
+            .as_str()
+            .and_then(|project_id| self.versions.get(project_id))
+            .cloned()
+            .ok_or_else(|| InstanceError::ContentDownloadError("unknown project".to_owned()))
+    }
+
+    fn get_update_data_by_download_url(&self, url: &str) -> Option<serde_json::Value> {
+        // `<prefix>/<project id>/<version>/<file name>`
+        let mut segments = url
+            .strip_prefix(&self.download_url_prefix)?
+            .trim_start_matches('/')
+            .split('/');
+        let (project_id, version) = (segments.next()?, segments.next()?);
+
+        Some(json!({ "project_id": project_id, "version": version }))
+    }
+}
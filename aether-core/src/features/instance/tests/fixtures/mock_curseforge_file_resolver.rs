@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use crate::features::instance::*;
+
+/// Serves CurseForge files from a local map instead of the API.
+#[derive(Default)]
+pub struct MockCurseForgeFileResolver {
+    files: HashMap<(u64, u64), CurseForgeFile>,
+}
+
+impl MockCurseForgeFileResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_file(
+        mut self,
+        project_id: u64,
+        file_id: u64,
+        file_name: &str,
+        content_type: ContentType,
+    ) -> Self {
+        self.files.insert(
+            (project_id, file_id),
+            CurseForgeFile {
+                file_name: file_name.to_string(),
+                name: Some(file_name.trim_end_matches(".jar").to_string()),
+                download_url: Some(format!(
+                    "https://edge.forgecdn.net/files/{file_id}/{file_name}"
+                )),
+                sha1: Some(format!("sha1-{file_id}")),
+                content_type,
+            },
+        );
+        self
+    }
+
+    pub fn with_blocked_file(mut self, project_id: u64, file_id: u64, file_name: &str) -> Self {
+        self.files.insert(
+            (project_id, file_id),
+            CurseForgeFile {
+                file_name: file_name.to_string(),
+                name: None,
+                download_url: None,
+                sha1: None,
+                content_type: ContentType::Mod,
+            },
+        );
+        self
+    }
+}
+
+#[async_trait]
+impl CurseForgeFileResolver for MockCurseForgeFileResolver {
+    async fn resolve(
+        &self,
+        project_id: u64,
+        file_id: u64,
+    ) -> Result<CurseForgeFile, InstanceError> {
+        self.files
+            .get(&(project_id, file_id))
+            .cloned()
+            .ok_or_else(|| {
+                InstanceError::ContentDownloadError(format!(
+                    "File {file_id} of project {project_id} not found"
+                ))
+            })
+    }
+}
@@ -0,0 +1,21 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::features::java::{Java, JavaDomainError, JavaInstallationService};
+
+/// Treats every path as a Java 17 installation
+#[derive(Default)]
+pub struct MockJavaInstallationService;
+
+#[async_trait]
+impl JavaInstallationService for MockJavaInstallationService {
+    async fn locate_java(&self, path: &Path) -> Result<Java, JavaDomainError> {
+        Ok(Java::new(
+            17,
+            "17.0.8".to_owned(),
+            "x86_64".to_owned(),
+            path.to_string_lossy().to_string(),
+        ))
+    }
+}
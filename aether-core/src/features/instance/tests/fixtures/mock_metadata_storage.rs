@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use serde_json::json;
+
+use crate::features::minecraft::{MetadataStorage, MinecraftDomainError, ModLoader};
+
+/// Metadata storage knowing only vanilla 1.20.1, the version of `test_instance`
+#[derive(Default)]
+pub struct MockMetadataStorage;
+
+#[async_trait]
+impl MetadataStorage for MockMetadataStorage {
+    async fn get_version_manifest(
+        &self,
+    ) -> Result<daedalus::minecraft::VersionManifest, MinecraftDomainError> {
+        Ok(serde_json::from_value(json!({
+            "latest": { "release": "1.20.1", "snapshot": "1.20.1" },
+            "versions": [{
+                "id": "1.20.1",
+                "type": "release",
+                "url": "https://mock.test/1.20.1.json",
+                "time": "2023-06-12T13:25:51Z",
+                "releaseTime": "2023-06-12T13:25:51Z",
+                "sha1": "0000000000000000000000000000000000000000",
+                "complianceLevel": 1
+            }]
+        }))
+        .unwrap())
+    }
+
+    async fn get_loader_version_manifest(
+        &self,
+        loader: ModLoader,
+    ) -> Result<daedalus::modded::Manifest, MinecraftDomainError> {
+        Err(MinecraftDomainError::VersionNotFound {
+            version: loader.as_str().to_owned(),
+        })
+    }
+}
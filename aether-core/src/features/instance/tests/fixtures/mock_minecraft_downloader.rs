@@ -0,0 +1,122 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use serde_json::json;
+
+use crate::features::{
+    events::ProgressBarId,
+    minecraft::{GameFileIssue, MinecraftDomainError, MinecraftDownloader},
+};
+
+use super::test_version_info;
+
+/// Minecraft downloader whose game files always have the given issues
+#[derive(Default)]
+pub struct MockMinecraftDownloader {
+    issues: Vec<GameFileIssue>,
+    failing_repair: bool,
+    version_info: serde_json::Value,
+    asset_hashes: Vec<String>,
+}
+
+impl MockMinecraftDownloader {
+    pub fn new(issues: Vec<GameFileIssue>) -> Self {
+        Self {
+            issues,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_failing_repair(mut self) -> Self {
+        self.failing_repair = true;
+        self
+    }
+
+    /// Fields merged into every version info, see `test_version_info`
+    pub fn with_version_info(mut self, extra: serde_json::Value) -> Self {
+        self.version_info = extra;
+        self
+    }
+
+    pub fn with_assets(mut self, hashes: &[&str]) -> Self {
+        self.asset_hashes = hashes.iter().map(|hash| (*hash).to_owned()).collect();
+        self
+    }
+}
+
+#[async_trait]
+impl MinecraftDownloader for MockMinecraftDownloader {
+    async fn download_minecraft(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+        _java_arch: &str,
+        _force: bool,
+        _minecraft_updated: bool,
+        _loading_bar: Option<&ProgressBarId>,
+    ) -> Result<(), MinecraftDomainError> {
+        Err(MinecraftDomainError::VersionNotFound {
+            version: version_info.id.clone(),
+        })
+    }
+
+    async fn get_version_info(
+        &self,
+        version: &daedalus::minecraft::Version,
+        _loader: Option<&daedalus::modded::LoaderVersion>,
+        _force: Option<bool>,
+        _loading_bar: Option<&ProgressBarId>,
+    ) -> Result<daedalus::minecraft::VersionInfo, MinecraftDomainError> {
+        Ok(test_version_info(&version.id, self.version_info.clone()))
+    }
+
+    async fn get_assets_index(
+        &self,
+        _version_info: &daedalus::minecraft::VersionInfo,
+    ) -> Result<daedalus::minecraft::AssetsIndex, MinecraftDomainError> {
+        let objects: serde_json::Map<String, serde_json::Value> = self
+            .asset_hashes
+            .iter()
+            .map(|hash| {
+                (
+                    format!("minecraft/{hash}"),
+                    json!({ "hash": hash, "size": 1 }),
+                )
+            })
+            .collect();
+
+        Ok(serde_json::from_value(json!({ "objects": objects })).unwrap())
+    }
+
+    async fn verify_minecraft(
+        &self,
+        _version_info: &daedalus::minecraft::VersionInfo,
+        _java_arch: &str,
+        _minecraft_updated: bool,
+    ) -> Result<Vec<GameFileIssue>, MinecraftDomainError> {
+        Ok(self.issues.clone())
+    }
+
+    async fn repair_minecraft(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+        _issues: &[GameFileIssue],
+    ) -> Result<(), MinecraftDomainError> {
+        if self.failing_repair {
+            return Err(MinecraftDomainError::VersionNotFound {
+                version: version_info.id.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn download_authlib_injector(
+        &self,
+        _force: bool,
+    ) -> Result<PathBuf, MinecraftDomainError> {
+        Err(MinecraftDomainError::PathNotFound {
+            path: PathBuf::new(),
+            entity_type: "authlib-injector".to_owned(),
+        })
+    }
+}
@@ -0,0 +1,29 @@
+mod mock_content_provider;
+mod mock_curseforge_file_resolver;
+mod mock_java_installation_service;
+mod mock_metadata_storage;
+mod mock_minecraft_downloader;
+mod noop_instance_watcher_service;
+mod test_create_instance_use_case;
+mod test_create_snapshot_use_case;
+mod test_instance;
+mod test_version_info;
+mod unavailable_jre_provider;
+mod unavailable_metadata_storage;
+mod unavailable_minecraft_downloader;
+mod zip_archive;
+
+pub use mock_content_provider::*;
+pub use mock_curseforge_file_resolver::*;
+pub use mock_java_installation_service::*;
+pub use mock_metadata_storage::*;
+pub use mock_minecraft_downloader::*;
+pub use noop_instance_watcher_service::*;
+pub use test_create_instance_use_case::*;
+pub use test_create_snapshot_use_case::*;
+pub use test_instance::*;
+pub use test_version_info::*;
+pub use unavailable_jre_provider::*;
+pub use unavailable_metadata_storage::*;
+pub use unavailable_minecraft_downloader::*;
+pub use zip_archive::*;
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::features::instance::{InstanceError, InstanceWatcherService};
+
+#[derive(Default)]
+pub struct NoopInstanceWatcherService;
+
+#[async_trait]
+impl InstanceWatcherService for NoopInstanceWatcherService {
+    async fn watch_instances(&self) -> Result<(), InstanceError> {
+        Ok(())
+    }
+
+    async fn watch_instance(&self, _instance_id: &str) -> Result<(), InstanceError> {
+        Ok(())
+    }
+
+    async fn unwatch_instance(&self, _instance_id: &str) -> Result<(), InstanceError> {
+        Ok(())
+    }
+}
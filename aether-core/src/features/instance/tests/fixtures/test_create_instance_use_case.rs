@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use crate::features::{
+    events::{
+        infra::InMemoryProgressBarStorage, tests::fixtures::MockEventEmitter, ProgressServiceImpl,
+    },
+    instance::{
+        app::{CreateInstanceUseCase, InstallInstanceUseCase},
+        infra::FsInstanceStorage,
+        InstanceLockService,
+    },
+    java::{
+        app::{GetJavaUseCase, InstallJavaUseCase},
+        infra::{FsJavaInstallationService, FsJavaStorage},
+    },
+    minecraft::{
+        app::{GetVersionManifestUseCase, InstallMinecraftUseCase},
+        LoaderVersionResolver,
+    },
+    settings::LocationInfo,
+};
+
+use super::{
+    NoopInstanceWatcherService, UnavailableJreProvider, UnavailableMetadataStorage,
+    UnavailableMinecraftDownloader,
+};
+
+pub type TestProgressService = ProgressServiceImpl<MockEventEmitter, InMemoryProgressBarStorage>;
+
+pub type TestCreateInstanceUseCase = CreateInstanceUseCase<
+    FsInstanceStorage,
+    UnavailableMetadataStorage,
+    MockEventEmitter,
+    UnavailableMinecraftDownloader,
+    TestProgressService,
+    NoopInstanceWatcherService,
+    FsJavaInstallationService,
+    FsJavaStorage,
+    UnavailableJreProvider,
+>;
+
+/// Creates vanilla instances without installing them, any install attempt fails
+pub fn test_create_instance_use_case(
+    instance_storage: Arc<FsInstanceStorage>,
+    location_info: Arc<LocationInfo>,
+) -> TestCreateInstanceUseCase {
+    let event_emitter = Arc::new(MockEventEmitter::new());
+    let progress_service = Arc::new(ProgressServiceImpl::new(
+        event_emitter.clone(),
+        Arc::new(InMemoryProgressBarStorage::default()),
+    ));
+    let metadata_storage = Arc::new(UnavailableMetadataStorage);
+    let loader_version_resolver = Arc::new(LoaderVersionResolver::new(metadata_storage.clone()));
+    let java_storage = Arc::new(FsJavaStorage::new(&location_info.java_dir()));
+
+    let install_minecraft_use_case = Arc::new(InstallMinecraftUseCase::new(
+        progress_service.clone(),
+        loader_version_resolver.clone(),
+        Arc::new(GetVersionManifestUseCase::new(metadata_storage)),
+        location_info.clone(),
+        UnavailableMinecraftDownloader,
+        FsJavaInstallationService,
+        Arc::new(GetJavaUseCase::new(
+            java_storage.clone(),
+            FsJavaInstallationService,
+        )),
+        Arc::new(InstallJavaUseCase::new(
+            java_storage,
+            FsJavaInstallationService,
+            Arc::new(UnavailableJreProvider),
+            location_info.clone(),
+        )),
+    ));
+
+    let install_instance_use_case = Arc::new(InstallInstanceUseCase::new(
+        instance_storage.clone(),
+        install_minecraft_use_case,
+        progress_service,
+        location_info.clone(),
+        Arc::new(InstanceLockService::new()),
+    ));
+
+    CreateInstanceUseCase::new(
+        instance_storage,
+        loader_version_resolver,
+        install_instance_use_case,
+        location_info,
+        event_emitter,
+        Arc::new(NoopInstanceWatcherService),
+    )
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use crate::features::{
+    events::{
+        infra::InMemoryProgressBarStorage, tests::fixtures::MockEventEmitter, ProgressServiceImpl,
+    },
+    instance::{
+        app::{ApplySnapshotRetentionUseCase, CreateSnapshotUseCase},
+        infra::{FsInstanceStorage, FsSnapshotStorage},
+        InstanceLockService,
+    },
+    settings::LocationInfo,
+};
+
+use super::TestProgressService;
+
+pub type TestCreateSnapshotUseCase =
+    CreateSnapshotUseCase<FsInstanceStorage, FsSnapshotStorage, TestProgressService>;
+
+pub fn test_create_snapshot_use_case(
+    instance_storage: Arc<FsInstanceStorage>,
+    location_info: Arc<LocationInfo>,
+) -> TestCreateSnapshotUseCase {
+    let snapshot_storage = Arc::new(FsSnapshotStorage::new(location_info.clone()));
+    let lock_service = Arc::new(InstanceLockService::new());
+
+    CreateSnapshotUseCase::new(
+        instance_storage.clone(),
+        snapshot_storage.clone(),
+        Arc::new(ApplySnapshotRetentionUseCase::new(
+            instance_storage,
+            snapshot_storage,
+            lock_service.clone(),
+        )),
+        Arc::new(ProgressServiceImpl::new(
+            Arc::new(MockEventEmitter::new()),
+            Arc::new(InMemoryProgressBarStorage::default()),
+        )),
+        location_info,
+        lock_service,
+    )
+}
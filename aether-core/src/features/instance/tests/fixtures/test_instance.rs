@@ -0,0 +1,40 @@
+use chrono::{TimeZone, Utc};
+
+use crate::features::{
+    instance::{Instance, InstanceInstallStage},
+    minecraft::ModLoader,
+    settings::Hooks,
+};
+
+/// Installed vanilla 1.20.1 instance whose directory name equals the id
+pub fn test_instance(id: &str) -> Instance {
+    Instance {
+        id: id.to_owned(),
+        name: id.to_owned(),
+        icon_path: None,
+        group: None,
+        tags: Vec::new(),
+        favorite: false,
+        install_stage: InstanceInstallStage::Installed,
+        game_version: "1.20.1".to_owned(),
+        loader: ModLoader::Vanilla,
+        loader_version: None,
+        java_path: None,
+        launch_args: None,
+        env_vars: None,
+        memory: None,
+        force_fullscreen: None,
+        game_resolution: None,
+        game_options: None,
+        account_id: None,
+        created: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        modified: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        last_played: None,
+        time_played: 0,
+        recent_time_played: 0,
+        hooks: Hooks::default(),
+        pack_info: None,
+        snapshot_policy: None,
+        shared_folders: Vec::new(),
+    }
+}
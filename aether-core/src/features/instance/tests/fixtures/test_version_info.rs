@@ -0,0 +1,29 @@
+use serde_json::json;
+
+/// Version info without libraries, `extra` fields are added on top of it
+pub fn test_version_info(id: &str, extra: serde_json::Value) -> daedalus::minecraft::VersionInfo {
+    let mut version_info = json!({
+        "assetIndex": {
+            "id": "5",
+            "sha1": "0000000000000000000000000000000000000000",
+            "size": 0,
+            "totalSize": 0,
+            "url": "https://mock.test/assets/5.json"
+        },
+        "assets": "5",
+        "downloads": {},
+        "id": id,
+        "libraries": [],
+        "mainClass": "net.minecraft.client.main.Main",
+        "minimumLauncherVersion": 21,
+        "releaseTime": "2023-06-12T13:25:51Z",
+        "time": "2023-06-12T13:25:51Z",
+        "type": "release"
+    });
+
+    if let (Some(version_info), Some(extra)) = (version_info.as_object_mut(), extra.as_object()) {
+        version_info.extend(extra.clone());
+    }
+
+    serde_json::from_value(version_info).unwrap()
+}
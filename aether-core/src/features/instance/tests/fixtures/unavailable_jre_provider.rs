@@ -0,0 +1,16 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+use crate::features::java::{JavaDomainError, JreProvider};
+
+/// JRE provider for tests that never install Java
+#[derive(Default)]
+pub struct UnavailableJreProvider;
+
+#[async_trait]
+impl JreProvider for UnavailableJreProvider {
+    async fn install(&self, version: u32, _install_dir: &Path) -> Result<PathBuf, JavaDomainError> {
+        Err(JavaDomainError::NotFound { version })
+    }
+}
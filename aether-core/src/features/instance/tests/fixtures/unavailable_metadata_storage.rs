@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+
+use crate::features::minecraft::{MetadataStorage, MinecraftDomainError, ModLoader};
+
+/// Metadata storage for tests that never need to resolve loader versions
+#[derive(Default)]
+pub struct UnavailableMetadataStorage;
+
+#[async_trait]
+impl MetadataStorage for UnavailableMetadataStorage {
+    async fn get_version_manifest(
+        &self,
+    ) -> Result<daedalus::minecraft::VersionManifest, MinecraftDomainError> {
+        Err(MinecraftDomainError::VersionNotFound {
+            version: "manifest".to_owned(),
+        })
+    }
+
+    async fn get_loader_version_manifest(
+        &self,
+        loader: ModLoader,
+    ) -> Result<daedalus::modded::Manifest, MinecraftDomainError> {
+        Err(MinecraftDomainError::VersionNotFound {
+            version: loader.as_str().to_owned(),
+        })
+    }
+}
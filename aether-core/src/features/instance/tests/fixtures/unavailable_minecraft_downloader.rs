@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use crate::features::{
+    events::ProgressBarId,
+    minecraft::{GameFileIssue, MinecraftDomainError, MinecraftDownloader},
+};
+
+/// Minecraft downloader for tests that never install game files
+#[derive(Default)]
+pub struct UnavailableMinecraftDownloader;
+
+#[async_trait]
+impl MinecraftDownloader for UnavailableMinecraftDownloader {
+    async fn download_minecraft(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+        _java_arch: &str,
+        _force: bool,
+        _minecraft_updated: bool,
+        _loading_bar: Option<&ProgressBarId>,
+    ) -> Result<(), MinecraftDomainError> {
+        Err(MinecraftDomainError::VersionNotFound {
+            version: version_info.id.clone(),
+        })
+    }
+
+    async fn get_version_info(
+        &self,
+        version: &daedalus::minecraft::Version,
+        _loader: Option<&daedalus::modded::LoaderVersion>,
+        _force: Option<bool>,
+        _loading_bar: Option<&ProgressBarId>,
+    ) -> Result<daedalus::minecraft::VersionInfo, MinecraftDomainError> {
+        Err(MinecraftDomainError::VersionNotFound {
+            version: version.id.clone(),
+        })
+    }
+
+    async fn get_assets_index(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+    ) -> Result<daedalus::minecraft::AssetsIndex, MinecraftDomainError> {
+        Err(MinecraftDomainError::VersionNotFound {
+            version: version_info.id.clone(),
+        })
+    }
+
+    async fn verify_minecraft(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+        _java_arch: &str,
+        _minecraft_updated: bool,
+    ) -> Result<Vec<GameFileIssue>, MinecraftDomainError> {
+        Err(MinecraftDomainError::VersionNotFound {
+            version: version_info.id.clone(),
+        })
+    }
+
+    async fn repair_minecraft(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+        _issues: &[GameFileIssue],
+    ) -> Result<(), MinecraftDomainError> {
+        Err(MinecraftDomainError::VersionNotFound {
+            version: version_info.id.clone(),
+        })
+    }
+
+    async fn download_authlib_injector(
+        &self,
+        _force: bool,
+    ) -> Result<PathBuf, MinecraftDomainError> {
+        Err(MinecraftDomainError::PathNotFound {
+            path: PathBuf::new(),
+            entity_type: "authlib-injector".to_owned(),
+        })
+    }
+}
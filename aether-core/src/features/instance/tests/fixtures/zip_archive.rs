@@ -0,0 +1,14 @@
+use std::{io::Write, path::Path};
+
+use zip::{write::SimpleFileOptions, ZipWriter};
+
+pub fn write_zip(path: &Path, entries: &[(&str, &[u8])]) {
+    let mut zip = ZipWriter::new(std::fs::File::create(path).unwrap());
+
+    for (name, content) in entries {
+        zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+        zip.write_all(content).unwrap();
+    }
+
+    zip.finish().unwrap();
+}
@@ -0,0 +1,121 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use serde_json::json;
+
+use crate::{
+    features::{
+        instance::{
+            app::ImportCurseForgeUseCase,
+            infra::{FsInstanceStorage, FsPackStorage},
+            CurseForgeManifestResolver, InstanceError, InstanceInstallStage, InstanceLockService,
+            InstanceStorage, CURSEFORGE_MANIFEST_FILE,
+        },
+        settings::LocationInfo,
+    },
+    libs::request_client::tests::fixtures::StubHttpServer,
+};
+
+use super::fixtures::{
+    test_create_instance_use_case, write_zip, MockCurseForgeFileResolver,
+    NoopInstanceWatcherService,
+};
+
+fn write_manifest(dir: &Path, overrides: &str, entries: &[(&str, &[u8])]) -> PathBuf {
+    let manifest = json!({
+        "minecraft": { "version": "1.20.1", "modLoaders": [] },
+        "manifestType": "minecraftModpack",
+        "manifestVersion": 1,
+        "name": "Test Pack",
+        "files": [],
+        "overrides": overrides,
+    })
+    .to_string();
+
+    let mut archive_entries = vec![(CURSEFORGE_MANIFEST_FILE, manifest.as_bytes())];
+    archive_entries.extend_from_slice(entries);
+
+    let path = dir.join("pack.zip");
+    write_zip(&path, &archive_entries);
+    path
+}
+
+async fn import(
+    location_info: Arc<LocationInfo>,
+    path: PathBuf,
+) -> (Result<String, InstanceError>, Arc<FsInstanceStorage>) {
+    let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+    let server = StubHttpServer::start().await;
+
+    let result = ImportCurseForgeUseCase::new(
+        instance_storage.clone(),
+        Arc::new(test_create_instance_use_case(
+            instance_storage.clone(),
+            location_info.clone(),
+        )),
+        Arc::new(NoopInstanceWatcherService),
+        Arc::new(FsPackStorage::new(location_info.clone())),
+        Arc::new(CurseForgeManifestResolver::new(Arc::new(
+            MockCurseForgeFileResolver::new(),
+        ))),
+        server.client(),
+        location_info,
+        Arc::new(InstanceLockService::new()),
+    )
+    .execute(path)
+    .await;
+
+    (result, instance_storage)
+}
+
+#[tokio::test]
+async fn test_import_curseforge_extracts_overrides() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().join("data"),
+        dir.path().join("data"),
+    ));
+    let path = write_manifest(
+        dir.path(),
+        "custom-overrides",
+        &[
+            ("custom-overrides/config/options.txt", b"options"),
+            ("overrides/config/ignored.txt", b"ignored"),
+        ],
+    );
+
+    let (result, instance_storage) = import(location_info.clone(), path).await;
+    let instance_id = result.unwrap();
+
+    let instance = instance_storage.get(&instance_id).await.unwrap();
+    assert_eq!(instance.install_stage, InstanceInstallStage::NotInstalled);
+
+    let instance_dir = location_info.instance_dir(&instance_id);
+    assert_eq!(
+        std::fs::read(instance_dir.join("config/options.txt")).unwrap(),
+        b"options"
+    );
+    assert!(!instance_dir.join("config/ignored.txt").exists());
+}
+
+#[tokio::test]
+async fn test_import_curseforge_rejects_pack_root_as_overrides() {
+    for overrides in ["", ".", "./", "../overrides"] {
+        let dir = tempfile::tempdir().unwrap();
+        let location_info = Arc::new(LocationInfo::new(
+            dir.path().join("data"),
+            dir.path().join("data"),
+        ));
+        let path = write_manifest(dir.path(), overrides, &[("config/options.txt", b"options")]);
+
+        let (result, instance_storage) = import(location_info, path).await;
+
+        assert!(
+            matches!(result, Err(InstanceError::InvalidModpack { .. })),
+            "overrides \"{overrides}\" must be rejected"
+        );
+        assert!(instance_storage.list().await.unwrap().is_empty());
+    }
+}
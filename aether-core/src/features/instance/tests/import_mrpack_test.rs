@@ -0,0 +1,278 @@
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+use serde_json::json;
+
+use crate::{
+    features::{
+        instance::{
+            app::ImportMrpackUseCase,
+            infra::{FsInstanceStorage, FsPackStorage},
+            ContentProviderRegistry, InstanceError, InstanceInstallStage, InstanceLockService,
+            InstanceStorage, PackStorage, MRPACK_INDEX_FILE,
+        },
+        minecraft::ModLoader,
+        settings::LocationInfo,
+    },
+    libs::request_client::tests::fixtures::StubHttpServer,
+    shared::sha1_async,
+};
+
+use super::fixtures::{
+    test_create_instance_use_case, write_zip, MockContentProvider, NoopInstanceWatcherService,
+};
+
+const SODIUM: &[u8] = b"sodium";
+const SHADERS: &[u8] = b"shaders";
+
+struct Setup {
+    _dir: tempfile::TempDir,
+    location_info: Arc<LocationInfo>,
+    instance_storage: Arc<FsInstanceStorage>,
+    pack_storage: Arc<FsPackStorage>,
+    server: StubHttpServer,
+}
+
+impl Setup {
+    async fn new() -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let location_info = Arc::new(LocationInfo::new(
+            dir.path().to_path_buf(),
+            dir.path().to_path_buf(),
+        ));
+
+        Self {
+            instance_storage: Arc::new(FsInstanceStorage::new(location_info.clone())),
+            pack_storage: Arc::new(FsPackStorage::new(location_info.clone())),
+            location_info,
+            server: StubHttpServer::start().await,
+            _dir: dir,
+        }
+    }
+
+    fn download_url(&self, path: &str) -> String {
+        format!("{}{}", self.server.url(), path)
+    }
+
+    fn write_mrpack(&self, files: serde_json::Value, overrides: &[(&str, &[u8])]) -> PathBuf {
+        let index = json!({
+            "formatVersion": 1,
+            "game": "minecraft",
+            "versionId": "1.0.0",
+            "name": "Test Pack",
+            "files": files,
+            "dependencies": { "minecraft": "1.20.1" },
+        })
+        .to_string();
+
+        let mut entries = vec![(MRPACK_INDEX_FILE, index.as_bytes())];
+        entries.extend_from_slice(overrides);
+
+        let path = self.location_info.cache_dir().join("pack.mrpack");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        write_zip(&path, &entries);
+        path
+    }
+
+    async fn import(&self, path: PathBuf) -> Result<String, InstanceError> {
+        let provider_registry = ContentProviderRegistry::new(HashMap::from([(
+            "mock".to_owned(),
+            MockContentProvider::new(&self.download_url("/data")),
+        )]));
+
+        ImportMrpackUseCase::new(
+            self.instance_storage.clone(),
+            Arc::new(test_create_instance_use_case(
+                self.instance_storage.clone(),
+                self.location_info.clone(),
+            )),
+            Arc::new(NoopInstanceWatcherService),
+            self.pack_storage.clone(),
+            Arc::new(provider_registry),
+            self.server.client(),
+            self.location_info.clone(),
+            Arc::new(InstanceLockService::new()),
+        )
+        .execute(path)
+        .await
+    }
+}
+
+async fn sha1(bytes: &[u8]) -> String {
+    sha1_async(bytes.to_vec()).await.unwrap()
+}
+
+fn read_instance_file(location_info: &LocationInfo, instance_id: &str, path: &str) -> Vec<u8> {
+    std::fs::read(location_info.instance_dir(instance_id).join(path)).unwrap()
+}
+
+#[tokio::test]
+async fn test_import_mrpack_downloads_files_and_extracts_overrides() {
+    let setup = Setup::new().await;
+    setup
+        .server
+        .respond("/data/sodium/v1/sodium.jar", 200, SODIUM);
+    setup
+        .server
+        .respond("/mirror/shaders.zip", 200, SHADERS.to_vec());
+
+    let path = setup.write_mrpack(
+        json!([
+            {
+                "path": "mods/sodium.jar",
+                "hashes": { "sha1": sha1(SODIUM).await },
+                "downloads": [setup.download_url("/data/sodium/v1/sodium.jar")],
+                "fileSize": SODIUM.len(),
+            },
+            {
+                "path": "shaderpacks/shaders.zip",
+                "hashes": { "sha1": sha1(SHADERS).await },
+                "env": { "client": "optional", "server": "unsupported" },
+                // First mirror is down, the next one is used
+                "downloads": [
+                    setup.download_url("/missing/shaders.zip"),
+                    setup.download_url("/mirror/shaders.zip"),
+                ],
+                "fileSize": SHADERS.len(),
+            },
+            {
+                "path": "mods/server-only.jar",
+                "hashes": { "sha1": "unused" },
+                "env": { "client": "unsupported", "server": "required" },
+                "downloads": [setup.download_url("/data/server/v1/server-only.jar")],
+                "fileSize": 1,
+            },
+        ]),
+        &[
+            ("overrides/config/options.txt", b"common"),
+            ("overrides/config/common.txt", b"common"),
+            ("client-overrides/config/options.txt", b"client"),
+            ("server-overrides/config/server.txt", b"server"),
+        ],
+    );
+
+    let instance_id = setup.import(path).await.unwrap();
+    let location_info = &setup.location_info;
+
+    let instance = setup.instance_storage.get(&instance_id).await.unwrap();
+    assert_eq!(instance.name, "Test Pack");
+    assert_eq!(instance.game_version, "1.20.1");
+    assert_eq!(instance.loader, ModLoader::Vanilla);
+    assert_eq!(instance.install_stage, InstanceInstallStage::NotInstalled);
+
+    assert_eq!(
+        read_instance_file(location_info, &instance_id, "mods/sodium.jar"),
+        SODIUM
+    );
+    assert_eq!(
+        read_instance_file(location_info, &instance_id, "shaderpacks/shaders.zip"),
+        SHADERS
+    );
+    assert!(!location_info
+        .instance_dir(&instance_id)
+        .join("mods/server-only.jar")
+        .exists());
+    assert!(setup
+        .server
+        .requests("/data/server/v1/server-only.jar")
+        .is_empty());
+
+    // Client overrides take precedence, server overrides are skipped
+    assert_eq!(
+        read_instance_file(location_info, &instance_id, "config/options.txt"),
+        b"client"
+    );
+    assert_eq!(
+        read_instance_file(location_info, &instance_id, "config/common.txt"),
+        b"common"
+    );
+    assert!(!location_info
+        .instance_dir(&instance_id)
+        .join("config/server.txt")
+        .exists());
+
+    let sodium = setup
+        .pack_storage
+        .get_pack_file(&instance_id, "mods/sodium.jar")
+        .await
+        .unwrap();
+    assert_eq!(sodium.hash, sha1(SODIUM).await);
+    assert_eq!(
+        sodium.download.unwrap().url,
+        setup.download_url("/data/sodium/v1/sodium.jar")
+    );
+    assert_eq!(sodium.update_provider.as_deref(), Some("mock"));
+    assert_eq!(
+        sodium.update.unwrap()["mock"],
+        json!({ "project_id": "sodium", "version": "v1" })
+    );
+    assert!(sodium.side.is_none());
+    assert!(sodium.option.is_none());
+
+    let shaders = setup
+        .pack_storage
+        .get_pack_file(&instance_id, "shaderpacks/shaders.zip")
+        .await
+        .unwrap();
+    assert_eq!(
+        shaders.download.unwrap().url,
+        setup.download_url("/mirror/shaders.zip")
+    );
+    assert!(shaders.update_provider.is_none());
+    assert_eq!(shaders.side.as_deref(), Some("client"));
+    assert!(shaders.option.is_some_and(|option| option.optional));
+}
+
+#[tokio::test]
+async fn test_import_mrpack_removes_instance_when_download_fails() {
+    let setup = Setup::new().await;
+    setup
+        .server
+        .respond("/data/sodium/v1/sodium.jar", 200, b"tampered".to_vec());
+
+    let path = setup.write_mrpack(
+        json!([{
+            "path": "mods/sodium.jar",
+            "hashes": { "sha1": sha1(SODIUM).await },
+            "downloads": [setup.download_url("/data/sodium/v1/sodium.jar")],
+            "fileSize": SODIUM.len(),
+        }]),
+        &[],
+    );
+
+    let result = setup.import(path).await;
+
+    assert!(matches!(
+        result,
+        Err(InstanceError::ContentDownloadError(_))
+    ));
+    assert!(setup.instance_storage.list().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_import_mrpack_rejects_paths_outside_instance() {
+    let setup = Setup::new().await;
+
+    let path = setup.write_mrpack(
+        json!([{
+            "path": "../escaped.jar",
+            "hashes": { "sha1": sha1(SODIUM).await },
+            "downloads": [setup.download_url("/data/sodium/v1/sodium.jar")],
+            "fileSize": SODIUM.len(),
+        }]),
+        &[],
+    );
+
+    let result = setup.import(path).await;
+
+    assert!(matches!(result, Err(InstanceError::InvalidModpack { .. })));
+    assert!(setup.instance_storage.list().await.unwrap().is_empty());
+    assert!(setup
+        .server
+        .requests("/data/sodium/v1/sodium.jar")
+        .is_empty());
+    assert!(!setup
+        .location_info
+        .instances_dir()
+        .join("escaped.jar")
+        .exists());
+}
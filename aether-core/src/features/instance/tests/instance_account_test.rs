@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::features::{
+    auth::{
+        tests::fixtures::{CredentialsBuilder, MockCredentialsStorage},
+        CredentialsStorage,
+    },
+    instance::{
+        app::{resolve_instance_account, EditInstance, EditInstanceUseCase},
+        infra::FsInstanceStorage,
+        InstanceError, InstanceLockService, InstanceStorage,
+    },
+    settings::LocationInfo,
+};
+
+use super::fixtures::test_instance;
+
+#[tokio::test]
+async fn test_instance_without_pinned_account_uses_active_account() {
+    let storage = MockCredentialsStorage::new();
+    storage
+        .upsert(CredentialsBuilder::new("Inactive").build())
+        .await
+        .unwrap();
+    let active = storage
+        .upsert(CredentialsBuilder::new("Active").with_active(true).build())
+        .await
+        .unwrap();
+
+    let account = resolve_instance_account(&storage, &test_instance("survival"))
+        .await
+        .unwrap();
+
+    assert_eq!(account.id(), active.id());
+}
+
+#[tokio::test]
+async fn test_pinned_account_is_used_even_when_inactive() {
+    let storage = MockCredentialsStorage::new();
+    storage
+        .upsert(CredentialsBuilder::new("Active").with_active(true).build())
+        .await
+        .unwrap();
+    let pinned = storage
+        .upsert(CredentialsBuilder::new("Pinned").build())
+        .await
+        .unwrap();
+
+    let mut instance = test_instance("survival");
+    instance.account_id = Some(pinned.id());
+
+    let account = resolve_instance_account(&storage, &instance).await.unwrap();
+
+    assert_eq!(account.id(), pinned.id());
+    assert!(!account.is_active());
+}
+
+#[tokio::test]
+async fn test_removed_pinned_account_is_reported() {
+    let storage = MockCredentialsStorage::new();
+    storage
+        .upsert(CredentialsBuilder::new("Active").with_active(true).build())
+        .await
+        .unwrap();
+
+    let removed_id = Uuid::new_v4();
+    let mut instance = test_instance("survival");
+    instance.account_id = Some(removed_id);
+
+    let result = resolve_instance_account(&storage, &instance).await;
+
+    assert!(matches!(
+        result,
+        Err(InstanceError::PinnedAccountNotFound { instance_id, account_id })
+            if instance_id == "survival" && account_id == removed_id
+    ));
+}
+
+#[tokio::test]
+async fn test_edit_instance_pins_and_clears_account() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+    let instance_storage = Arc::new(FsInstanceStorage::new(location_info));
+    instance_storage
+        .upsert(&test_instance("survival"))
+        .await
+        .unwrap();
+
+    let use_case = EditInstanceUseCase::new(
+        instance_storage.clone(),
+        Arc::new(InstanceLockService::new()),
+    );
+    let account_id = Uuid::new_v4();
+
+    let edit: EditInstance = serde_json::from_value(json!({ "accountId": account_id })).unwrap();
+    use_case.execute("survival".to_owned(), edit).await.unwrap();
+    assert_eq!(
+        instance_storage.get("survival").await.unwrap().account_id,
+        Some(account_id)
+    );
+
+    // Omitted field keeps the pinned account
+    let edit: EditInstance = serde_json::from_value(json!({ "name": "Renamed" })).unwrap();
+    use_case.execute("survival".to_owned(), edit).await.unwrap();
+    assert_eq!(
+        instance_storage.get("survival").await.unwrap().account_id,
+        Some(account_id)
+    );
+
+    let edit: EditInstance = serde_json::from_value(json!({ "accountId": null })).unwrap();
+    use_case.execute("survival".to_owned(), edit).await.unwrap();
+    assert_eq!(
+        instance_storage.get("survival").await.unwrap().account_id,
+        None
+    );
+}
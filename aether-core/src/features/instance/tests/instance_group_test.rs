@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use crate::features::{
+    instance::{
+        app::{RemoveGroupUseCase, RenameGroupUseCase},
+        infra::FsInstanceStorage,
+        InstanceError, InstanceStorage, InstanceStorageExt,
+    },
+    settings::LocationInfo,
+};
+
+use super::fixtures::test_instance;
+
+async fn setup() -> (tempfile::TempDir, Arc<FsInstanceStorage>) {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+    let instance_storage = Arc::new(FsInstanceStorage::new(location_info));
+
+    for (id, group) in [
+        ("survival", Some("Friends")),
+        ("creative", Some("Friends")),
+        ("modpack", Some("Packs")),
+        ("vanilla", None),
+    ] {
+        let mut instance = test_instance(id);
+        instance.group = group.map(ToOwned::to_owned);
+        instance_storage.upsert(&instance).await.unwrap();
+    }
+
+    (dir, instance_storage)
+}
+
+async fn group_of(instance_storage: &FsInstanceStorage, id: &str) -> Option<String> {
+    instance_storage.get(id).await.unwrap().group
+}
+
+#[tokio::test]
+async fn test_rename_group_moves_only_members() {
+    let (_dir, instance_storage) = setup().await;
+
+    let mut updated = RenameGroupUseCase::new(instance_storage.clone())
+        .execute("Friends".to_owned(), " Server ".to_owned())
+        .await
+        .unwrap();
+    updated.sort();
+
+    assert_eq!(updated, vec!["creative", "survival"]);
+    assert_eq!(
+        group_of(&instance_storage, "survival").await.as_deref(),
+        Some("Server")
+    );
+    assert_eq!(
+        group_of(&instance_storage, "creative").await.as_deref(),
+        Some("Server")
+    );
+    assert_eq!(
+        group_of(&instance_storage, "modpack").await.as_deref(),
+        Some("Packs")
+    );
+}
+
+#[tokio::test]
+async fn test_remove_group_keeps_instances() {
+    let (_dir, instance_storage) = setup().await;
+
+    RemoveGroupUseCase::new(instance_storage.clone())
+        .execute("Friends".to_owned())
+        .await
+        .unwrap();
+
+    assert_eq!(instance_storage.list().await.unwrap().len(), 4);
+    assert_eq!(group_of(&instance_storage, "survival").await, None);
+    assert_eq!(group_of(&instance_storage, "creative").await, None);
+    assert_eq!(
+        group_of(&instance_storage, "modpack").await.as_deref(),
+        Some("Packs")
+    );
+
+    let result = RemoveGroupUseCase::new(instance_storage)
+        .execute("Friends".to_owned())
+        .await;
+    assert!(matches!(result, Err(InstanceError::GroupNotFound { .. })));
+}
+
+#[tokio::test]
+async fn test_upsert_all_with_reverts_updated_instances_on_failure() {
+    let (_dir, instance_storage) = setup().await;
+
+    let ids = ["survival", "missing", "creative"].map(ToOwned::to_owned);
+    let result = instance_storage
+        .upsert_all_with(
+            &ids,
+            |instance| {
+                instance.name = "Renamed".to_owned();
+                Ok(())
+            },
+            |instance| {
+                instance.name = instance.id.clone();
+                Ok(())
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        instance_storage.get("survival").await.unwrap().name,
+        "survival"
+    );
+    assert_eq!(
+        instance_storage.get("creative").await.unwrap().name,
+        "creative"
+    );
+}
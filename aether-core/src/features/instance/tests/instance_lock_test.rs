@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use crate::features::instance::{InstanceError, InstanceLockService, InstanceOperation};
+
+#[test]
+fn test_conflicting_lock_names_operation() {
+    let service = InstanceLockService::new();
+    let _lock = service
+        .try_lock("instance", InstanceOperation::Install)
+        .unwrap();
+
+    let err = service
+        .try_lock("instance", InstanceOperation::Launch)
+        .err()
+        .unwrap();
+
+    assert!(matches!(
+        err,
+        InstanceError::InstanceBusy {
+            operation: InstanceOperation::Install,
+            ..
+        }
+    ));
+    assert!(service.try_lock("other", InstanceOperation::Launch).is_ok());
+}
+
+#[test]
+fn test_lock_released_on_drop() {
+    let service = InstanceLockService::new();
+
+    let lock = service
+        .try_lock("instance", InstanceOperation::Update)
+        .unwrap();
+    assert_eq!(
+        service.get_operation("instance"),
+        Some(InstanceOperation::Update)
+    );
+
+    drop(lock);
+    assert_eq!(service.get_operation("instance"), None);
+    assert!(service
+        .try_lock("instance", InstanceOperation::Launch)
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_lock_released_on_panic() {
+    let service = Arc::new(InstanceLockService::new());
+
+    let task_service = service.clone();
+    let result = tokio::spawn(async move {
+        let _lock = task_service
+            .try_lock("instance", InstanceOperation::InstallContent)
+            .unwrap();
+        panic!("install failed");
+    })
+    .await;
+
+    assert!(result.is_err());
+    assert_eq!(service.get_operation("instance"), None);
+}
+
+#[test]
+fn test_running_instance_blocks_file_changes() {
+    let service = InstanceLockService::new();
+    let running = service.mark_running("instance");
+
+    assert_eq!(
+        service.get_operation("instance"),
+        Some(InstanceOperation::Running)
+    );
+
+    for operation in [
+        InstanceOperation::Install,
+        InstanceOperation::Update,
+        InstanceOperation::InstallContent,
+        InstanceOperation::RestoreSnapshot,
+        InstanceOperation::Repair,
+        InstanceOperation::ChangeSharedFolder,
+    ] {
+        let err = service.try_lock("instance", operation).err().unwrap();
+        assert!(matches!(
+            err,
+            InstanceError::InstanceBusy {
+                operation: InstanceOperation::Running,
+                ..
+            }
+        ));
+    }
+
+    // Launcher settings and reading files for a snapshot don't touch what the game uses
+    drop(
+        service
+            .try_lock("instance", InstanceOperation::Edit)
+            .unwrap(),
+    );
+    drop(
+        service
+            .try_lock("instance", InstanceOperation::CreateSnapshot)
+            .unwrap(),
+    );
+
+    drop(running);
+    assert_eq!(service.get_operation("instance"), None);
+    assert!(service
+        .try_lock("instance", InstanceOperation::Install)
+        .is_ok());
+}
+
+#[test]
+fn test_running_instance_blocks_world_and_server_changes() {
+    let service = InstanceLockService::new();
+    let running = service.mark_running("instance");
+
+    // The game writes level.dat and servers.dat itself while running
+    for operation in [
+        InstanceOperation::ChangeWorlds,
+        InstanceOperation::ChangeServers,
+    ] {
+        assert!(operation.is_blocked_while_running());
+
+        let err = service.try_lock("instance", operation).err().unwrap();
+        assert!(matches!(
+            err,
+            InstanceError::InstanceBusy {
+                operation: InstanceOperation::Running,
+                ..
+            }
+        ));
+    }
+
+    drop(running);
+    assert!(service
+        .try_lock("instance", InstanceOperation::ChangeWorlds)
+        .is_ok());
+}
@@ -0,0 +1,297 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde_json::json;
+
+use crate::features::{
+    instance::{
+        app::{
+            CheckInstanceMigrationUseCase, InstallContentUseCase, InstanceMigration,
+            ListContentUseCase, MigrateInstanceUseCase,
+        },
+        infra::{FsInstanceStorage, FsPackStorage, FsSnapshotStorage},
+        ContentCompatibilityStatus, ContentProviderRegistry, ContentUpdateResult,
+        ContentUpdateStatus, InstanceInstallStage, InstanceLockService, InstanceStorage, PackFile,
+        PackStorage,
+    },
+    minecraft::{LoaderVersionResolver, ModLoader},
+    settings::LocationInfo,
+};
+
+use super::fixtures::{
+    test_create_snapshot_use_case, test_instance, MockContentProvider, TestProgressService,
+    UnavailableMetadataStorage,
+};
+
+const INSTANCE_ID: &str = "survival";
+
+type TestMigrateInstanceUseCase = MigrateInstanceUseCase<
+    FsInstanceStorage,
+    UnavailableMetadataStorage,
+    FsPackStorage,
+    MockContentProvider,
+    FsSnapshotStorage,
+    TestProgressService,
+>;
+
+struct Setup {
+    _dir: tempfile::TempDir,
+    location_info: Arc<LocationInfo>,
+    instance_storage: Arc<FsInstanceStorage>,
+    pack_storage: Arc<FsPackStorage>,
+    check_instance_migration_use_case:
+        Arc<CheckInstanceMigrationUseCase<FsPackStorage, MockContentProvider>>,
+    migrate_instance_use_case: TestMigrateInstanceUseCase,
+}
+
+impl Setup {
+    async fn new() -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let location_info = Arc::new(LocationInfo::new(
+            dir.path().to_path_buf(),
+            dir.path().to_path_buf(),
+        ));
+        let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+        let pack_storage = Arc::new(FsPackStorage::new(location_info.clone()));
+        let lock_service = Arc::new(InstanceLockService::new());
+
+        instance_storage
+            .upsert(&test_instance(INSTANCE_ID))
+            .await
+            .unwrap();
+
+        let provider = MockContentProvider::new("https://mock.test")
+            .with_location_info(location_info.clone())
+            .with_versions("sodium", &["2.0", "1.5"])
+            .with_versions("lithium", &["1.1", "1.0"])
+            .with_versions("iris", &[])
+            .with_versions("phosphor", &["2.0"])
+            .with_failing_install("phosphor");
+        let provider_registry = Arc::new(ContentProviderRegistry::new(HashMap::from([(
+            "mock".to_owned(),
+            provider,
+        )])));
+
+        let check_instance_migration_use_case = Arc::new(CheckInstanceMigrationUseCase::new(
+            Arc::new(ListContentUseCase::new(
+                pack_storage.clone(),
+                location_info.clone(),
+            )),
+            pack_storage.clone(),
+            provider_registry.clone(),
+        ));
+
+        let migrate_instance_use_case = MigrateInstanceUseCase::new(
+            instance_storage.clone(),
+            pack_storage.clone(),
+            Arc::new(LoaderVersionResolver::new(Arc::new(
+                UnavailableMetadataStorage,
+            ))),
+            check_instance_migration_use_case.clone(),
+            Arc::new(InstallContentUseCase::new(
+                pack_storage.clone(),
+                provider_registry,
+                lock_service.clone(),
+            )),
+            Arc::new(test_create_snapshot_use_case(
+                instance_storage.clone(),
+                location_info.clone(),
+            )),
+            location_info.clone(),
+            lock_service,
+        );
+
+        let setup = Self {
+            _dir: dir,
+            location_info,
+            instance_storage,
+            pack_storage,
+            check_instance_migration_use_case,
+            migrate_instance_use_case,
+        };
+
+        for project_id in ["sodium", "lithium", "iris", "broken", "phosphor"] {
+            setup.add_provider_content(project_id, "1.0").await;
+        }
+        setup.write_content("mods/custom.jar");
+
+        setup
+    }
+
+    fn write_content(&self, content_path: &str) {
+        let path = self
+            .location_info
+            .instance_dir(INSTANCE_ID)
+            .join(content_path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content_path).unwrap();
+    }
+
+    fn content_exists(&self, content_path: &str) -> bool {
+        self.location_info
+            .instance_dir(INSTANCE_ID)
+            .join(content_path)
+            .exists()
+    }
+
+    async fn add_provider_content(&self, project_id: &str, version: &str) {
+        let file_name = format!("{project_id}-{version}.jar");
+        let content_path = format!("mods/{file_name}");
+        self.write_content(&content_path);
+
+        self.pack_storage
+            .update_pack_file(
+                INSTANCE_ID,
+                &content_path,
+                &PackFile {
+                    file_name,
+                    name: Some(project_id.to_owned()),
+                    hash: String::new(),
+                    download: None,
+                    option: None,
+                    side: None,
+                    update_provider: Some("mock".to_owned()),
+                    update: Some(HashMap::from([(
+                        "mock".to_owned(),
+                        json!({ "project_id": project_id, "version": version }),
+                    )])),
+                },
+            )
+            .await
+            .unwrap();
+    }
+}
+
+fn migration() -> InstanceMigration {
+    InstanceMigration {
+        game_version: "1.21".to_owned(),
+        mod_loader: ModLoader::Vanilla,
+        loader_version: None,
+    }
+}
+
+#[tokio::test]
+async fn test_check_migration_reports_status_per_content() {
+    let setup = Setup::new().await;
+
+    let contents = setup
+        .check_instance_migration_use_case
+        .execute(INSTANCE_ID.to_owned(), &migration())
+        .await
+        .unwrap();
+
+    let statuses: Vec<(&str, &ContentCompatibilityStatus)> = contents
+        .iter()
+        .map(|content| (content.content_path.as_str(), &content.status))
+        .collect();
+
+    assert_eq!(statuses.len(), 6);
+    assert!(matches!(
+        statuses[0],
+        (
+            "mods/broken-1.0.jar",
+            ContentCompatibilityStatus::CheckFailed { .. }
+        )
+    ));
+    assert_eq!(
+        statuses[1],
+        ("mods/custom.jar", &ContentCompatibilityStatus::Unknown)
+    );
+    assert_eq!(
+        statuses[2],
+        (
+            "mods/iris-1.0.jar",
+            &ContentCompatibilityStatus::Incompatible
+        )
+    );
+    assert_eq!(
+        statuses[3],
+        (
+            "mods/lithium-1.0.jar",
+            &ContentCompatibilityStatus::Compatible
+        )
+    );
+    assert!(matches!(
+        statuses[4],
+        ("mods/phosphor-1.0.jar", ContentCompatibilityStatus::UpdateAvailable { version })
+            if version.id == "2.0"
+    ));
+    assert!(matches!(
+        statuses[5],
+        ("mods/sodium-1.0.jar", ContentCompatibilityStatus::UpdateAvailable { version })
+            if version.id == "2.0"
+    ));
+}
+
+#[tokio::test]
+async fn test_migrate_reports_result_per_updated_content() {
+    let setup = Setup::new().await;
+
+    let report = setup
+        .migrate_instance_use_case
+        .execute(INSTANCE_ID.to_owned(), migration(), true)
+        .await
+        .unwrap();
+
+    assert_eq!(report.contents.len(), 6);
+    assert_eq!(report.content_updates.len(), 2);
+    assert!(matches!(
+        &report.content_updates[0],
+        ContentUpdateResult {
+            content_path,
+            status: ContentUpdateStatus::Failed { .. },
+        } if content_path == "mods/phosphor-1.0.jar"
+    ));
+    assert_eq!(
+        report.content_updates[1],
+        ContentUpdateResult {
+            content_path: "mods/sodium-1.0.jar".to_owned(),
+            status: ContentUpdateStatus::Updated,
+        }
+    );
+
+    // Failed update keeps the old file and its pack entry
+    assert!(setup.content_exists("mods/phosphor-1.0.jar"));
+    assert!(!setup.content_exists("mods/phosphor-2.0.jar"));
+    assert!(setup
+        .pack_storage
+        .get_pack_file(INSTANCE_ID, "mods/phosphor-1.0.jar")
+        .await
+        .is_ok());
+
+    assert!(setup.content_exists("mods/sodium-2.0.jar"));
+    assert!(!setup.content_exists("mods/sodium-1.0.jar"));
+    assert!(setup
+        .pack_storage
+        .get_pack_file(INSTANCE_ID, "mods/sodium-1.0.jar")
+        .await
+        .is_err());
+    assert_eq!(
+        setup
+            .pack_storage
+            .get_pack_file(INSTANCE_ID, "mods/sodium-2.0.jar")
+            .await
+            .unwrap()
+            .update_provider
+            .as_deref(),
+        Some("mock")
+    );
+
+    let instance = setup.instance_storage.get(INSTANCE_ID).await.unwrap();
+    assert_eq!(instance.game_version, "1.21");
+    assert_eq!(instance.install_stage, InstanceInstallStage::NotInstalled);
+}
+
+#[tokio::test]
+async fn test_migrate_without_content_update_keeps_files() {
+    let setup = Setup::new().await;
+
+    let report = setup
+        .migrate_instance_use_case
+        .execute(INSTANCE_ID.to_owned(), migration(), false)
+        .await
+        .unwrap();
+
+    assert!(report.content_updates.is_empty());
+    assert!(setup.content_exists("mods/sodium-1.0.jar"));
+    assert!(!setup.content_exists("mods/sodium-2.0.jar"));
+}
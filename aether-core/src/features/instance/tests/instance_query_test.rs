@@ -0,0 +1,144 @@
+use chrono::{TimeZone, Utc};
+
+use crate::features::{
+    instance::{
+        Instance, InstanceInstallStage, InstanceQuery, InstanceSortKey, PackInfo, SortOrder,
+    },
+    minecraft::ModLoader,
+    settings::Hooks,
+};
+
+fn instance(name: &str, loader: ModLoader, time_played: u64) -> Instance {
+    Instance {
+        id: name.to_lowercase(),
+        name: name.to_owned(),
+        icon_path: None,
+        group: None,
+        tags: Vec::new(),
+        favorite: false,
+        install_stage: InstanceInstallStage::Installed,
+        game_version: "1.20.1".to_owned(),
+        loader,
+        loader_version: None,
+        java_path: None,
+        launch_args: None,
+        env_vars: None,
+        memory: None,
+        force_fullscreen: None,
+        game_resolution: None,
+        game_options: None,
+        account_id: None,
+        created: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        modified: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        last_played: None,
+        time_played,
+        recent_time_played: 0,
+        hooks: Hooks::default(),
+        pack_info: None,
+        snapshot_policy: None,
+        shared_folders: Vec::new(),
+    }
+}
+
+fn names(instances: &[Instance]) -> Vec<&str> {
+    instances
+        .iter()
+        .map(|instance| instance.name.as_str())
+        .collect()
+}
+
+#[test]
+fn test_query_filters() {
+    let mut survival = instance("Survival", ModLoader::Fabric, 0);
+    survival.group = Some("Friends".to_owned());
+    survival.tags = vec!["smp".to_owned()];
+
+    let mut modpack = instance("Modpack", ModLoader::Forge, 0);
+    modpack.pack_info = Some(PackInfo {
+        plugin_id: "modrinth".to_owned(),
+        modpack_id: "abc".to_owned(),
+        version: "1.0.0".to_owned(),
+    });
+
+    let instances = vec![
+        survival,
+        modpack,
+        instance("Vanilla", ModLoader::Vanilla, 0),
+    ];
+
+    let query = |query: InstanceQuery| query.apply(instances.clone());
+
+    assert_eq!(
+        names(&query(InstanceQuery {
+            loader: Some(ModLoader::Fabric),
+            ..Default::default()
+        })),
+        vec!["Survival"]
+    );
+    assert_eq!(
+        names(&query(InstanceQuery {
+            plugin_id: Some("modrinth".to_owned()),
+            ..Default::default()
+        })),
+        vec!["Modpack"]
+    );
+    assert_eq!(
+        names(&query(InstanceQuery {
+            search: Some("SMP".to_owned()),
+            ..Default::default()
+        })),
+        vec!["Survival"]
+    );
+    assert_eq!(
+        names(&query(InstanceQuery {
+            group: Some("Friends".to_owned()),
+            tag: Some("smp".to_owned()),
+            ..Default::default()
+        })),
+        vec!["Survival"]
+    );
+    assert_eq!(
+        names(&query(InstanceQuery {
+            tag: Some("SMP".to_owned()),
+            ..Default::default()
+        })),
+        vec!["Survival"]
+    );
+    assert!(query(InstanceQuery {
+        tag: Some("sm".to_owned()),
+        ..Default::default()
+    })
+    .is_empty());
+}
+
+#[test]
+fn test_query_sort() {
+    let mut favorite = instance("Beta", ModLoader::Vanilla, 10);
+    favorite.favorite = true;
+
+    let instances = vec![
+        instance("alpha", ModLoader::Vanilla, 30),
+        favorite,
+        instance("Gamma", ModLoader::Vanilla, 20),
+    ];
+
+    let by_time_played = InstanceQuery {
+        sort_by: InstanceSortKey::TimePlayed,
+        ..Default::default()
+    };
+    assert_eq!(
+        names(&by_time_played.apply(instances.clone())),
+        vec!["alpha", "Gamma", "Beta"]
+    );
+
+    let by_name = InstanceQuery {
+        sort_by: InstanceSortKey::Name,
+        sort_order: SortOrder::Ascending,
+        favorites_first: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        names(&by_name.apply(instances)),
+        vec!["Beta", "alpha", "Gamma"]
+    );
+}
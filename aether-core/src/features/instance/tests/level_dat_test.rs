@@ -0,0 +1,69 @@
+use std::io::Write;
+
+use flate2::{write::GzEncoder, Compression};
+
+use crate::features::instance::utils::{
+    parse_level_dat, LevelDat, LevelData, LevelDataPacks, LevelVersion, LevelWorldGenSettings,
+};
+
+fn to_level_dat_bytes(level_dat: &LevelDat) -> Vec<u8> {
+    let nbt = fastnbt::to_bytes(level_dat).unwrap();
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&nbt).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn test_parse_level_dat() {
+    let bytes = to_level_dat_bytes(&LevelDat {
+        data: LevelData {
+            level_name: "New World".to_owned(),
+            game_type: 1,
+            hardcore: 0,
+            last_played: 1_718_000_000_000,
+            version: Some(LevelVersion {
+                name: "1.20.1".to_owned(),
+            }),
+            random_seed: Some(1),
+            world_gen_settings: Some(LevelWorldGenSettings { seed: 42 }),
+            data_packs: Some(LevelDataPacks {
+                enabled: vec!["vanilla".to_owned()],
+                disabled: vec!["file/old.zip".to_owned()],
+            }),
+        },
+    });
+
+    let level = parse_level_dat(&bytes).unwrap().data;
+
+    assert_eq!(level.level_name, "New World");
+    assert_eq!(level.game_type, 1);
+    assert_eq!(level.last_played, 1_718_000_000_000);
+    assert_eq!(level.version.unwrap().name, "1.20.1");
+    assert_eq!(level.data_packs.unwrap().disabled, vec!["file/old.zip"]);
+}
+
+#[test]
+fn test_seed_prefers_world_gen_settings() {
+    let mut level = LevelData {
+        level_name: "Old World".to_owned(),
+        game_type: 0,
+        hardcore: 1,
+        last_played: 0,
+        version: None,
+        random_seed: Some(7),
+        world_gen_settings: Some(LevelWorldGenSettings { seed: 42 }),
+        data_packs: None,
+    };
+
+    assert_eq!(level.seed(), Some(42));
+
+    // Worlds before 1.16 only have `RandomSeed`
+    level.world_gen_settings = None;
+    assert_eq!(level.seed(), Some(7));
+}
+
+#[test]
+fn test_parse_invalid_level_dat() {
+    assert!(parse_level_dat(b"not a level.dat").is_err());
+}
@@ -0,0 +1,286 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use serde_json::json;
+
+use crate::features::{
+    instance::{
+        app::CollectMetadataGarbageUseCase, infra::FsInstanceStorage, DiskUsageService,
+        GarbageKind, GarbageReport, InstanceLockService, InstanceStorage,
+    },
+    java::{infra::FsJavaStorage, Java, JavaStorage},
+    minecraft::{
+        app::{GetVersionFilesUseCase, GetVersionManifestUseCase},
+        utils::AUTHLIB_INJECTOR_PATH,
+        LoaderVersionResolver,
+    },
+    settings::LocationInfo,
+};
+
+use super::fixtures::{test_instance, MockMetadataStorage, MockMinecraftDownloader};
+
+const USED_ASSET: &str = "aa00000000000000000000000000000000000000";
+const UNUSED_ASSET: &str = "bb00000000000000000000000000000000000000";
+const NATIVES_PATH: &str =
+    "org/lwjgl/lwjgl/lwjgl-platform/2.9.4/lwjgl-platform-2.9.4-natives-linux.jar";
+
+type TestCollectMetadataGarbageUseCase = CollectMetadataGarbageUseCase<
+    FsInstanceStorage,
+    MockMetadataStorage,
+    MockMinecraftDownloader,
+    FsJavaStorage,
+>;
+
+struct Setup {
+    _dir: tempfile::TempDir,
+    location_info: Arc<LocationInfo>,
+    java_storage: Arc<FsJavaStorage>,
+    collect_metadata_garbage_use_case: TestCollectMetadataGarbageUseCase,
+}
+
+impl Setup {
+    /// Two 1.20.1 instances, one with its own Java and one using the managed Java 8
+    async fn new() -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let location_info = Arc::new(LocationInfo::new(
+            dir.path().to_path_buf(),
+            dir.path().to_path_buf(),
+        ));
+        let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+        let java_storage = Arc::new(FsJavaStorage::new(&location_info.java_dir()));
+
+        let mut custom_java = test_instance("custom-java");
+        custom_java.java_path = Some(java_path(&location_info, "zulu17"));
+        instance_storage.upsert(&custom_java).await.unwrap();
+        instance_storage
+            .upsert(&test_instance("managed-java"))
+            .await
+            .unwrap();
+
+        for (major_version, folder) in [(8, "zulu8"), (21, "zulu21")] {
+            java_storage
+                .upsert(Java::new(
+                    major_version,
+                    format!("{major_version}.0.0"),
+                    "x86_64".to_owned(),
+                    java_path(&location_info, folder),
+                ))
+                .await
+                .unwrap();
+        }
+
+        let metadata_storage = Arc::new(MockMetadataStorage);
+        let get_version_files_use_case = Arc::new(GetVersionFilesUseCase::new(
+            Arc::new(LoaderVersionResolver::new(metadata_storage.clone())),
+            Arc::new(GetVersionManifestUseCase::new(metadata_storage)),
+            MockMinecraftDownloader::default()
+                .with_version_info(json!({
+                    "libraries": [
+                        { "name": "com.mojang:brigadier:1.1.8" },
+                        {
+                            "name": "org.lwjgl.lwjgl:lwjgl-platform:2.9.4",
+                            "downloads": {
+                                "classifiers": {
+                                    "natives-linux": {
+                                        "path": "",
+                                        "sha1": "0000000000000000000000000000000000000000",
+                                        "size": 0,
+                                        "url": "https://mock.test/natives.jar"
+                                    }
+                                }
+                            },
+                            "natives": { "linux": "natives-linux" }
+                        }
+                    ]
+                }))
+                .with_assets(&[USED_ASSET]),
+            location_info.clone(),
+        ));
+
+        let collect_metadata_garbage_use_case = CollectMetadataGarbageUseCase::new(
+            instance_storage,
+            java_storage.clone(),
+            get_version_files_use_case,
+            Arc::new(DiskUsageService::new()),
+            location_info.clone(),
+            Arc::new(InstanceLockService::new()),
+        );
+
+        let setup = Self {
+            _dir: dir,
+            location_info,
+            java_storage,
+            collect_metadata_garbage_use_case,
+        };
+
+        setup.write_file(&setup.location_info.version_dir("1.20.1").join("1.20.1.jar"));
+        setup.write_file(&setup.location_info.version_dir("1.19.4").join("1.19.4.jar"));
+        setup.write_file(&setup.library("com/mojang/brigadier/1.1.8/brigadier-1.1.8.jar"));
+        setup.write_file(&setup.library(NATIVES_PATH));
+        setup.write_file(&setup.library("org/unused/lib/1.0/lib-1.0.jar"));
+        setup.write_file(&setup.library(AUTHLIB_INJECTOR_PATH));
+        setup.write_file(&setup.location_info.assets_index_dir().join("5.json"));
+        setup.write_file(&setup.location_info.assets_index_dir().join("3.json"));
+        setup.write_file(&setup.location_info.object_dir(USED_ASSET));
+        setup.write_file(&setup.location_info.object_dir(UNUSED_ASSET));
+        setup.write_file(&setup.location_info.legacy_assets_dir().join("sound.ogg"));
+        for folder in ["zulu8", "zulu17", "zulu21"] {
+            setup.write_file(Path::new(&java_path(&setup.location_info, folder)));
+        }
+
+        setup
+    }
+
+    fn library(&self, path: &str) -> PathBuf {
+        self.location_info.libraries_dir().join(path)
+    }
+
+    fn java_dir(&self, folder: &str) -> PathBuf {
+        self.location_info.java_dir().join(folder)
+    }
+
+    fn write_file(&self, path: &Path) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, [0; 4]).unwrap();
+    }
+
+    fn garbage(&self) -> Vec<(GarbageKind, PathBuf)> {
+        vec![
+            (
+                GarbageKind::Version,
+                self.location_info.version_dir("1.19.4"),
+            ),
+            (
+                GarbageKind::Library,
+                self.library("org/unused/lib/1.0/lib-1.0.jar"),
+            ),
+            (
+                GarbageKind::AssetIndex,
+                self.location_info.assets_index_dir().join("3.json"),
+            ),
+            (
+                GarbageKind::AssetObject,
+                self.location_info.object_dir(UNUSED_ASSET),
+            ),
+            (
+                GarbageKind::LegacyAssets,
+                self.location_info.legacy_assets_dir(),
+            ),
+            (GarbageKind::Java, self.java_dir("zulu21")),
+        ]
+    }
+}
+
+fn java_path(location_info: &LocationInfo, folder: &str) -> String {
+    location_info
+        .java_dir()
+        .join(folder)
+        .join("bin/java")
+        .to_string_lossy()
+        .to_string()
+}
+
+fn entries(report: &GarbageReport) -> Vec<(GarbageKind, PathBuf)> {
+    report
+        .entries
+        .iter()
+        .map(|entry| (entry.kind, PathBuf::from(&entry.path)))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_dry_run_lists_only_unreferenced_metadata() {
+    let setup = Setup::new().await;
+
+    let report = setup
+        .collect_metadata_garbage_use_case
+        .execute(true)
+        .await
+        .unwrap();
+
+    assert_eq!(entries(&report), setup.garbage());
+    assert_eq!(report.size, 6 * 4);
+    assert_eq!(report.reclaimed, None);
+
+    for (_, path) in setup.garbage() {
+        assert!(path.exists());
+    }
+}
+
+#[tokio::test]
+async fn test_collect_keeps_referenced_metadata() {
+    let setup = Setup::new().await;
+
+    let report = setup
+        .collect_metadata_garbage_use_case
+        .execute(false)
+        .await
+        .unwrap();
+
+    assert_eq!(report.reclaimed, Some(report.size));
+    assert!(report.failed.is_empty());
+
+    for (_, path) in setup.garbage() {
+        assert!(!path.exists());
+    }
+
+    // Files inside referenced folders are kept with them
+    assert!(setup
+        .location_info
+        .version_dir("1.20.1")
+        .join("1.20.1.jar")
+        .exists());
+    assert!(setup
+        .library("com/mojang/brigadier/1.1.8/brigadier-1.1.8.jar")
+        .exists());
+    assert!(setup.library(NATIVES_PATH).exists());
+    assert!(setup.location_info.object_dir(USED_ASSET).exists());
+
+    // Shared by all versions, so no version references it
+    assert!(setup.library(AUTHLIB_INJECTOR_PATH).exists());
+
+    // Emptied library folders go away, the libraries dir stays
+    assert!(!setup.library("org").exists());
+    assert!(setup.location_info.libraries_dir().exists());
+
+    // Java set on the instance and the managed Java of its version
+    assert!(setup.java_dir("zulu17").exists());
+    assert!(setup.java_dir("zulu8").exists());
+    assert_eq!(
+        setup
+            .java_storage
+            .list()
+            .await
+            .unwrap()
+            .iter()
+            .map(Java::major_version)
+            .collect::<Vec<_>>(),
+        vec![8]
+    );
+}
+
+#[tokio::test]
+async fn test_collect_continues_after_failed_removal() {
+    let setup = Setup::new().await;
+
+    // Versions are expected to be folders, a stray file can't be removed as one
+    let stray_file = setup.location_info.versions_dir().join("versions.txt");
+    setup.write_file(&stray_file);
+
+    let report = setup
+        .collect_metadata_garbage_use_case
+        .execute(false)
+        .await
+        .unwrap();
+
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].path, stray_file.to_string_lossy());
+    assert_eq!(report.reclaimed, Some(report.size - 4));
+    assert!(stray_file.exists());
+
+    for (_, path) in setup.garbage() {
+        assert!(!path.exists());
+    }
+}
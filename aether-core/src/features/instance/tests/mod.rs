@@ -0,0 +1,23 @@
+mod curseforge_manifest_resolver_test;
+mod disk_usage_test;
+mod duplicate_instance_test;
+mod export_mrpack_test;
+mod fixtures;
+mod import_curseforge_test;
+mod import_mrpack_test;
+mod instance_account_test;
+mod instance_group_test;
+mod instance_lock_test;
+mod instance_migration_test;
+mod instance_query_test;
+mod level_dat_test;
+mod metadata_garbage_test;
+mod packwiz_test;
+mod playtime_test;
+mod server_list_ping_test;
+mod server_list_storage_test;
+mod shared_folder_test;
+mod snapshot_restore_test;
+mod snapshot_retention_test;
+mod update_contents_test;
+mod verify_instance_test;
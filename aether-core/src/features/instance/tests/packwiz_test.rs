@@ -0,0 +1,285 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+use crate::features::{
+    instance::{
+        app::{ExportPackwizOptions, ExportPackwizUseCase, ListContentUseCase},
+        infra::{FsInstanceStorage, FsPackStorage},
+        utils::{compute_packwiz_hash, curseforge_murmur2, verify_packwiz_hash},
+        CurseForgeUpdateData, InstanceError, InstanceStorage, PackFile, PackFileDownload,
+        PackStorage, PackwizHashFormat, PackwizIndex, PackwizMetafile, PackwizPack,
+    },
+    minecraft::{LoaderVersionResolver, ModLoader},
+    settings::LocationInfo,
+};
+
+use super::fixtures::{test_instance, UnavailableMetadataStorage};
+
+const MODRINTH_METAFILE: &str = r#"
+name = "Sodium"
+filename = "sodium-fabric-0.5.8+mc1.20.1.jar"
+side = "client"
+
+[download]
+url = "https://cdn.modrinth.com/data/AANobbMI/versions/b4hTi3mo/sodium-fabric-0.5.8%2Bmc1.20.1.jar"
+hash-format = "sha512"
+hash = "aaaa"
+
+[option]
+optional = true
+default = false
+
+[update]
+[update.modrinth]
+mod-id = "AANobbMI"
+version = "b4hTi3mo"
+"#;
+
+const CURSEFORGE_METAFILE: &str = r#"
+name = "Just Enough Items"
+filename = "jei-1.20.1-fabric-15.3.0.4.jar"
+side = "both"
+
+[download]
+hash-format = "sha1"
+hash = "bbbb"
+mode = "metadata:curseforge"
+
+[update]
+[update.curseforge]
+file-id = 5101366
+project-id = 238222
+"#;
+
+#[test]
+fn test_parse_pack_and_index() {
+    let pack: PackwizPack = toml::from_str(
+        r#"
+name = "Test Pack"
+author = "Tester"
+version = "1.0.0"
+pack-format = "packwiz:1.1.0"
+
+[index]
+file = "index.toml"
+hash-format = "sha256"
+hash = "cccc"
+
+[versions]
+fabric = "0.15.7"
+minecraft = "1.20.1"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(pack.game_version().map(String::as_str), Some("1.20.1"));
+    assert_eq!(
+        pack.loader(),
+        (ModLoader::Fabric, Some(&"0.15.7".to_string()))
+    );
+
+    let index: PackwizIndex = toml::from_str(
+        r#"
+hash-format = "sha256"
+
+[[files]]
+file = "config/sodium-options.json"
+hash = "dddd"
+
+[[files]]
+file = "mods/sodium.pw.toml"
+hash = "eeee"
+metafile = true
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(index.hash_format, PackwizHashFormat::Sha256);
+    assert!(!index.files[0].metafile);
+    assert!(index.files[1].metafile);
+}
+
+#[test]
+fn test_modrinth_metafile_round_trip() {
+    let metafile: PackwizMetafile = toml::from_str(MODRINTH_METAFILE).unwrap();
+    let url = metafile.download.url.clone().unwrap();
+
+    let pack_file = metafile.to_pack_file(url.clone(), "ffff".to_string());
+
+    assert_eq!(pack_file.hash, "ffff");
+    assert_eq!(pack_file.side.as_deref(), Some("client"));
+    assert_eq!(pack_file.update_provider.as_deref(), Some("modrinth"));
+    assert!(pack_file
+        .option
+        .as_ref()
+        .is_some_and(|option| option.optional));
+
+    let exported = PackwizMetafile::from_pack_file(&pack_file).unwrap();
+
+    assert_eq!(exported.filename, metafile.filename);
+    assert_eq!(exported.download.url, Some(url));
+    assert_eq!(exported.download.hash_format, PackwizHashFormat::Sha1);
+    assert_eq!(exported.download.hash, "ffff");
+    assert_eq!(
+        exported.update.and_then(|update| update.modrinth),
+        metafile.update.and_then(|update| update.modrinth)
+    );
+}
+
+#[test]
+fn test_curseforge_metafile_round_trip() {
+    let metafile: PackwizMetafile = toml::from_str(CURSEFORGE_METAFILE).unwrap();
+    assert!(metafile.download.url.is_none());
+
+    let pack_file = metafile.to_pack_file(
+        "https://edge.forgecdn.net/files/5101/366/jei.jar".to_string(),
+        "bbbb".to_string(),
+    );
+
+    assert!(pack_file.side.is_none());
+    assert_eq!(pack_file.update_provider.as_deref(), Some("curseforge"));
+    assert_eq!(
+        CurseForgeUpdateData::from_pack_file(&pack_file),
+        Some(CurseForgeUpdateData {
+            project_id: 238222,
+            file_id: 5101366,
+        })
+    );
+
+    let exported = PackwizMetafile::from_pack_file(&pack_file).unwrap();
+    let serialized = toml::to_string(&exported).unwrap();
+
+    assert!(serialized.contains("[update.curseforge]"));
+    assert!(serialized.contains("project-id = 238222"));
+    assert!(serialized.contains("side = \"both\""));
+}
+
+#[tokio::test]
+async fn test_compute_md5_and_murmur2_hashes() {
+    assert_eq!(
+        compute_packwiz_hash(PackwizHashFormat::Md5, Bytes::from_static(b"abc"))
+            .await
+            .unwrap(),
+        "900150983cd24fb0d6963f7d28e17f72"
+    );
+    assert_eq!(
+        compute_packwiz_hash(PackwizHashFormat::Murmur2, Bytes::from_static(b"packwiz"))
+            .await
+            .unwrap(),
+        "2676380970"
+    );
+
+    verify_packwiz_hash(
+        PackwizHashFormat::Md5,
+        "900150983CD24FB0D6963F7D28E17F72",
+        Bytes::from_static(b"abc"),
+        "file",
+    )
+    .await
+    .unwrap();
+
+    assert!(matches!(
+        verify_packwiz_hash(
+            PackwizHashFormat::Murmur2,
+            "1",
+            Bytes::from_static(b"packwiz"),
+            "mods/sodium.jar",
+        )
+        .await,
+        Err(InstanceError::InvalidModpack { .. })
+    ));
+}
+
+#[test]
+fn test_curseforge_murmur2_ignores_whitespace() {
+    assert_eq!(
+        curseforge_murmur2(b"pack wiz\r\n\t"),
+        curseforge_murmur2(b"packwiz")
+    );
+    assert_ne!(
+        curseforge_murmur2(b"packwiz"),
+        curseforge_murmur2(b"packwix")
+    );
+}
+
+#[tokio::test]
+async fn test_export_removes_stale_metafiles() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().join("data"),
+        dir.path().join("data"),
+    ));
+    let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+    let pack_storage = Arc::new(FsPackStorage::new(location_info.clone()));
+
+    instance_storage
+        .upsert(&test_instance("survival"))
+        .await
+        .unwrap();
+
+    let instance_dir = location_info.instance_dir("survival");
+    std::fs::create_dir_all(instance_dir.join("mods")).unwrap();
+    std::fs::write(instance_dir.join("mods/sodium.jar"), "sodium").unwrap();
+    pack_storage
+        .update_pack_file(
+            "survival",
+            "mods/sodium.jar",
+            &PackFile {
+                file_name: "sodium.jar".to_owned(),
+                name: Some("Sodium".to_owned()),
+                hash: "sodium-sha1".to_owned(),
+                download: Some(PackFileDownload {
+                    hash: "sodium-sha1".to_owned(),
+                    url: "https://cdn.modrinth.com/sodium.jar".to_owned(),
+                }),
+                option: None,
+                side: None,
+                update_provider: None,
+                update: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Left by an export made before the mod was removed from the instance
+    let output_dir = dir.path().join("pack");
+    std::fs::create_dir_all(output_dir.join("mods")).unwrap();
+    std::fs::write(output_dir.join("mods/removed.pw.toml"), "stale").unwrap();
+    std::fs::write(output_dir.join("README.md"), "readme").unwrap();
+    // Not a content folder, so not written by the export
+    std::fs::create_dir_all(output_dir.join("docs")).unwrap();
+    std::fs::write(output_dir.join("docs/example.pw.toml"), "example").unwrap();
+
+    ExportPackwizUseCase::new(
+        instance_storage,
+        pack_storage.clone(),
+        Arc::new(ListContentUseCase::new(pack_storage, location_info.clone())),
+        Arc::new(LoaderVersionResolver::new(Arc::new(
+            UnavailableMetadataStorage,
+        ))),
+        location_info,
+    )
+    .execute(
+        "survival".to_owned(),
+        ExportPackwizOptions {
+            output_dir: output_dir.clone(),
+            version: None,
+            name: None,
+            author: None,
+            include_folders: Vec::new(),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert!(!output_dir.join("mods/removed.pw.toml").exists());
+    assert!(output_dir.join("mods/sodium.pw.toml").exists());
+    assert!(output_dir.join("README.md").exists());
+    assert!(output_dir.join("docs/example.pw.toml").exists());
+
+    let index: PackwizIndex =
+        toml::from_str(&std::fs::read_to_string(output_dir.join("index.toml")).unwrap()).unwrap();
+    let files: Vec<&str> = index.files.iter().map(|file| file.file.as_str()).collect();
+    assert_eq!(files, vec!["mods/sodium.pw.toml"]);
+}
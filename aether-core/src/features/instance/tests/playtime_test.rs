@@ -0,0 +1,112 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+
+use crate::features::instance::{
+    aggregate_playtime, get_recent_playtime, PlaySession, PlaytimePeriod, PlaytimeStats,
+};
+
+fn session(start: DateTime<Utc>, end: DateTime<Utc>) -> PlaySession {
+    PlaySession {
+        start,
+        end,
+        duration: (end - start).num_seconds() as u64,
+        exit_code: Some(0),
+        account_id: None,
+    }
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn test_recent_playtime_clips_sessions_to_window() {
+    let now = Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+    let sessions = vec![
+        // Entirely outside the window
+        session(
+            Utc.with_ymd_and_hms(2024, 2, 1, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 2, 1, 12, 0, 0).unwrap(),
+        ),
+        // One hour inside the window
+        session(
+            Utc.with_ymd_and_hms(2024, 3, 6, 11, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 6, 13, 0, 0).unwrap(),
+        ),
+        session(
+            Utc.with_ymd_and_hms(2024, 3, 19, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 19, 10, 30, 0).unwrap(),
+        ),
+    ];
+
+    assert_eq!(get_recent_playtime(&sessions, now), 3600 + 1800);
+}
+
+#[test]
+fn test_daily_playtime_splits_sessions_at_midnight() {
+    let sessions = vec![
+        session(
+            Utc.with_ymd_and_hms(2024, 3, 18, 23, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 19, 1, 0, 0).unwrap(),
+        ),
+        session(
+            Utc.with_ymd_and_hms(2024, 3, 19, 15, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 19, 15, 10, 0).unwrap(),
+        ),
+    ];
+
+    let stats = aggregate_playtime(
+        &sessions,
+        PlaytimePeriod::Day,
+        FixedOffset::east_opt(0).unwrap(),
+    );
+
+    assert_eq!(
+        stats,
+        vec![
+            PlaytimeStats {
+                period_start: date(2024, 3, 18),
+                duration: 3600,
+                sessions: 1,
+            },
+            PlaytimeStats {
+                period_start: date(2024, 3, 19),
+                duration: 3600 + 600,
+                sessions: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_weekly_playtime_uses_local_time() {
+    // Sunday 23:30 UTC is Monday 01:30 in UTC+2
+    let sessions = vec![session(
+        Utc.with_ymd_and_hms(2024, 3, 17, 23, 30, 0).unwrap(),
+        Utc.with_ymd_and_hms(2024, 3, 18, 0, 30, 0).unwrap(),
+    )];
+
+    let utc = aggregate_playtime(
+        &sessions,
+        PlaytimePeriod::Week,
+        FixedOffset::east_opt(0).unwrap(),
+    );
+    let local = aggregate_playtime(
+        &sessions,
+        PlaytimePeriod::Week,
+        FixedOffset::east_opt(2 * 3600).unwrap(),
+    );
+
+    assert_eq!(
+        utc.iter()
+            .map(|stats| (stats.period_start, stats.duration))
+            .collect::<Vec<_>>(),
+        vec![(date(2024, 3, 11), 1800), (date(2024, 3, 18), 1800)]
+    );
+    assert_eq!(
+        local
+            .iter()
+            .map(|stats| (stats.period_start, stats.duration))
+            .collect::<Vec<_>>(),
+        vec![(date(2024, 3, 18), 3600)]
+    );
+}
@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use tokio::{net::TcpListener, sync::oneshot};
+
+use crate::features::{
+    instance::{
+        infra::TcpServerPinger,
+        utils::{
+            read_packet, read_string, read_varint, write_packet, write_string, StatusPlayerSample,
+            StatusPlayers, StatusResponse, StatusVersion, HANDSHAKE_PACKET_ID, PING_PACKET_ID,
+            STATUS_REQUEST_PACKET_ID,
+        },
+        InstanceError, ServerPinger,
+    },
+    minecraft::utils::parse_server_address,
+};
+
+/// Answers a single status query like a server would, sends back the handshake data
+async fn spawn_stub_server(response: StatusResponse) -> (String, oneshot::Receiver<Vec<u8>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let (handshake_sender, handshake_receiver) = oneshot::channel();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        let (packet_id, handshake) = read_packet(&mut stream).await.unwrap();
+        assert_eq!(packet_id, HANDSHAKE_PACKET_ID);
+        let _ = handshake_sender.send(handshake);
+
+        let (packet_id, _) = read_packet(&mut stream).await.unwrap();
+        assert_eq!(packet_id, STATUS_REQUEST_PACKET_ID);
+
+        let mut data = Vec::new();
+        write_string(&mut data, &serde_json::to_string(&response).unwrap());
+        write_packet(&mut stream, STATUS_REQUEST_PACKET_ID, &data)
+            .await
+            .unwrap();
+
+        let (packet_id, payload) = read_packet(&mut stream).await.unwrap();
+        assert_eq!(packet_id, PING_PACKET_ID);
+        write_packet(&mut stream, PING_PACKET_ID, &payload)
+            .await
+            .unwrap();
+    });
+
+    (address, handshake_receiver)
+}
+
+#[tokio::test]
+async fn test_ping_server() {
+    let (address, handshake) = spawn_stub_server(StatusResponse {
+        version: Some(StatusVersion {
+            name: "1.20.1".to_owned(),
+            protocol: 763,
+        }),
+        players: Some(StatusPlayers {
+            max: 20,
+            online: 1,
+            sample: Some(vec![StatusPlayerSample {
+                name: "Steve".to_owned(),
+                id: "00000000-0000-0000-0000-000000000000".to_owned(),
+            }]),
+        }),
+        description: Some(serde_json::json!({
+            "text": "A ",
+            "extra": [{ "text": "Minecraft" }, " Server"]
+        })),
+        favicon: Some("data:image/png;base64,aWNvbg==".to_owned()),
+    })
+    .await;
+
+    let status = TcpServerPinger::default().ping(&address).await.unwrap();
+
+    assert_eq!(status.motd, "A Minecraft Server");
+    assert_eq!(status.version_name, "1.20.1");
+    assert_eq!(status.protocol, 763);
+    assert_eq!(status.players_online, 1);
+    assert_eq!(status.players_max, 20);
+    assert_eq!(status.players_sample, vec!["Steve"]);
+    assert_eq!(status.favicon.as_deref(), Some("aWNvbg=="));
+
+    let handshake = handshake.await.unwrap();
+    let mut data = handshake.as_slice();
+    read_varint(&mut data).await.unwrap();
+    let host = read_string(&mut data).await.unwrap();
+    let port = u16::from_be_bytes([data[0], data[1]]);
+
+    let (expected_host, expected_port) = parse_server_address(&address).unwrap();
+    assert_eq!(host, expected_host);
+    assert_eq!(Some(port), expected_port);
+}
+
+#[tokio::test]
+async fn test_ping_server_timeout() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    // Accepts the connection but never answers
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let _ = read_packet(&mut stream).await;
+        let _ = read_packet(&mut stream).await;
+        tokio::time::sleep(Duration::from_secs(5)).await;
+    });
+
+    let result = TcpServerPinger::new(Duration::from_millis(200))
+        .ping(&address)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(InstanceError::ServerPingFailed { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_read_string_rejects_invalid_length() {
+    let data = [10, b'a'];
+    assert!(read_string(&mut data.as_slice()).await.is_err());
+}
+
+#[test]
+fn test_parse_server_address() {
+    assert_eq!(
+        parse_server_address("mc.example.com"),
+        Some(("mc.example.com".to_owned(), None))
+    );
+    assert_eq!(
+        parse_server_address("mc.example.com:25566"),
+        Some(("mc.example.com".to_owned(), Some(25566)))
+    );
+    assert_eq!(
+        parse_server_address("[::1]:25566"),
+        Some(("::1".to_owned(), Some(25566)))
+    );
+    assert_eq!(parse_server_address("::1"), Some(("::1".to_owned(), None)));
+    assert_eq!(parse_server_address("mc.example.com:port"), None);
+    assert_eq!(parse_server_address(""), None);
+}
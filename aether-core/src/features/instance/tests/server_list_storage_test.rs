@@ -0,0 +1,137 @@
+use std::{path::Path, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::features::{
+    instance::{infra::FsServerListStorage, ResourcePackPolicy, ServerListStorage},
+    settings::LocationInfo,
+};
+
+const INSTANCE_ID: &str = "survival";
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct RawServersDat {
+    servers: Vec<RawServer>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct RawServer {
+    name: String,
+    ip: String,
+    #[serde(
+        default,
+        rename = "acceptTextures",
+        skip_serializing_if = "Option::is_none"
+    )]
+    accept_textures: Option<i8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hidden: Option<i8>,
+}
+
+fn raw_server(name: &str, ip: &str, accept_textures: Option<i8>, hidden: Option<i8>) -> RawServer {
+    RawServer {
+        name: name.to_owned(),
+        ip: ip.to_owned(),
+        accept_textures,
+        hidden,
+    }
+}
+
+fn write_raw(path: &Path, servers: RawServersDat) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, fastnbt::to_bytes(&servers).unwrap()).unwrap();
+}
+
+fn read_raw(path: &Path) -> RawServersDat {
+    fastnbt::from_bytes(&std::fs::read(path).unwrap()).unwrap()
+}
+
+fn setup() -> (tempfile::TempDir, Arc<LocationInfo>, FsServerListStorage) {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+    let storage = FsServerListStorage::new(location_info.clone());
+
+    write_raw(
+        &location_info.instance_servers_file(INSTANCE_ID),
+        RawServersDat {
+            servers: vec![
+                raw_server("Hidden", "play.example.com", None, Some(1)),
+                raw_server("Textures", "mc.example.com", Some(1), None),
+                raw_server("Same address", "play.example.com", Some(0), Some(0)),
+            ],
+        },
+    );
+
+    (dir, location_info, storage)
+}
+
+#[tokio::test]
+async fn test_servers_dat_round_trip() {
+    let (_dir, location_info, storage) = setup();
+    let path = location_info.instance_servers_file(INSTANCE_ID);
+    let before = read_raw(&path);
+
+    let servers = storage.get(INSTANCE_ID).await.unwrap();
+
+    assert_eq!(servers.len(), 3);
+    assert_eq!(servers[0].name, "Hidden");
+    assert_eq!(servers[0].resource_pack_policy, ResourcePackPolicy::Prompt);
+    assert_eq!(servers[1].resource_pack_policy, ResourcePackPolicy::Enabled);
+    assert_eq!(
+        servers[2].resource_pack_policy,
+        ResourcePackPolicy::Disabled
+    );
+
+    storage.set(INSTANCE_ID, &servers).await.unwrap();
+
+    assert_eq!(read_raw(&path), before);
+    assert!(!path.with_file_name("servers.dat.tmp").exists());
+}
+
+#[tokio::test]
+async fn test_unknown_fields_follow_moved_servers() {
+    let (_dir, location_info, storage) = setup();
+
+    let mut servers = storage.get(INSTANCE_ID).await.unwrap();
+    // Servers with the same address keep their own fields
+    let same_address = servers.remove(2);
+    servers.insert(0, same_address);
+    servers.remove(2);
+    servers[1].name = "Renamed".to_owned();
+
+    storage.set(INSTANCE_ID, &servers).await.unwrap();
+
+    assert_eq!(
+        read_raw(&location_info.instance_servers_file(INSTANCE_ID)),
+        RawServersDat {
+            servers: vec![
+                raw_server("Same address", "play.example.com", Some(0), Some(0)),
+                raw_server("Renamed", "play.example.com", None, Some(1)),
+            ],
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_new_servers_have_no_unknown_fields() {
+    let (_dir, location_info, storage) = setup();
+
+    let mut servers = storage.get(INSTANCE_ID).await.unwrap();
+    let mut copy = servers[0].clone();
+    copy.stored_index = None;
+    copy.name = "Copy".to_owned();
+    servers.push(copy);
+
+    storage.set(INSTANCE_ID, &servers).await.unwrap();
+
+    let raw = read_raw(&location_info.instance_servers_file(INSTANCE_ID));
+    assert_eq!(raw.servers.len(), 4);
+    assert_eq!(raw.servers[0].hidden, Some(1));
+    assert_eq!(
+        raw.servers[3],
+        raw_server("Copy", "play.example.com", None, None)
+    );
+}
@@ -0,0 +1,346 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{
+    features::{
+        instance::{
+            app::ChangeSharedFolderUseCase,
+            infra::{FsInstanceStorage, FsPackStorage},
+            InstanceError, InstanceLockService, InstanceOperation, InstanceStorage, PackFile,
+            PackStorage, SharedFolder,
+        },
+        settings::LocationInfo,
+    },
+    shared::create_dir_link,
+};
+
+use super::fixtures::{test_instance, NoopInstanceWatcherService};
+
+const INSTANCE_ID: &str = "survival";
+
+struct Setup {
+    _dir: tempfile::TempDir,
+    location_info: Arc<LocationInfo>,
+    instance_storage: Arc<FsInstanceStorage>,
+    pack_storage: Arc<FsPackStorage>,
+    lock_service: Arc<InstanceLockService>,
+    change_shared_folder_use_case:
+        ChangeSharedFolderUseCase<FsInstanceStorage, FsPackStorage, NoopInstanceWatcherService>,
+}
+
+impl Setup {
+    async fn new() -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let location_info = Arc::new(LocationInfo::new(
+            dir.path().to_path_buf(),
+            dir.path().to_path_buf(),
+        ));
+        let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+        let pack_storage = Arc::new(FsPackStorage::new(location_info.clone()));
+        let lock_service = Arc::new(InstanceLockService::new());
+
+        instance_storage
+            .upsert(&test_instance(INSTANCE_ID))
+            .await
+            .unwrap();
+
+        let change_shared_folder_use_case = ChangeSharedFolderUseCase::new(
+            instance_storage.clone(),
+            pack_storage.clone(),
+            Arc::new(NoopInstanceWatcherService),
+            location_info.clone(),
+            lock_service.clone(),
+        );
+
+        Self {
+            _dir: dir,
+            location_info,
+            instance_storage,
+            pack_storage,
+            lock_service,
+            change_shared_folder_use_case,
+        }
+    }
+
+    fn instance_folder(&self) -> PathBuf {
+        self.location_info
+            .instance_dir(INSTANCE_ID)
+            .join("resourcepacks")
+    }
+
+    fn shared_folder(&self) -> PathBuf {
+        self.location_info.shared_folder("resourcepacks")
+    }
+
+    async fn add_resource_pack(&self, file_name: &str, bytes: &str) {
+        write_file(&self.instance_folder().join(file_name), bytes);
+
+        self.pack_storage
+            .update_pack_file(
+                INSTANCE_ID,
+                &format!("resourcepacks/{file_name}"),
+                &pack_file(file_name),
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn execute(&self, shared: bool) -> Result<(), InstanceError> {
+        self.change_shared_folder_use_case
+            .execute(INSTANCE_ID.to_owned(), SharedFolder::ResourcePacks, shared)
+            .await
+    }
+
+    async fn shared_folders(&self) -> Vec<SharedFolder> {
+        self.instance_storage
+            .get(INSTANCE_ID)
+            .await
+            .unwrap()
+            .shared_folders
+    }
+}
+
+fn write_file(path: &Path, bytes: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, bytes).unwrap();
+}
+
+fn pack_file(file_name: &str) -> PackFile {
+    PackFile {
+        file_name: file_name.to_owned(),
+        name: None,
+        hash: "hash".to_owned(),
+        download: None,
+        option: None,
+        side: None,
+        update_provider: None,
+        update: None,
+    }
+}
+
+async fn link_folder(location_info: &LocationInfo, instance_id: &str, folder: SharedFolder) {
+    let shared_dir = location_info.shared_folder(folder.get_folder());
+    std::fs::create_dir_all(&shared_dir).unwrap();
+    std::fs::create_dir_all(location_info.instance_dir(instance_id)).unwrap();
+
+    create_dir_link(
+        &shared_dir,
+        location_info
+            .instance_dir(instance_id)
+            .join(folder.get_folder()),
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_linked_folder_content_is_visible_to_every_instance() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+    link_folder(&location_info, "first", SharedFolder::ResourcePacks).await;
+    link_folder(&location_info, "second", SharedFolder::ResourcePacks).await;
+
+    let pack_storage = FsPackStorage::new(location_info.clone());
+    pack_storage
+        .update_pack_file(
+            "first",
+            "resourcepacks/faithful.zip",
+            &pack_file("faithful.zip"),
+        )
+        .await
+        .unwrap();
+    pack_storage
+        .update_pack_file("first", "mods/sodium.jar", &pack_file("sodium.jar"))
+        .await
+        .unwrap();
+
+    let second_pack = pack_storage.get_pack("second").await.unwrap();
+    let files: Vec<&str> = second_pack
+        .files
+        .iter()
+        .map(|entry| entry.file.as_str())
+        .collect();
+
+    assert_eq!(files, ["resourcepacks/faithful.zip"]);
+    assert!(pack_storage
+        .get_pack_file("second", "resourcepacks/faithful.zip")
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_concurrent_updates_keep_every_shared_entry() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+    link_folder(&location_info, "first", SharedFolder::ResourcePacks).await;
+    link_folder(&location_info, "second", SharedFolder::ResourcePacks).await;
+
+    let pack_storage = FsPackStorage::new(location_info.clone());
+    let file_names: Vec<String> = (0..10)
+        .flat_map(|i| [format!("first-{i}.zip"), format!("second-{i}.zip")])
+        .collect();
+
+    // Both instances rewrite the same shared pack, no update may be lost
+    let pack_storage = &pack_storage;
+    futures::future::join_all(file_names.iter().map(|file_name| async move {
+        let instance_id = if file_name.starts_with("first") {
+            "first"
+        } else {
+            "second"
+        };
+
+        pack_storage
+            .update_pack_file(
+                instance_id,
+                &format!("resourcepacks/{file_name}"),
+                &pack_file(file_name),
+            )
+            .await
+    }))
+    .await
+    .into_iter()
+    .collect::<Result<Vec<_>, _>>()
+    .unwrap();
+
+    let pack = pack_storage.get_pack("second").await.unwrap();
+    assert_eq!(pack.files.len(), file_names.len());
+}
+
+#[tokio::test]
+async fn test_remove_instance_keeps_shared_folder() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+    link_folder(&location_info, "first", SharedFolder::Saves).await;
+
+    let world_file = location_info.shared_folder("saves").join("level.dat");
+    std::fs::write(&world_file, [0; 8]).unwrap();
+
+    FsInstanceStorage::new(location_info.clone())
+        .remove("first")
+        .await
+        .unwrap();
+
+    assert!(!location_info.instance_dir("first").exists());
+    assert!(world_file.exists());
+}
+
+#[tokio::test]
+async fn test_share_folder_moves_files_and_pack_metadata() {
+    let setup = Setup::new().await;
+    setup.add_resource_pack("faithful.zip", "faithful").await;
+
+    setup.execute(true).await.unwrap();
+
+    assert!(setup.instance_folder().is_symlink());
+    assert_eq!(
+        std::fs::read_to_string(setup.shared_folder().join("faithful.zip")).unwrap(),
+        "faithful"
+    );
+    assert_eq!(setup.shared_folders().await, [SharedFolder::ResourcePacks]);
+
+    // Metadata follows the file into the shared pack
+    assert!(setup
+        .location_info
+        .shared_pack_dir()
+        .join("resourcepacks/faithful.toml")
+        .exists());
+    assert!(!setup
+        .location_info
+        .instance_pack_dir(INSTANCE_ID)
+        .join("resourcepacks/faithful.toml")
+        .exists());
+    assert!(setup
+        .pack_storage
+        .get_pack_file(INSTANCE_ID, "resourcepacks/faithful.zip")
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_unshare_folder_copies_shared_files_back() {
+    let setup = Setup::new().await;
+    setup.add_resource_pack("faithful.zip", "faithful").await;
+    setup.execute(true).await.unwrap();
+
+    setup.execute(false).await.unwrap();
+
+    assert!(!setup.instance_folder().is_symlink());
+    assert_eq!(
+        std::fs::read_to_string(setup.instance_folder().join("faithful.zip")).unwrap(),
+        "faithful"
+    );
+    assert!(setup.shared_folders().await.is_empty());
+
+    // Other instances may still link the shared copy
+    assert!(setup.shared_folder().join("faithful.zip").exists());
+
+    assert!(setup
+        .location_info
+        .instance_pack_dir(INSTANCE_ID)
+        .join("resourcepacks/faithful.toml")
+        .exists());
+    assert!(setup
+        .pack_storage
+        .get_pack_file(INSTANCE_ID, "resourcepacks/faithful.zip")
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_share_folder_conflict_leaves_instance_untouched() {
+    let setup = Setup::new().await;
+    write_file(&setup.shared_folder().join("faithful.zip"), "other");
+    write_file(&setup.shared_folder().join("default.zip"), "default");
+    setup.add_resource_pack("faithful.zip", "faithful").await;
+    setup.add_resource_pack("default.zip", "default").await;
+    setup.add_resource_pack("vanilla.zip", "vanilla").await;
+
+    let err = setup.execute(true).await.err().unwrap();
+
+    // Identical files aren't conflicts
+    assert!(matches!(
+        err,
+        InstanceError::SharedFolderConflict { folder, files }
+            if folder == "resourcepacks" && files == ["faithful.zip"]
+    ));
+
+    assert!(!setup.instance_folder().is_symlink());
+    assert!(setup.instance_folder().join("vanilla.zip").exists());
+    assert!(!setup.shared_folder().join("vanilla.zip").exists());
+    assert!(setup.shared_folders().await.is_empty());
+    assert!(setup
+        .location_info
+        .instance_pack_dir(INSTANCE_ID)
+        .join("resourcepacks/vanilla.toml")
+        .exists());
+}
+
+#[tokio::test]
+async fn test_change_shared_folder_refused_while_running() {
+    let setup = Setup::new().await;
+    setup.add_resource_pack("faithful.zip", "faithful").await;
+    let _running = setup.lock_service.mark_running(INSTANCE_ID);
+
+    let err = setup.execute(true).await.err().unwrap();
+
+    assert!(matches!(
+        err,
+        InstanceError::InstanceBusy {
+            operation: InstanceOperation::Running,
+            ..
+        }
+    ));
+    assert!(!setup.instance_folder().is_symlink());
+    assert!(!setup.shared_folder().join("faithful.zip").exists());
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use crate::features::{
+    events::{
+        infra::InMemoryProgressBarStorage, tests::fixtures::MockEventEmitter, ProgressServiceImpl,
+    },
+    instance::{
+        app::RestoreSnapshotUseCase,
+        infra::{FsInstanceStorage, FsSnapshotStorage},
+        InstanceLockService, InstanceStorage, SnapshotReason,
+    },
+    settings::LocationInfo,
+};
+
+use super::fixtures::{test_create_snapshot_use_case, test_instance};
+
+const INSTANCE_ID: &str = "survival";
+
+#[tokio::test]
+async fn test_restore_replaces_folders_with_snapshot_content() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+    let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+    instance_storage
+        .upsert(&test_instance(INSTANCE_ID))
+        .await
+        .unwrap();
+
+    let instance_dir = location_info.instance_dir(INSTANCE_ID);
+    let level_dat = instance_dir.join("saves/World/level.dat");
+    std::fs::create_dir_all(level_dat.parent().unwrap()).unwrap();
+    std::fs::write(&level_dat, "before").unwrap();
+    std::fs::create_dir_all(instance_dir.join("mods")).unwrap();
+    std::fs::write(instance_dir.join("mods/sodium.jar"), "sodium").unwrap();
+    let sessions_file = location_info.instance_sessions_file(INSTANCE_ID);
+    std::fs::write(&sessions_file, "[]").unwrap();
+
+    let snapshot = test_create_snapshot_use_case(instance_storage.clone(), location_info.clone())
+        .execute(INSTANCE_ID.to_owned(), SnapshotReason::Manual)
+        .await
+        .unwrap();
+
+    std::fs::write(&level_dat, "after").unwrap();
+    std::fs::write(&sessions_file, "[session]").unwrap();
+    std::fs::remove_file(instance_dir.join("mods/sodium.jar")).unwrap();
+    std::fs::write(instance_dir.join("mods/lithium.jar"), "lithium").unwrap();
+    std::fs::create_dir_all(instance_dir.join("resourcepacks")).unwrap();
+    std::fs::write(instance_dir.join("resourcepacks/pack.zip"), "pack").unwrap();
+
+    RestoreSnapshotUseCase::new(
+        instance_storage,
+        Arc::new(FsSnapshotStorage::new(location_info.clone())),
+        Arc::new(ProgressServiceImpl::new(
+            Arc::new(MockEventEmitter::new()),
+            Arc::new(InMemoryProgressBarStorage::default()),
+        )),
+        location_info.clone(),
+        Arc::new(InstanceLockService::new()),
+    )
+    .execute(INSTANCE_ID.to_owned(), snapshot.id)
+    .await
+    .unwrap();
+
+    assert_eq!(std::fs::read_to_string(&level_dat).unwrap(), "before");
+    assert!(instance_dir.join("mods/sodium.jar").exists());
+    assert!(!instance_dir.join("mods/lithium.jar").exists());
+    assert!(!instance_dir.join("resourcepacks").exists());
+
+    // Play sessions recorded after the snapshot are kept
+    assert_eq!(
+        std::fs::read_to_string(&sessions_file).unwrap(),
+        "[session]"
+    );
+
+    // Staged and replaced folders are cleaned up
+    let snapshots_dir = location_info.instance_snapshots_dir(INSTANCE_ID);
+    assert!(!snapshots_dir.join(".restore").exists());
+    assert!(!snapshots_dir.join(".restore-backup").exists());
+}
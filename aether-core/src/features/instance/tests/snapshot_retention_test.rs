@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::features::{
+    instance::{
+        app::ApplySnapshotRetentionUseCase,
+        infra::{FsInstanceStorage, FsSnapshotStorage},
+        InstanceError, InstanceLockService, InstanceOperation, InstanceSnapshot, InstanceStorage,
+        SnapshotReason, SnapshotRetention,
+    },
+    settings::LocationInfo,
+};
+
+use super::fixtures::test_instance;
+
+fn snapshot(created: DateTime<Utc>) -> InstanceSnapshot {
+    InstanceSnapshot {
+        id: InstanceSnapshot::get_id(&created),
+        instance_id: "instance".to_owned(),
+        created,
+        reason: SnapshotReason::Manual,
+        size: 0,
+    }
+}
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 6, 10, 12, 0, 0).unwrap()
+}
+
+#[test]
+fn test_keep_last_removes_oldest() {
+    let snapshots: Vec<InstanceSnapshot> = (0..5)
+        .map(|i| snapshot(now() - Duration::hours(i)))
+        .collect();
+
+    let expired = SnapshotRetention::KeepLast { count: 2 }.get_expired(&snapshots, now());
+
+    assert_eq!(
+        expired,
+        vec![
+            snapshots[2].id.clone(),
+            snapshots[3].id.clone(),
+            snapshots[4].id.clone()
+        ]
+    );
+}
+
+#[test]
+fn test_keep_daily_keeps_newest_of_each_day() {
+    let snapshots = vec![
+        snapshot(now() - Duration::hours(1)),
+        snapshot(now() - Duration::hours(2)),
+        snapshot(now() - Duration::days(1)),
+        snapshot(now() - Duration::days(1) - Duration::hours(1)),
+        snapshot(now() - Duration::days(5)),
+    ];
+
+    let expired = SnapshotRetention::KeepDaily { days: 3 }.get_expired(&snapshots, now());
+
+    assert_eq!(
+        expired,
+        vec![
+            snapshots[1].id.clone(),
+            snapshots[3].id.clone(),
+            snapshots[4].id.clone()
+        ]
+    );
+}
+
+#[test]
+fn test_newest_snapshot_is_always_kept() {
+    let snapshots = vec![
+        snapshot(now() - Duration::days(30)),
+        snapshot(now() - Duration::days(10)),
+    ];
+
+    let expired = SnapshotRetention::KeepDaily { days: 7 }.get_expired(&snapshots, now());
+    assert_eq!(expired, vec![snapshots[0].id.clone()]);
+
+    let expired = SnapshotRetention::KeepLast { count: 0 }.get_expired(&snapshots, now());
+    assert_eq!(expired, vec![snapshots[0].id.clone()]);
+}
+
+#[tokio::test]
+async fn test_apply_retention_waits_for_other_operations() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+    let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+    instance_storage
+        .upsert(&test_instance("instance"))
+        .await
+        .unwrap();
+    let lock_service = Arc::new(InstanceLockService::new());
+
+    let apply_snapshot_retention_use_case = ApplySnapshotRetentionUseCase::new(
+        instance_storage,
+        Arc::new(FsSnapshotStorage::new(location_info)),
+        lock_service.clone(),
+    );
+
+    let lock = lock_service
+        .try_lock("instance", InstanceOperation::RestoreSnapshot)
+        .unwrap();
+    let result = apply_snapshot_retention_use_case
+        .execute(
+            "instance".to_owned(),
+            Some(SnapshotRetention::KeepLast { count: 1 }),
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(InstanceError::InstanceBusy {
+            operation: InstanceOperation::RestoreSnapshot,
+            ..
+        })
+    ));
+    drop(lock);
+
+    // Snapshot creation applies the policy while holding its own lock
+    let _lock = lock_service
+        .try_lock("instance", InstanceOperation::CreateSnapshot)
+        .unwrap();
+    let removed = apply_snapshot_retention_use_case
+        .execute_auto("instance".to_owned())
+        .await
+        .unwrap();
+    assert!(removed.is_empty());
+}
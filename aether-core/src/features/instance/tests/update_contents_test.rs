@@ -0,0 +1,331 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde_json::json;
+
+use crate::features::{
+    events::{tests::fixtures::MockEventEmitter, InstanceEventType},
+    instance::{
+        app::{
+            CheckContentUpdatesUseCase, InstallContentUseCase, ListContentUseCase,
+            UpdateContentsUseCase,
+        },
+        infra::{FsInstanceStorage, FsPackStorage, FsSnapshotStorage},
+        ContentProviderRegistry, ContentUpdate, ContentVersionType, InstanceLockService,
+        InstanceStorage, PackFile, PackStorage,
+    },
+    settings::LocationInfo,
+};
+
+use super::fixtures::{
+    test_create_snapshot_use_case, test_instance, MockContentProvider, TestProgressService,
+};
+
+const INSTANCE_ID: &str = "survival";
+
+type TestUpdateContentsUseCase = UpdateContentsUseCase<
+    MockEventEmitter,
+    FsInstanceStorage,
+    FsPackStorage,
+    MockContentProvider,
+    FsSnapshotStorage,
+    TestProgressService,
+>;
+
+struct Setup {
+    _dir: tempfile::TempDir,
+    location_info: Arc<LocationInfo>,
+    pack_storage: Arc<FsPackStorage>,
+    event_emitter: Arc<MockEventEmitter>,
+    check_content_updates_use_case:
+        Arc<CheckContentUpdatesUseCase<FsInstanceStorage, FsPackStorage, MockContentProvider>>,
+    update_contents_use_case: TestUpdateContentsUseCase,
+}
+
+impl Setup {
+    async fn new(provider: MockContentProvider) -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let location_info = Arc::new(LocationInfo::new(
+            dir.path().to_path_buf(),
+            dir.path().to_path_buf(),
+        ));
+        let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+        let pack_storage = Arc::new(FsPackStorage::new(location_info.clone()));
+        let event_emitter = Arc::new(MockEventEmitter::new());
+        let lock_service = Arc::new(InstanceLockService::new());
+
+        instance_storage
+            .upsert(&test_instance(INSTANCE_ID))
+            .await
+            .unwrap();
+
+        let provider_registry = Arc::new(ContentProviderRegistry::new(HashMap::from([(
+            "mock".to_owned(),
+            provider.with_location_info(location_info.clone()),
+        )])));
+
+        let check_content_updates_use_case = Arc::new(CheckContentUpdatesUseCase::new(
+            instance_storage.clone(),
+            Arc::new(ListContentUseCase::new(
+                pack_storage.clone(),
+                location_info.clone(),
+            )),
+            pack_storage.clone(),
+            provider_registry.clone(),
+        ));
+
+        let update_contents_use_case = UpdateContentsUseCase::new(
+            event_emitter.clone(),
+            instance_storage.clone(),
+            pack_storage.clone(),
+            check_content_updates_use_case.clone(),
+            Arc::new(InstallContentUseCase::new(
+                pack_storage.clone(),
+                provider_registry,
+                lock_service.clone(),
+            )),
+            Arc::new(test_create_snapshot_use_case(
+                instance_storage,
+                location_info.clone(),
+            )),
+            location_info.clone(),
+            lock_service,
+        );
+
+        Self {
+            _dir: dir,
+            location_info,
+            pack_storage,
+            event_emitter,
+            check_content_updates_use_case,
+            update_contents_use_case,
+        }
+    }
+
+    fn write_content(&self, content_path: &str) {
+        let path = self
+            .location_info
+            .instance_dir(INSTANCE_ID)
+            .join(content_path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content_path).unwrap();
+    }
+
+    fn read_content(&self, content_path: &str) -> Option<String> {
+        std::fs::read_to_string(
+            self.location_info
+                .instance_dir(INSTANCE_ID)
+                .join(content_path),
+        )
+        .ok()
+    }
+
+    async fn add_provider_content(
+        &self,
+        project_id: &str,
+        version: &str,
+        disabled: bool,
+    ) -> String {
+        let file_name = format!("{project_id}-{version}.jar");
+        let content_path = format!("mods/{file_name}");
+
+        if disabled {
+            self.write_content(&format!("{content_path}.disabled"));
+        } else {
+            self.write_content(&content_path);
+        }
+
+        self.pack_storage
+            .update_pack_file(
+                INSTANCE_ID,
+                &content_path,
+                &PackFile {
+                    file_name,
+                    name: Some(project_id.to_owned()),
+                    hash: String::new(),
+                    download: None,
+                    option: None,
+                    side: None,
+                    update_provider: Some("mock".to_owned()),
+                    update: Some(HashMap::from([(
+                        "mock".to_owned(),
+                        json!({ "project_id": project_id, "version": version }),
+                    )])),
+                },
+            )
+            .await
+            .unwrap();
+
+        content_path
+    }
+
+    async fn has_pack_file(&self, content_path: &str) -> bool {
+        self.pack_storage
+            .get_pack_file(INSTANCE_ID, content_path)
+            .await
+            .is_ok()
+    }
+
+    async fn update(&self, content_paths: &[&str]) -> Vec<String> {
+        self.update_contents_use_case
+            .execute(
+                INSTANCE_ID.to_owned(),
+                content_paths
+                    .iter()
+                    .map(|path| (*path).to_owned())
+                    .collect(),
+            )
+            .await
+            .unwrap()
+    }
+
+    fn was_edited(&self) -> bool {
+        self.event_emitter
+            .has_instance_event(INSTANCE_ID, InstanceEventType::Edited)
+    }
+}
+
+fn latest_version(update: &ContentUpdate) -> Option<&str> {
+    update
+        .latest_version
+        .as_ref()
+        .map(|version| version.id.as_str())
+}
+
+#[tokio::test]
+async fn test_update_replaces_file_and_pack_entry() {
+    let setup = Setup::new(
+        MockContentProvider::new("https://mock.test").with_versions("sodium", &["2.0", "1.0"]),
+    )
+    .await;
+    let sodium = setup.add_provider_content("sodium", "1.0", false).await;
+
+    let updated = setup.update(&[&sodium]).await;
+
+    assert_eq!(updated, [sodium.clone()]);
+    assert_eq!(
+        setup.read_content("mods/sodium-2.0.jar").as_deref(),
+        Some("sodium 2.0")
+    );
+    assert_eq!(setup.read_content(&sodium), None);
+
+    // File name changed, so the old entry must not linger in the pack
+    assert!(!setup.has_pack_file(&sodium).await);
+    assert!(setup.has_pack_file("mods/sodium-2.0.jar").await);
+
+    assert!(setup.was_edited());
+}
+
+#[tokio::test]
+async fn test_update_keeps_disabled_state() {
+    let setup = Setup::new(
+        MockContentProvider::new("https://mock.test").with_versions("lithium", &["1.1", "1.0"]),
+    )
+    .await;
+    let lithium = setup.add_provider_content("lithium", "1.0", true).await;
+
+    let updated = setup.update(&[&lithium]).await;
+
+    assert_eq!(updated, [lithium.clone()]);
+    assert_eq!(
+        setup
+            .read_content("mods/lithium-1.1.jar.disabled")
+            .as_deref(),
+        Some("lithium 1.1")
+    );
+    assert_eq!(setup.read_content("mods/lithium-1.1.jar"), None);
+    assert_eq!(setup.read_content(&format!("{lithium}.disabled")), None);
+}
+
+#[tokio::test]
+async fn test_failed_update_keeps_old_file() {
+    let setup = Setup::new(
+        MockContentProvider::new("https://mock.test")
+            .with_versions("phosphor", &["2.0", "1.0"])
+            .with_failing_install("phosphor"),
+    )
+    .await;
+    let phosphor = setup.add_provider_content("phosphor", "1.0", false).await;
+
+    let updated = setup.update(&[&phosphor]).await;
+
+    assert!(updated.is_empty());
+    assert_eq!(setup.read_content(&phosphor), Some(phosphor.clone()));
+    assert!(setup.has_pack_file(&phosphor).await);
+
+    // Nothing changed, so the instance isn't reported as edited
+    assert!(!setup.was_edited());
+}
+
+#[tokio::test]
+async fn test_check_updates_skips_less_stable_and_older_versions() {
+    let setup = Setup::new(
+        MockContentProvider::new("https://mock.test")
+            .with_typed_versions(
+                "sodium",
+                &[
+                    ("2.0-beta", ContentVersionType::Beta, 3),
+                    ("1.5", ContentVersionType::Release, 2),
+                    ("1.0", ContentVersionType::Release, 1),
+                ],
+            )
+            .with_typed_versions(
+                "iris",
+                &[
+                    ("1.5", ContentVersionType::Release, 2),
+                    ("2.0-beta", ContentVersionType::Beta, 3),
+                ],
+            )
+            .with_typed_versions(
+                "lithium",
+                &[
+                    ("1.2", ContentVersionType::Release, 3),
+                    ("1.1-beta", ContentVersionType::Beta, 2),
+                ],
+            )
+            .with_typed_versions(
+                "phosphor",
+                &[
+                    ("2.0-alpha", ContentVersionType::Alpha, 3),
+                    ("1.5", ContentVersionType::Release, 2),
+                ],
+            ),
+    )
+    .await;
+    setup.add_provider_content("sodium", "1.0", false).await;
+    setup.add_provider_content("iris", "2.0-beta", false).await;
+    setup
+        .add_provider_content("lithium", "1.1-beta", false)
+        .await;
+    setup.add_provider_content("phosphor", "1.0", false).await;
+
+    let updates = setup
+        .check_content_updates_use_case
+        .execute(INSTANCE_ID.to_owned())
+        .await
+        .unwrap();
+
+    let latest_versions: Vec<(&str, Option<&str>, bool)> = updates
+        .iter()
+        .map(|update| {
+            (
+                update.content_path.as_str(),
+                latest_version(update),
+                update.update_available,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        latest_versions,
+        [
+            // Older release isn't offered over the installed beta
+            ("mods/iris-2.0-beta.jar", Some("2.0-beta"), false),
+            // Newer release is more stable than the installed beta
+            ("mods/lithium-1.1-beta.jar", Some("1.2"), true),
+            // Installed version isn't listed, only releases are offered
+            ("mods/phosphor-1.0.jar", Some("1.5"), true),
+            // Beta isn't offered over the installed release
+            ("mods/sodium-1.0.jar", Some("1.5"), true),
+        ]
+    );
+}
@@ -0,0 +1,307 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde_json::json;
+
+use crate::{
+    features::{
+        instance::{
+            app::{InstallContentUseCase, VerifyInstanceUseCase},
+            infra::{FsInstanceStorage, FsPackStorage},
+            ContentProviderRegistry, InstanceError, InstanceIntegrityReport, InstanceLockService,
+            InstanceOperation, InstanceStorage, IntegrityFile, PackFile, PackStorage,
+        },
+        java::{app::GetJavaUseCase, infra::FsJavaStorage},
+        minecraft::{
+            app::{GetVersionManifestUseCase, VerifyMinecraftUseCase},
+            FileIntegrityStatus, GameFileIssue, GameFileKind, LoaderVersionResolver,
+        },
+        settings::LocationInfo,
+    },
+    shared::sha1_async,
+};
+
+use super::fixtures::{
+    test_instance, MockContentProvider, MockJavaInstallationService, MockMetadataStorage,
+    MockMinecraftDownloader,
+};
+
+const INSTANCE_ID: &str = "survival";
+
+type TestVerifyInstanceUseCase = VerifyInstanceUseCase<
+    FsInstanceStorage,
+    FsPackStorage,
+    MockContentProvider,
+    MockMetadataStorage,
+    MockMinecraftDownloader,
+    MockJavaInstallationService,
+    FsJavaStorage,
+>;
+
+struct Setup {
+    _dir: tempfile::TempDir,
+    location_info: Arc<LocationInfo>,
+    pack_storage: Arc<FsPackStorage>,
+    lock_service: Arc<InstanceLockService>,
+    verify_instance_use_case: TestVerifyInstanceUseCase,
+}
+
+impl Setup {
+    async fn new(minecraft_downloader: MockMinecraftDownloader, failing_installs: &[&str]) -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let location_info = Arc::new(LocationInfo::new(
+            dir.path().to_path_buf(),
+            dir.path().to_path_buf(),
+        ));
+        let instance_storage = Arc::new(FsInstanceStorage::new(location_info.clone()));
+        let pack_storage = Arc::new(FsPackStorage::new(location_info.clone()));
+        let lock_service = Arc::new(InstanceLockService::new());
+
+        let mut instance = test_instance(INSTANCE_ID);
+        instance.java_path = Some("java".to_owned());
+        instance_storage.upsert(&instance).await.unwrap();
+
+        let provider = failing_installs.iter().fold(
+            MockContentProvider::new("https://mock.test").with_location_info(location_info.clone()),
+            |provider, project_id| provider.with_failing_install(project_id),
+        );
+        let provider_registry = Arc::new(ContentProviderRegistry::new(HashMap::from([(
+            "mock".to_owned(),
+            provider,
+        )])));
+
+        let metadata_storage = Arc::new(MockMetadataStorage);
+        let verify_minecraft_use_case = Arc::new(VerifyMinecraftUseCase::new(
+            Arc::new(LoaderVersionResolver::new(metadata_storage.clone())),
+            Arc::new(GetVersionManifestUseCase::new(metadata_storage)),
+            minecraft_downloader,
+            MockJavaInstallationService,
+            Arc::new(GetJavaUseCase::new(
+                Arc::new(FsJavaStorage::new(dir.path())),
+                MockJavaInstallationService,
+            )),
+        ));
+
+        let verify_instance_use_case = VerifyInstanceUseCase::new(
+            instance_storage,
+            pack_storage.clone(),
+            provider_registry.clone(),
+            verify_minecraft_use_case,
+            Arc::new(InstallContentUseCase::new(
+                pack_storage.clone(),
+                provider_registry,
+                lock_service.clone(),
+            )),
+            location_info.clone(),
+            lock_service.clone(),
+        );
+
+        Self {
+            _dir: dir,
+            location_info,
+            pack_storage,
+            lock_service,
+            verify_instance_use_case,
+        }
+    }
+
+    /// Records provider content whose expected bytes are the ones the mock provider installs,
+    /// `bytes` is what's on disk now
+    async fn add_content(
+        &self,
+        project_id: &str,
+        version: Option<&str>,
+        bytes: Option<&str>,
+        disabled: bool,
+    ) -> String {
+        let version_name = version.unwrap_or("1.0");
+        let file_name = format!("{project_id}-{version_name}.jar");
+        let content_path = format!("mods/{file_name}");
+
+        if let Some(bytes) = bytes {
+            let path = if disabled {
+                format!("{content_path}.disabled")
+            } else {
+                content_path.clone()
+            };
+            self.write_file(&path, bytes);
+        }
+
+        let mut update_data = json!({ "project_id": project_id });
+        if let Some(version) = version {
+            update_data["version"] = json!(version);
+        }
+
+        self.pack_storage
+            .update_pack_file(
+                INSTANCE_ID,
+                &content_path,
+                &PackFile {
+                    file_name,
+                    name: Some(project_id.to_owned()),
+                    hash: sha1_async(format!("{project_id} {version_name}").into_bytes())
+                        .await
+                        .unwrap(),
+                    download: None,
+                    option: None,
+                    side: None,
+                    update_provider: Some("mock".to_owned()),
+                    update: Some(HashMap::from([("mock".to_owned(), update_data)])),
+                },
+            )
+            .await
+            .unwrap();
+
+        content_path
+    }
+
+    fn write_file(&self, relative_path: &str, bytes: &str) {
+        let path = self
+            .location_info
+            .instance_dir(INSTANCE_ID)
+            .join(relative_path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, bytes).unwrap();
+    }
+
+    fn read_file(&self, relative_path: &str) -> Option<String> {
+        std::fs::read_to_string(
+            self.location_info
+                .instance_dir(INSTANCE_ID)
+                .join(relative_path),
+        )
+        .ok()
+    }
+
+    async fn execute(&self, repair: bool) -> Result<InstanceIntegrityReport, InstanceError> {
+        self.verify_instance_use_case
+            .execute(INSTANCE_ID.to_owned(), repair)
+            .await
+    }
+}
+
+fn missing_client() -> GameFileIssue {
+    GameFileIssue {
+        kind: GameFileKind::Client,
+        name: "1.20.1".to_owned(),
+        status: FileIntegrityStatus::Missing,
+    }
+}
+
+fn game_file(issue: &GameFileIssue) -> IntegrityFile {
+    IntegrityFile::Game {
+        kind: issue.kind,
+        name: issue.name.clone(),
+    }
+}
+
+fn content_file(content_path: &str) -> IntegrityFile {
+    IntegrityFile::Content {
+        content_path: content_path.to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn test_verify_reports_broken_files_without_changes() {
+    let setup = Setup::new(MockMinecraftDownloader::new(vec![missing_client()]), &[]).await;
+    let sodium = setup
+        .add_content("sodium", Some("1.0"), Some("broken"), false)
+        .await;
+    let lithium = setup.add_content("lithium", Some("1.0"), None, false).await;
+    setup
+        .add_content("iris", Some("1.0"), Some("iris 1.0"), false)
+        .await;
+    setup.write_file("mods/custom.jar", "custom");
+
+    let report = setup.execute(false).await.unwrap();
+
+    assert_eq!(report.missing.len(), 2);
+    assert!(report.missing.contains(&game_file(&missing_client())));
+    assert!(report.missing.contains(&content_file(&lithium)));
+    assert_eq!(report.corrupted, vec![content_file(&sodium)]);
+    assert_eq!(report.untracked, vec!["mods/custom.jar".to_owned()]);
+    assert!(report.repaired.is_empty());
+    assert_eq!(setup.read_file(&sodium).as_deref(), Some("broken"));
+}
+
+#[tokio::test]
+async fn test_repair_downloads_broken_files_again() {
+    let setup = Setup::new(MockMinecraftDownloader::new(vec![missing_client()]), &[]).await;
+    let sodium = setup
+        .add_content("sodium", Some("1.0"), Some("broken"), false)
+        .await;
+    let lithium = setup
+        .add_content("lithium", Some("1.0"), Some("broken"), true)
+        .await;
+
+    let report = setup.execute(true).await.unwrap();
+
+    assert_eq!(report.repaired.len(), 3);
+    assert!(report.repaired.contains(&game_file(&missing_client())));
+    assert!(report.repaired.contains(&content_file(&sodium)));
+    assert!(report.repaired.contains(&content_file(&lithium)));
+
+    assert_eq!(setup.read_file(&sodium).as_deref(), Some("sodium 1.0"));
+
+    // Disabled content stays disabled
+    assert_eq!(
+        setup.read_file(&format!("{lithium}.disabled")).as_deref(),
+        Some("lithium 1.0")
+    );
+    assert_eq!(setup.read_file(&lithium), None);
+
+    assert!(report.untracked.is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_repair_keeps_broken_files() {
+    let setup = Setup::new(
+        MockMinecraftDownloader::new(vec![missing_client()]).with_failing_repair(),
+        &["phosphor"],
+    )
+    .await;
+    let phosphor = setup
+        .add_content("phosphor", Some("1.0"), Some("broken"), false)
+        .await;
+
+    let report = setup.execute(true).await.unwrap();
+
+    assert!(report.repaired.is_empty());
+    assert_eq!(report.missing, vec![game_file(&missing_client())]);
+    assert_eq!(report.corrupted, vec![content_file(&phosphor)]);
+
+    // Broken copy is put back instead of leaving the content missing
+    assert_eq!(setup.read_file(&phosphor).as_deref(), Some("broken"));
+    assert!(report.untracked.is_empty());
+}
+
+#[tokio::test]
+async fn test_content_without_version_is_not_repaired() {
+    let setup = Setup::new(MockMinecraftDownloader::default(), &[]).await;
+    let sodium = setup
+        .add_content("sodium", None, Some("broken"), false)
+        .await;
+
+    let report = setup.execute(true).await.unwrap();
+
+    assert!(report.repaired.is_empty());
+    assert_eq!(report.corrupted, vec![content_file(&sodium)]);
+    assert_eq!(setup.read_file(&sodium).as_deref(), Some("broken"));
+}
+
+#[tokio::test]
+async fn test_repair_refused_while_running() {
+    let setup = Setup::new(MockMinecraftDownloader::default(), &[]).await;
+    let _running = setup.lock_service.mark_running(INSTANCE_ID);
+
+    let err = setup.execute(true).await.err().unwrap();
+    assert!(matches!(
+        err,
+        InstanceError::InstanceBusy {
+            operation: InstanceOperation::Running,
+            ..
+        }
+    ));
+
+    // Checking alone doesn't touch game files
+    assert!(setup.execute(false).await.is_ok());
+}
@@ -0,0 +1,9 @@
+use crate::shared::IoError;
+
+pub fn get_zip_error(err: zip::result::ZipError) -> IoError {
+    IoError::IoError(std::io::Error::other(err))
+}
+
+pub fn get_join_error(err: tokio::task::JoinError) -> IoError {
+    IoError::IoError(std::io::Error::other(err))
+}
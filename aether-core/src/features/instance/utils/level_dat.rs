@@ -0,0 +1,78 @@
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
+
+use crate::features::instance::InstanceError;
+
+pub const LEVEL_DAT_FILE: &str = "level.dat";
+
+/// Part of `level.dat` shown to the user, the rest of the NBT tree is skipped
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LevelDat {
+    #[serde(rename = "Data")]
+    pub data: LevelData,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LevelData {
+    pub level_name: String,
+    #[serde(default)]
+    pub game_type: i32,
+    #[serde(default, rename = "hardcore")]
+    pub hardcore: i8,
+    /// Unix time in milliseconds
+    #[serde(default)]
+    pub last_played: i64,
+    pub version: Option<LevelVersion>,
+    /// Seed before 1.16
+    pub random_seed: Option<i64>,
+    pub world_gen_settings: Option<LevelWorldGenSettings>,
+    pub data_packs: Option<LevelDataPacks>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LevelVersion {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LevelWorldGenSettings {
+    pub seed: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct LevelDataPacks {
+    #[serde(default)]
+    pub enabled: Vec<String>,
+    #[serde(default)]
+    pub disabled: Vec<String>,
+}
+
+impl LevelData {
+    pub fn seed(&self) -> Option<i64> {
+        self.world_gen_settings
+            .as_ref()
+            .map(|settings| settings.seed)
+            .or(self.random_seed)
+    }
+}
+
+/// Parses gzip compressed `level.dat` content
+pub fn parse_level_dat(bytes: &[u8]) -> Result<LevelDat, InstanceError> {
+    let mut decompressed = Vec::new();
+    GzDecoder::new(bytes)
+        .read_to_end(&mut decompressed)
+        .map_err(get_invalid_world_error)?;
+
+    fastnbt::from_bytes(&decompressed).map_err(get_invalid_world_error)
+}
+
+fn get_invalid_world_error(err: impl std::fmt::Display) -> InstanceError {
+    InstanceError::InvalidWorld {
+        reason: format!("failed to parse {LEVEL_DAT_FILE}: {err}"),
+    }
+}
@@ -0,0 +1,23 @@
+use crate::features::minecraft::ModLoader;
+
+/// Converts a loader version from a modpack manifest to the metadata id.
+/// Forge metadata prefixes loader versions with the game version, packs don't.
+pub fn to_metadata_loader_version(
+    mod_loader: ModLoader,
+    game_version: &str,
+    version: &str,
+) -> String {
+    match mod_loader {
+        ModLoader::Forge if !version.starts_with(&format!("{game_version}-")) => {
+            format!("{game_version}-{version}")
+        }
+        _ => version.to_owned(),
+    }
+}
+
+/// Inverse of [`to_metadata_loader_version`]
+pub fn to_pack_loader_version(game_version: &str, version: &str) -> String {
+    version
+        .trim_start_matches(&format!("{game_version}-"))
+        .to_owned()
+}
@@ -0,0 +1,17 @@
+mod archive_error;
+mod level_dat;
+mod loader_version;
+mod pack_archive;
+mod pack_import;
+mod packwiz_hash;
+mod server_list_ping;
+mod world_archive;
+
+pub use archive_error::*;
+pub use level_dat::*;
+pub use loader_version::*;
+pub use pack_archive::*;
+pub use pack_import::*;
+pub use packwiz_hash::*;
+pub use server_list_ping::*;
+pub use world_archive::*;
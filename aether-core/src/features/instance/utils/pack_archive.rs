@@ -0,0 +1,187 @@
+use std::{
+    collections::BTreeSet,
+    path::{Component, Path, PathBuf},
+};
+
+use path_slash::PathBufExt;
+use serde::{de::DeserializeOwned, Serialize};
+use zip::{write::SimpleFileOptions, ZipArchive, ZipWriter};
+
+use crate::{features::instance::InstanceError, shared::IoError};
+
+use super::{get_join_error, get_zip_error};
+
+/// Checks that a path from a pack or user input stays inside the instance directory.
+/// Empty paths and `.` are rejected, they point at the directory itself
+pub fn is_inner_relative_path(path: &str) -> bool {
+    let mut components = Path::new(path).components().peekable();
+
+    components.peek().is_some()
+        && components.all(|component| matches!(component, Component::Normal(_)))
+}
+
+pub fn validate_include_folders(folders: &[String]) -> Result<(), InstanceError> {
+    for folder in folders {
+        if !is_inner_relative_path(folder) {
+            return Err(InstanceError::ValidationError {
+                field: "includeFolders".to_owned(),
+                reason: format!("\"{folder}\" is outside of the instance directory"),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Collects files under `dir` as slash separated paths relative to `base`.
+/// Links are skipped, a linked shared folder belongs to other instances too
+pub fn collect_files(base: &Path, dir: &Path, files: &mut BTreeSet<String>) -> Result<(), IoError> {
+    match std::fs::symlink_metadata(dir) {
+        Ok(metadata) if metadata.is_dir() => {}
+        Ok(_) => return Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(IoError::with_path(err, dir)),
+    }
+
+    for entry in std::fs::read_dir(dir).map_err(|e| IoError::with_path(e, dir))? {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry
+            .file_type()
+            .map_err(|e| IoError::with_path(e, &path))?;
+
+        if file_type.is_symlink() {
+            continue;
+        }
+
+        if file_type.is_dir() {
+            collect_files(base, &path, files)?;
+        } else if let Ok(relative_path) = path.strip_prefix(base) {
+            files.insert(relative_path.to_slash_lossy().to_string());
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs `collect_files` on the blocking pool, folders can be large
+pub async fn collect_files_async(base: PathBuf, dir: PathBuf) -> Result<BTreeSet<String>, IoError> {
+    tokio::task::spawn_blocking(move || {
+        let mut files = BTreeSet::new();
+        collect_files(&base, &dir, &mut files)?;
+        Ok(files)
+    })
+    .await
+    .map_err(get_join_error)?
+}
+
+pub async fn read_pack_manifest<T>(
+    path: PathBuf,
+    manifest_name: &'static str,
+) -> Result<T, InstanceError>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        let file = std::fs::File::open(&path).map_err(|e| IoError::with_path(e, &path))?;
+
+        let mut archive = ZipArchive::new(file).map_err(get_invalid_modpack_error)?;
+        let manifest_file = archive
+            .by_name(manifest_name)
+            .map_err(get_invalid_modpack_error)?;
+
+        serde_json::from_reader(manifest_file).map_err(get_invalid_modpack_error)
+    })
+    .await
+    .map_err(get_join_error)?
+}
+
+/// Extracts the content of `overrides_dirs` into `target_dir`, later dirs take precedence
+pub async fn extract_pack_overrides(
+    path: PathBuf,
+    overrides_dirs: Vec<String>,
+    target_dir: PathBuf,
+) -> Result<(), InstanceError> {
+    tokio::task::spawn_blocking(move || {
+        let file = std::fs::File::open(&path).map_err(|e| IoError::with_path(e, &path))?;
+        let mut archive = ZipArchive::new(file).map_err(get_invalid_modpack_error)?;
+
+        for overrides_dir in &overrides_dirs {
+            for i in 0..archive.len() {
+                let mut entry = archive.by_index(i).map_err(get_invalid_modpack_error)?;
+
+                if entry.is_dir() {
+                    continue;
+                }
+
+                let Some(relative_path) = entry
+                    .enclosed_name()
+                    .and_then(|name| name.strip_prefix(overrides_dir).ok().map(Path::to_path_buf))
+                else {
+                    continue;
+                };
+
+                let target_path = target_dir.join(relative_path);
+                if let Some(parent) = target_path.parent() {
+                    std::fs::create_dir_all(parent).map_err(|e| IoError::with_path(e, parent))?;
+                }
+
+                let mut target = std::fs::File::create(&target_path)
+                    .map_err(|e| IoError::with_path(e, &target_path))?;
+                std::io::copy(&mut entry, &mut target).map_err(IoError::from)?;
+            }
+        }
+
+        Ok(())
+    })
+    .await
+    .map_err(get_join_error)?
+}
+
+/// Writes a pack archive with a JSON manifest and `overrides` copied from `source_dir`
+pub async fn write_pack_archive<T>(
+    output_path: PathBuf,
+    manifest_name: &'static str,
+    manifest: T,
+    overrides_dir: String,
+    source_dir: PathBuf,
+    overrides: BTreeSet<String>,
+) -> Result<(), InstanceError>
+where
+    T: Serialize + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        let file =
+            std::fs::File::create(&output_path).map_err(|e| IoError::with_path(e, &output_path))?;
+
+        let mut zip = ZipWriter::new(file);
+        let options = SimpleFileOptions::default();
+
+        zip.start_file(manifest_name, options)
+            .map_err(get_zip_error)?;
+        serde_json::to_writer_pretty(&mut zip, &manifest)
+            .map_err(|err| IoError::SerializationError(err.to_string()))?;
+
+        for path in &overrides {
+            let source_path = source_dir.join(path);
+            let mut source = std::fs::File::open(&source_path)
+                .map_err(|e| IoError::with_path(e, &source_path))?;
+
+            zip.start_file(format!("{overrides_dir}/{path}"), options)
+                .map_err(get_zip_error)?;
+            std::io::copy(&mut source, &mut zip).map_err(IoError::from)?;
+        }
+
+        zip.finish().map_err(get_zip_error)?;
+
+        Ok(())
+    })
+    .await
+    .map_err(get_join_error)?
+}
+
+fn get_invalid_modpack_error(err: impl std::fmt::Display) -> InstanceError {
+    InstanceError::InvalidModpack {
+        reason: err.to_string(),
+    }
+}
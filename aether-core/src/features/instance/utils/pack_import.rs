@@ -0,0 +1,68 @@
+use std::future::Future;
+
+use log::error;
+
+use crate::features::instance::{
+    InstanceError, InstanceInstallStage, InstanceStorage, InstanceStorageExt,
+    InstanceWatcherService,
+};
+
+/// Pack files downloaded at the same time during an import
+pub const PACK_CONCURRENT_DOWNLOADS: usize = 8;
+
+/// Installs pack files into an instance just created for the import.
+/// The instance is marked as installing the pack while `install` runs and is removed if any step fails
+pub async fn install_imported_pack<IS, IWS, F>(
+    instance_storage: &IS,
+    instance_watcher_service: &IWS,
+    instance_id: &str,
+    install: F,
+) -> Result<(), InstanceError>
+where
+    IS: InstanceStorage,
+    IWS: InstanceWatcherService,
+    F: Future<Output = Result<(), InstanceError>>,
+{
+    let result = async {
+        set_install_stage(
+            instance_storage,
+            instance_id,
+            InstanceInstallStage::PackInstalling,
+        )
+        .await?;
+        install.await?;
+        set_install_stage(
+            instance_storage,
+            instance_id,
+            InstanceInstallStage::NotInstalled,
+        )
+        .await
+    }
+    .await;
+
+    if result.is_err() {
+        // The watcher would otherwise keep reporting changes of the removed folder
+        if let Err(unwatch_err) = instance_watcher_service.unwatch_instance(instance_id).await {
+            error!("Failed to unwatch instance: {}", unwatch_err);
+        }
+
+        if let Err(cleanup_err) = instance_storage.remove(instance_id).await {
+            error!("Failed to cleanup instance: {}", cleanup_err);
+        }
+    }
+
+    result
+}
+
+async fn set_install_stage<IS: InstanceStorage>(
+    instance_storage: &IS,
+    instance_id: &str,
+    install_stage: InstanceInstallStage,
+) -> Result<(), InstanceError> {
+    instance_storage
+        .upsert_with(instance_id, |instance| {
+            instance.install_stage = install_stage;
+            Ok(())
+        })
+        .await
+}
@@ -0,0 +1,84 @@
+use bytes::Bytes;
+use log::debug;
+
+use crate::{
+    features::instance::{InstanceError, PackwizHashFormat},
+    shared::{md5_async, sha1_async, sha256_async, sha512_async},
+};
+
+const MURMUR2_MULTIPLIER: u32 = 0x5bd1_e995;
+const CURSEFORGE_MURMUR2_SEED: u32 = 1;
+
+pub async fn compute_packwiz_hash(
+    format: PackwizHashFormat,
+    bytes: Bytes,
+) -> Result<String, InstanceError> {
+    let hash = match format {
+        PackwizHashFormat::Sha1 => sha1_async(bytes).await,
+        PackwizHashFormat::Sha256 => sha256_async(bytes).await,
+        PackwizHashFormat::Sha512 => sha512_async(bytes).await,
+        PackwizHashFormat::Md5 => md5_async(bytes).await,
+        PackwizHashFormat::Murmur2 => {
+            tokio::task::spawn_blocking(move || curseforge_murmur2(&bytes).to_string()).await
+        }
+    };
+
+    hash.map_err(|error| {
+        debug!("Failed to compute {format:?} hash: {error}");
+        InstanceError::HashConstructError
+    })
+}
+
+pub async fn verify_packwiz_hash(
+    format: PackwizHashFormat,
+    expected: &str,
+    bytes: Bytes,
+    file: &str,
+) -> Result<(), InstanceError> {
+    let hash = compute_packwiz_hash(format, bytes).await?;
+
+    if !hash.eq_ignore_ascii_case(expected) {
+        return Err(InstanceError::InvalidModpack {
+            reason: format!("hash mismatch for \"{file}\""),
+        });
+    }
+
+    Ok(())
+}
+
+/// CurseForge file fingerprint, 32-bit MurmurHash2 of the file without whitespace bytes
+pub fn curseforge_murmur2(bytes: &[u8]) -> u32 {
+    let bytes: Vec<u8> = bytes
+        .iter()
+        .copied()
+        .filter(|byte| !matches!(byte, b'\t' | b'\n' | b'\r' | b' '))
+        .collect();
+
+    murmur2(&bytes, CURSEFORGE_MURMUR2_SEED)
+}
+
+fn murmur2(bytes: &[u8], seed: u32) -> u32 {
+    let mut hash = seed ^ bytes.len() as u32;
+
+    let mut chunks = bytes.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(MURMUR2_MULTIPLIER);
+        k ^= k >> 24;
+        k = k.wrapping_mul(MURMUR2_MULTIPLIER);
+
+        hash = hash.wrapping_mul(MURMUR2_MULTIPLIER) ^ k;
+    }
+
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        for (i, byte) in remainder.iter().enumerate() {
+            hash ^= u32::from(*byte) << (8 * i);
+        }
+        hash = hash.wrapping_mul(MURMUR2_MULTIPLIER);
+    }
+
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(MURMUR2_MULTIPLIER);
+    hash ^ (hash >> 15)
+}
@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Protocol version sent in the handshake when the client version doesn't matter
+pub const STATUS_PROTOCOL_VERSION: i32 = -1;
+
+pub const HANDSHAKE_PACKET_ID: i32 = 0x00;
+pub const STATUS_REQUEST_PACKET_ID: i32 = 0x00;
+pub const PING_PACKET_ID: i32 = 0x01;
+
+/// Next state value of the handshake that switches the connection to status
+pub const STATUS_NEXT_STATE: i32 = 1;
+
+/// Largest packet length the game accepts
+const MAX_PACKET_LENGTH: usize = 2_097_151;
+
+/// JSON payload of the status response
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct StatusResponse {
+    #[serde(default)]
+    pub version: Option<StatusVersion>,
+    #[serde(default)]
+    pub players: Option<StatusPlayers>,
+    /// Plain string or a text component
+    #[serde(default)]
+    pub description: Option<serde_json::Value>,
+    /// `data:image/png;base64,` URL
+    #[serde(default)]
+    pub favicon: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatusVersion {
+    pub name: String,
+    pub protocol: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatusPlayers {
+    pub max: i64,
+    pub online: i64,
+    #[serde(default)]
+    pub sample: Option<Vec<StatusPlayerSample>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatusPlayerSample {
+    pub name: String,
+    pub id: String,
+}
+
+/// Flattens a text component into plain text
+pub fn get_description_text(description: &serde_json::Value) -> String {
+    match description {
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Array(components) => {
+            components.iter().map(get_description_text).collect()
+        }
+        serde_json::Value::Object(component) => {
+            let mut text = component
+                .get("text")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default()
+                .to_owned();
+
+            if let Some(extra) = component.get("extra") {
+                text.push_str(&get_description_text(extra));
+            }
+
+            text
+        }
+        _ => String::new(),
+    }
+}
+
+pub fn write_varint(buf: &mut Vec<u8>, value: i32) {
+    let mut value = value as u32;
+
+    loop {
+        if value & !0x7F == 0 {
+            buf.push(value as u8);
+            return;
+        }
+
+        buf.push((value & 0x7F | 0x80) as u8);
+        value >>= 7;
+    }
+}
+
+pub fn write_string(buf: &mut Vec<u8>, value: &str) {
+    write_varint(buf, value.len() as i32);
+    buf.extend_from_slice(value.as_bytes());
+}
+
+pub async fn read_varint<R: AsyncRead + Unpin>(reader: &mut R) -> std::io::Result<i32> {
+    let mut value = 0u32;
+
+    for i in 0..5 {
+        let byte = reader.read_u8().await?;
+        value |= ((byte & 0x7F) as u32) << (7 * i);
+
+        if byte & 0x80 == 0 {
+            return Ok(value as i32);
+        }
+    }
+
+    Err(get_invalid_data_error("varint is too long"))
+}
+
+/// Writes a packet prefixed with its length
+pub async fn write_packet<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    packet_id: i32,
+    data: &[u8],
+) -> std::io::Result<()> {
+    let mut body = Vec::with_capacity(data.len() + 5);
+    write_varint(&mut body, packet_id);
+    body.extend_from_slice(data);
+
+    let mut packet = Vec::with_capacity(body.len() + 5);
+    write_varint(&mut packet, body.len() as i32);
+    packet.extend_from_slice(&body);
+
+    writer.write_all(&packet).await?;
+    writer.flush().await
+}
+
+/// Reads a length prefixed packet and returns its id and data
+pub async fn read_packet<R: AsyncRead + Unpin>(reader: &mut R) -> std::io::Result<(i32, Vec<u8>)> {
+    let length = usize::try_from(read_varint(reader).await?)
+        .ok()
+        .filter(|length| *length <= MAX_PACKET_LENGTH)
+        .ok_or_else(|| get_invalid_data_error("invalid packet length"))?;
+
+    let mut packet = vec![0; length];
+    reader.read_exact(&mut packet).await?;
+
+    let mut cursor = packet.as_slice();
+    let packet_id = read_varint(&mut cursor).await?;
+
+    Ok((packet_id, cursor.to_vec()))
+}
+
+/// Reads a length prefixed string from packet data
+pub async fn read_string(data: &mut &[u8]) -> std::io::Result<String> {
+    let length = usize::try_from(read_varint(data).await?)
+        .ok()
+        .filter(|length| *length <= data.len())
+        .ok_or_else(|| get_invalid_data_error("invalid string length"))?;
+
+    let (value, rest) = data.split_at(length);
+    *data = rest;
+
+    String::from_utf8(value.to_vec()).map_err(get_invalid_data_error)
+}
+
+fn get_invalid_data_error(
+    err: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+}
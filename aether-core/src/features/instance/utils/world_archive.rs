@@ -0,0 +1,120 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::{features::instance::InstanceError, shared::IoError};
+
+use super::{collect_files, get_join_error, get_zip_error, LEVEL_DAT_FILE};
+
+/// Held by the game while the world is open
+const SESSION_LOCK_FILE: &str = "session.lock";
+
+/// Writes the world folder into a zip archive with the folder as its root, like the game expects
+pub async fn write_world_archive(
+    world_dir: PathBuf,
+    output_path: PathBuf,
+) -> Result<(), InstanceError> {
+    tokio::task::spawn_blocking(move || {
+        let world_name = world_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let mut files = BTreeSet::new();
+        collect_files(&world_dir, &world_dir, &mut files)?;
+
+        let file =
+            std::fs::File::create(&output_path).map_err(|e| IoError::with_path(e, &output_path))?;
+
+        let mut zip = ZipWriter::new(file);
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .large_file(true);
+
+        for path in files.iter().filter(|path| *path != SESSION_LOCK_FILE) {
+            let source_path = world_dir.join(path);
+            let mut source = std::fs::File::open(&source_path)
+                .map_err(|e| IoError::with_path(e, &source_path))?;
+
+            zip.start_file(format!("{world_name}/{path}"), options)
+                .map_err(get_zip_error)?;
+            std::io::copy(&mut source, &mut zip).map_err(IoError::from)?;
+        }
+
+        zip.finish().map_err(get_zip_error)?;
+
+        Ok(())
+    })
+    .await
+    .map_err(get_join_error)?
+}
+
+/// Finds the folder of `level.dat` closest to the archive root
+pub async fn find_world_archive_root(path: PathBuf) -> Result<PathBuf, InstanceError> {
+    tokio::task::spawn_blocking(move || {
+        let file = std::fs::File::open(&path).map_err(|e| IoError::with_path(e, &path))?;
+        let archive = ZipArchive::new(file).map_err(get_invalid_world_error)?;
+
+        archive
+            .file_names()
+            .map(Path::new)
+            .filter(|name| name.file_name().is_some_and(|name| name == LEVEL_DAT_FILE))
+            .filter_map(|name| name.parent())
+            .min_by_key(|parent| parent.components().count())
+            .map(Path::to_path_buf)
+            .ok_or_else(|| InstanceError::InvalidWorld {
+                reason: format!("{LEVEL_DAT_FILE} not found in archive"),
+            })
+    })
+    .await
+    .map_err(get_join_error)?
+}
+
+/// Extracts entries under `root` of the archive into `target_dir`
+pub async fn extract_world_archive(
+    path: PathBuf,
+    root: PathBuf,
+    target_dir: PathBuf,
+) -> Result<(), InstanceError> {
+    tokio::task::spawn_blocking(move || {
+        let file = std::fs::File::open(&path).map_err(|e| IoError::with_path(e, &path))?;
+        let mut archive = ZipArchive::new(file).map_err(get_invalid_world_error)?;
+
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i).map_err(get_invalid_world_error)?;
+
+            if entry.is_dir() {
+                continue;
+            }
+
+            let Some(relative_path) = entry
+                .enclosed_name()
+                .and_then(|name| name.strip_prefix(&root).ok().map(Path::to_path_buf))
+            else {
+                continue;
+            };
+
+            let target_path = target_dir.join(relative_path);
+            if let Some(parent) = target_path.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| IoError::with_path(e, parent))?;
+            }
+
+            let mut target = std::fs::File::create(&target_path)
+                .map_err(|e| IoError::with_path(e, &target_path))?;
+            std::io::copy(&mut entry, &mut target).map_err(IoError::from)?;
+        }
+
+        Ok(())
+    })
+    .await
+    .map_err(get_join_error)?
+}
+
+fn get_invalid_world_error(err: impl std::fmt::Display) -> InstanceError {
+    InstanceError::InvalidWorld {
+        reason: err.to_string(),
+    }
+}
@@ -7,6 +7,7 @@ pub trait JavaStorage: Send + Sync {
     async fn list(&self) -> Result<Vec<Java>, JavaStorageError>;
     async fn get(&self, version: u32) -> Result<Option<Java>, JavaStorageError>;
     async fn upsert(&self, java: Java) -> Result<Java, JavaStorageError>;
+    async fn remove(&self, version: u32) -> Result<(), JavaStorageError>;
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -47,4 +47,20 @@ impl JavaStorage for FsJavaStorage {
             })
             .await?)
     }
+
+    async fn remove(&self, version: u32) -> Result<(), JavaStorageError> {
+        Ok(self
+            .store
+            .update(|list| {
+                let len = list.len();
+                list.retain(|java| java.major_version() != version);
+
+                if list.len() == len {
+                    UpdateAction::NoChanges(())
+                } else {
+                    UpdateAction::Save(())
+                }
+            })
+            .await?)
+    }
 }
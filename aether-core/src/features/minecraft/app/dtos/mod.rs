@@ -1,3 +1,7 @@
 mod install_minecraft_params;
+mod verify_minecraft_params;
+mod verify_minecraft_result;
 
 pub use install_minecraft_params::*;
+pub use verify_minecraft_params::*;
+pub use verify_minecraft_result::*;
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::features::minecraft::{LoaderVersionPreference, ModLoader};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VerifyMinecraftParams {
+    pub game_version: String,
+    pub loader: ModLoader,
+    pub loader_version: Option<LoaderVersionPreference>,
+    pub java_path: Option<String>,
+}
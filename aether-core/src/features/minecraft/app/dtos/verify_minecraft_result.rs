@@ -0,0 +1,8 @@
+use crate::features::minecraft::GameFileIssue;
+
+#[derive(Debug)]
+pub struct VerifyMinecraftResult {
+    pub issues: Vec<GameFileIssue>,
+    /// Set only when repair was requested and every issue was downloaded again
+    pub repaired: bool,
+}
@@ -0,0 +1,20 @@
+use std::path::Path;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+
+use crate::features::auth::AuthServer;
+
+pub fn get_authlib_injector_arguments(agent_path: &Path, auth_server: &AuthServer) -> Vec<String> {
+    vec![
+        format!(
+            "-javaagent:{}={}",
+            agent_path.to_string_lossy(),
+            auth_server.url
+        ),
+        // Saves authlib-injector a request to the auth server on startup
+        format!(
+            "-Dauthlibinjector.yggdrasil.prefetched={}",
+            BASE64_STANDARD.encode(&auth_server.metadata)
+        ),
+    ]
+}
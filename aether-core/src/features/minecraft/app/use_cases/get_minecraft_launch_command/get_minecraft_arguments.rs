@@ -4,8 +4,13 @@ use uuid::Uuid;
 
 use crate::{
     features::{
-        auth::Credentials,
-        minecraft::{utils::parse_arguments, MinecraftDomainError},
+        auth::{AccountType, Credentials},
+        minecraft::{
+            utils::{
+                get_legacy_quick_play_arguments, parse_arguments, supports_quick_play, RuleFeatures,
+            },
+            MinecraftDomainError, QuickPlayTarget,
+        },
         settings::WindowSize,
     },
     shared::canonicalize,
@@ -26,10 +31,17 @@ pub fn get_minecraft_arguments(
     version_type: &daedalus::minecraft::VersionType,
     resolution: WindowSize,
     java_arch: &str,
+    quick_play: Option<&QuickPlayTarget>,
 ) -> Result<Vec<String>, MinecraftDomainError> {
     if let Some(arguments) = arguments {
         let mut parsed_arguments = Vec::new();
 
+        // Versions before 1.20 have no quick play rules and fall back to the legacy arguments
+        let (quick_play, legacy_quick_play) = match quick_play {
+            Some(target) if supports_quick_play(arguments, target) => (Some(target), None),
+            target => (None, target),
+        };
+
         parse_arguments(
             arguments,
             &mut parsed_arguments,
@@ -39,17 +51,24 @@ pub fn get_minecraft_arguments(
                     credentials.access_token(),
                     credentials.username.as_ref(),
                     credentials.id(),
+                    get_user_type(credentials),
                     version,
                     asset_index_name,
                     game_directory,
                     assets_directory,
                     version_type,
                     resolution,
+                    quick_play,
                 )
             },
             java_arch,
+            &RuleFeatures::for_launch(quick_play),
         )?;
 
+        if let Some(target) = legacy_quick_play {
+            parsed_arguments.extend(get_legacy_quick_play_arguments(target));
+        }
+
         Ok(parsed_arguments)
     } else if let Some(legacy_arguments) = legacy_arguments {
         let mut parsed_arguments = Vec::new();
@@ -59,32 +78,49 @@ pub fn get_minecraft_arguments(
                 credentials.access_token(),
                 credentials.username.as_ref(),
                 credentials.id(),
+                get_user_type(credentials),
                 version,
                 asset_index_name,
                 game_directory,
                 assets_directory,
                 version_type,
                 resolution,
+                None,
             )?);
         }
+
+        if let Some(target) = quick_play {
+            parsed_arguments.extend(get_legacy_quick_play_arguments(target));
+        }
+
         Ok(parsed_arguments)
     } else {
         Ok(Vec::new())
     }
 }
 
+/// authlib-injector expects Yggdrasil sessions to be launched as Mojang accounts
+fn get_user_type(credentials: &Credentials) -> &'static str {
+    match credentials.account_type() {
+        AccountType::Yggdrasil => "mojang",
+        AccountType::Microsoft | AccountType::Offline => "msa",
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn replace_placeholders_in_argument_string(
     argument: &str,
     access_token: &str,
     username: &str,
     uuid: Uuid,
+    user_type: &str,
     version: &str,
     asset_index_name: &str,
     game_directory: &Path,
     assets_directory: &Path,
     version_type: &daedalus::minecraft::VersionType,
     resolution: WindowSize,
+    quick_play: Option<&QuickPlayTarget>,
 ) -> Result<String, MinecraftDomainError> {
     fn resolve_path(path: &Path, name: &str) -> Result<String, MinecraftDomainError> {
         Ok(canonicalize(path)
@@ -102,6 +138,13 @@ fn replace_placeholders_in_argument_string(
     let resolution_width = resolution.0.to_string();
     let resolution_height = resolution.1.to_string();
 
+    let (quick_play_singleplayer, quick_play_multiplayer, quick_play_realms) = match quick_play {
+        Some(QuickPlayTarget::Singleplayer { world }) => (world.as_str(), "", ""),
+        Some(QuickPlayTarget::Multiplayer { address }) => ("", address.as_str(), ""),
+        Some(QuickPlayTarget::Realms { realm_id }) => ("", "", realm_id.as_str()),
+        None => ("", "", ""),
+    };
+
     Ok(argument
         .replace("${accessToken}", access_token)
         .replace("${auth_access_token}", access_token)
@@ -112,7 +155,7 @@ fn replace_placeholders_in_argument_string(
         .replace("${uuid}", &uuid_str)
         .replace("${clientid}", "c4502edb-87c6-40cb-b595-64a280cf8906")
         .replace("${user_properties}", "{}")
-        .replace("${user_type}", "msa")
+        .replace("${user_type}", user_type)
         .replace("${version_name}", version)
         .replace("${assets_index_name}", asset_index_name)
         .replace("${game_directory}", &game_dir_str)
@@ -120,5 +163,8 @@ fn replace_placeholders_in_argument_string(
         .replace("${game_assets}", &assets_dir_str)
         .replace("${version_type}", version_type.as_str())
         .replace("${resolution_width}", &resolution_width)
-        .replace("${resolution_height}", &resolution_height))
+        .replace("${resolution_height}", &resolution_height)
+        .replace("${quickPlaySingleplayer}", quick_play_singleplayer)
+        .replace("${quickPlayMultiplayer}", quick_play_multiplayer)
+        .replace("${quickPlayRealms}", quick_play_realms))
 }
@@ -16,17 +16,18 @@ use crate::{
         minecraft::{
             app::{GetVersionManifestUseCase, MinecraftApplicationError},
             resolve_minecraft_version,
-            utils::get_compatible_java_version,
+            utils::{get_compatible_java_version, merge_options_txt, OPTIONS_TXT_FILE},
             LaunchSettings, LoaderVersionPreference, LoaderVersionResolver, MetadataStorage,
-            MinecraftDownloader, ModLoader,
+            MinecraftDownloader, ModLoader, QuickPlayTarget,
         },
         settings::LocationInfo,
     },
-    shared::create_dir_all,
+    shared::{create_dir_all, read_async, write_async},
     with_mut_ref,
 };
 
 use super::{
+    get_authlib_injector_arguments::get_authlib_injector_arguments,
     get_minecraft_arguments::get_minecraft_arguments,
     get_minecraft_jvm_arguments::get_minecraft_jvm_arguments,
 };
@@ -38,6 +39,7 @@ pub struct GetMinecraftLaunchCommandParams {
     pub loader_version: Option<LoaderVersionPreference>,
     pub launch_dir: PathBuf,
     pub java_path: Option<String>,
+    pub quick_play: Option<QuickPlayTarget>,
 }
 
 pub struct GetMinecraftLaunchCommandUseCase<
@@ -91,6 +93,7 @@ impl<
             loader_version,
             launch_dir,
             java_path,
+            quick_play,
         } = get_minecraft_launch_command_params;
 
         let version_manifest = self.get_version_manifest_use_case.execute().await?;
@@ -142,7 +145,7 @@ impl<
             create_dir_all(&natives_dir).await?;
         }
 
-        let jvm_arguments = get_minecraft_jvm_arguments(
+        let mut jvm_arguments = get_minecraft_jvm_arguments(
             args.get(&daedalus::minecraft::ArgumentType::Jvm)
                 .map(|x| x.as_slice()),
             &self.location_info.libraries_dir(),
@@ -156,6 +159,18 @@ impl<
             minecraft_updated,
         )?;
 
+        if let Some(auth_server) = credentials.auth_server() {
+            let agent_path = self
+                .minecraft_downloader
+                .download_authlib_injector(false)
+                .await?;
+
+            jvm_arguments.splice(
+                0..0,
+                get_authlib_injector_arguments(&agent_path, auth_server),
+            );
+        }
+
         let minecraft_arguments = get_minecraft_arguments(
             args.get(&daedalus::minecraft::ArgumentType::Game)
                 .map(|x| x.as_slice()),
@@ -168,6 +183,7 @@ impl<
             &version.type_,
             launch_settings.game_resolution,
             java.architecture(),
+            quick_play.as_ref(),
         )?
         .into_iter()
         .collect::<Vec<_>>();
@@ -196,7 +212,20 @@ impl<
 
         command.envs(env_args_vec);
 
-        // options.txt override
+        if !launch_settings.game_options.is_empty() {
+            let options_path = launch_dir.join(OPTIONS_TXT_FILE);
+
+            let content = if options_path.exists() {
+                String::from_utf8_lossy(&read_async(&options_path).await?).to_string()
+            } else {
+                String::new()
+            };
+
+            let merged = merge_options_txt(&content, &launch_settings.game_options);
+            if merged != content {
+                write_async(&options_path, merged).await?;
+            }
+        }
 
         Ok(command)
     }
@@ -1,3 +1,4 @@
+mod get_authlib_injector_arguments;
 mod get_minecraft_arguments;
 mod get_minecraft_jvm_arguments;
 mod get_minecraft_launch_command_uc;
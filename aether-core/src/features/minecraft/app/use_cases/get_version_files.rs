@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use crate::features::{
+    minecraft::{
+        app::{GetVersionManifestUseCase, MinecraftApplicationError},
+        resolve_minecraft_version,
+        utils::{get_compatible_java_version, get_version_files},
+        LoaderVersionPreference, LoaderVersionResolver, MetadataStorage, MinecraftDownloader,
+        ModLoader, VersionFiles,
+    },
+    settings::LocationInfo,
+};
+
+pub struct GetVersionFilesUseCase<MS: MetadataStorage, MD: MinecraftDownloader> {
+    loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+    get_version_manifest_use_case: Arc<GetVersionManifestUseCase<MS>>,
+    minecraft_downloader: MD,
+    location_info: Arc<LocationInfo>,
+}
+
+impl<MS: MetadataStorage, MD: MinecraftDownloader> GetVersionFilesUseCase<MS, MD> {
+    pub fn new(
+        loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+        get_version_manifest_use_case: Arc<GetVersionManifestUseCase<MS>>,
+        minecraft_downloader: MD,
+        location_info: Arc<LocationInfo>,
+    ) -> Self {
+        Self {
+            loader_version_resolver,
+            get_version_manifest_use_case,
+            minecraft_downloader,
+            location_info,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        game_version: String,
+        loader: ModLoader,
+        loader_version: Option<LoaderVersionPreference>,
+    ) -> Result<VersionFiles, MinecraftApplicationError> {
+        let version_manifest = self.get_version_manifest_use_case.execute().await?;
+
+        let (version, _) = resolve_minecraft_version(&game_version, version_manifest)?;
+
+        let loader_version = self
+            .loader_version_resolver
+            .resolve(&game_version, &loader, loader_version.as_ref())
+            .await?;
+
+        let version_jar = loader_version
+            .as_ref()
+            .map_or(version.id.clone(), |it| format!("{}-{}", version.id, it.id));
+
+        let version_info = self
+            .minecraft_downloader
+            .get_version_info(&version, loader_version.as_ref(), None, None)
+            .await?;
+
+        let assets_index = self
+            .minecraft_downloader
+            .get_assets_index(&version_info)
+            .await?;
+
+        Ok(VersionFiles {
+            files: get_version_files(
+                &self.location_info,
+                &version_info,
+                &version_jar,
+                &assets_index,
+            )?,
+            java_version: get_compatible_java_version(&version_info),
+        })
+    }
+}
@@ -1,9 +1,13 @@
 mod get_loader_version_manifest;
 mod get_minecraft_launch_command;
+mod get_version_files;
 mod get_version_manifest;
 mod install_minecraft;
+mod verify_minecraft;
 
 pub use get_loader_version_manifest::*;
 pub use get_minecraft_launch_command::*;
+pub use get_version_files::*;
 pub use get_version_manifest::*;
 pub use install_minecraft::*;
+pub use verify_minecraft::*;
@@ -0,0 +1,122 @@
+use std::{path::Path, sync::Arc};
+
+use tracing::warn;
+
+use crate::features::{
+    java::{
+        app::{GetJavaUseCase, JavaApplicationError},
+        JavaInstallationService, JavaStorage,
+    },
+    minecraft::{
+        app::{
+            GetVersionManifestUseCase, MinecraftApplicationError, VerifyMinecraftParams,
+            VerifyMinecraftResult,
+        },
+        resolve_minecraft_version,
+        utils::get_compatible_java_version,
+        LoaderVersionResolver, MetadataStorage, MinecraftDownloader,
+    },
+};
+
+pub struct VerifyMinecraftUseCase<
+    MS: MetadataStorage,
+    MD: MinecraftDownloader,
+    JIS: JavaInstallationService,
+    JS: JavaStorage,
+> {
+    loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+    get_version_manifest_use_case: Arc<GetVersionManifestUseCase<MS>>,
+    minecraft_downloader: MD,
+    java_installation_service: JIS,
+    get_java_use_case: Arc<GetJavaUseCase<JS, JIS>>,
+}
+
+impl<
+        MS: MetadataStorage,
+        MD: MinecraftDownloader,
+        JIS: JavaInstallationService,
+        JS: JavaStorage,
+    > VerifyMinecraftUseCase<MS, MD, JIS, JS>
+{
+    pub fn new(
+        loader_version_resolver: Arc<LoaderVersionResolver<MS>>,
+        get_version_manifest_use_case: Arc<GetVersionManifestUseCase<MS>>,
+        minecraft_downloader: MD,
+        java_installation_service: JIS,
+        get_java_use_case: Arc<GetJavaUseCase<JS, JIS>>,
+    ) -> Self {
+        Self {
+            loader_version_resolver,
+            get_version_manifest_use_case,
+            minecraft_downloader,
+            java_installation_service,
+            get_java_use_case,
+        }
+    }
+
+    /// Returns broken game files, with `repair` they are downloaded again.
+    /// A failed repair still returns the issues, only marked as not repaired
+    pub async fn execute(
+        &self,
+        verify_minecraft_params: VerifyMinecraftParams,
+        repair: bool,
+    ) -> Result<VerifyMinecraftResult, MinecraftApplicationError> {
+        let VerifyMinecraftParams {
+            game_version,
+            loader,
+            loader_version,
+            java_path,
+        } = verify_minecraft_params;
+
+        let version_manifest = self.get_version_manifest_use_case.execute().await?;
+
+        let (version, minecraft_updated) =
+            resolve_minecraft_version(&game_version, version_manifest)?;
+
+        let loader_version = self
+            .loader_version_resolver
+            .resolve(&game_version, &loader, loader_version.as_ref())
+            .await?;
+
+        let version_info = self
+            .minecraft_downloader
+            .get_version_info(&version, loader_version.as_ref(), None, None)
+            .await?;
+
+        // Library rules depend on the architecture of the java used to launch the game
+        let java = if let Some(java_path) = java_path.as_ref() {
+            self.java_installation_service
+                .locate_java(Path::new(java_path))
+                .await
+                .map_err(|err| {
+                    MinecraftApplicationError::JavaError(JavaApplicationError::Domain(err))
+                })
+        } else {
+            let compatible_java_version = get_compatible_java_version(&version_info);
+            self.get_java_use_case
+                .execute(compatible_java_version)
+                .await
+                .map_err(Into::into)
+        }?;
+
+        let issues = self
+            .minecraft_downloader
+            .verify_minecraft(&version_info, java.architecture(), minecraft_updated)
+            .await?;
+
+        let mut repaired = false;
+
+        if repair && !issues.is_empty() {
+            match self
+                .minecraft_downloader
+                .repair_minecraft(&version_info, &issues)
+                .await
+            {
+                Ok(()) => repaired = true,
+                Err(err) => warn!("Failed to repair game files: {}", err),
+            }
+        }
+
+        Ok(VerifyMinecraftResult { issues, repaired })
+    }
+}
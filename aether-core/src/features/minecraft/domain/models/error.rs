@@ -42,4 +42,11 @@ pub enum MinecraftDomainError {
 
     #[error("Storage failure: {reason}")]
     StorageFailure { reason: String },
+
+    #[error("Checksum mismatch for {name}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        name: String,
+        expected: String,
+        actual: String,
+    },
 }
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GameFileKind {
+    Client,
+    Library,
+    Asset,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileIntegrityStatus {
+    Missing,
+    /// Hash doesn't match the expected one
+    Corrupted,
+}
+
+/// Downloaded game file that doesn't match the version info
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFileIssue {
+    pub kind: GameFileKind,
+    /// Version id for the client, artifact for libraries and object name for assets
+    pub name: String,
+    pub status: FileIntegrityStatus,
+}
@@ -1,6 +1,8 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-use crate::features::settings::{Hooks, MemorySettings, WindowSize};
+use crate::features::settings::{GameOptionOverride, Hooks, MemorySettings, WindowSize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LaunchSettings {
@@ -9,4 +11,6 @@ pub struct LaunchSettings {
     pub memory: MemorySettings,
     pub game_resolution: WindowSize,
     pub hooks: Hooks,
+    /// Entries merged into `options.txt` before launch
+    pub game_options: BTreeMap<String, GameOptionOverride>,
 }
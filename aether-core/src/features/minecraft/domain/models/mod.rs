@@ -1,9 +1,15 @@
 mod error;
+mod game_file_issue;
 mod launch_settings;
 mod loader_version;
 mod mod_loader;
+mod quick_play;
+mod version_files;
 
 pub use error::*;
+pub use game_file_issue::*;
 pub use launch_settings::*;
 pub use loader_version::*;
 pub use mod_loader::*;
+pub use quick_play::*;
+pub use version_files::*;
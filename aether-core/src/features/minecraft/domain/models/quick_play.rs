@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Where the game goes right after start instead of the title screen
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QuickPlayTarget {
+    /// World folder name inside `saves/`
+    Singleplayer {
+        world: String,
+    },
+    /// Server address in the `host[:port]` form
+    Multiplayer {
+        address: String,
+    },
+    Realms {
+        realm_id: String,
+    },
+}
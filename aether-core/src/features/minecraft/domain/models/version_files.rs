@@ -0,0 +1,9 @@
+use std::path::PathBuf;
+
+/// Shared files a version needs, see `get_version_files`
+#[derive(Debug, Clone)]
+pub struct VersionFiles {
+    pub files: Vec<PathBuf>,
+    /// Java major version the version runs with by default
+    pub java_version: u32,
+}
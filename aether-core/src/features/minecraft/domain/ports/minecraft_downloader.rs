@@ -1,6 +1,11 @@
+use std::path::PathBuf;
+
 use async_trait::async_trait;
 
-use crate::features::{events::ProgressBarId, minecraft::MinecraftDomainError};
+use crate::features::{
+    events::ProgressBarId,
+    minecraft::{GameFileIssue, MinecraftDomainError},
+};
 
 #[async_trait]
 pub trait MinecraftDownloader: Send + Sync {
@@ -20,4 +25,29 @@ pub trait MinecraftDownloader: Send + Sync {
         force: Option<bool>,
         loading_bar: Option<&ProgressBarId>,
     ) -> Result<daedalus::minecraft::VersionInfo, MinecraftDomainError>;
+
+    /// Returns the cached assets index of the version, downloading it if missing
+    async fn get_assets_index(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+    ) -> Result<daedalus::minecraft::AssetsIndex, MinecraftDomainError>;
+
+    /// Checks the client jar, libraries and asset objects against hashes of the version info
+    async fn verify_minecraft(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+        java_arch: &str,
+        minecraft_updated: bool,
+    ) -> Result<Vec<GameFileIssue>, MinecraftDomainError>;
+
+    /// Downloads again only the files reported by `verify_minecraft`
+    async fn repair_minecraft(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+        issues: &[GameFileIssue],
+    ) -> Result<(), MinecraftDomainError>;
+
+    /// Returns the path to the authlib-injector agent jar, downloading it if missing
+    async fn download_authlib_injector(&self, force: bool)
+        -> Result<PathBuf, MinecraftDomainError>;
 }
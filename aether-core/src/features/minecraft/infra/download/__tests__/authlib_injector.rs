@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use serde_json::json;
+
+use crate::{
+    features::{
+        events::{
+            infra::InMemoryProgressBarStorage, tests::fixtures::MockEventEmitter,
+            ProgressServiceImpl,
+        },
+        minecraft::{infra::LibrariesService, utils::AUTHLIB_INJECTOR_PATH, MinecraftDomainError},
+        settings::LocationInfo,
+    },
+    libs::request_client::tests::fixtures::{StubHttpServer, StubRequestClient},
+};
+
+const JAR: &[u8] = b"authlib-injector jar";
+const WRONG_SHA256: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+fn create_service(
+    server: &StubHttpServer,
+    location_info: Arc<LocationInfo>,
+) -> LibrariesService<
+    StubRequestClient,
+    ProgressServiceImpl<MockEventEmitter, InMemoryProgressBarStorage>,
+> {
+    let progress_service = Arc::new(ProgressServiceImpl::new(
+        Arc::new(MockEventEmitter::new()),
+        Arc::new(InMemoryProgressBarStorage::default()),
+    ));
+
+    LibrariesService::new(progress_service, server.client(), location_info)
+        .with_authlib_injector_url(format!("{}/latest.json", server.url()))
+}
+
+fn respond_artifact(server: &StubHttpServer, sha256: &str) {
+    server.respond_json(
+        "/latest.json",
+        json!({
+            "build_number": 53,
+            "version": "1.2.5",
+            "download_url": format!("{}/authlib-injector.jar", server.url()),
+            "checksums": { "sha256": sha256 },
+        }),
+    );
+    server.respond("/authlib-injector.jar", 200, JAR);
+}
+
+#[tokio::test]
+async fn authlib_injector_is_saved_when_checksum_matches() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+
+    let server = StubHttpServer::start().await;
+    respond_artifact(&server, &crate::shared::sha256_async(JAR).await.unwrap());
+
+    let path = create_service(&server, location_info.clone())
+        .download_authlib_injector(false)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        path,
+        location_info.libraries_dir().join(AUTHLIB_INJECTOR_PATH)
+    );
+    assert_eq!(std::fs::read(&path).unwrap(), JAR);
+}
+
+#[tokio::test]
+async fn authlib_injector_with_wrong_checksum_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+
+    let server = StubHttpServer::start().await;
+    respond_artifact(&server, WRONG_SHA256);
+
+    let result = create_service(&server, location_info.clone())
+        .download_authlib_injector(false)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(MinecraftDomainError::ChecksumMismatch { .. })
+    ));
+    assert!(!location_info
+        .libraries_dir()
+        .join(AUTHLIB_INJECTOR_PATH)
+        .exists());
+}
+
+#[tokio::test]
+async fn existing_authlib_injector_is_not_downloaded_again() {
+    let dir = tempfile::tempdir().unwrap();
+    let location_info = Arc::new(LocationInfo::new(
+        dir.path().to_path_buf(),
+        dir.path().to_path_buf(),
+    ));
+    let path = location_info.libraries_dir().join(AUTHLIB_INJECTOR_PATH);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "cached").unwrap();
+
+    let server = StubHttpServer::start().await;
+
+    create_service(&server, location_info)
+        .download_authlib_injector(false)
+        .await
+        .unwrap();
+
+    assert!(server.requests("/latest.json").is_empty());
+    assert_eq!(std::fs::read(&path).unwrap(), b"cached");
+}
@@ -0,0 +1 @@
+mod authlib_injector;
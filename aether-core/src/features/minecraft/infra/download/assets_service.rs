@@ -1,7 +1,7 @@
 use std::{path::PathBuf, sync::Arc};
 
 use bytes::Bytes;
-use futures::StreamExt;
+use futures::{StreamExt, TryStreamExt};
 use serde::de::DeserializeOwned;
 use tracing::trace;
 
@@ -11,7 +11,9 @@ use crate::{
             utils::{try_for_each_concurrent_with_progress, ProgressConfigWithMessage},
             ProgressBarId, ProgressConfig, ProgressService, ProgressServiceExt,
         },
-        minecraft::MinecraftDomainError,
+        minecraft::{
+            utils::get_file_integrity_status, GameFileIssue, GameFileKind, MinecraftDomainError,
+        },
         settings::LocationInfo,
     },
     libs::request_client::{Request, RequestClient, RequestClientExt},
@@ -22,6 +24,7 @@ use super::assets_index_key;
 
 const MINECRAFT_RESOURCES_BASE_URL: &str = "https://resources.download.minecraft.net/";
 const HASH_PREFIX_LENGTH: usize = 2;
+const VERIFY_CONCURRENCY_LIMIT: usize = 16;
 
 pub struct AssetsService<RC: RequestClient, PS: ProgressService, C: Cache> {
     progress_service: Arc<PS>,
@@ -141,6 +144,30 @@ impl<RC: RequestClient, PS: ProgressService, C: Cache> AssetsService<RC, PS, C>
         Ok(())
     }
 
+    /// Checks asset objects, legacy copies are restored together with their object
+    pub async fn verify_assets(
+        &self,
+        index: &daedalus::minecraft::AssetsIndex,
+    ) -> Result<Vec<GameFileIssue>, MinecraftDomainError> {
+        let issues: Vec<_> = futures::stream::iter(index.objects.iter())
+            .map(|(name, asset)| async move {
+                let status =
+                    get_file_integrity_status(&self.get_asset_path(&asset.hash), Some(&asset.hash))
+                        .await?;
+
+                Ok::<_, MinecraftDomainError>(status.map(|status| GameFileIssue {
+                    kind: GameFileKind::Asset,
+                    name: name.clone(),
+                    status,
+                }))
+            })
+            .buffer_unordered(VERIFY_CONCURRENCY_LIMIT)
+            .try_collect()
+            .await?;
+
+        Ok(issues.into_iter().flatten().collect())
+    }
+
     pub async fn download_asset(
         &self,
         name: &str,
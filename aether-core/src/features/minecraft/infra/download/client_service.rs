@@ -5,7 +5,10 @@ use bytes::Bytes;
 use crate::{
     features::{
         events::{ProgressBarId, ProgressService, ProgressServiceExt},
-        minecraft::MinecraftDomainError,
+        minecraft::{
+            utils::get_bytes_integrity_status, FileIntegrityStatus, GameFileIssue, GameFileKind,
+            MinecraftDomainError,
+        },
     },
     libs::request_client::{Request, RequestClient},
     shared::{FileStore, InfinityCachedResource, IoError},
@@ -84,6 +87,30 @@ impl<RC: RequestClient, PS: ProgressService, FS: FileStore> ClientService<RC, PS
 
         Ok(())
     }
+
+    pub async fn verify_client(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+    ) -> Result<Option<GameFileIssue>, MinecraftDomainError> {
+        let version_id = &version_info.id;
+        let client_download = Self::get_client_download(version_id, version_info)?;
+
+        let status = match self
+            .cached_resource
+            .cache
+            .read(&version_jar_key(version_id.to_string()))
+            .await
+        {
+            Some(bytes) => get_bytes_integrity_status(bytes, &client_download.sha1).await?,
+            None => Some(FileIntegrityStatus::Missing),
+        };
+
+        Ok(status.map(|status| GameFileIssue {
+            kind: GameFileKind::Client,
+            name: version_id.clone(),
+            status,
+        }))
+    }
 }
 
 fn get_network_error<E>(error: E) -> IoError
@@ -2,6 +2,7 @@ use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use bytes::Bytes;
 use futures::StreamExt;
+use serde::Deserialize;
 use tracing::{debug, error, info, trace, warn};
 
 use crate::{
@@ -10,19 +11,36 @@ use crate::{
             utils::{try_for_each_concurrent_with_progress, ProgressConfigWithMessage},
             ProgressConfig, ProgressService,
         },
-        minecraft::{utils::parse_rules, MinecraftDomainError},
+        minecraft::{
+            utils::{get_file_integrity_status, parse_rules, RuleFeatures, AUTHLIB_INJECTOR_PATH},
+            GameFileIssue, GameFileKind, MinecraftDomainError,
+        },
         settings::LocationInfo,
     },
-    libs::request_client::{Request, RequestClient},
-    shared::{create_dir_all, write_async, IoError},
+    libs::request_client::{Request, RequestClient, RequestClientExt},
+    shared::{create_dir_all, sha256_async, write_async, IoError},
 };
 
 const MINECRAFT_LIBRARIES_BASE_URL: &str = "https://libraries.minecraft.net/";
 
+const AUTHLIB_INJECTOR_LATEST_URL: &str = "https://authlib-injector.yushi.moe/artifact/latest.json";
+
+#[derive(Deserialize)]
+struct AuthlibInjectorArtifact {
+    download_url: String,
+    checksums: AuthlibInjectorChecksums,
+}
+
+#[derive(Deserialize)]
+struct AuthlibInjectorChecksums {
+    sha256: String,
+}
+
 pub struct LibrariesService<RC: RequestClient, PS: ProgressService> {
     progress_service: Arc<PS>,
     request_client: Arc<RC>,
     location_info: Arc<LocationInfo>,
+    authlib_injector_url: String,
 }
 
 impl<RC: RequestClient, PS: ProgressService> LibrariesService<RC, PS> {
@@ -35,9 +53,16 @@ impl<RC: RequestClient, PS: ProgressService> LibrariesService<RC, PS> {
             progress_service,
             request_client,
             location_info,
+            authlib_injector_url: AUTHLIB_INJECTOR_LATEST_URL.to_string(),
         }
     }
 
+    /// Overrides where the latest authlib-injector artifact is looked up
+    pub fn with_authlib_injector_url(mut self, url: impl Into<String>) -> Self {
+        self.authlib_injector_url = url.into();
+        self
+    }
+
     async fn fetch_bytes(&self, url: &str) -> Result<Bytes, IoError> {
         self.request_client
             .fetch_bytes(Request::get(url))
@@ -117,7 +142,12 @@ impl<RC: RequestClient, PS: ProgressService> LibrariesService<RC, PS> {
         minecraft_updated: bool,
     ) -> bool {
         if let Some(rules) = &library.rules {
-            if !parse_rules(rules, java_arch, minecraft_updated) {
+            if !parse_rules(
+                rules,
+                java_arch,
+                minecraft_updated,
+                &RuleFeatures::default(),
+            ) {
                 trace!("Library {} skipped due to rules", library.name);
                 return false;
             }
@@ -164,6 +194,43 @@ impl<RC: RequestClient, PS: ProgressService> LibrariesService<RC, PS> {
         Ok(())
     }
 
+    /// Checks java libraries, natives are extracted and can't be verified
+    pub async fn verify_libraries(
+        &self,
+        libraries: &[daedalus::minecraft::Library],
+        java_arch: &str,
+        minecraft_updated: bool,
+    ) -> Result<Vec<GameFileIssue>, MinecraftDomainError> {
+        let mut issues = Vec::new();
+
+        for library in libraries {
+            if !Self::should_download_library(library, java_arch, minecraft_updated) {
+                continue;
+            }
+
+            let library_path = self
+                .location_info
+                .libraries_dir()
+                .join(daedalus::get_path_from_artifact(&library.name)?);
+
+            let sha1 = library
+                .downloads
+                .as_ref()
+                .and_then(|downloads| downloads.artifact.as_ref())
+                .map(|artifact| artifact.sha1.as_str());
+
+            if let Some(status) = get_file_integrity_status(&library_path, sha1).await? {
+                issues.push(GameFileIssue {
+                    kind: GameFileKind::Library,
+                    name: library.name.clone(),
+                    status,
+                });
+            }
+        }
+
+        Ok(issues)
+    }
+
     async fn try_download_from_artifact(
         &self,
         library: &daedalus::minecraft::Library,
@@ -247,6 +314,46 @@ impl<RC: RequestClient, PS: ProgressService> LibrariesService<RC, PS> {
         Ok(())
     }
 
+    pub async fn download_authlib_injector(
+        &self,
+        force: bool,
+    ) -> Result<PathBuf, MinecraftDomainError> {
+        let path = self
+            .location_info
+            .libraries_dir()
+            .join(AUTHLIB_INJECTOR_PATH);
+
+        if path.exists() && !force {
+            return Ok(path);
+        }
+
+        debug!("Downloading authlib-injector");
+
+        let artifact: AuthlibInjectorArtifact = self
+            .request_client
+            .fetch_json(Request::get(&self.authlib_injector_url))
+            .await
+            .map_err(get_network_error)?;
+
+        let bytes = self.fetch_bytes(&artifact.download_url).await?;
+
+        // The agent runs inside the game process, a tampered jar must never reach the disk
+        let actual = sha256_async(bytes.clone())
+            .await
+            .map_err(|err| IoError::IoError(std::io::Error::other(err)))?;
+        if !actual.eq_ignore_ascii_case(&artifact.checksums.sha256) {
+            return Err(MinecraftDomainError::ChecksumMismatch {
+                name: "authlib-injector".to_string(),
+                expected: artifact.checksums.sha256,
+                actual,
+            });
+        }
+
+        write_async(&path, &bytes).await?;
+
+        Ok(path)
+    }
+
     fn get_native_classifiers<'a>(
         &self,
         library: &'a daedalus::minecraft::Library,
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashSet, path::PathBuf, sync::Arc};
 
 use async_trait::async_trait;
 use serde::de::DeserializeOwned;
@@ -7,7 +7,7 @@ use tracing::info;
 use crate::{
     features::{
         events::{ProgressBarId, ProgressConfig, ProgressService, ProgressServiceExt},
-        minecraft::{MinecraftDomainError, MinecraftDownloader},
+        minecraft::{GameFileIssue, GameFileKind, MinecraftDomainError, MinecraftDownloader},
     },
     libs::request_client::{Request, RequestClient, RequestClientExt},
     shared::{Cache, FileStore, InfinityCachedResource, IoError},
@@ -150,6 +150,111 @@ impl<RC: RequestClient, PS: ProgressService, C: Cache, FS: FileStore> MinecraftD
 
         Ok(version_info)
     }
+
+    async fn get_assets_index(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+    ) -> Result<daedalus::minecraft::AssetsIndex, MinecraftDomainError> {
+        self.assets_service
+            .get_assets_index(version_info, false, None)
+            .await
+    }
+
+    async fn verify_minecraft(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+        java_arch: &str,
+        minecraft_updated: bool,
+    ) -> Result<Vec<GameFileIssue>, MinecraftDomainError> {
+        info!("Verifying minecraft {}", version_info.id);
+
+        let assets_index = self
+            .assets_service
+            .get_assets_index(version_info, false, None)
+            .await?;
+
+        let (client_issue, library_issues, asset_issues) = tokio::try_join! {
+            self.client_service.verify_client(version_info),
+            self.libraries_service.verify_libraries(version_info.libraries.as_slice(), java_arch, minecraft_updated),
+            self.assets_service.verify_assets(&assets_index)
+        }?;
+
+        Ok(client_issue
+            .into_iter()
+            .chain(library_issues)
+            .chain(asset_issues)
+            .collect())
+    }
+
+    async fn repair_minecraft(
+        &self,
+        version_info: &daedalus::minecraft::VersionInfo,
+        issues: &[GameFileIssue],
+    ) -> Result<(), MinecraftDomainError> {
+        let get_names = |kind: GameFileKind| {
+            issues
+                .iter()
+                .filter(|issue| issue.kind == kind)
+                .map(|issue| issue.name.as_str())
+                .collect::<HashSet<_>>()
+        };
+
+        let libraries = get_names(GameFileKind::Library);
+        let assets = get_names(GameFileKind::Asset);
+
+        info!(
+            "Repairing minecraft {} ({} files)",
+            version_info.id,
+            issues.len()
+        );
+
+        if issues
+            .iter()
+            .any(|issue| issue.kind == GameFileKind::Client)
+        {
+            self.client_service
+                .download_client(version_info, true, None)
+                .await?;
+        }
+
+        for library in version_info
+            .libraries
+            .iter()
+            .filter(|library| libraries.contains(library.name.as_str()))
+        {
+            self.libraries_service
+                .download_java_library(library, true)
+                .await?;
+        }
+
+        if !assets.is_empty() {
+            let assets_index = self
+                .assets_service
+                .get_assets_index(version_info, false, None)
+                .await?;
+
+            for (name, asset) in assets_index
+                .objects
+                .iter()
+                .filter(|(name, _)| assets.contains(name.as_str()))
+            {
+                self.assets_service
+                    .download_asset(name, asset, version_info.assets == "legacy", true)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn download_authlib_injector(
+        &self,
+        force: bool,
+    ) -> Result<PathBuf, MinecraftDomainError> {
+        self.libraries_service
+            .download_authlib_injector(force)
+            .await
+    }
 }
 
 fn get_version_id(
@@ -5,6 +5,9 @@ mod minecraft_download_cache_keys;
 mod minecraft_download_resolver;
 mod minecraft_download_service;
 
+#[cfg(test)]
+mod __tests__;
+
 pub use assets_service::*;
 pub use client_service::*;
 pub use libraries_service::*;
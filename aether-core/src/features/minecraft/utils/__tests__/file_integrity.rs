@@ -0,0 +1,40 @@
+use crate::features::minecraft::{utils::get_file_integrity_status, FileIntegrityStatus};
+
+const HELLO_SHA1: &str = "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d";
+
+#[tokio::test]
+async fn missing_file_is_reported() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let status = get_file_integrity_status(&dir.path().join("client.jar"), Some(HELLO_SHA1))
+        .await
+        .unwrap();
+
+    assert_eq!(status, Some(FileIntegrityStatus::Missing));
+}
+
+#[tokio::test]
+async fn hash_is_compared_case_insensitively() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("client.jar");
+    std::fs::write(&path, "hello").unwrap();
+
+    let status = get_file_integrity_status(&path, Some(&HELLO_SHA1.to_uppercase()))
+        .await
+        .unwrap();
+
+    assert_eq!(status, None);
+}
+
+#[tokio::test]
+async fn changed_file_is_corrupted() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("client.jar");
+    std::fs::write(&path, "hello world").unwrap();
+
+    let status = get_file_integrity_status(&path, Some(HELLO_SHA1))
+        .await
+        .unwrap();
+
+    assert_eq!(status, Some(FileIntegrityStatus::Corrupted));
+}
@@ -0,0 +1,5 @@
+mod file_integrity;
+mod options_txt;
+mod quick_play;
+mod rules;
+mod version_files;
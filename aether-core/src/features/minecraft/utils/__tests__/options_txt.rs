@@ -0,0 +1,47 @@
+use std::collections::BTreeMap;
+
+use crate::features::{
+    minecraft::utils::merge_options_txt,
+    settings::{GameOptions, GameOptionsApplyMode},
+};
+
+#[test]
+fn merge_keeps_other_options() {
+    let overrides = GameOptions {
+        language: Some("de_de".to_owned()),
+        render_distance: Some(8),
+        key_bindings: BTreeMap::from([("key.jump".to_owned(), "key.keyboard.j".to_owned())]),
+        ..Default::default()
+    }
+    .to_overrides();
+
+    let merged = merge_options_txt(
+        "version:3465\nlang:en_us\nkey_key.jump:key.keyboard.space\nfov:0.0\n",
+        &overrides,
+    );
+
+    assert_eq!(
+        merged,
+        "version:3465\nlang:de_de\nkey_key.jump:key.keyboard.j\nfov:0.0\nrenderDistance:8\n"
+    );
+}
+
+#[test]
+fn merge_first_launch_keeps_player_value() {
+    let overrides = GameOptions {
+        gui_scale: Some(2),
+        fullscreen: Some(true),
+        apply_mode: GameOptionsApplyMode::FirstLaunch,
+        ..Default::default()
+    }
+    .to_overrides();
+
+    assert_eq!(
+        merge_options_txt("guiScale:4\n", &overrides),
+        "guiScale:4\nfullscreen:true\n"
+    );
+    assert_eq!(
+        merge_options_txt("", &overrides),
+        "fullscreen:true\nguiScale:2\n"
+    );
+}
@@ -0,0 +1,22 @@
+use crate::features::minecraft::{utils::get_legacy_quick_play_arguments, QuickPlayTarget};
+
+#[test]
+fn legacy_multiplayer_uses_server_and_port() {
+    let arguments = get_legacy_quick_play_arguments(&QuickPlayTarget::Multiplayer {
+        address: "mc.example.com".to_owned(),
+    });
+
+    assert_eq!(
+        arguments,
+        vec!["--server", "mc.example.com", "--port", "25565"]
+    );
+}
+
+#[test]
+fn legacy_singleplayer_is_skipped() {
+    let arguments = get_legacy_quick_play_arguments(&QuickPlayTarget::Singleplayer {
+        world: "New World".to_owned(),
+    });
+
+    assert!(arguments.is_empty());
+}
@@ -0,0 +1,159 @@
+use daedalus::minecraft;
+use serde_json::json;
+
+use crate::features::minecraft::{
+    utils::{parse_rules, supports_quick_play, RuleFeatures},
+    QuickPlayTarget,
+};
+
+fn feature_rules(features: serde_json::Value) -> Vec<minecraft::Rule> {
+    serde_json::from_value(json!([{ "action": "allow", "features": features }])).unwrap()
+}
+
+fn enabled(feature: &str) -> serde_json::Value {
+    serde_json::Value::Object(serde_json::Map::from_iter([(
+        feature.to_owned(),
+        json!(true),
+    )]))
+}
+
+fn matches(features: serde_json::Value, rule_features: &RuleFeatures) -> bool {
+    parse_rules(&feature_rules(features), "x86_64", true, rule_features)
+}
+
+/// Game arguments of 1.20+ versions, quick play arguments are gated by features
+fn modern_arguments() -> Vec<minecraft::Argument> {
+    serde_json::from_value(json!([
+        "--username",
+        "${auth_player_name}",
+        {
+            "rules": [{ "action": "allow", "features": { "has_custom_resolution": true } }],
+            "value": ["--width", "${resolution_width}", "--height", "${resolution_height}"]
+        },
+        {
+            "rules": [{ "action": "allow", "features": { "has_quick_plays_support": true } }],
+            "value": ["--quickPlayPath", "${quickPlayPath}"]
+        },
+        {
+            "rules": [{ "action": "allow", "features": { "is_quick_play_singleplayer": true } }],
+            "value": ["--quickPlaySingleplayer", "${quickPlaySingleplayer}"]
+        },
+        {
+            "rules": [{ "action": "allow", "features": { "is_quick_play_multiplayer": true } }],
+            "value": ["--quickPlayMultiplayer", "${quickPlayMultiplayer}"]
+        },
+        {
+            "rules": [{ "action": "allow", "features": { "is_quick_play_realms": true } }],
+            "value": ["--quickPlayRealms", "${quickPlayRealms}"]
+        }
+    ]))
+    .unwrap()
+}
+
+fn legacy_arguments() -> Vec<minecraft::Argument> {
+    serde_json::from_value(json!([
+        "--username",
+        "${auth_player_name}",
+        {
+            "rules": [{ "action": "allow", "features": { "is_demo_user": true } }],
+            "value": "--demo"
+        },
+        {
+            "rules": [{ "action": "allow", "features": { "has_custom_resolution": true } }],
+            "value": ["--width", "${resolution_width}", "--height", "${resolution_height}"]
+        }
+    ]))
+    .unwrap()
+}
+
+fn multiplayer() -> QuickPlayTarget {
+    QuickPlayTarget::Multiplayer {
+        address: "mc.example.com".to_owned(),
+    }
+}
+
+fn singleplayer() -> QuickPlayTarget {
+    QuickPlayTarget::Singleplayer {
+        world: "New World".to_owned(),
+    }
+}
+
+fn realms() -> QuickPlayTarget {
+    QuickPlayTarget::Realms {
+        realm_id: "1".to_owned(),
+    }
+}
+
+#[test]
+fn launch_has_custom_resolution_but_no_demo() {
+    let features = RuleFeatures::for_launch(None);
+
+    assert!(matches(json!({ "has_custom_resolution": true }), &features));
+    assert!(!matches(json!({ "is_demo_user": true }), &features));
+    assert!(!matches(
+        json!({ "has_custom_resolution": true }),
+        &RuleFeatures::default()
+    ));
+}
+
+#[test]
+fn quick_play_rules_match_only_the_launch_target() {
+    let rules = [
+        ("is_quick_play_singleplayer", singleplayer()),
+        ("is_quick_play_multiplayer", multiplayer()),
+        ("is_quick_play_realms", realms()),
+    ];
+
+    for (feature, target) in &rules {
+        for (_, other_target) in &rules {
+            assert_eq!(
+                matches(
+                    enabled(feature),
+                    &RuleFeatures::for_launch(Some(other_target))
+                ),
+                target == other_target,
+                "{feature} with {other_target:?}"
+            );
+        }
+
+        assert!(!matches(enabled(feature), &RuleFeatures::for_launch(None)));
+    }
+
+    // The launcher doesn't pass a quick play log path
+    assert!(!matches(
+        json!({ "has_quick_plays_support": true }),
+        &RuleFeatures::for_launch(Some(&multiplayer()))
+    ));
+}
+
+#[test]
+fn rule_with_several_features_needs_all_of_them() {
+    let features = RuleFeatures::for_launch(Some(&multiplayer()));
+
+    assert!(matches(
+        json!({ "has_custom_resolution": true, "is_quick_play_multiplayer": true }),
+        &features
+    ));
+    assert!(!matches(
+        json!({ "has_custom_resolution": true, "is_quick_play_realms": true }),
+        &features
+    ));
+}
+
+#[test]
+fn modern_versions_support_every_quick_play_target() {
+    let arguments = modern_arguments();
+
+    assert!(supports_quick_play(&arguments, &singleplayer()));
+    assert!(supports_quick_play(&arguments, &multiplayer()));
+    assert!(supports_quick_play(&arguments, &realms()));
+}
+
+#[test]
+fn legacy_versions_do_not_support_quick_play() {
+    let arguments = legacy_arguments();
+
+    assert!(!supports_quick_play(&arguments, &singleplayer()));
+    assert!(!supports_quick_play(&arguments, &multiplayer()));
+    assert!(!supports_quick_play(&arguments, &realms()));
+}
@@ -0,0 +1,213 @@
+use std::path::PathBuf;
+
+use daedalus::minecraft;
+use serde_json::json;
+
+use crate::features::{minecraft::utils::get_version_files, settings::LocationInfo};
+
+fn location_info() -> LocationInfo {
+    LocationInfo::new(PathBuf::from("/settings"), PathBuf::from("/cache"))
+}
+
+fn version_info(extra: serde_json::Value) -> minecraft::VersionInfo {
+    let mut version_info = json!({
+        "assetIndex": {
+            "id": "5",
+            "sha1": "0000000000000000000000000000000000000000",
+            "size": 0,
+            "totalSize": 0,
+            "url": "https://mock.test/assets/5.json"
+        },
+        "assets": "5",
+        "downloads": {},
+        "id": "1.20.1-forge-47.2.0",
+        "libraries": [{ "name": "com.mojang:brigadier:1.1.8" }],
+        "mainClass": "net.minecraft.client.main.Main",
+        "minimumLauncherVersion": 21,
+        "releaseTime": "2023-06-12T13:25:51Z",
+        "time": "2023-06-12T13:25:51Z",
+        "type": "release"
+    });
+
+    if let (Some(version_info), Some(extra)) = (version_info.as_object_mut(), extra.as_object()) {
+        version_info.extend(extra.clone());
+    }
+
+    serde_json::from_value(version_info).unwrap()
+}
+
+fn assets_index(hashes: &[&str]) -> minecraft::AssetsIndex {
+    let objects: serde_json::Map<String, serde_json::Value> = hashes
+        .iter()
+        .map(|hash| {
+            (
+                format!("minecraft/{hash}"),
+                json!({ "hash": hash, "size": 1 }),
+            )
+        })
+        .collect();
+
+    serde_json::from_value(json!({ "objects": objects })).unwrap()
+}
+
+fn library(location_info: &LocationInfo, path: &str) -> PathBuf {
+    location_info.libraries_dir().join(path)
+}
+
+#[test]
+fn version_files_include_version_dirs_libraries_and_assets() {
+    let location_info = location_info();
+    let hash = "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678";
+
+    let files = get_version_files(
+        &location_info,
+        &version_info(json!({})),
+        "1.20.1",
+        &assets_index(&[hash]),
+    )
+    .unwrap();
+
+    assert!(files.contains(&location_info.version_dir("1.20.1")));
+    assert!(files.contains(&location_info.version_dir("1.20.1-forge-47.2.0")));
+    assert!(files.contains(&location_info.version_natives_dir("1.20.1")));
+    assert!(files.contains(&location_info.assets_index_dir().join("5.json")));
+    assert!(files.contains(&library(
+        &location_info,
+        "com/mojang/brigadier/1.1.8/brigadier-1.1.8.jar"
+    )));
+    assert!(files.contains(&location_info.object_dir(hash)));
+    assert!(!files.contains(&location_info.legacy_assets_dir()));
+}
+
+#[test]
+fn version_files_include_processor_libraries() {
+    let location_info = location_info();
+
+    let files = get_version_files(
+        &location_info,
+        &version_info(json!({
+            "processors": [{
+                "jar": "net.minecraftforge:installertools:1.3.0",
+                "classpath": ["net.md-5:SpecialSource:1.11.0"],
+                "args": [
+                    "--task",
+                    "MCP_DATA",
+                    "--input",
+                    "[de.oceanlabs.mcp:mcp_config:1.20.1@zip]",
+                    "{MAPPINGS}"
+                ]
+            }]
+        })),
+        "1.20.1",
+        &assets_index(&[]),
+    )
+    .unwrap();
+
+    assert!(files.contains(&library(
+        &location_info,
+        "net/minecraftforge/installertools/1.3.0/installertools-1.3.0.jar"
+    )));
+    assert!(files.contains(&library(
+        &location_info,
+        "net/md-5/SpecialSource/1.11.0/SpecialSource-1.11.0.jar"
+    )));
+    assert!(files.contains(&library(
+        &location_info,
+        "de/oceanlabs/mcp/mcp_config/1.20.1/mcp_config-1.20.1.zip"
+    )));
+}
+
+#[test]
+fn version_files_include_data_artifacts_only() {
+    let location_info = location_info();
+
+    let files = get_version_files(
+        &location_info,
+        &version_info(json!({
+            "data": {
+                "MAPPINGS": {
+                    "client": "[de.oceanlabs.mcp:mcp_config:1.20.1:mappings@txt]",
+                    "server": "[de.oceanlabs.mcp:mcp_config:1.20.1:mappings@txt]"
+                },
+                "BINPATCH": {
+                    "client": "/data/client.lzma",
+                    "server": "/data/server.lzma"
+                },
+                "MCP_VERSION": {
+                    "client": "'20230612.114412'",
+                    "server": "'20230612.114412'"
+                }
+            }
+        })),
+        "1.20.1",
+        &assets_index(&[]),
+    )
+    .unwrap();
+
+    assert!(files.contains(&library(
+        &location_info,
+        "de/oceanlabs/mcp/mcp_config/1.20.1/mcp_config-1.20.1-mappings.txt"
+    )));
+
+    // Brigadier and the mappings of both sides, plain values aren't libraries
+    let libraries_dir = location_info.libraries_dir();
+    assert_eq!(
+        files
+            .iter()
+            .filter(|file| file.starts_with(&libraries_dir))
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn version_files_include_native_classifiers() {
+    let location_info = location_info();
+    let native = json!({
+        "path": "",
+        "sha1": "0000000000000000000000000000000000000000",
+        "size": 0,
+        "url": "https://mock.test/natives.jar"
+    });
+
+    let files = get_version_files(
+        &location_info,
+        &version_info(json!({
+            "libraries": [{
+                "name": "org.lwjgl.lwjgl:lwjgl-platform:2.9.4",
+                "downloads": {
+                    "classifiers": {
+                        "natives-linux": native,
+                        "natives-windows": native
+                    }
+                },
+                "natives": { "linux": "natives-linux", "windows": "natives-windows" }
+            }]
+        })),
+        "1.8.9",
+        &assets_index(&[]),
+    )
+    .unwrap();
+
+    for classifier in ["natives-linux", "natives-windows"] {
+        assert!(files.contains(&library(
+            &location_info,
+            &format!("org/lwjgl/lwjgl/lwjgl-platform/2.9.4/lwjgl-platform-2.9.4-{classifier}.jar")
+        )));
+    }
+}
+
+#[test]
+fn version_files_include_legacy_assets() {
+    let location_info = location_info();
+
+    let files = get_version_files(
+        &location_info,
+        &version_info(json!({ "assets": "legacy" })),
+        "1.6.4",
+        &assets_index(&[]),
+    )
+    .unwrap();
+
+    assert!(files.contains(&location_info.legacy_assets_dir()));
+}
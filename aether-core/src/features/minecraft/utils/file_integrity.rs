@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use crate::{
+    features::minecraft::FileIntegrityStatus,
+    shared::{read_async, sha1_async, IoError},
+};
+
+/// Returns `None` for an intact file, without `sha1` only the presence is checked
+pub async fn get_file_integrity_status(
+    path: &Path,
+    sha1: Option<&str>,
+) -> Result<Option<FileIntegrityStatus>, IoError> {
+    if !path.is_file() {
+        return Ok(Some(FileIntegrityStatus::Missing));
+    }
+
+    let Some(sha1) = sha1 else {
+        return Ok(None);
+    };
+
+    get_bytes_integrity_status(read_async(path).await?, sha1).await
+}
+
+/// Compares the SHA1 of `bytes` case-insensitively
+pub async fn get_bytes_integrity_status(
+    bytes: impl Into<bytes::Bytes> + Send,
+    sha1: &str,
+) -> Result<Option<FileIntegrityStatus>, IoError> {
+    let hash = sha1_async(bytes)
+        .await
+        .map_err(|err| IoError::IoError(std::io::Error::other(err)))?;
+
+    Ok((!hash.eq_ignore_ascii_case(sha1)).then_some(FileIntegrityStatus::Corrupted))
+}
@@ -7,7 +7,7 @@ use crate::{
     shared::{canonicalize, utils::get_classpath_separator},
 };
 
-use super::{parse_arguments, parse_rules};
+use super::{parse_arguments, parse_rules, RuleFeatures};
 
 // TODO: Wrap arguments in struct
 #[allow(clippy::too_many_arguments)]
@@ -38,6 +38,7 @@ pub fn get_jvm_arguments(
                 )
             },
             java_arch,
+            &RuleFeatures::default(),
         )?;
     } else {
         parsed_arguments.push(format!(
@@ -108,7 +109,12 @@ pub fn get_class_paths(
         .iter()
         .filter_map(|library| {
             if let Some(rules) = &library.rules {
-                if !parse_rules(rules, java_arch, minecraft_updated) {
+                if !parse_rules(
+                    rules,
+                    java_arch,
+                    minecraft_updated,
+                    &RuleFeatures::default(),
+                ) {
                     return None;
                 }
             }
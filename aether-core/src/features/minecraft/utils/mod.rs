@@ -1,9 +1,22 @@
+mod file_integrity;
 mod get_compatible_java_version;
 mod jvm_arguments;
+mod options_txt;
 mod parse_arguments;
+mod quick_play;
 mod rules;
+mod server_address;
+mod version_files;
 
+#[cfg(test)]
+mod __tests__;
+
+pub use file_integrity::*;
 pub use get_compatible_java_version::*;
 pub use jvm_arguments::*;
+pub use options_txt::*;
 pub use parse_arguments::*;
+pub use quick_play::*;
 pub use rules::*;
+pub use server_address::*;
+pub use version_files::*;
@@ -0,0 +1,46 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::features::settings::{GameOptionOverride, GameOptionsApplyMode};
+
+pub const OPTIONS_TXT_FILE: &str = "options.txt";
+
+/// Applies overrides to `options.txt` content line by line,
+/// entries without an override and unknown lines are kept as is
+pub fn merge_options_txt(
+    content: &str,
+    overrides: &BTreeMap<String, GameOptionOverride>,
+) -> String {
+    let mut present = HashSet::new();
+    let mut lines = Vec::new();
+
+    for line in content.lines() {
+        let Some((key, _)) = line.split_once(':') else {
+            lines.push(line.to_owned());
+            continue;
+        };
+
+        present.insert(key.to_owned());
+
+        match overrides.get(key) {
+            Some(GameOptionOverride {
+                value,
+                apply_mode: GameOptionsApplyMode::Always,
+            }) => lines.push(format!("{key}:{value}")),
+            _ => lines.push(line.to_owned()),
+        }
+    }
+
+    lines.extend(
+        overrides
+            .iter()
+            .filter(|(key, _)| !present.contains(*key))
+            .map(|(key, GameOptionOverride { value, .. })| format!("{key}:{value}")),
+    );
+
+    let mut merged = lines.join("\n");
+    if !merged.is_empty() {
+        merged.push('\n');
+    }
+
+    merged
+}
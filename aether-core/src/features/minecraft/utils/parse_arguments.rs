@@ -2,13 +2,14 @@ use daedalus::minecraft;
 
 use crate::features::minecraft::{MinecraftDomainError, TEMPORARY_REPLACE_CHAR};
 
-use super::parse_rules;
+use super::{parse_rules, RuleFeatures};
 
 pub fn parse_arguments<F>(
     arguments: &[minecraft::Argument],
     parsed_arguments: &mut Vec<String>,
     parse_function: F,
     java_arch: &str,
+    features: &RuleFeatures,
 ) -> Result<(), MinecraftDomainError>
 where
     F: Fn(&str) -> Result<String, MinecraftDomainError>,
@@ -22,7 +23,7 @@ where
                 }
             }
             minecraft::Argument::Ruled { rules, value } => {
-                if parse_rules(rules, java_arch, true) {
+                if parse_rules(rules, java_arch, true, features) {
                     match value {
                         minecraft::ArgumentValue::Single(arg) => {
                             parsed_arguments
@@ -0,0 +1,48 @@
+use daedalus::minecraft;
+
+use crate::features::minecraft::QuickPlayTarget;
+
+use super::{parse_server_address, DEFAULT_SERVER_PORT};
+
+/// Checks if game arguments of the version have a quick play rule for the target
+pub fn supports_quick_play(arguments: &[minecraft::Argument], target: &QuickPlayTarget) -> bool {
+    arguments
+        .iter()
+        .filter_map(|argument| match argument {
+            minecraft::Argument::Ruled { rules, .. } => Some(rules),
+            minecraft::Argument::Normal(_) => None,
+        })
+        .flatten()
+        .filter_map(|rule| rule.features.as_ref())
+        .any(|features| {
+            let flag = match target {
+                QuickPlayTarget::Singleplayer { .. } => features.is_quick_play_singleplayer,
+                QuickPlayTarget::Multiplayer { .. } => features.is_quick_play_multiplayer,
+                QuickPlayTarget::Realms { .. } => features.is_quick_play_realms,
+            };
+
+            flag == Some(true)
+        })
+}
+
+/// Arguments for versions without quick play, only joining a server is possible there
+pub fn get_legacy_quick_play_arguments(target: &QuickPlayTarget) -> Vec<String> {
+    match target {
+        QuickPlayTarget::Multiplayer { address } => match parse_server_address(address) {
+            Some((host, port)) => vec![
+                "--server".to_owned(),
+                host,
+                "--port".to_owned(),
+                port.unwrap_or(DEFAULT_SERVER_PORT).to_string(),
+            ],
+            None => {
+                tracing::warn!("Skipping quick play to invalid server address \"{address}\"");
+                Vec::new()
+            }
+        },
+        QuickPlayTarget::Singleplayer { .. } | QuickPlayTarget::Realms { .. } => {
+            tracing::warn!("Quick play to {target:?} is not supported by this version");
+            Vec::new()
+        }
+    }
+}
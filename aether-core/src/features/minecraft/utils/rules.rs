@@ -1,10 +1,64 @@
 use daedalus::minecraft;
 use regex::Regex;
 
-pub fn parse_rules(rules: &[minecraft::Rule], java_version: &str, minecraft_updated: bool) -> bool {
+use crate::features::minecraft::QuickPlayTarget;
+
+/// Launcher features checked by `features` rules of the version manifest
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuleFeatures {
+    pub is_demo_user: bool,
+    pub has_custom_resolution: bool,
+    pub has_quick_plays_support: bool,
+    pub is_quick_play_singleplayer: bool,
+    pub is_quick_play_multiplayer: bool,
+    pub is_quick_play_realms: bool,
+}
+
+impl RuleFeatures {
+    /// Features of a game launch, quick play is enabled only for the given target
+    pub fn for_launch(quick_play: Option<&QuickPlayTarget>) -> Self {
+        Self {
+            has_custom_resolution: true,
+            is_quick_play_singleplayer: matches!(
+                quick_play,
+                Some(QuickPlayTarget::Singleplayer { .. })
+            ),
+            is_quick_play_multiplayer: matches!(
+                quick_play,
+                Some(QuickPlayTarget::Multiplayer { .. })
+            ),
+            is_quick_play_realms: matches!(quick_play, Some(QuickPlayTarget::Realms { .. })),
+            ..Default::default()
+        }
+    }
+
+    fn matches(&self, rule: &minecraft::FeatureRule) -> bool {
+        let is_match = |rule: Option<bool>, value: bool| rule.is_none_or(|rule| rule == value);
+
+        is_match(rule.is_demo_user, self.is_demo_user)
+            && is_match(rule.has_custom_resolution, self.has_custom_resolution)
+            && is_match(rule.has_quick_plays_support, self.has_quick_plays_support)
+            && is_match(
+                rule.is_quick_play_singleplayer,
+                self.is_quick_play_singleplayer,
+            )
+            && is_match(
+                rule.is_quick_play_multiplayer,
+                self.is_quick_play_multiplayer,
+            )
+            && is_match(rule.is_quick_play_realms, self.is_quick_play_realms)
+    }
+}
+
+pub fn parse_rules(
+    rules: &[minecraft::Rule],
+    java_version: &str,
+    minecraft_updated: bool,
+    features: &RuleFeatures,
+) -> bool {
     let mut parse_results = rules
         .iter()
-        .map(|rule| parse_rule(rule, java_version, minecraft_updated))
+        .map(|rule| parse_rule(rule, java_version, minecraft_updated, features))
         .collect::<Vec<Option<bool>>>();
 
     if rules
@@ -17,7 +71,12 @@ pub fn parse_rules(rules: &[minecraft::Rule], java_version: &str, minecraft_upda
     !(parse_results.iter().any(|x| x == &Some(false)) || parse_results.iter().all(|x| x.is_none()))
 }
 
-fn parse_rule(rule: &minecraft::Rule, java_version: &str, minecraft_updated: bool) -> Option<bool> {
+fn parse_rule(
+    rule: &minecraft::Rule,
+    java_version: &str,
+    minecraft_updated: bool,
+    features: &RuleFeatures,
+) -> Option<bool> {
     use minecraft::{Rule, RuleAction};
 
     let res = match rule {
@@ -25,16 +84,9 @@ fn parse_rule(rule: &minecraft::Rule, java_version: &str, minecraft_updated: boo
             os: Some(ref os), ..
         } => parse_os_rule(os, java_version, minecraft_updated),
         Rule {
-            features: Some(ref features),
+            features: Some(ref feature_rule),
             ..
-        } => {
-            !features.is_demo_user.unwrap_or(true)
-                || features.has_custom_resolution.unwrap_or(false)
-                || !features.has_quick_plays_support.unwrap_or(true)
-                || !features.is_quick_play_multiplayer.unwrap_or(true)
-                || !features.is_quick_play_realms.unwrap_or(true)
-                || !features.is_quick_play_singleplayer.unwrap_or(true)
-        }
+        } => features.matches(feature_rule),
         _ => return Some(true),
     };
 
@@ -0,0 +1,26 @@
+pub const DEFAULT_SERVER_PORT: u16 = 25565;
+
+/// Splits `host[:port]`, IPv6 hosts need brackets to carry a port
+pub fn parse_server_address(address: &str) -> Option<(String, Option<u16>)> {
+    let address = address.trim();
+
+    if let Some(rest) = address.strip_prefix('[') {
+        let (host, rest) = rest.split_once(']')?;
+        let port = match rest.strip_prefix(':') {
+            Some(port) => Some(port.parse().ok()?),
+            None if rest.is_empty() => None,
+            None => return None,
+        };
+
+        return Some((host.to_owned(), port));
+    }
+
+    match address.rsplit_once(':') {
+        // More than one colon is a bare IPv6 address
+        Some((host, _)) if host.contains(':') => Some((address.to_owned(), None)),
+        Some((host, port)) if !host.is_empty() => Some((host.to_owned(), Some(port.parse().ok()?))),
+        Some(_) => None,
+        None if !address.is_empty() => Some((address.to_owned(), None)),
+        None => None,
+    }
+}
@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+
+use crate::features::{minecraft::MinecraftDomainError, settings::LocationInfo};
+
+/// Relative to the libraries dir, shared by all versions
+pub const AUTHLIB_INJECTOR_PATH: &str = "moe/yushi/authlib-injector/authlib-injector.jar";
+
+/// Paths in the metadata dir needed to launch the version, listed folders are needed as a whole
+pub fn get_version_files(
+    location_info: &LocationInfo,
+    version_info: &daedalus::minecraft::VersionInfo,
+    version_jar: &str,
+    assets_index: &daedalus::minecraft::AssetsIndex,
+) -> Result<Vec<PathBuf>, MinecraftDomainError> {
+    let mut files = vec![
+        location_info.version_dir(version_jar),
+        location_info.version_dir(&version_info.id),
+        location_info.version_natives_dir(version_jar),
+        location_info.version_natives_dir(&version_info.id),
+        location_info
+            .assets_index_dir()
+            .join(format!("{}.json", version_info.asset_index.id)),
+    ];
+
+    // Legacy natives are stored as separate artifacts, one per classifier
+    let classifier_artifacts: Vec<String> = version_info
+        .libraries
+        .iter()
+        .filter_map(|library| {
+            let classifiers = library.downloads.as_ref()?.classifiers.as_ref()?;
+            Some((library, classifiers))
+        })
+        .flat_map(|(library, classifiers)| {
+            classifiers
+                .keys()
+                .map(move |classifier| format!("{}:{}", library.name, classifier))
+        })
+        .collect();
+
+    // Forge processors reference libraries as [group:artifact:version]
+    let mut artifacts: Vec<&str> = version_info
+        .libraries
+        .iter()
+        .map(|library| library.name.as_str())
+        .chain(classifier_artifacts.iter().map(String::as_str))
+        .collect();
+
+    for processor in version_info.processors.iter().flatten() {
+        artifacts.push(&processor.jar);
+        artifacts.extend(processor.classpath.iter().map(String::as_str));
+        artifacts.extend(
+            processor
+                .args
+                .iter()
+                .filter_map(|arg| get_artifact_reference(arg)),
+        );
+    }
+
+    for entry in version_info.data.iter().flat_map(|data| data.values()) {
+        artifacts.extend(get_artifact_reference(&entry.client));
+        artifacts.extend(get_artifact_reference(&entry.server));
+    }
+
+    for artifact in artifacts {
+        files.push(
+            location_info
+                .libraries_dir()
+                .join(daedalus::get_path_from_artifact(artifact)?),
+        );
+    }
+
+    files.extend(
+        assets_index
+            .objects
+            .values()
+            .map(|asset| location_info.object_dir(&asset.hash)),
+    );
+
+    if version_info.assets == "legacy" {
+        files.push(location_info.legacy_assets_dir());
+    }
+
+    Ok(files)
+}
+
+fn get_artifact_reference(value: &str) -> Option<&str> {
+    value.strip_prefix('[')?.strip_suffix(']')
+}
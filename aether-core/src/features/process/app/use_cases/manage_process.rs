@@ -5,7 +5,7 @@ use uuid::Uuid;
 use crate::{
     features::{
         events::{EventEmitter, EventEmitterExt, ProcessEventType},
-        instance::InstanceStorage,
+        instance::{InstanceRunningGuard, InstanceStorage, PlaySessionStorage},
         process::{ProcessError, ProcessStorage},
         settings::LocationInfo,
     },
@@ -17,21 +17,30 @@ use super::{TrackProcessParams, TrackProcessUseCase};
 pub struct ManageProcessParams {
     pub process_uuid: Uuid,
     pub instance_id: String,
+    pub account_id: Option<Uuid>,
     pub post_exit_command: Option<String>,
+    pub running_guard: InstanceRunningGuard,
 }
 
-pub struct ManageProcessUseCase<E: EventEmitter, PS: ProcessStorage, IS: InstanceStorage> {
+pub struct ManageProcessUseCase<
+    E: EventEmitter,
+    PS: ProcessStorage,
+    IS: InstanceStorage,
+    PSS: PlaySessionStorage,
+> {
     event_emitter: Arc<E>,
     process_storage: Arc<PS>,
-    track_process_use_case: Arc<TrackProcessUseCase<PS, IS>>,
+    track_process_use_case: Arc<TrackProcessUseCase<PS, IS, PSS>>,
     location_info: Arc<LocationInfo>,
 }
 
-impl<E: EventEmitter, PS: ProcessStorage, IS: InstanceStorage> ManageProcessUseCase<E, PS, IS> {
+impl<E: EventEmitter, PS: ProcessStorage, IS: InstanceStorage, PSS: PlaySessionStorage>
+    ManageProcessUseCase<E, PS, IS, PSS>
+{
     pub fn new(
         event_emitter: Arc<E>,
         process_storage: Arc<PS>,
-        track_process_use_case: Arc<TrackProcessUseCase<PS, IS>>,
+        track_process_use_case: Arc<TrackProcessUseCase<PS, IS, PSS>>,
         location_info: Arc<LocationInfo>,
     ) -> Self {
         Self {
@@ -45,7 +54,9 @@ impl<E: EventEmitter, PS: ProcessStorage, IS: InstanceStorage> ManageProcessUseC
         let ManageProcessParams {
             process_uuid,
             instance_id,
+            account_id,
             post_exit_command,
+            running_guard,
         } = params;
 
         let mc_exit_status = self
@@ -53,11 +64,15 @@ impl<E: EventEmitter, PS: ProcessStorage, IS: InstanceStorage> ManageProcessUseC
             .execute(TrackProcessParams {
                 process_uuid,
                 instance_id: instance_id.clone(),
+                account_id,
             })
             .await;
 
         self.process_storage.remove(process_uuid).await?;
 
+        // Game files are free again, blocked instance operations can run
+        drop(running_guard);
+
         self.event_emitter
             .emit_process_safe(
                 instance_id.clone(),
@@ -1,11 +1,12 @@
 use std::sync::Arc;
 
 use tokio::process::Command;
+use uuid::Uuid;
 
 use crate::{
     features::{
         events::{EventEmitter, EventEmitterExt, ProcessEventType},
-        instance::InstanceStorage,
+        instance::{InstanceRunningGuard, InstanceStorage, PlaySessionStorage},
         process::{MinecraftProcessMetadata, ProcessError, ProcessStorage},
     },
     shared::IoError,
@@ -13,19 +14,28 @@ use crate::{
 
 use super::{ManageProcessParams, ManageProcessUseCase};
 
-pub struct StartProcessUseCase<E: EventEmitter, PS: ProcessStorage, IS: InstanceStorage> {
+pub struct StartProcessUseCase<
+    E: EventEmitter,
+    PS: ProcessStorage,
+    IS: InstanceStorage,
+    PSS: PlaySessionStorage,
+> {
     event_emitter: Arc<E>,
     process_storage: Arc<PS>,
-    manage_process_use_case: Arc<ManageProcessUseCase<E, PS, IS>>,
+    manage_process_use_case: Arc<ManageProcessUseCase<E, PS, IS, PSS>>,
 }
 
-impl<E: EventEmitter + 'static, PS: ProcessStorage + 'static, IS: InstanceStorage + 'static>
-    StartProcessUseCase<E, PS, IS>
+impl<
+        E: EventEmitter + 'static,
+        PS: ProcessStorage + 'static,
+        IS: InstanceStorage + 'static,
+        PSS: PlaySessionStorage + 'static,
+    > StartProcessUseCase<E, PS, IS, PSS>
 {
     pub fn new(
         event_emitter: Arc<E>,
         process_storage: Arc<PS>,
-        manage_process_use_case: Arc<ManageProcessUseCase<E, PS, IS>>,
+        manage_process_use_case: Arc<ManageProcessUseCase<E, PS, IS, PSS>>,
     ) -> Self {
         Self {
             event_emitter,
@@ -38,7 +48,9 @@ impl<E: EventEmitter + 'static, PS: ProcessStorage + 'static, IS: InstanceStorag
         &self,
         instance_id: String,
         mut command: Command,
+        account_id: Option<Uuid>,
         post_exit_command: Option<String>,
+        running_guard: InstanceRunningGuard,
     ) -> Result<MinecraftProcessMetadata, ProcessError> {
         let process = command.spawn().map_err(IoError::from)?;
         let metadata = MinecraftProcessMetadata::new(instance_id.clone());
@@ -56,7 +68,9 @@ impl<E: EventEmitter + 'static, PS: ProcessStorage + 'static, IS: InstanceStorag
                 .execute(ManageProcessParams {
                     process_uuid: process_uuid_clone,
                     instance_id: instance_id_clone,
+                    account_id,
                     post_exit_command,
+                    running_guard,
                 })
                 .await;
         });
@@ -4,7 +4,10 @@ use chrono::{DateTime, Duration, Utc};
 use uuid::Uuid;
 
 use crate::features::{
-    instance::{InstanceStorage, InstanceStorageExt},
+    instance::{
+        InstanceStorage, InstanceStorageExt, PlaySession, PlaySessionStorage,
+        RecordPlaySessionUseCase,
+    },
     process::ProcessStorage,
 };
 
@@ -14,18 +17,27 @@ const UPDATE_PLAYTIME_INTERVAL: Duration = Duration::seconds(60);
 pub struct TrackProcessParams {
     pub process_uuid: Uuid,
     pub instance_id: String,
+    pub account_id: Option<Uuid>,
 }
 
-pub struct TrackProcessUseCase<PS, IS> {
+pub struct TrackProcessUseCase<PS, IS, PSS> {
     process_storage: Arc<PS>,
     instance_storage: Arc<IS>,
+    record_play_session_use_case: Arc<RecordPlaySessionUseCase<IS, PSS>>,
 }
 
-impl<PS: ProcessStorage, IS: InstanceStorage> TrackProcessUseCase<PS, IS> {
-    pub fn new(process_storage: Arc<PS>, instance_storage: Arc<IS>) -> Self {
+impl<PS: ProcessStorage, IS: InstanceStorage, PSS: PlaySessionStorage>
+    TrackProcessUseCase<PS, IS, PSS>
+{
+    pub fn new(
+        process_storage: Arc<PS>,
+        instance_storage: Arc<IS>,
+        record_play_session_use_case: Arc<RecordPlaySessionUseCase<IS, PSS>>,
+    ) -> Self {
         Self {
             process_storage,
             instance_storage,
+            record_play_session_use_case,
         }
     }
 
@@ -37,6 +49,8 @@ impl<PS: ProcessStorage, IS: InstanceStorage> TrackProcessUseCase<PS, IS> {
                 self.instance_storage
                     .upsert_with(id, |instance| {
                         instance.time_played += elapsed_seconds.num_seconds() as u64;
+                        // Recomputed over the window when the session is recorded
+                        instance.recent_time_played += elapsed_seconds.num_seconds() as u64;
                         Ok(())
                     })
                     .await
@@ -51,13 +65,44 @@ impl<PS: ProcessStorage, IS: InstanceStorage> TrackProcessUseCase<PS, IS> {
         }
     }
 
+    async fn record_session(
+        &self,
+        instance_id: &str,
+        start: DateTime<Utc>,
+        exit_code: Option<i32>,
+        account_id: Option<Uuid>,
+    ) {
+        let end = Utc::now();
+        let session = PlaySession {
+            start,
+            end,
+            duration: end.signed_duration_since(start).num_seconds().max(0) as u64,
+            exit_code,
+            account_id,
+        };
+
+        if let Err(e) = self
+            .record_play_session_use_case
+            .execute(instance_id.to_owned(), session)
+            .await
+        {
+            tracing::warn!(
+                "Failed to record play session for profile {}: {}",
+                instance_id,
+                e
+            );
+        }
+    }
+
     pub async fn execute(&self, params: TrackProcessParams) -> ExitStatus {
         let TrackProcessParams {
             process_uuid,
             instance_id,
+            account_id,
         } = params;
 
-        let mut last_updated_playtime = Utc::now();
+        let started = Utc::now();
+        let mut last_updated_playtime = started;
 
         loop {
             match self.process_storage.try_wait(process_uuid).await {
@@ -65,12 +110,16 @@ impl<PS: ProcessStorage, IS: InstanceStorage> TrackProcessUseCase<PS, IS> {
                     // Process exited successfully
                     self.update_playtime(&mut last_updated_playtime, &instance_id, true)
                         .await;
+                    self.record_session(&instance_id, started, exit_status.code(), account_id)
+                        .await;
                     return exit_status;
                 }
                 Ok(Some(None)) => {} // Still running
                 Ok(None) | Err(_) => {
                     self.update_playtime(&mut last_updated_playtime, &instance_id, true)
                         .await;
+                    self.record_session(&instance_id, started, None, account_id)
+                        .await;
                     return ExitStatus::default();
                 }
             }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::features::settings::{DefaultInstanceSettings, Hooks, MemorySettings, WindowSize};
+use crate::features::settings::{
+    DefaultInstanceSettings, GameOptions, Hooks, MemorySettings, WindowSize,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -10,6 +12,7 @@ pub struct EditDefaultInstanceSettings {
     pub memory: Option<MemorySettings>,
     pub game_resolution: Option<WindowSize>,
     pub hooks: Option<EditHooks>,
+    pub game_options: Option<GameOptions>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -64,6 +67,11 @@ impl EditDefaultInstanceSettings {
             is_changed = true;
         }
 
+        if let Some(game_options) = self.game_options {
+            settings.set_game_options(game_options);
+            is_changed = true;
+        }
+
         is_changed
     }
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::GameOptions;
+
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DefaultInstanceSettings {
@@ -10,6 +12,9 @@ pub struct DefaultInstanceSettings {
     game_resolution: WindowSize,
 
     hooks: Hooks,
+
+    #[serde(default)]
+    game_options: GameOptions,
 }
 
 impl DefaultInstanceSettings {
@@ -37,6 +42,10 @@ impl DefaultInstanceSettings {
         &mut self.hooks
     }
 
+    pub fn game_options(&self) -> &GameOptions {
+        &self.game_options
+    }
+
     pub fn set_launch_args(&mut self, launch_args: Vec<String>) {
         self.launch_args = launch_args;
     }
@@ -52,6 +61,10 @@ impl DefaultInstanceSettings {
     pub fn set_resolution(&mut self, resolution: WindowSize) {
         self.game_resolution = resolution;
     }
+
+    pub fn set_game_options(&mut self, game_options: GameOptions) {
+        self.game_options = game_options;
+    }
 }
 
 /// Memory usage settings for Java.
@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// When overrides are written to `options.txt`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GameOptionsApplyMode {
+    /// Overwrite the value before every launch
+    #[default]
+    Always,
+    /// Only set the value when `options.txt` doesn't have it yet,
+    /// so the player can change it later
+    FirstLaunch,
+}
+
+/// Overrides for the game `options.txt`, unset fields keep the player's value
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameOptions {
+    /// Language code, e.g. `en_us`
+    pub language: Option<String>,
+    pub fullscreen: Option<bool>,
+    /// `0` is auto
+    pub gui_scale: Option<u32>,
+    /// In chunks
+    pub render_distance: Option<u32>,
+    /// Bindings by name without the `key_` prefix, e.g. `key.jump` to `key.keyboard.space`
+    #[serde(default)]
+    pub key_bindings: BTreeMap<String, String>,
+    /// Any other raw `options.txt` entries
+    #[serde(default)]
+    pub other: BTreeMap<String, String>,
+    #[serde(default)]
+    pub apply_mode: GameOptionsApplyMode,
+}
+
+/// Single `options.txt` value resolved from the instance and default settings
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GameOptionOverride {
+    pub value: String,
+    pub apply_mode: GameOptionsApplyMode,
+}
+
+impl GameOptions {
+    /// Entries keyed by their `options.txt` name
+    pub fn to_overrides(&self) -> BTreeMap<String, GameOptionOverride> {
+        let mut values: BTreeMap<String, String> = self.other.clone();
+
+        values.extend(
+            self.key_bindings
+                .iter()
+                .map(|(name, key)| (format!("key_{name}"), key.clone())),
+        );
+
+        if let Some(language) = &self.language {
+            values.insert("lang".to_owned(), language.clone());
+        }
+        if let Some(fullscreen) = self.fullscreen {
+            values.insert("fullscreen".to_owned(), fullscreen.to_string());
+        }
+        if let Some(gui_scale) = self.gui_scale {
+            values.insert("guiScale".to_owned(), gui_scale.to_string());
+        }
+        if let Some(render_distance) = self.render_distance {
+            values.insert("renderDistance".to_owned(), render_distance.to_string());
+        }
+
+        values
+            .into_iter()
+            .map(|(key, value)| {
+                (
+                    key,
+                    GameOptionOverride {
+                        value,
+                        apply_mode: self.apply_mode,
+                    },
+                )
+            })
+            .collect()
+    }
+}
@@ -4,6 +4,8 @@ pub const METADATA_FOLDER_NAME: &str = ".minecraft";
 pub const CACHE_FOLDER_NAME: &str = "cache";
 pub const INSTANCES_FOLDER_NAME: &str = "instances";
 pub const PLUGINS_FOLDER_NAME: &str = "plugins";
+pub const SNAPSHOTS_FOLDER_NAME: &str = "snapshots";
+pub const SHARED_FOLDER_NAME: &str = "shared";
 
 #[derive(Debug)]
 pub struct LocationInfo {
@@ -144,6 +146,41 @@ impl LocationInfo {
         self.instance_pack_dir(id).join("content.toml")
     }
 
+    /// Get the directory for instance snapshots
+    #[inline]
+    pub fn snapshots_dir(&self) -> PathBuf {
+        self.config_dir.join(SNAPSHOTS_FOLDER_NAME)
+    }
+
+    /// Get the snapshots directory for a specific instance
+    #[inline]
+    pub fn instance_snapshots_dir(&self, id: &str) -> PathBuf {
+        self.snapshots_dir().join(id)
+    }
+
+    /// Get the directory for folders shared between instances
+    #[inline]
+    pub fn shared_dir(&self) -> PathBuf {
+        self.config_dir.join(SHARED_FOLDER_NAME)
+    }
+
+    /// Get the shared copy of an instance folder
+    #[inline]
+    pub fn shared_folder(&self, folder: &str) -> PathBuf {
+        self.shared_dir().join(folder)
+    }
+
+    /// Get the pack dir for content inside shared folders
+    #[inline]
+    pub fn shared_pack_dir(&self) -> PathBuf {
+        self.shared_dir().join(".metadata").join("pack")
+    }
+
+    #[inline]
+    pub fn shared_pack(&self) -> PathBuf {
+        self.shared_pack_dir().join("content.toml")
+    }
+
     /// Get the cache directory
     #[inline]
     pub fn cache_dir(&self) -> PathBuf {
@@ -186,6 +223,24 @@ impl LocationInfo {
             .join(plugin_id)
     }
 
+    /// Get the worlds directory for a specific instance
+    #[inline]
+    pub fn instance_saves_dir(&self, id: &str) -> PathBuf {
+        self.instance_dir(id).join("saves")
+    }
+
+    /// Get the multiplayer server list for a specific instance
+    #[inline]
+    pub fn instance_servers_file(&self, id: &str) -> PathBuf {
+        self.instance_dir(id).join("servers.dat")
+    }
+
+    /// Get the play session history for a specific instance
+    #[inline]
+    pub fn instance_sessions_file(&self, id: &str) -> PathBuf {
+        self.instance_metadata_dir(id).join("sessions.json")
+    }
+
     #[inline]
     pub fn crash_reports_dir(&self, id: &str) -> PathBuf {
         self.instance_dir(id).join("crash-reports")
@@ -1,9 +1,11 @@
 mod default_instance_settings;
 mod error;
+mod game_options;
 mod location_info;
 mod settings;
 
 pub use default_instance_settings::*;
 pub use error::*;
+pub use game_options::*;
 pub use location_info::*;
 pub use settings::*;
@@ -3,3 +3,6 @@ pub mod infra;
 
 pub use domain::*;
 pub use infra::*;
+
+#[cfg(test)]
+pub mod tests;
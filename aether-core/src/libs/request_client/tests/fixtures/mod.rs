@@ -0,0 +1,3 @@
+mod stub_http_server;
+
+pub use stub_http_server::*;
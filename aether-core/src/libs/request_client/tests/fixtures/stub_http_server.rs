@@ -0,0 +1,198 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::Semaphore,
+    task::JoinHandle,
+    time::Instant,
+};
+
+use crate::{
+    features::events::{
+        infra::InMemoryProgressBarStorage, tests::fixtures::MockEventEmitter, ProgressServiceImpl,
+    },
+    libs::request_client::ReqwestClient,
+    shared::FetchSemaphore,
+};
+
+pub type StubRequestClient =
+    ReqwestClient<ProgressServiceImpl<MockEventEmitter, InMemoryProgressBarStorage>>;
+
+#[derive(Debug, Clone)]
+pub struct StubRequest {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+    pub received: Instant,
+}
+
+#[derive(Debug, Clone)]
+struct StubResponse {
+    status: u16,
+    body: Vec<u8>,
+}
+
+#[derive(Default)]
+struct StubState {
+    routes: HashMap<String, VecDeque<StubResponse>>,
+    requests: Vec<StubRequest>,
+}
+
+/// Minimal HTTP/1.1 server answering every path from a queue of canned responses,
+/// the last response of a queue is repeated. Unknown paths get 404.
+pub struct StubHttpServer {
+    url: String,
+    state: Arc<Mutex<StubState>>,
+    handle: JoinHandle<()>,
+}
+
+impl StubHttpServer {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(StubState::default()));
+
+        let server_state = state.clone();
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(handle_connection(stream, server_state.clone()));
+            }
+        });
+
+        Self { url, state, handle }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn respond(&self, path: &str, status: u16, body: impl Into<Vec<u8>>) {
+        self.state
+            .lock()
+            .unwrap()
+            .routes
+            .entry(path.to_string())
+            .or_default()
+            .push_back(StubResponse {
+                status,
+                body: body.into(),
+            });
+    }
+
+    pub fn respond_json(&self, path: &str, body: serde_json::Value) {
+        self.respond(path, 200, body.to_string());
+    }
+
+    pub fn requests(&self, path: &str) -> Vec<StubRequest> {
+        self.state
+            .lock()
+            .unwrap()
+            .requests
+            .iter()
+            .filter(|request| request.path == path)
+            .cloned()
+            .collect()
+    }
+
+    pub fn client(&self) -> Arc<StubRequestClient> {
+        let event_emitter = Arc::new(MockEventEmitter::new());
+        let progress_service = Arc::new(ProgressServiceImpl::new(
+            event_emitter,
+            Arc::new(InMemoryProgressBarStorage::default()),
+        ));
+        let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
+
+        Arc::new(ReqwestClient::new(
+            progress_service,
+            Arc::new(client),
+            Arc::new(FetchSemaphore(Semaphore::new(10))),
+        ))
+    }
+}
+
+impl Drop for StubHttpServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, state: Arc<Mutex<StubState>>) {
+    let Some(request) = read_request(&mut stream).await else {
+        return;
+    };
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        let response = state.routes.get_mut(&request.path).and_then(|queue| {
+            if queue.len() > 1 {
+                queue.pop_front()
+            } else {
+                queue.front().cloned()
+            }
+        });
+        state.requests.push(request);
+        response
+    }
+    .unwrap_or(StubResponse {
+        status: 404,
+        body: Vec::new(),
+    });
+
+    let head = format!(
+        "HTTP/1.1 {} Stub\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        response.body.len()
+    );
+
+    let _ = stream.write_all(head.as_bytes()).await;
+    let _ = stream.write_all(&response.body).await;
+    let _ = stream.shutdown().await;
+}
+
+async fn read_request(stream: &mut TcpStream) -> Option<StubRequest> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0; 4096];
+
+    let header_end = loop {
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+
+        if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            break position + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut request_line = head.lines().next()?.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+
+    let content_length = head
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+
+    while buffer.len() < header_end + content_length {
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            break;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+
+    Some(StubRequest {
+        method,
+        path,
+        body: buffer[header_end..].to_vec(),
+        received: Instant::now(),
+    })
+}
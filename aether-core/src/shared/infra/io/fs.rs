@@ -27,6 +27,25 @@ pub async fn write_async(path: impl AsRef<Path>, data: impl AsRef<[u8]>) -> Resu
         .map_err(|err| IoError::with_path(err, path_ref))
 }
 
+/// Writes to a temporary file next to `path` and renames it over the old file.
+/// Hardlinks to the old file keep its content, writing in place would change them too
+pub async fn replace_async(path: impl AsRef<Path>, data: impl AsRef<[u8]>) -> Result<(), IoError> {
+    let path_ref = path.as_ref();
+
+    let mut temp_file_name = path_ref.file_name().unwrap_or_default().to_os_string();
+    temp_file_name.push(".tmp");
+    let temp_path = path_ref.with_file_name(temp_file_name);
+
+    write_async(&temp_path, data).await?;
+
+    if let Err(err) = rename(&temp_path, path_ref).await {
+        let _ = tokio::fs::remove_file(&temp_path).await;
+        return Err(err);
+    }
+
+    Ok(())
+}
+
 // JSON
 
 pub async fn read_json_async<T>(path: impl AsRef<Path>) -> Result<T, IoError>
@@ -140,6 +159,41 @@ pub async fn read_dir(path: impl AsRef<Path>) -> Result<ReadDir, IoError> {
         .map_err(|e| IoError::with_path(e, path_ref))
 }
 
+/// Links `link` to the `original` directory, a symlink on unix and an NTFS junction on windows,
+/// since directory symlinks there need admin rights or developer mode
+pub async fn create_dir_link(
+    original: impl AsRef<Path>,
+    link: impl AsRef<Path>,
+) -> Result<(), IoError> {
+    let link_ref = link.as_ref();
+
+    #[cfg(target_os = "windows")]
+    let result = {
+        let original = original.as_ref().to_path_buf();
+        let link = link_ref.to_path_buf();
+
+        tokio::task::spawn_blocking(move || junction::create(original, link))
+            .await
+            .unwrap_or_else(|err| Err(std::io::Error::other(err)))
+    };
+    #[cfg(not(target_os = "windows"))]
+    let result = tokio::fs::symlink(original.as_ref(), link_ref).await;
+
+    result.map_err(|e| IoError::with_path(e, link_ref))
+}
+
+/// Removes a directory link without touching the directory it points to
+pub async fn remove_dir_link(link: impl AsRef<Path>) -> Result<(), IoError> {
+    let link_ref = link.as_ref();
+
+    #[cfg(target_os = "windows")]
+    let result = tokio::fs::remove_dir(link_ref).await;
+    #[cfg(not(target_os = "windows"))]
+    let result = tokio::fs::remove_file(link_ref).await;
+
+    result.map_err(|e| IoError::with_path(e, link_ref))
+}
+
 pub fn copy_dir_all(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> Result<(), IoError> {
     std::fs::create_dir_all(&dst)?;
 
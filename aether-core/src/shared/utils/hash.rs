@@ -1,4 +1,6 @@
 use bytes::Bytes;
+use md5::Md5;
+use sha2::{Digest, Sha256, Sha512};
 use tokio::task::{spawn_blocking, JoinError};
 
 pub async fn sha1_async<T>(input: T) -> Result<String, JoinError>
@@ -8,3 +10,27 @@ where
     let bytes = input.into();
     spawn_blocking(move || sha1_smol::Sha1::from(bytes).hexdigest()).await
 }
+
+pub async fn sha256_async<T>(input: T) -> Result<String, JoinError>
+where
+    T: Into<Bytes> + Send,
+{
+    let bytes = input.into();
+    spawn_blocking(move || format!("{:x}", Sha256::digest(&bytes))).await
+}
+
+pub async fn sha512_async<T>(input: T) -> Result<String, JoinError>
+where
+    T: Into<Bytes> + Send,
+{
+    let bytes = input.into();
+    spawn_blocking(move || format!("{:x}", Sha512::digest(&bytes))).await
+}
+
+pub async fn md5_async<T>(input: T) -> Result<String, JoinError>
+where
+    T: Into<Bytes> + Send,
+{
+    let bytes = input.into();
+    spawn_blocking(move || format!("{:x}", Md5::digest(&bytes))).await
+}